@@ -5,15 +5,16 @@
 
 use hound::{WavSpec, WavWriter};
 use paraphase_core::{
-    ConvertError, ConvertOutput, Converter, ConverterDecl, Properties, PropertyPattern, Registry,
+    ConvertError, ConvertOutput, Converter, ConverterDecl, PortDecl, Properties, PropertiesExt,
+    PropertyPattern, Registry,
 };
 use std::io::Cursor;
 use symphonia::core::audio::AudioBufferRef;
 use symphonia::core::codecs::{CODEC_TYPE_NULL, DecoderOptions};
 use symphonia::core::formats::FormatOptions;
 use symphonia::core::io::MediaSourceStream;
-use symphonia::core::meta::MetadataOptions;
-use symphonia::core::probe::Hint;
+use symphonia::core::meta::{MetadataOptions, MetadataRevision, StandardTagKey};
+use symphonia::core::probe::{Hint, ProbeResult};
 
 /// Audio formats we can decode from
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -76,8 +77,8 @@ struct DecodedAudio {
     sample_rate: u32,
 }
 
-/// Decode audio from any supported format
-fn decode_audio(input: &[u8], hint: Option<&str>) -> Result<DecodedAudio, ConvertError> {
+/// Probe the container format of `input`.
+fn probe(input: &[u8], hint: Option<&str>) -> Result<ProbeResult, ConvertError> {
     let cursor = Cursor::new(input.to_vec());
     let mss = MediaSourceStream::new(Box::new(cursor), Default::default());
 
@@ -86,14 +87,29 @@ fn decode_audio(input: &[u8], hint: Option<&str>) -> Result<DecodedAudio, Conver
         format_hint.with_extension(ext);
     }
 
-    let probed = symphonia::default::get_probe()
+    symphonia::default::get_probe()
         .format(
             &format_hint,
             mss,
             &FormatOptions::default(),
             &MetadataOptions::default(),
         )
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to probe audio format: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to probe audio format", e))
+}
+
+/// Decode audio from any supported format
+fn decode_audio(input: &[u8], hint: Option<&str>) -> Result<DecodedAudio, ConvertError> {
+    decode_audio_with_tags(input, hint).map(|(audio, _)| audio)
+}
+
+/// Decode audio, also returning its tags (title, artist, ...).
+fn decode_audio_with_tags(
+    input: &[u8],
+    hint: Option<&str>,
+) -> Result<(DecodedAudio, AudioMetadata), ConvertError> {
+    let mut probed = probe(input, hint)?;
+    let mut tags = AudioMetadata::default();
+    read_tags(&mut probed, &mut tags);
 
     let mut format = probed.format;
 
@@ -101,7 +117,7 @@ fn decode_audio(input: &[u8], hint: Option<&str>) -> Result<DecodedAudio, Conver
         .tracks()
         .iter()
         .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
-        .ok_or_else(|| ConvertError::InvalidInput("No audio track found".into()))?;
+        .ok_or_else(|| ConvertError::invalid_input_msg("No audio track found"))?;
 
     let codec_params = &track.codec_params;
     let channels = codec_params.channels.map(|c| c.count() as u16).unwrap_or(2);
@@ -109,7 +125,7 @@ fn decode_audio(input: &[u8], hint: Option<&str>) -> Result<DecodedAudio, Conver
 
     let mut decoder = symphonia::default::get_codecs()
         .make(codec_params, &DecoderOptions::default())
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to create decoder: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to create decoder", e))?;
 
     let track_id = track.id;
     let mut samples: Vec<i16> = Vec::new();
@@ -123,10 +139,7 @@ fn decode_audio(input: &[u8], hint: Option<&str>) -> Result<DecodedAudio, Conver
                 break;
             }
             Err(e) => {
-                return Err(ConvertError::InvalidInput(format!(
-                    "Failed to read packet: {}",
-                    e
-                )));
+                return Err(ConvertError::invalid_input("Failed to read packet", e));
             }
         };
 
@@ -134,19 +147,160 @@ fn decode_audio(input: &[u8], hint: Option<&str>) -> Result<DecodedAudio, Conver
             continue;
         }
 
-        let decoded = decoder.decode(&packet).map_err(|e| {
-            ConvertError::InvalidInput(format!("Failed to decode audio packet: {}", e))
-        })?;
+        let decoded = decoder
+            .decode(&packet)
+            .map_err(|e| ConvertError::invalid_input("Failed to decode audio packet", e))?;
 
         // Convert to i16 samples
         convert_to_i16(&decoded, &mut samples);
     }
 
-    Ok(DecodedAudio {
+    let audio = DecodedAudio {
         samples,
         channels,
         sample_rate,
-    })
+    };
+    Ok((audio, tags))
+}
+
+/// Audio stream information read from container headers and tags.
+#[derive(Debug, Clone, Default)]
+struct AudioMetadata {
+    duration_secs: Option<f64>,
+    sample_rate: Option<u32>,
+    channels: Option<u16>,
+    bit_depth: Option<u32>,
+    codec: Option<String>,
+    bitrate_kbps: Option<f64>,
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    track: Option<i64>,
+    year: Option<i64>,
+    genre: Option<String>,
+}
+
+impl AudioMetadata {
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "duration_secs": self.duration_secs,
+            "sample_rate": self.sample_rate,
+            "channels": self.channels,
+            "bit_depth": self.bit_depth,
+            "codec": self.codec,
+            "bitrate_kbps": self.bitrate_kbps,
+            "title": self.title,
+            "artist": self.artist,
+            "album": self.album,
+            "track": self.track,
+            "year": self.year,
+            "genre": self.genre,
+        })
+    }
+
+    /// Add the tags as `audio_*` properties.
+    fn insert_tags(&self, props: &mut Properties) {
+        let strings = [
+            ("audio_title", &self.title),
+            ("audio_artist", &self.artist),
+            ("audio_album", &self.album),
+            ("audio_genre", &self.genre),
+        ];
+        for (key, value) in strings {
+            if let Some(value) = value {
+                props.insert(key.into(), value.as_str().into());
+            }
+        }
+        if let Some(track) = self.track {
+            props.insert("audio_track".into(), track.into());
+        }
+        if let Some(year) = self.year {
+            props.insert("audio_year".into(), year.into());
+        }
+    }
+
+    /// Fill tag fields from a metadata revision, keeping values already set.
+    fn apply_tags(&mut self, revision: &MetadataRevision) {
+        for tag in revision.tags() {
+            let value = tag.value.to_string();
+            match tag.std_key {
+                Some(StandardTagKey::TrackTitle) if self.title.is_none() => {
+                    self.title = Some(value);
+                }
+                Some(StandardTagKey::Artist) if self.artist.is_none() => {
+                    self.artist = Some(value);
+                }
+                Some(StandardTagKey::Album) if self.album.is_none() => {
+                    self.album = Some(value);
+                }
+                Some(StandardTagKey::TrackNumber) if self.track.is_none() => {
+                    // Track numbers may be "3" or "3/12"
+                    self.track = value.split('/').next().and_then(|t| t.trim().parse().ok());
+                }
+                Some(StandardTagKey::Genre) if self.genre.is_none() => {
+                    self.genre = Some(value);
+                }
+                Some(StandardTagKey::Date | StandardTagKey::ReleaseDate) if self.year.is_none() => {
+                    // Dates may be "2001", "2001-04-12", etc.
+                    self.year = value.get(..4).and_then(|y| y.parse().ok());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Fill `meta`'s tag fields from a probed file.
+fn read_tags(probed: &mut ProbeResult, meta: &mut AudioMetadata) {
+    // Tags may live in the container (Vorbis comments, RIFF INFO) or ahead
+    // of it (ID3v2), so check both.
+    if let Some(revision) = probed.format.metadata().current() {
+        meta.apply_tags(revision);
+    }
+    if let Some(metadata) = probed.metadata.get()
+        && let Some(revision) = metadata.current()
+    {
+        meta.apply_tags(revision);
+    }
+}
+
+/// Read stream information and tags without decoding any packets
+fn probe_metadata(input: &[u8], hint: Option<&str>) -> Result<AudioMetadata, ConvertError> {
+    let mut probed = probe(input, hint)?;
+
+    let track = probed
+        .format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| ConvertError::invalid_input_msg("No audio track found"))?;
+
+    let params = &track.codec_params;
+    let duration_secs = match (params.time_base, params.n_frames) {
+        (Some(tb), Some(frames)) => {
+            let time = tb.calc_time(frames);
+            Some(time.seconds as f64 + time.frac)
+        }
+        (None, Some(frames)) => params.sample_rate.map(|rate| frames as f64 / rate as f64),
+        _ => None,
+    };
+
+    let mut meta = AudioMetadata {
+        duration_secs,
+        sample_rate: params.sample_rate,
+        channels: params.channels.map(|c| c.count() as u16),
+        bit_depth: params.bits_per_sample,
+        codec: symphonia::default::get_codecs()
+            .get_codec(params.codec)
+            .map(|d| d.short_name.to_string()),
+        bitrate_kbps: duration_secs
+            .filter(|d| *d > 0.0)
+            .map(|d| input.len() as f64 * 8.0 / d / 1000.0),
+        ..Default::default()
+    };
+    read_tags(&mut probed, &mut meta);
+
+    Ok(meta)
 }
 
 /// Convert decoded audio buffer to i16 samples
@@ -239,25 +393,28 @@ fn encode_wav(audio: &DecodedAudio) -> Result<Vec<u8>, ConvertError> {
     let mut buffer = Vec::new();
     {
         let cursor = Cursor::new(&mut buffer);
-        let mut writer = WavWriter::new(cursor, spec).map_err(|e| {
-            ConvertError::InvalidInput(format!("Failed to create WAV writer: {}", e))
-        })?;
+        let mut writer = WavWriter::new(cursor, spec)
+            .map_err(|e| ConvertError::invalid_input("Failed to create WAV writer", e))?;
 
         for &sample in &audio.samples {
-            writer.write_sample(sample).map_err(|e| {
-                ConvertError::InvalidInput(format!("Failed to write WAV sample: {}", e))
-            })?;
+            writer
+                .write_sample(sample)
+                .map_err(|e| ConvertError::invalid_input("Failed to write WAV sample", e))?;
         }
 
         writer
             .finalize()
-            .map_err(|e| ConvertError::InvalidInput(format!("Failed to finalize WAV: {}", e)))?;
+            .map_err(|e| ConvertError::invalid_input("Failed to finalize WAV", e))?;
     }
 
     Ok(buffer)
 }
 
 /// Audio converter (any supported format -> WAV)
+///
+/// Tags in the source are kept as output properties: `audio_title`,
+/// `audio_artist`, `audio_album`, `audio_track`, `audio_year` and
+/// `audio_genre`, when present, plus `audio_duration_secs`.
 pub struct AudioToWavConverter {
     decl: ConverterDecl,
     from: AudioFormat,
@@ -284,7 +441,7 @@ impl Converter for AudioToWavConverter {
     }
 
     fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-        let audio = decode_audio(input, Some(self.from.as_str()))?;
+        let (audio, tags) = decode_audio_with_tags(input, Some(self.from.as_str()))?;
 
         let output = encode_wav(&audio)?;
 
@@ -293,6 +450,12 @@ impl Converter for AudioToWavConverter {
         out_props.insert("channels".into(), (audio.channels as i64).into());
         out_props.insert("sample_rate".into(), (audio.sample_rate as i64).into());
         out_props.insert("bits_per_sample".into(), 16i64.into());
+        tags.insert_tags(&mut out_props);
+        let frames = audio.samples.len() / audio.channels.max(1) as usize;
+        out_props.insert(
+            "audio_duration_secs".into(),
+            (frames as f64 / audio.sample_rate as f64).into(),
+        );
 
         Ok(ConvertOutput::Single(output, out_props))
     }
@@ -341,6 +504,369 @@ impl Converter for WavPassthroughConverter {
     }
 }
 
+/// Audio metadata extractor (any supported format -> JSON)
+///
+/// Reads container headers and tags only; no packets are decoded.
+pub struct AudioMetadataToJson {
+    decl: ConverterDecl,
+    from: AudioFormat,
+}
+
+impl AudioMetadataToJson {
+    pub fn new(from: AudioFormat) -> Self {
+        let name = format!("audio.{}-metadata-to-json", from.as_str());
+
+        let decl = ConverterDecl::simple(
+            &name,
+            PropertyPattern::new().eq("format", from.as_str()),
+            PropertyPattern::new().eq("format", "json"),
+        )
+        .description(format!(
+            "Extract {} duration and tags as JSON",
+            from.as_str().to_uppercase()
+        ));
+
+        Self { decl, from }
+    }
+}
+
+impl Converter for AudioMetadataToJson {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        let meta = probe_metadata(input, Some(self.from.as_str()))?;
+
+        let output = serde_json::to_vec_pretty(&meta.to_json())
+            .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+        let mut out_props = props.clone();
+        out_props.insert("format".into(), "json".into());
+
+        Ok(ConvertOutput::Single(output, out_props))
+    }
+}
+
+/// Silence trimmer (WAV -> WAV)
+///
+/// Removes leading and trailing silence, measured as RMS energy over
+/// 10 ms windows.
+///
+/// Options (via properties):
+/// - `silence_threshold_db`: windows quieter than this are silent (default: -40)
+/// - `silence_min_ms`: only trim silences at least this long (default: 500)
+/// - `trim_start`: trim leading silence (default: true)
+/// - `trim_end`: trim trailing silence (default: true)
+///
+/// Output properties `trimmed_start_ms` and `trimmed_end_ms` report how
+/// much was removed.
+pub struct WavTrimSilence {
+    decl: ConverterDecl,
+}
+
+impl WavTrimSilence {
+    pub fn new() -> Self {
+        let decl = ConverterDecl::simple(
+            "audio.wav-trim-silence",
+            PropertyPattern::new().eq("format", "wav"),
+            PropertyPattern::new().eq("format", "wav"),
+        )
+        .description("Trim leading and trailing silence from WAV");
+
+        Self { decl }
+    }
+}
+
+impl Default for WavTrimSilence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter for WavTrimSilence {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        let threshold_db = props
+            .get_typed::<Option<f64>>("silence_threshold_db")?
+            .unwrap_or(-40.0);
+        let min_ms = props
+            .get_typed::<Option<f64>>("silence_min_ms")?
+            .unwrap_or(500.0)
+            .max(0.0);
+        let trim_start = props
+            .get_typed::<Option<bool>>("trim_start")?
+            .unwrap_or(true);
+        let trim_end = props.get_typed::<Option<bool>>("trim_end")?.unwrap_or(true);
+
+        let audio = decode_audio(input, Some("wav"))?;
+        let channels = audio.channels.max(1) as usize;
+        let frames = audio.samples.len() / channels;
+
+        let (start, end) = find_non_silent(&audio, threshold_db);
+
+        // Only trim silences that reach the minimum duration
+        let min_frames = (min_ms / 1000.0 * audio.sample_rate as f64).round() as usize;
+        let start = if trim_start && start >= min_frames {
+            start
+        } else {
+            0
+        };
+        let end = if trim_end && frames - end >= min_frames {
+            end
+        } else {
+            frames
+        };
+
+        let frames_to_ms = |n: usize| (n as f64 * 1000.0 / audio.sample_rate as f64).round() as i64;
+        let trimmed_start_ms = frames_to_ms(start);
+        let trimmed_end_ms = frames_to_ms(frames - end);
+
+        let trimmed = DecodedAudio {
+            samples: audio.samples[start * channels..end * channels].to_vec(),
+            channels: audio.channels,
+            sample_rate: audio.sample_rate,
+        };
+        let output = encode_wav(&trimmed)?;
+
+        let mut out_props = props.clone();
+        for key in [
+            "silence_threshold_db",
+            "silence_min_ms",
+            "trim_start",
+            "trim_end",
+        ] {
+            out_props.shift_remove(key);
+        }
+        out_props.insert("format".into(), "wav".into());
+        out_props.insert("channels".into(), (trimmed.channels as i64).into());
+        out_props.insert("sample_rate".into(), (trimmed.sample_rate as i64).into());
+        out_props.insert("bits_per_sample".into(), 16i64.into());
+        out_props.insert("trimmed_start_ms".into(), trimmed_start_ms.into());
+        out_props.insert("trimmed_end_ms".into(), trimmed_end_ms.into());
+
+        Ok(ConvertOutput::Single(output, out_props))
+    }
+}
+
+/// WAV volume normalization
+///
+/// Scales all samples by one gain so the peak (or RMS) level reaches a
+/// target, clipping anything that would exceed the 16-bit range. Levels are
+/// in dBFS, relative to `i16::MAX`.
+///
+/// Options (via properties):
+/// - `target_dbfs`: level to reach (default: -3.0)
+/// - `normalize_rms`: measure RMS instead of peak level (default: false)
+/// - `max_gain_db`: never amplify by more than this, so near-silent input
+///   is not boosted to full scale (default: 20.0)
+///
+/// The output property `applied_gain_db` reports the gain used.
+pub struct WavNormalize {
+    decl: ConverterDecl,
+}
+
+impl WavNormalize {
+    pub fn new() -> Self {
+        let decl = ConverterDecl::simple(
+            "audio.wav-normalize",
+            PropertyPattern::new().eq("format", "wav"),
+            PropertyPattern::new().eq("format", "wav"),
+        )
+        .description("Normalize WAV volume to a target level");
+
+        Self { decl }
+    }
+}
+
+impl Default for WavNormalize {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter for WavNormalize {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        let target_dbfs = props
+            .get_typed::<Option<f64>>("target_dbfs")?
+            .unwrap_or(-3.0);
+        let normalize_rms = props
+            .get_typed::<Option<bool>>("normalize_rms")?
+            .unwrap_or(false);
+        let max_gain_db = props
+            .get_typed::<Option<f64>>("max_gain_db")?
+            .unwrap_or(20.0);
+
+        let mut audio = decode_audio(input, Some("wav"))?;
+
+        let level = if normalize_rms {
+            let energy: f64 = audio.samples.iter().map(|&s| (s as f64).powi(2)).sum();
+            (energy / audio.samples.len().max(1) as f64).sqrt()
+        } else {
+            audio
+                .samples
+                .iter()
+                .map(|&s| (s as f64).abs())
+                .fold(0.0, f64::max)
+        };
+
+        // Silence has no level to normalize
+        let gain_db = if level > 0.0 {
+            let level_dbfs = 20.0 * (level / i16::MAX as f64).log10();
+            (target_dbfs - level_dbfs).min(max_gain_db)
+        } else {
+            0.0
+        };
+
+        let gain = 10f64.powf(gain_db / 20.0);
+        for sample in &mut audio.samples {
+            *sample = (*sample as f64 * gain)
+                .round()
+                .clamp(i16::MIN as f64, i16::MAX as f64) as i16;
+        }
+        let output = encode_wav(&audio)?;
+
+        let mut out_props = props.clone();
+        for key in ["target_dbfs", "normalize_rms", "max_gain_db"] {
+            out_props.shift_remove(key);
+        }
+        out_props.insert("format".into(), "wav".into());
+        out_props.insert("channels".into(), (audio.channels as i64).into());
+        out_props.insert("sample_rate".into(), (audio.sample_rate as i64).into());
+        out_props.insert("bits_per_sample".into(), 16i64.into());
+        out_props.insert("applied_gain_db".into(), gain_db.into());
+
+        Ok(ConvertOutput::Single(output, out_props))
+    }
+}
+
+/// WAV concatenation (N WAVs -> 1 WAV)
+///
+/// Joins the sample data of all inputs in order. If every input has a
+/// `path` property, inputs are sorted by path first. All inputs must share
+/// the same sample rate and channel count.
+///
+/// The output `duration_secs` property is the total duration.
+pub struct WavConcat {
+    decl: ConverterDecl,
+}
+
+impl WavConcat {
+    pub fn new() -> Self {
+        let decl = ConverterDecl::new("audio.wav-concat")
+            .input(
+                "in",
+                PortDecl::list(PropertyPattern::new().eq("format", "wav")),
+            )
+            .output(
+                "out",
+                PortDecl::single(PropertyPattern::new().eq("format", "wav")),
+            )
+            .description("Concatenate WAV files");
+
+        Self { decl }
+    }
+}
+
+impl Default for WavConcat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter for WavConcat {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, _input: &[u8], _props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        Err(ConvertError::BatchNotSupported)
+    }
+
+    fn convert_batch(
+        &self,
+        inputs: &[(&[u8], &Properties)],
+    ) -> Result<ConvertOutput, ConvertError> {
+        let path =
+            |props: &Properties| props.get("path").and_then(|v| v.as_str()).map(String::from);
+
+        let mut ordered: Vec<_> = inputs.iter().collect();
+        if ordered.iter().all(|(_, props)| path(props).is_some()) {
+            ordered.sort_by_key(|(_, props)| path(props));
+        }
+
+        let mut joined: Option<DecodedAudio> = None;
+        for (index, (data, props)) in ordered.iter().enumerate() {
+            let audio = decode_audio(data, Some("wav"))?;
+            let Some(out) = joined.as_mut() else {
+                joined = Some(audio);
+                continue;
+            };
+
+            if audio.sample_rate != out.sample_rate || audio.channels != out.channels {
+                let name = path(props).unwrap_or_else(|| format!("input #{}", index + 1));
+                return Err(ConvertError::invalid_input_msg(format!(
+                    "Cannot concatenate {}: {} Hz / {} channel(s), expected {} Hz / {} channel(s). \
+                     Resample the inputs to a common format first.",
+                    name, audio.sample_rate, audio.channels, out.sample_rate, out.channels
+                )));
+            }
+            out.samples.extend_from_slice(&audio.samples);
+        }
+
+        let audio = joined.ok_or_else(|| ConvertError::invalid_input_msg("No WAV inputs"))?;
+        let output = encode_wav(&audio)?;
+
+        let channels = audio.channels.max(1) as usize;
+        let duration_secs = (audio.samples.len() / channels) as f64 / audio.sample_rate as f64;
+
+        let mut out_props = Properties::new();
+        out_props.insert("format".into(), "wav".into());
+        out_props.insert("channels".into(), (audio.channels as i64).into());
+        out_props.insert("sample_rate".into(), (audio.sample_rate as i64).into());
+        out_props.insert("bits_per_sample".into(), 16i64.into());
+        out_props.insert("duration_secs".into(), duration_secs.into());
+
+        Ok(ConvertOutput::Single(output, out_props))
+    }
+}
+
+/// Find the frame range `[start, end)` between the first and last window
+/// louder than `threshold_db` (dBFS).
+///
+/// Returns the full range if no window exceeds the threshold.
+fn find_non_silent(audio: &DecodedAudio, threshold_db: f64) -> (usize, usize) {
+    let channels = audio.channels.max(1) as usize;
+    let frames = audio.samples.len() / channels;
+    let window = (audio.sample_rate as usize / 100).max(1);
+
+    // Compare mean square energy against the threshold to avoid a sqrt per window
+    let threshold = (10f64.powf(threshold_db / 20.0) * i16::MAX as f64).powi(2);
+    let is_loud = |w: usize| {
+        let from = w * window * channels;
+        let to = ((w + 1) * window).min(frames) * channels;
+        let chunk = &audio.samples[from..to];
+        let energy: f64 = chunk.iter().map(|&s| (s as f64).powi(2)).sum();
+        energy / chunk.len() as f64 > threshold
+    };
+
+    let windows = frames.div_ceil(window);
+    match (0..windows).position(is_loud) {
+        Some(first) => {
+            let last = (0..windows).rposition(is_loud).unwrap_or(first);
+            (first * window, ((last + 1) * window).min(frames))
+        }
+        None => (0, frames),
+    }
+}
+
 /// Register all audio converters
 pub fn register_all(registry: &mut Registry) {
     // X -> WAV converters
@@ -359,6 +885,31 @@ pub fn register_all(registry: &mut Registry) {
     // WAV passthrough (always available with wav feature)
     #[cfg(feature = "wav")]
     registry.register(WavPassthroughConverter::new());
+
+    #[cfg(feature = "wav")]
+    registry.register(WavTrimSilence::new());
+
+    #[cfg(feature = "wav")]
+    registry.register(WavNormalize::new());
+
+    #[cfg(feature = "wav")]
+    registry.register(WavConcat::new());
+
+    // X -> JSON metadata
+    #[cfg(feature = "wav")]
+    registry.register(AudioMetadataToJson::new(AudioFormat::Wav));
+
+    #[cfg(feature = "flac")]
+    registry.register(AudioMetadataToJson::new(AudioFormat::Flac));
+
+    #[cfg(feature = "mp3")]
+    registry.register(AudioMetadataToJson::new(AudioFormat::Mp3));
+
+    #[cfg(feature = "ogg")]
+    registry.register(AudioMetadataToJson::new(AudioFormat::Ogg));
+
+    #[cfg(feature = "aac")]
+    registry.register(AudioMetadataToJson::new(AudioFormat::Aac));
 }
 
 #[cfg(test)]
@@ -374,4 +925,323 @@ mod tests {
         assert_eq!(AudioFormat::parse("ogg"), Some(AudioFormat::Ogg));
         assert_eq!(AudioFormat::parse("invalid"), None);
     }
+
+    #[test]
+    #[cfg(feature = "wav")]
+    fn test_wav_metadata_to_json() {
+        // 1.5 seconds of stereo 16-bit silence at 22050 Hz
+        let audio = DecodedAudio {
+            samples: vec![0; 22050 * 3],
+            channels: 2,
+            sample_rate: 22050,
+        };
+        let wav = encode_wav(&audio).unwrap();
+
+        let converter = AudioMetadataToJson::new(AudioFormat::Wav);
+        let props = Properties::new();
+        let (output, out_props) = match converter.convert(&wav, &props).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("json"));
+
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        let duration = json["duration_secs"].as_f64().unwrap();
+        assert!((duration - 1.5).abs() < 0.01, "duration was {}", duration);
+        assert_eq!(json["sample_rate"], 22050);
+        assert_eq!(json["channels"], 2);
+        assert_eq!(json["bit_depth"], 16);
+        assert!(json["title"].is_null());
+    }
+
+    /// A mono 16-bit 8 kHz FLAC with one verbatim frame of `samples`,
+    /// tagged with the given Vorbis comments.
+    #[cfg(feature = "flac")]
+    fn tagged_flac(samples: &[i16], comments: &[&str]) -> Vec<u8> {
+        fn crc8(data: &[u8]) -> u8 {
+            data.iter().fold(0u8, |mut crc, &byte| {
+                crc ^= byte;
+                for _ in 0..8 {
+                    crc = if crc & 0x80 != 0 {
+                        (crc << 1) ^ 0x07
+                    } else {
+                        crc << 1
+                    };
+                }
+                crc
+            })
+        }
+        fn crc16(data: &[u8]) -> u16 {
+            data.iter().fold(0u16, |mut crc, &byte| {
+                crc ^= (byte as u16) << 8;
+                for _ in 0..8 {
+                    crc = if crc & 0x8000 != 0 {
+                        (crc << 1) ^ 0x8005
+                    } else {
+                        crc << 1
+                    };
+                }
+                crc
+            })
+        }
+        fn block(out: &mut Vec<u8>, kind: u8, last: bool, body: &[u8]) {
+            out.push(kind | if last { 0x80 } else { 0 });
+            out.extend_from_slice(&(body.len() as u32).to_be_bytes()[1..]);
+            out.extend_from_slice(body);
+        }
+
+        let n = samples.len() as u64;
+        let mut flac = b"fLaC".to_vec();
+
+        let mut info = Vec::new();
+        info.extend_from_slice(&(n as u16).to_be_bytes()); // min block size
+        info.extend_from_slice(&(n as u16).to_be_bytes()); // max block size
+        info.extend_from_slice(&[0; 6]); // frame sizes unknown
+        // Sample rate (20 bits), channels - 1 (3), bits - 1 (5), total samples (36)
+        info.extend_from_slice(&((8000u64 << 44) | (15 << 36) | n).to_be_bytes());
+        info.extend_from_slice(&[0; 16]); // MD5 unknown
+        block(&mut flac, 0, false, &info);
+
+        let vendor = b"paraphase";
+        let mut tags = (vendor.len() as u32).to_le_bytes().to_vec();
+        tags.extend_from_slice(vendor);
+        tags.extend_from_slice(&(comments.len() as u32).to_le_bytes());
+        for comment in comments {
+            tags.extend_from_slice(&(comment.len() as u32).to_le_bytes());
+            tags.extend_from_slice(comment.as_bytes());
+        }
+        block(&mut flac, 4, true, &tags);
+
+        // Frame header: fixed blocksize, 16-bit block size follows, 8 kHz,
+        // mono, 16 bits per sample, frame 0
+        let mut frame = vec![0xff, 0xf8, 0x74, 0x08, 0x00];
+        frame.extend_from_slice(&(n as u16 - 1).to_be_bytes());
+        frame.push(crc8(&frame));
+        frame.push(0x02); // Verbatim subframe
+        for sample in samples {
+            frame.extend_from_slice(&sample.to_be_bytes());
+        }
+        let crc = crc16(&frame);
+        frame.extend_from_slice(&crc.to_be_bytes());
+        flac.extend_from_slice(&frame);
+        flac
+    }
+
+    #[test]
+    #[cfg(all(feature = "flac", feature = "wav"))]
+    fn test_audio_to_wav_keeps_tags() {
+        let samples: Vec<i16> = (0..800).map(|i| ((i % 50) * 100) as i16).collect();
+        let flac = tagged_flac(
+            &samples,
+            &[
+                "TITLE=Test Tone",
+                "ARTIST=Paraphase",
+                "ALBUM=Fixtures",
+                "TRACKNUMBER=3/12",
+                "DATE=2024-05-01",
+                "GENRE=Electronic",
+            ],
+        );
+
+        let converter = AudioToWavConverter::new(AudioFormat::Flac);
+        let props = Properties::new().with("format", "flac");
+        let (wav, out_props) = match converter.convert(&flac, &props).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+
+        let text = |key: &str| out_props.get(key).and_then(|v| v.as_str());
+        assert_eq!(text("audio_title"), Some("Test Tone"));
+        assert_eq!(text("audio_artist"), Some("Paraphase"));
+        assert_eq!(text("audio_album"), Some("Fixtures"));
+        assert_eq!(text("audio_genre"), Some("Electronic"));
+        assert_eq!(out_props.get("audio_track").unwrap().as_i64(), Some(3));
+        assert_eq!(out_props.get("audio_year").unwrap().as_i64(), Some(2024));
+        let duration = out_props.get("audio_duration_secs").unwrap().as_f64();
+        assert_eq!(duration, Some(0.1));
+
+        assert_eq!(decode_audio(&wav, Some("wav")).unwrap().samples, samples);
+
+        // Untagged sources add no tag properties
+        let flac = tagged_flac(&samples, &[]);
+        match converter.convert(&flac, &props).unwrap() {
+            ConvertOutput::Single(_, p) => {
+                assert!(!p.contains_key("audio_title"));
+                assert!(p.contains_key("audio_duration_secs"));
+            }
+            _ => panic!("Expected single output"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "wav")]
+    fn test_wav_trim_silence() {
+        // 1s silence + 1s 440 Hz tone + 1s silence, mono 8 kHz
+        let rate = 8000;
+        let mut samples = vec![0i16; rate];
+        samples.extend((0..rate).map(|i| {
+            let t = i as f64 / rate as f64;
+            ((2.0 * std::f64::consts::PI * 440.0 * t).sin() * 16000.0) as i16
+        }));
+        samples.extend(vec![0i16; rate]);
+        let wav = encode_wav(&DecodedAudio {
+            samples,
+            channels: 1,
+            sample_rate: rate as u32,
+        })
+        .unwrap();
+
+        let converter = WavTrimSilence::new();
+        let props = Properties::new().with("format", "wav");
+        let (output, out_props) = match converter.convert(&wav, &props).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+
+        let trimmed = decode_audio(&output, Some("wav")).unwrap();
+        let duration_ms = trimmed.samples.len() as f64 * 1000.0 / rate as f64;
+        assert!(
+            (duration_ms - 1000.0).abs() <= 50.0,
+            "duration was {} ms",
+            duration_ms
+        );
+
+        let start_ms = out_props.get("trimmed_start_ms").unwrap().as_i64().unwrap();
+        let end_ms = out_props.get("trimmed_end_ms").unwrap().as_i64().unwrap();
+        assert!(
+            (start_ms - 1000).abs() <= 50,
+            "trimmed {} ms at start",
+            start_ms
+        );
+        assert!((end_ms - 1000).abs() <= 50, "trimmed {} ms at end", end_ms);
+
+        // Silences shorter than silence_min_ms are kept
+        let props = Properties::new()
+            .with("format", "wav")
+            .with("silence_min_ms", 2000i64)
+            .with("trim_end", false);
+        let out_props = match converter.convert(&wav, &props).unwrap() {
+            ConvertOutput::Single(_, props) => props,
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(out_props.get("trimmed_start_ms").unwrap().as_i64(), Some(0));
+        assert_eq!(out_props.get("trimmed_end_ms").unwrap().as_i64(), Some(0));
+    }
+
+    #[test]
+    #[cfg(feature = "wav")]
+    fn test_wav_normalize() {
+        // 1s 440 Hz sine peaking at -12 dBFS, mono 8 kHz
+        let rate = 8000;
+        let amplitude = 10f64.powf(-12.0 / 20.0) * i16::MAX as f64;
+        let samples = (0..rate)
+            .map(|i| {
+                let t = i as f64 / rate as f64;
+                ((2.0 * std::f64::consts::PI * 440.0 * t).sin() * amplitude).round() as i16
+            })
+            .collect();
+        let wav = encode_wav(&DecodedAudio {
+            samples,
+            channels: 1,
+            sample_rate: rate as u32,
+        })
+        .unwrap();
+        let peak = |data: &[u8]| {
+            let audio = decode_audio(data, Some("wav")).unwrap();
+            audio
+                .samples
+                .iter()
+                .map(|s| s.unsigned_abs())
+                .max()
+                .unwrap() as f64
+        };
+
+        let converter = WavNormalize::new();
+        let props = Properties::new().with("format", "wav");
+        let (output, out_props) = match converter.convert(&wav, &props).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+        let expected = 10f64.powf(-3.0 / 20.0) * i16::MAX as f64;
+        assert!(
+            (peak(&output) - expected).abs() <= 3.0,
+            "peak was {}, expected {}",
+            peak(&output),
+            expected
+        );
+        let gain = out_props.get("applied_gain_db").unwrap().as_f64().unwrap();
+        assert!((gain - 9.0).abs() < 0.01, "gain was {} dB", gain);
+
+        // Gain is capped by max_gain_db
+        let props = Properties::new()
+            .with("format", "wav")
+            .with("max_gain_db", 6.0);
+        let out_props = match converter.convert(&wav, &props).unwrap() {
+            ConvertOutput::Single(_, props) => props,
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(
+            out_props.get("applied_gain_db").unwrap().as_f64(),
+            Some(6.0)
+        );
+        assert!(!out_props.contains_key("max_gain_db"));
+
+        // A sine's RMS is 3 dB below its peak
+        let props = Properties::new()
+            .with("format", "wav")
+            .with("normalize_rms", true)
+            .with("target_dbfs", -6.0);
+        let output = match converter.convert(&wav, &props).unwrap() {
+            ConvertOutput::Single(data, _) => data,
+            _ => panic!("Expected single output"),
+        };
+        let expected = 10f64.powf(-3.0 / 20.0) * i16::MAX as f64;
+        assert!((peak(&output) - expected).abs() <= 40.0);
+    }
+
+    #[test]
+    #[cfg(feature = "wav")]
+    fn test_wav_concat() {
+        let rate = 8000u32;
+        let wav = |value: i16, channels: u16, rate: u32| {
+            encode_wav(&DecodedAudio {
+                samples: vec![value; rate as usize * channels as usize],
+                channels,
+                sample_rate: rate,
+            })
+            .unwrap()
+        };
+        let first = wav(1000, 1, rate);
+        let second = wav(-1000, 1, rate);
+
+        // Sorted by path, so "a.wav" comes first despite input order
+        let props_a = Properties::new()
+            .with("format", "wav")
+            .with("path", "a.wav");
+        let props_b = Properties::new()
+            .with("format", "wav")
+            .with("path", "b.wav");
+        let inputs: Vec<(&[u8], &Properties)> = vec![(&second, &props_b), (&first, &props_a)];
+
+        let (output, out_props) = match WavConcat::new().convert_batch(&inputs).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+
+        let joined = decode_audio(&output, Some("wav")).unwrap();
+        assert_eq!(joined.samples.len(), 2 * rate as usize);
+        assert_eq!(joined.samples[0], 1000);
+        assert_eq!(joined.samples[rate as usize], -1000);
+        assert_eq!(out_props.get("duration_secs").unwrap().as_f64(), Some(2.0));
+
+        // Mismatched sample rates are rejected
+        let other = wav(0, 1, 16000);
+        let props = Properties::new().with("format", "wav");
+        let inputs: Vec<(&[u8], &Properties)> = vec![(&first, &props), (&other, &props)];
+        assert!(matches!(
+            WavConcat::new().convert_batch(&inputs),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+    }
 }
@@ -0,0 +1,172 @@
+//! Output checks for `convert --check` and `--check-schema`.
+//!
+//! Checks run on the final output before it is written, so a failed check
+//! leaves no partial file behind.
+
+use crate::errors;
+use anyhow::{Context, Result, bail};
+use jsonschema::Validator;
+use paraphase_core::{
+    Cardinality, ExecutionContext, Executor, Planner, Properties, PropertiesExt, PropertyPattern,
+    Registry, SimpleExecutor,
+};
+use serde_json::Value;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Formats identified by their magic bytes rather than by parsing.
+const MAGIC_FORMATS: &[&str] = &[
+    "png", "jpg", "webp", "gif", "bmp", "ico", "tiff", "avif", "exr", "hdr", "wav", "flac", "mp3",
+    "ogg", "aac", "mp4", "webm", "mkv", "avi", "mov", "pdf",
+];
+
+/// Check that `data` is valid `expected` output.
+///
+/// JSON is parsed directly and binary media formats are identified by
+/// their magic bytes. Any other format must convert to JSON through the
+/// registry, which deserializes it.
+pub fn check_output(registry: &Arc<Registry>, data: &[u8], expected: &str) -> Result<()> {
+    if expected == "json" {
+        serde_json::from_slice::<Value>(data).context("Check failed: output is not valid JSON")?;
+        return Ok(());
+    }
+
+    if MAGIC_FORMATS.contains(&expected) {
+        return match crate::detect_format_from_magic(data) {
+            Some(detected) if detected == expected => Ok(()),
+            Some(detected) => bail!(
+                "Check failed: expected {} output, but it looks like {}",
+                expected,
+                detected
+            ),
+            None => bail!("Check failed: output is not recognizable {} data", expected),
+        };
+    }
+
+    let props = Properties::new().with("format", expected);
+    let plan = Planner::new(registry)
+        .plan(
+            &props,
+            &PropertyPattern::new().eq("format", "json"),
+            Cardinality::One,
+            Cardinality::One,
+        )
+        .with_context(|| format!("--check does not support '{}'", expected))?;
+
+    let ctx = ExecutionContext::new(Arc::clone(registry));
+    SimpleExecutor::new()
+        .execute(&ctx, &plan, data.to_vec(), props)
+        .map_err(|e| {
+            anyhow::anyhow!(
+                "Check failed: output is not valid {}: {}",
+                expected,
+                errors::describe(&e)
+            )
+        })?;
+    Ok(())
+}
+
+/// Read and compile a JSON Schema file for `--check-schema`.
+///
+/// Local `$ref`s are resolved; references to other files or URLs are not
+/// fetched.
+pub fn load_schema(path: &Path) -> Result<Arc<Validator>> {
+    let data =
+        std::fs::read(path).with_context(|| format!("Failed to read schema {}", path.display()))?;
+    let schema: Value = serde_json::from_slice(&data)
+        .with_context(|| format!("Schema {} is not valid JSON", path.display()))?;
+    let validator = jsonschema::validator_for(&schema).map_err(|e| {
+        anyhow::anyhow!(
+            "Schema {} is not a valid JSON Schema: {}",
+            path.display(),
+            e
+        )
+    })?;
+    Ok(Arc::new(validator))
+}
+
+/// Check JSON output against a compiled schema.
+pub fn check_schema(data: &[u8], validator: &Validator) -> Result<()> {
+    let value: Value =
+        serde_json::from_slice(data).context("--check-schema requires JSON output")?;
+
+    let errors: Vec<String> = validator
+        .iter_errors(&value)
+        .map(|e| {
+            let path = e.instance_path().to_string();
+            format!("{}: {}", if path.is_empty() { "/" } else { &path }, e)
+        })
+        .collect();
+    if !errors.is_empty() {
+        bail!(
+            "Check failed: output does not match schema\n  {}",
+            errors.join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_output() {
+        let registry = Arc::new(Registry::new());
+
+        assert!(check_output(&registry, br#"{"name": "demo"}"#, "json").is_ok());
+        let err = check_output(&registry, br#"{"name": "demo""#, "json").unwrap_err();
+        assert!(err.to_string().contains("not valid JSON"));
+
+        let err = check_output(&registry, b"\0\x01\x02\x03", "png").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Check failed: output is not recognizable png data"
+        );
+
+        // No converter reads this format back
+        let err = check_output(&registry, b"data", "xyz").unwrap_err();
+        assert_eq!(err.to_string(), "--check does not support 'xyz'");
+    }
+
+    #[test]
+    fn test_check_schema() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "required": ["name", "port"],
+            "properties": {
+                "name": {"type": "string", "minLength": 1},
+                "port": {"type": "integer", "minimum": 1, "maximum": 65535},
+                "tags": {"type": "array", "items": {"enum": ["a", "b"]}}
+            },
+            "additionalProperties": false
+        });
+        let validator = jsonschema::validator_for(&schema).unwrap();
+
+        assert!(check_schema(br#"{"name": "web", "port": 80, "tags": ["a"]}"#, &validator).is_ok());
+
+        let err = check_schema(br#"{"port": 70000, "tags": ["c"], "x": 1}"#, &validator)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("/: \"name\" is a required property"));
+        assert!(err.contains("/port: 70000 is greater than the maximum of 65535"));
+        assert!(err.contains("/tags/0: \"c\" is not one of"));
+        assert!(err.contains("/: Additional properties are not allowed ('x' was unexpected)"));
+
+        assert!(check_schema(b"name: web", &validator).is_err());
+
+        // $ref, anyOf and pattern
+        let schema = serde_json::json!({
+            "$defs": {"id": {"type": "string", "pattern": "^[a-z]+-[0-9]+$"}},
+            "type": "array",
+            "items": {"anyOf": [{"$ref": "#/$defs/id"}, {"type": "integer"}]}
+        });
+        let validator = jsonschema::validator_for(&schema).unwrap();
+        assert!(check_schema(br#"["web-1", 2]"#, &validator).is_ok());
+        let err = check_schema(br#"["web-1", "web"]"#, &validator)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("/1: "), "{err}");
+        assert!(!err.contains("/0: "), "{err}");
+    }
+}
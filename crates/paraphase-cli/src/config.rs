@@ -155,7 +155,7 @@ impl NumericValue {
 ///
 /// Numeric fields can be literals or expressions (when `dew` feature is enabled):
 /// ```toml
-/// [preset.smart-web]
+/// [presets.smart-web]
 /// max_width = "min(width, 1920)"
 /// quality = "if file_size > 5000000 then 70 else 85"
 /// ```
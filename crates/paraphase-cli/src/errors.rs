@@ -4,7 +4,7 @@
 #![allow(clippy::collapsible_match)]
 #![allow(clippy::manual_find)]
 
-use paraphase_core::Registry;
+use paraphase_core::{Registry, WorkflowError};
 use std::path::Path;
 
 /// Supported format categories for error messages.
@@ -16,6 +16,9 @@ const DATA_FORMATS: &[&str] = &[
     "csv",
     "ron",
     "json5",
+    "hcl",
+    "edn",
+    "kdl",
     "msgpack",
     "cbor",
     "bincode",
@@ -25,12 +28,13 @@ const DATA_FORMATS: &[&str] = &[
     "bencode",
     "pickle",
     "plist",
+    "plist-xml",
     "lexpr",
 ];
 
 const IMAGE_FORMATS: &[&str] = &[
     "png", "jpg", "webp", "gif", "bmp", "ico", "tiff", "tga", "pnm", "farbfeld", "qoi", "avif",
-    "exr", "hdr",
+    "exr", "hdr", "svg",
 ];
 
 const AUDIO_FORMATS: &[&str] = &["wav", "flac", "mp3", "ogg", "aac"];
@@ -167,6 +171,46 @@ pub fn file_read_error(path: &str, err: &std::io::Error) -> String {
     msg
 }
 
+/// Build an error message for a workflow that cannot be parsed or run.
+pub fn workflow_error(path: &str, err: &WorkflowError) -> String {
+    match err {
+        WorkflowError::ParseError {
+            format,
+            line,
+            source,
+        } => {
+            let mut msg = format!(
+                "Your workflow file '{}' contains invalid {}",
+                path,
+                format.to_uppercase()
+            );
+            if let Some(line) = line {
+                msg.push_str(&format!(" at line {}", line));
+            }
+            msg.push_str(&format!(": {}", source));
+            msg
+        }
+        WorkflowError::MissingField { field } => format!(
+            "Workflow '{}' has no {}. Add a '{}:' section to run it.",
+            path, field, field
+        ),
+        WorkflowError::InvalidStep { index, reason } => {
+            format!("Step {} of workflow '{}' {}", index + 1, path, reason)
+        }
+        WorkflowError::UnknownConverter { index, converter } => format!(
+            "Step {} of workflow '{}' references an unknown converter '{}'.\n\n\
+             Run 'paraphase list' to see available converters.",
+            index + 1,
+            path,
+            converter
+        ),
+        WorkflowError::IncompatibleSource { message } => {
+            format!("Workflow '{}' cannot run: {}", path, message)
+        }
+        other => format!("Workflow '{}': {}", path, other),
+    }
+}
+
 /// Check if a format is in our known list.
 fn is_known_format(format: &str) -> bool {
     let format = format.to_lowercase();
@@ -332,6 +376,8 @@ fn find_similar_files(path: &str) -> Option<Vec<String>> {
     Some(suggestions)
 }
 
+pub use paraphase_core::describe_error as describe;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -352,6 +398,29 @@ mod tests {
         assert_eq!(levenshtein("abc", "xyz"), 3);
     }
 
+    #[test]
+    fn test_workflow_error() {
+        use paraphase_core::Workflow;
+
+        let err = Workflow::from_bytes_format(b"source:\n  path: [a\n", "yaml").unwrap_err();
+        assert!(
+            workflow_error("flow.yaml", &err)
+                .starts_with("Your workflow file 'flow.yaml' contains invalid YAML at line ")
+        );
+
+        let err = Workflow::new()
+            .source_file("in.json")
+            .step("serde.nope")
+            .sink_file("out.json")
+            .validate(&Registry::new())
+            .unwrap_err();
+        let msg = workflow_error("flow.yaml", &err);
+        assert!(msg.starts_with(
+            "Step 1 of workflow 'flow.yaml' references an unknown converter 'serde.nope'"
+        ));
+        assert!(msg.ends_with("Run 'paraphase list' to see available converters."));
+    }
+
     #[test]
     fn test_is_known_format() {
         assert!(is_known_format("png"));
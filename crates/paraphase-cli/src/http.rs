@@ -0,0 +1,126 @@
+//! HTTP sources and sinks for workflows.
+//!
+//! Requests are blocking; a workflow fetches its input once and sends its
+//! output once, so there is nothing to overlap.
+
+use anyhow::{Context, Result};
+use indexmap::IndexMap;
+use reqwest::Method;
+use reqwest::blocking::{Client, RequestBuilder};
+
+/// Fetch `url`, returning the body and the format named by its `Content-Type`.
+pub fn fetch(
+    url: &str,
+    method: &str,
+    headers: &IndexMap<String, String>,
+) -> Result<(Vec<u8>, Option<String>)> {
+    let response = request(url, method, headers)?
+        .send()
+        .with_context(|| format!("Failed to fetch {}", url))?
+        .error_for_status()?;
+
+    let format = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(content_type_format);
+    let data = response.bytes().context("Failed to read response body")?;
+
+    Ok((data.to_vec(), format))
+}
+
+/// Send `data` to `url` as the request body.
+pub fn send(
+    url: &str,
+    method: &str,
+    headers: &IndexMap<String, String>,
+    data: Vec<u8>,
+) -> Result<()> {
+    request(url, method, headers)?
+        .body(data)
+        .send()
+        .with_context(|| format!("Failed to send output to {}", url))?
+        .error_for_status()?;
+    Ok(())
+}
+
+fn request(url: &str, method: &str, headers: &IndexMap<String, String>) -> Result<RequestBuilder> {
+    let method = Method::from_bytes(method.to_uppercase().as_bytes())
+        .with_context(|| format!("Invalid HTTP method: {}", method))?;
+    let mut request = Client::new().request(method, url);
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+    Ok(request)
+}
+
+/// Map a `Content-Type` header value to a format name.
+///
+/// Parameters (`; charset=utf-8`) are ignored, and structured syntax
+/// suffixes (`application/ld+json`) fall back to their base format.
+fn content_type_format(content_type: &str) -> Option<String> {
+    let mime = content_type.split(';').next()?.trim().to_lowercase();
+    crate::mime_to_format(&mime).or_else(|| match mime.rsplit_once('+')?.1 {
+        "json" => Some("json".into()),
+        "xml" => Some("xml".into()),
+        "yaml" => Some("yaml".into()),
+        "cbor" => Some("cbor".into()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type_format() {
+        assert_eq!(
+            content_type_format("application/json; charset=utf-8").as_deref(),
+            Some("json")
+        );
+        assert_eq!(
+            content_type_format("application/ld+json").as_deref(),
+            Some("json")
+        );
+        assert_eq!(content_type_format("Image/PNG").as_deref(), Some("png"));
+        assert_eq!(content_type_format("text/html"), None);
+    }
+
+    #[test]
+    fn test_fetch_and_send() {
+        let mut server = mockito::Server::new();
+        let get = server
+            .mock("GET", "/data")
+            .match_header("authorization", "Bearer token")
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"a": 1}"#)
+            .create();
+        let post = server
+            .mock("PUT", "/upload")
+            .match_body("a: 1\n")
+            .with_status(201)
+            .create();
+
+        let headers = IndexMap::from([("Authorization".to_string(), "Bearer token".to_string())]);
+        let (data, format) = fetch(&format!("{}/data", server.url()), "get", &headers).unwrap();
+        assert_eq!(data, br#"{"a": 1}"#);
+        assert_eq!(format.as_deref(), Some("json"));
+
+        let url = format!("{}/upload", server.url());
+        send(&url, "PUT", &IndexMap::new(), b"a: 1\n".to_vec()).unwrap();
+
+        // Error statuses fail the request
+        assert!(
+            fetch(
+                &format!("{}/missing", server.url()),
+                "GET",
+                &IndexMap::new()
+            )
+            .is_err()
+        );
+
+        get.assert();
+        post.assert();
+    }
+}
@@ -6,10 +6,13 @@
 #![allow(clippy::redundant_closure)]
 #![allow(clippy::unnecessary_map_or)]
 
+mod check;
 mod config;
 mod errors;
 #[cfg(feature = "dew")]
 mod expr;
+#[cfg(feature = "http")]
+mod http;
 
 use anyhow::{Context, Result, bail};
 use clap::{CommandFactory, Parser, Subcommand};
@@ -18,12 +21,13 @@ use config::{Config, Preset};
 use indexmap::IndexMap;
 use indicatif::{ProgressBar, ProgressStyle};
 use paraphase_core::{
-    BoundedExecutor, Cardinality, ConvertOutput, ExecutionContext, Executor, NamedInput,
-    OptimizeTarget, Plan, Planner, Properties, PropertiesExt, PropertyPattern, Registry,
-    SimpleExecutor, Sink, Source, Workflow,
+    BoundedExecutor, Cardinality, ConvertOutput, ConverterDecl, ExecutionContext, ExecutionStats,
+    Executor, NamedInput, OptimizeTarget, Planner, Properties, PropertiesExt, PropertyPattern,
+    Registry, SimpleExecutor, Sink, Source, Value, Workflow,
 };
-use std::io::{Cursor, Read, Write};
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::io::{Cursor, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// Parse --optimize argument.
@@ -39,6 +43,56 @@ fn parse_optimize_target(s: &str) -> Result<OptimizeTarget, String> {
     }
 }
 
+/// Parse `--from-props` / `--to-props` arguments.
+///
+/// Each argument is either `KEY=VALUE` or `@file.json`, a JSON object whose
+/// entries are merged in. Later arguments override earlier ones.
+fn parse_props_args(args: &[String]) -> Result<Properties> {
+    let mut props = Properties::new();
+    for arg in args {
+        if let Some(path) = arg.strip_prefix('@') {
+            let text = std::fs::read_to_string(path)
+                .map_err(|e| anyhow::anyhow!("{}", errors::file_read_error(path, &e)))?;
+            let json: serde_json::Value = serde_json::from_str(&text)
+                .with_context(|| format!("Invalid JSON in properties file {}", path))?;
+            props.extend(
+                Properties::from_json(&json).map_err(|e| anyhow::anyhow!("{}: {}", path, e))?,
+            );
+        } else {
+            let (key, value) = arg
+                .split_once('=')
+                .filter(|(key, _)| !key.is_empty())
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Expected KEY=VALUE or @file.json, got '{}'", arg)
+                })?;
+            props.insert(key.to_string(), parse_prop_value(value));
+        }
+    }
+    Ok(props)
+}
+
+/// Type a command-line property value: integers, floats and booleans keep
+/// their type, anything else is a string.
+fn parse_prop_value(s: &str) -> Value {
+    if let Ok(n) = s.parse::<i64>() {
+        n.into()
+    } else if let Some(f) = s.parse::<f64>().ok().filter(|f| f.is_finite()) {
+        f.into()
+    } else if let Ok(b) = s.parse::<bool>() {
+        b.into()
+    } else {
+        s.into()
+    }
+}
+
+/// Take the `format` entry out of `--from-props` / `--to-props`, so it can
+/// act like `--from` / `--to`.
+fn take_format(props: &mut Properties) -> Option<String> {
+    props
+        .shift_remove("format")
+        .and_then(|v| v.as_str().map(String::from))
+}
+
 /// Collect files from patterns, directories, and globs.
 ///
 /// When `recursive` is true, directories are walked recursively.
@@ -201,6 +255,39 @@ impl Verbosity {
         }
     }
 
+    /// Print the timing and sizes of each executed step.
+    fn step_stats(self, stats: &ExecutionStats) {
+        for (idx, step) in stats.per_step_stats.iter().enumerate() {
+            self.debug(&format!(
+                "step {} ({}): {:?}, {} -> {} bytes, ~{} bytes peak",
+                idx + 1,
+                step.converter_id,
+                step.duration,
+                step.input_bytes,
+                step.output_bytes,
+                step.memory_estimate
+            ));
+        }
+        if stats.per_step_stats.len() > 1 {
+            if let Some(step) = stats.slowest_step() {
+                self.debug(&format!("slowest step: {}", step.converter_id));
+            }
+            if let Some(step) = stats.largest_expansion() {
+                self.debug(&format!(
+                    "largest expansion: {} ({:.2}x)",
+                    step.converter_id,
+                    step.expansion()
+                ));
+            }
+        }
+    }
+
+    fn warn(self, msg: &str) {
+        if !matches!(self, Verbosity::Quiet) {
+            eprintln!("warning: {msg}");
+        }
+    }
+
     fn result(self, msg: &str) {
         if !matches!(self, Verbosity::Quiet) {
             println!("{msg}");
@@ -219,14 +306,45 @@ struct ConvertOptions {
     scale: Option<config::NumericValue>,
     aspect: Option<String>,
     gravity: String,
+    // Resampling filter and fit/fill/stretch mode for resizes
+    resize_filter: Option<String>,
+    resize_mode: Option<String>,
+    // Apply the EXIF Orientation tag to the pixels
+    exif_auto_rotate: bool,
     // Watermark options
     watermark: Option<PathBuf>,
     watermark_position: String,
     watermark_opacity: config::NumericValue,
     watermark_margin: config::NumericValue,
+    // Pixel format of the output (rgb, rgba, grayscale, ...)
+    color_mode: Option<String>,
+    // Palette quantization (PNG output)
+    quantize: Option<u32>,
     // Video options (reserved for future use)
     #[allow(dead_code)]
     quality: Option<String>,
+    // JSON output options
+    compact: bool,
+    sorted_keys: bool,
+    // Archive options
+    strip_prefix: Option<String>,
+    base_dir: Option<String>,
+    zip_method: Option<String>,
+    zip_level: Option<i64>,
+    stream: bool,
+    // Video frame extraction
+    extract_frame: bool,
+    frame_time: Option<f64>,
+    // Structured data filtering
+    filter: Option<String>,
+    // Output checks (--check, --check-schema)
+    check: Option<String>,
+    check_schema: Option<Arc<jsonschema::Validator>>,
+    // Write outputs directly instead of via a renamed temp file
+    no_atomic_write: bool,
+    // Extra properties from --from-props / --to-props
+    from_props: Properties,
+    to_props: Properties,
 }
 
 impl ConvertOptions {
@@ -270,6 +388,16 @@ impl ConvertOptions {
         self.watermark_margin.eval_u32(&vars).unwrap_or(10)
     }
 
+    /// Add serialization options to the properties used for planning.
+    fn apply_serialization(&self, props: &mut Properties) {
+        if self.compact {
+            props.insert("json_pretty".into(), false.into());
+        }
+        if self.sorted_keys {
+            props.insert("json_sorted_keys".into(), true.into());
+        }
+    }
+
     /// Check if any resize options are set.
     fn needs_resize(&self) -> bool {
         self.max_width.is_some() || self.max_height.is_some() || self.scale.is_some()
@@ -292,7 +420,7 @@ struct Cli {
     #[arg(short, long, global = true)]
     quiet: bool,
 
-    /// Path to config file (default: ~/.config/cambium/config.toml)
+    /// Path to config file (default: ~/.config/paraphase/config.toml)
     #[arg(long, global = true)]
     config: Option<PathBuf>,
 
@@ -303,7 +431,28 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// List available converters
-    List,
+    List {
+        /// Print the converter graph in Graphviz DOT format
+        #[arg(long)]
+        dot: bool,
+        /// Report cycles in the converter graph
+        #[arg(long, conflicts_with = "dot")]
+        check_cycles: bool,
+        /// Only show converters that accept this format
+        #[arg(long, conflicts_with_all = ["dot", "check_cycles"])]
+        from: Option<String>,
+        /// Only show converters that produce this format
+        #[arg(long, conflicts_with_all = ["dot", "check_cycles"])]
+        to: Option<String>,
+        /// List conversion paths between two formats, cheapest first
+        #[arg(
+            long,
+            num_args = 2,
+            value_names = ["FROM", "TO"],
+            conflicts_with_all = ["dot", "check_cycles", "from", "to"]
+        )]
+        capable_of: Option<Vec<String>>,
+    },
 
     /// Plan a conversion (show steps without executing)
     Plan {
@@ -320,6 +469,15 @@ enum Commands {
         /// Optimize path selection: quality (minimize loss), speed (fastest), size (smallest output)
         #[arg(long, value_parser = parse_optimize_target)]
         optimize: Option<OptimizeTarget>,
+        /// Maximum number of conversion steps to consider (default: 5)
+        #[arg(long, value_name = "N")]
+        max_depth: Option<usize>,
+        /// Print the plan as JSON (see schemas/plan.json)
+        #[arg(long)]
+        json: bool,
+        /// Print nothing; exit with 0 if a plan exists and 1 if not
+        #[arg(long)]
+        validate_only: bool,
     },
 
     /// Convert file(s)
@@ -333,6 +491,10 @@ enum Commands {
         /// Output directory for batch conversions
         #[arg(long)]
         output_dir: Option<PathBuf>,
+        /// Output file name template, e.g. "thumb_{width}x{height}_{stem}.jpg".
+        /// Variables: {stem}, {ext}, {width}, {height}, {input_dir}, {N}
+        #[arg(long)]
+        output_template: Option<String>,
         /// Recursively process directories
         #[arg(short = 'r', long)]
         recursive: bool,
@@ -348,12 +510,27 @@ enum Commands {
         /// Explicit target format (required for batch, optional for single)
         #[arg(long)]
         to: Option<String>,
+        /// Extra source properties as KEY=VALUE pairs or @file.json
+        /// (e.g. "--from-props sheet=Sheet1 headers=true")
+        #[arg(long, value_name = "KEY=VALUE", num_args = 1..)]
+        from_props: Vec<String>,
+        /// Extra target properties (converter options such as jpeg_quality
+        /// or bzip2_level) as KEY=VALUE pairs or @file.json
+        #[arg(long, value_name = "KEY=VALUE", num_args = 1..)]
+        to_props: Vec<String>,
         /// Apply a preset (web, thumbnail, social, avatar, print, lossless)
         #[arg(long)]
         preset: Option<String>,
         /// Optimize path selection: quality (minimize loss), speed (fastest), size (smallest output)
         #[arg(long, value_parser = parse_optimize_target)]
         optimize: Option<OptimizeTarget>,
+        /// Verify the output parses as FORMAT before writing it (exits with
+        /// an error and writes nothing if it does not)
+        #[arg(long, value_name = "FORMAT")]
+        check: Option<String>,
+        /// Verify JSON output against a JSON Schema file before writing it
+        #[arg(long, value_name = "SCHEMA_FILE")]
+        check_schema: Option<PathBuf>,
 
         // Image transform options
         /// Maximum width (fit within, preserves aspect ratio)
@@ -371,6 +548,16 @@ enum Commands {
         /// Gravity/anchor for cropping (center, top, bottom, left, right, top-left, etc.)
         #[arg(long, default_value = "center")]
         gravity: String,
+        /// Resampling filter for resizes (nearest, linear, cubic, lanczos3)
+        #[arg(long)]
+        resize_filter: Option<String>,
+        /// How to resize into --max-width x --max-height: fit (within bounds),
+        /// fill (scale and crop around --gravity) or stretch (exact size)
+        #[arg(long)]
+        resize_mode: Option<String>,
+        /// Rotate/flip images upright according to their EXIF Orientation tag
+        #[arg(long)]
+        exif_auto_rotate: bool,
 
         // Watermark options
         /// Watermark image file to composite onto the image
@@ -385,11 +572,69 @@ enum Commands {
         /// Watermark margin from edge in pixels
         #[arg(long, default_value = "10")]
         watermark_margin: u32,
+        /// Convert output pixels to rgb, rgba, grayscale or grayscale_alpha
+        #[arg(long)]
+        color_mode: Option<String>,
+        /// Reduce PNG output to an indexed palette of N colors (2-256)
+        #[arg(long, value_name = "N")]
+        quantize: Option<u32>,
 
         // Video options
         /// Video quality preset (low, medium, high, lossless)
         #[arg(long)]
         quality: Option<String>,
+        /// Extract a single frame from a video as an image
+        #[arg(long)]
+        extract_frame: bool,
+        /// Timestamp of the frame to extract, in seconds (default: 0.1)
+        #[arg(long)]
+        frame_time: Option<f64>,
+
+        // Serialization options
+        /// Write JSON without indentation
+        #[arg(long)]
+        compact: bool,
+        /// Sort object keys in serialized output
+        #[arg(long)]
+        sorted_keys: bool,
+        /// Keep only these fields ("name,version,deps.name"), drop fields
+        /// prefixed with '-' ("--filter=-auth.password"), or apply a JSONPath
+        /// query starting with '$'
+        #[arg(long)]
+        filter: Option<String>,
+
+        // Archive options
+        /// Strip this prefix from input paths when creating archives
+        /// (entries keep their directory structure below it)
+        #[arg(long)]
+        strip_prefix: Option<String>,
+        /// Directory to place all entries under when creating archives
+        #[arg(long)]
+        base_dir: Option<String>,
+        /// Zip compression method (store, deflate, bzip2, zstd)
+        #[arg(long)]
+        zip_method: Option<String>,
+        /// Zip compression level (0-9 for deflate/bzip2, 1-22 for zstd)
+        #[arg(long)]
+        zip_level: Option<i64>,
+        /// Write tar archives to disk entry by entry instead of building them
+        /// in memory (for archives larger than available memory)
+        #[arg(long)]
+        stream: bool,
+
+        /// Write output files directly instead of through a temp file that
+        /// is renamed into place (for filesystems without atomic rename)
+        #[arg(long)]
+        no_atomic_write: bool,
+    },
+
+    /// Show metadata for a media file (video, audio)
+    Info {
+        /// Input file
+        input: String,
+        /// Explicit format (overrides detection)
+        #[arg(long)]
+        from: Option<String>,
     },
 
     /// Run a workflow file
@@ -422,20 +667,8 @@ fn main() -> Result<()> {
         Config::load()
     };
 
-    // Create registry with enabled converters
-    let mut registry = Registry::new();
-
-    #[cfg(feature = "serde")]
-    paraphase_serde::register_all(&mut registry);
-
-    #[cfg(feature = "image")]
-    paraphase_image::register_all(&mut registry);
-
-    #[cfg(feature = "video")]
-    paraphase_video::register_all(&mut registry);
-
-    #[cfg(feature = "audio")]
-    paraphase_audio::register_all(&mut registry);
+    // Registry with the enabled converters, shared by every execution context
+    let registry = Arc::new(paraphase::default_registry());
 
     // Apply config defaults, CLI flags override
     let memory_limit = cli.memory_limit.or(config.defaults.memory_limit);
@@ -444,35 +677,87 @@ fn main() -> Result<()> {
     let verbosity = Verbosity::from_flags(verbose, quiet);
 
     match cli.command {
-        Commands::List => cmd_list(&registry, verbosity),
+        Commands::List {
+            dot,
+            check_cycles,
+            from,
+            to,
+            capable_of,
+        } => {
+            if let Some([from, to]) = capable_of.as_deref() {
+                return cmd_capable_of(&registry, from, to, verbosity);
+            }
+            cmd_list(
+                &registry,
+                dot,
+                check_cycles,
+                from.as_deref(),
+                to.as_deref(),
+                verbosity,
+            )
+        }
         Commands::Plan {
             input,
             output,
             from,
             to,
             optimize,
-        } => cmd_plan(&registry, &input, output, from, to, optimize, verbosity),
+            max_depth,
+            json,
+            validate_only,
+        } => cmd_plan(
+            &registry,
+            &input,
+            output,
+            from,
+            to,
+            optimize,
+            max_depth,
+            PlanOutput::from_flags(json, validate_only),
+            verbosity,
+        ),
         Commands::Convert {
             input,
             output,
             output_dir,
+            output_template,
             recursive,
             batch_mode,
             aggregate,
             from,
             to,
+            from_props,
+            to_props,
             preset,
             optimize,
+            check,
+            check_schema,
             max_width,
             max_height,
             scale,
             aspect,
             gravity,
+            resize_filter,
+            resize_mode,
+            exif_auto_rotate,
             watermark,
             watermark_position,
             watermark_opacity,
             watermark_margin,
+            color_mode,
+            quantize,
             quality,
+            extract_frame,
+            frame_time,
+            compact,
+            sorted_keys,
+            filter,
+            strip_prefix,
+            base_dir,
+            zip_method,
+            zip_level,
+            stream,
+            no_atomic_write,
         } => {
             // Build options from preset (if any) + CLI overrides
             let opts = build_convert_options(
@@ -489,6 +774,40 @@ fn main() -> Result<()> {
                 watermark_margin,
                 quality,
             )?;
+            let opts = ConvertOptions {
+                resize_filter,
+                resize_mode,
+                exif_auto_rotate,
+                color_mode,
+                quantize,
+                compact,
+                sorted_keys,
+                strip_prefix,
+                base_dir,
+                zip_method,
+                zip_level,
+                stream,
+                extract_frame,
+                frame_time,
+                filter,
+                check,
+                check_schema: check_schema
+                    .as_deref()
+                    .map(check::load_schema)
+                    .transpose()?,
+                no_atomic_write,
+                ..opts
+            };
+
+            let mut from_props = parse_props_args(&from_props)?;
+            let mut to_props = parse_props_args(&to_props)?;
+            let from = from.or_else(|| take_format(&mut from_props));
+            let to = to.or_else(|| take_format(&mut to_props));
+            let opts = ConvertOptions {
+                from_props,
+                to_props,
+                ..opts
+            };
 
             // Collect files (handles globs, directories, and recursion)
             let collected = collect_files(input, recursive, from.as_deref(), verbosity);
@@ -499,6 +818,8 @@ fn main() -> Result<()> {
             // Group files by batch mode
             let batches = group_by_batch_mode(&collected, batch_mode);
 
+            let to = resolve_target_format(to, output.as_deref(), verbosity);
+
             // Auto-detect aggregation for archive formats (including compound like tar.gz)
             let should_aggregate =
                 aggregate || to.as_deref().map_or(false, |t| is_archive_format(t));
@@ -513,6 +834,7 @@ fn main() -> Result<()> {
                     files,
                     output.clone(),
                     output_dir.clone(),
+                    output_template.clone(),
                     from.clone(),
                     to.clone(),
                     opts.clone(),
@@ -536,6 +858,7 @@ fn main() -> Result<()> {
             man.render(&mut std::io::stdout())?;
             Ok(())
         }
+        Commands::Info { input, from } => cmd_info(&registry, &input, from),
         Commands::Presets => cmd_presets(&config, verbosity),
     }
 }
@@ -561,7 +884,10 @@ fn build_convert_options(
     // Start with preset values if specified
     let preset = if let Some(ref name) = preset_name {
         config.get_preset(name).ok_or_else(|| {
-            anyhow::anyhow!("Unknown preset: '{}'. Use 'cambium presets' to list.", name)
+            anyhow::anyhow!(
+                "Unknown preset: '{}'. Use 'paraphase presets' to list.",
+                name
+            )
         })?
     } else {
         Preset::default()
@@ -602,6 +928,7 @@ fn build_convert_options(
                 .unwrap_or_else(|| NumericValue::from_u32(10))
         },
         quality: quality.or(preset.quality),
+        ..Default::default()
     })
 }
 
@@ -662,10 +989,36 @@ fn cmd_presets(config: &Config, v: Verbosity) -> Result<()> {
     Ok(())
 }
 
-fn cmd_list(registry: &Registry, v: Verbosity) -> Result<()> {
+fn cmd_list(
+    registry: &Registry,
+    dot: bool,
+    check_cycles: bool,
+    from: Option<&str>,
+    to: Option<&str>,
+    v: Verbosity,
+) -> Result<()> {
+    if dot {
+        print!("{}", registry.to_dot());
+        return Ok(());
+    }
+
+    if check_cycles {
+        let cycles = registry.find_cycles();
+        if cycles.is_empty() {
+            println!("No cycles in the converter graph");
+        }
+        for (i, cycle) in cycles.iter().enumerate() {
+            println!("Cycle {} ({} converters):", i + 1, cycle.len());
+            for id in cycle {
+                println!("  {}", id);
+            }
+        }
+        return Ok(());
+    }
+
     v.info("Available converters:\n");
 
-    for decl in registry.declarations() {
+    let print_decl = |decl: &ConverterDecl| {
         let inputs: Vec<_> = decl.inputs.keys().collect();
         let outputs: Vec<_> = decl.outputs.keys().collect();
 
@@ -675,13 +1028,203 @@ fn cmd_list(registry: &Registry, v: Verbosity) -> Result<()> {
         }
         v.info(&format!("    inputs:  {:?}", inputs));
         v.info(&format!("    outputs: {:?}", outputs));
+        if matches!(v, Verbosity::Verbose) {
+            if decl.is_lossless() {
+                v.info("    lossless");
+            } else {
+                v.info(&format!("    lossy (quality loss {})", decl.quality_loss()));
+            }
+        }
         v.info("");
+    };
+
+    if from.is_some() || to.is_some() {
+        let decls = filter_converters(registry, from, to);
+        for decl in &decls {
+            print_decl(decl);
+        }
+        v.info(&format!("Total: {} converters", decls.len()));
+        return Ok(());
+    }
+
+    if matches!(v, Verbosity::Verbose) {
+        for (namespace, decls) in registry.namespaced() {
+            let name = if namespace.is_empty() {
+                "(no namespace)"
+            } else {
+                namespace.as_str()
+            };
+            v.info(&format!("[{}]\n", name));
+            for decl in decls {
+                print_decl(decl);
+            }
+        }
+    } else {
+        for decl in registry.declarations_sorted() {
+            print_decl(decl);
+        }
     }
 
     v.info(&format!("Total: {} converters", registry.len()));
     Ok(())
 }
 
+/// Converters that accept `from` and produce `to` (either may be omitted),
+/// sorted by ID.
+fn filter_converters<'a>(
+    registry: &'a Registry,
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Vec<&'a ConverterDecl> {
+    let mut decls = match from {
+        Some(format) => registry.declarations_by_input_format(format),
+        None => registry.declarations_sorted(),
+    };
+    if let Some(format) = to {
+        let producers: HashSet<&str> = registry
+            .declarations_by_output_format(format)
+            .into_iter()
+            .map(|decl| decl.id.as_str())
+            .collect();
+        decls.retain(|decl| producers.contains(decl.id.as_str()));
+    }
+    decls
+}
+
+/// Conversion paths from `from` to `to`, cheapest first.
+///
+/// The planner only returns the best path, so alternatives are found by
+/// planning again without the first converter of each path found so far:
+/// every path starts with a different converter.
+fn conversion_paths(registry: &Registry, from: &str, to: &str) -> Vec<paraphase_core::Plan> {
+    let mut excluded = HashSet::new();
+    let mut paths = Vec::new();
+    loop {
+        let mut remaining = Registry::new();
+        for decl in registry.declarations() {
+            if !excluded.contains(&decl.id) {
+                remaining.register_decl(decl.clone());
+            }
+        }
+        let Some(plan) = plan_formats(&remaining, from, to, None, None) else {
+            break;
+        };
+        let Some(first) = plan.steps.first() else {
+            break;
+        };
+        excluded.insert(first.converter_id.clone());
+        paths.push(plan);
+    }
+    paths
+}
+
+fn cmd_capable_of(registry: &Registry, from: &str, to: &str, v: Verbosity) -> Result<()> {
+    let paths = conversion_paths(registry, from, to);
+    if paths.is_empty() {
+        bail!("No conversion path from {} to {}", from, to);
+    }
+
+    v.info(&format!("Paths from {} to {}:\n", from, to));
+    for plan in &paths {
+        let ids: Vec<&str> = plan
+            .steps
+            .iter()
+            .map(|step| step.converter_id.as_str())
+            .collect();
+        println!("  {} (cost {})", ids.join(" -> "), plan.cost);
+    }
+    Ok(())
+}
+
+fn cmd_info(registry: &Registry, input: &str, from: Option<String>) -> Result<()> {
+    let data = std::fs::read(input)
+        .map_err(|e| anyhow::anyhow!("{}", errors::file_read_error(input, &e)))?;
+
+    // Detect format: --from flag > magic bytes > extension
+    let format = from
+        .or_else(|| detect_format_from_magic(&data))
+        .or_else(|| detect_format(input))
+        .ok_or_else(|| anyhow::anyhow!("{}", errors::format_detection_error(input, true)))?;
+    let props = Properties::new().with("format", format.as_str());
+
+    // Metadata extractors are named `*metadata-to-json`
+    // (`video.metadata-to-json`, `audio.mp3-metadata-to-json`, ...)
+    let converter = registry
+        .declarations_sorted()
+        .into_iter()
+        .find(|decl| decl.id.ends_with("metadata-to-json") && decl.matches_input(&props).is_some())
+        .and_then(|decl| registry.get(&decl.id))
+        .with_context(|| format!("No metadata extractor available for '{}' files", format))?;
+
+    match converter
+        .convert(&data, &props)
+        .map_err(|e| anyhow::anyhow!("Failed to read metadata: {}", errors::describe(&e)))?
+    {
+        ConvertOutput::Single(json, _) => {
+            std::io::stdout().write_all(&json)?;
+            println!();
+        }
+        _ => bail!("Unexpected output from metadata extractor"),
+    }
+    Ok(())
+}
+
+/// How `plan` reports its result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlanOutput {
+    Text,
+    Json,
+    /// Exit code only.
+    Validate,
+}
+
+impl PlanOutput {
+    fn from_flags(json: bool, validate_only: bool) -> Self {
+        if validate_only {
+            PlanOutput::Validate
+        } else if json {
+            PlanOutput::Json
+        } else {
+            PlanOutput::Text
+        }
+    }
+}
+
+/// JSON reported by `plan --json`; see `schemas/plan.json`.
+fn plan_json(plan: Option<&paraphase_core::Plan>, source_format: &str) -> serde_json::Value {
+    match plan {
+        Some(plan) => plan.to_json(&Properties::new().with("format", source_format)),
+        None => serde_json::json!({ "steps": [], "cost": null, "found": false }),
+    }
+}
+
+/// Find a plan for a single-item format conversion.
+fn plan_formats(
+    registry: &Registry,
+    source_format: &str,
+    target_format: &str,
+    optimize: Option<OptimizeTarget>,
+    max_depth: Option<usize>,
+) -> Option<paraphase_core::Plan> {
+    let source_props = Properties::new().with("format", source_format);
+    let target_pattern = PropertyPattern::new().eq("format", target_format);
+
+    let mut planner = Planner::new(registry);
+    if let Some(opt) = optimize {
+        planner = planner.optimize(opt);
+    }
+    if let Some(depth) = max_depth {
+        planner.set_max_depth(depth);
+    }
+    planner.plan(
+        &source_props,
+        &target_pattern,
+        Cardinality::One,
+        Cardinality::One,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 fn cmd_plan(
     registry: &Registry,
     input: &str,
@@ -689,11 +1232,16 @@ fn cmd_plan(
     from: Option<String>,
     to: Option<String>,
     optimize: Option<OptimizeTarget>,
+    max_depth: Option<usize>,
+    mode: PlanOutput,
     v: Verbosity,
 ) -> Result<()> {
     // Check if input is a workflow file
     if is_workflow_file(input) {
-        return cmd_plan_workflow(registry, input, optimize, v);
+        if mode != PlanOutput::Text {
+            bail!("--json and --validate-only are not supported for workflow files");
+        }
+        return cmd_plan_workflow(registry, input, optimize, max_depth, v);
     }
 
     // Otherwise, plan a simple conversion
@@ -703,10 +1251,35 @@ fn cmd_plan(
         .or_else(|| detect_format(input))
         .ok_or_else(|| anyhow::anyhow!("{}", errors::format_detection_error(input, true)))?;
 
-    let target_format = to
-        .or_else(|| detect_format(&output))
+    let target_format = resolve_target_format(to, Some(&output), v)
         .ok_or_else(|| anyhow::anyhow!("{}", errors::format_detection_error(&output, false)))?;
 
+    let plan = plan_formats(
+        registry,
+        &source_format,
+        &target_format,
+        optimize,
+        max_depth,
+    );
+
+    match mode {
+        PlanOutput::Validate => {
+            // The exit status is the answer; failure goes through main's error path
+            if plan.is_none() {
+                bail!(
+                    "{}",
+                    errors::no_path_error(&source_format, &target_format, registry)
+                );
+            }
+            return Ok(());
+        }
+        PlanOutput::Json => {
+            println!("{}", plan_json(plan.as_ref(), &source_format));
+            return Ok(());
+        }
+        PlanOutput::Text => {}
+    }
+
     let opt_str = match optimize {
         Some(OptimizeTarget::Quality) => " (optimize: quality)",
         Some(OptimizeTarget::Speed) => " (optimize: speed)",
@@ -719,26 +1292,12 @@ fn cmd_plan(
     ));
     v.info("");
 
-    let source_props = Properties::new().with("format", source_format.as_str());
-    let target_pattern = PropertyPattern::new().eq("format", target_format.as_str());
-
-    let mut planner = Planner::new(registry);
-    if let Some(opt) = optimize {
-        planner = planner.optimize(opt);
-    }
-    let plan = planner
-        .plan(
-            &source_props,
-            &target_pattern,
-            Cardinality::One,
-            Cardinality::One,
+    let plan = plan.ok_or_else(|| {
+        anyhow::anyhow!(
+            "{}",
+            errors::no_path_error(&source_format, &target_format, registry)
         )
-        .ok_or_else(|| {
-            anyhow::anyhow!(
-                "{}",
-                errors::no_path_error(&source_format, &target_format, registry)
-            )
-        })?;
+    })?;
 
     if plan.steps.is_empty() {
         v.info("Already at target format (no conversion needed)");
@@ -764,11 +1323,13 @@ fn cmd_plan_workflow(
     registry: &Registry,
     path: &str,
     optimize: Option<OptimizeTarget>,
+    max_depth: Option<usize>,
     v: Verbosity,
 ) -> Result<()> {
     let data = std::fs::read(path).context("Failed to read workflow file")?;
     let workflow = Workflow::from_bytes(&data, Some(path))
-        .map_err(|e| anyhow::anyhow!("Failed to parse workflow: {}", e))?;
+        .and_then(|workflow| workflow.validate_steps(registry).map(|()| workflow))
+        .map_err(|e| anyhow::anyhow!("{}", errors::workflow_error(path, &e)))?;
 
     v.info(&format!("Workflow: {}", path));
     v.info("");
@@ -779,6 +1340,7 @@ fn cmd_plan_workflow(
         match source {
             Source::File { path } => v.info(&format!("  file: {}", path)),
             Source::Glob { glob } => v.info(&format!("  glob: {}", glob)),
+            Source::Http { url, method, .. } => v.info(&format!("  http: {} {}", method, url)),
             Source::Properties { properties } => v.info(&format!("  properties: {:?}", properties)),
         }
         v.info("");
@@ -793,6 +1355,7 @@ fn cmd_plan_workflow(
         match sink {
             Sink::File { path } => v.info(&format!("  file: {}", path)),
             Sink::Directory { directory } => v.info(&format!("  directory: {}", directory)),
+            Sink::Http { url, method, .. } => v.info(&format!("  http: {} {}", method, url)),
             Sink::Properties { properties } => v.info(&format!("  properties: {:?}", properties)),
         }
         v.info("");
@@ -833,6 +1396,9 @@ fn cmd_plan_workflow(
         if let Some(opt) = optimize {
             planner = planner.optimize(opt);
         }
+        if let Some(depth) = max_depth {
+            planner.set_max_depth(depth);
+        }
         match planner.plan(
             &source_props,
             &target_pattern,
@@ -869,26 +1435,28 @@ fn cmd_plan_workflow(
 }
 
 fn cmd_run(
-    registry: &Registry,
+    registry: &Arc<Registry>,
     workflow_path: &PathBuf,
     memory_limit: Option<usize>,
     v: Verbosity,
 ) -> Result<()> {
     let data = std::fs::read(workflow_path).context("Failed to read workflow file")?;
-    let workflow = Workflow::from_bytes(&data, Some(&workflow_path.to_string_lossy()))
-        .map_err(|e| anyhow::anyhow!("Failed to parse workflow: {}", e))?;
+    let path = workflow_path.to_string_lossy();
+    let workflow = Workflow::from_bytes(&data, Some(&path))
+        .and_then(|workflow| workflow.validate(registry).map(|()| workflow))
+        .map_err(|e| anyhow::anyhow!("{}", errors::workflow_error(&path, &e)))?;
+
+    // Checked by validate()
+    let source = workflow.source.as_ref().unwrap();
+    let sink = workflow.sink.as_ref().unwrap();
+    let output_label = sink_label(sink)?;
 
-    // Get source and sink
-    let source = workflow
-        .source
-        .as_ref()
-        .context("Workflow missing source")?;
-    let sink = workflow.sink.as_ref().context("Workflow missing sink")?;
+    // Read input (HTTP sources only know their format once fetched)
+    let (input_data, input_props, input_label) = read_source(source)?;
 
     // Determine plan (explicit steps or auto-planned)
     let plan = if workflow.steps.is_empty() {
         // Auto-plan
-        let source_props = source.to_properties();
         let target_pattern = sink.to_pattern();
 
         let source_cardinality = if source.is_batch() {
@@ -900,7 +1468,7 @@ fn cmd_run(
         let planner = Planner::new(registry);
         planner
             .plan(
-                &source_props,
+                &input_props,
                 &target_pattern,
                 source_cardinality,
                 Cardinality::One,
@@ -908,45 +1476,11 @@ fn cmd_run(
             .context("No conversion path found for workflow")?
     } else {
         // Build plan from explicit steps
-        Plan {
-            steps: workflow
-                .steps
-                .iter()
-                .map(|s| paraphase_core::PlanStep {
-                    converter_id: s.converter.clone(),
-                    input_port: "in".into(),
-                    output_port: "out".into(),
-                    output_properties: Properties::new(),
-                })
-                .collect(),
-            cost: workflow.steps.len() as f64,
-        }
-    };
-
-    // Get input file path
-    let input_path = match source {
-        Source::File { path } => PathBuf::from(path),
-        Source::Glob { .. } => bail!("Glob sources not yet implemented"),
-        Source::Properties { .. } => bail!("Properties-only source cannot be executed"),
-    };
-
-    // Get output file path
-    let output_path = match sink {
-        Sink::File { path } => PathBuf::from(path),
-        Sink::Directory { .. } => bail!("Directory sinks not yet implemented"),
-        Sink::Properties { .. } => bail!("Properties-only sink cannot be executed"),
+        workflow.to_plan()
     };
 
-    // Read input
-    let input_data = std::fs::read(&input_path).context("Failed to read input file")?;
-    let input_props = source.to_properties();
-
     v.info(&format!("Running workflow: {}", workflow_path.display()));
-    v.info(&format!(
-        "  {} -> {}",
-        input_path.display(),
-        output_path.display()
-    ));
+    v.info(&format!("  {} -> {}", input_label, output_label));
     v.info("");
 
     for step in &plan.steps {
@@ -954,32 +1488,91 @@ fn cmd_run(
     }
 
     // Execute using appropriate executor
-    let mut ctx = ExecutionContext::new(Arc::new(registry.clone()));
+    let mut ctx = ExecutionContext::new(Arc::clone(registry));
     if let Some(limit) = memory_limit {
         ctx = ctx.with_memory_limit(limit);
     }
+    if matches!(v, Verbosity::Verbose) {
+        ctx = ctx.with_debug_log(move |msg| v.debug(msg));
+    }
 
     let result = if memory_limit.is_some() {
         BoundedExecutor::new().execute(&ctx, &plan, input_data, input_props)
     } else {
         SimpleExecutor::new().execute(&ctx, &plan, input_data, input_props)
     }
-    .map_err(|e| anyhow::anyhow!("Execution failed: {}", e))?;
+    .map_err(|e| anyhow::anyhow!("Execution failed: {}", errors::describe(&e)))?;
+    v.step_stats(&result.stats);
 
-    // Write output
-    std::fs::write(&output_path, &result.data).context("Failed to write output file")?;
+    let output_len = result.data.len();
+    write_sink(sink, result.data)?;
 
     v.info("");
     v.result(&format!(
         "Completed: {} ({} bytes, {:?})",
-        output_path.display(),
-        result.data.len(),
-        result.stats.duration
+        output_label, output_len, result.stats.duration
     ));
 
     Ok(())
 }
 
+/// Read a workflow source, returning its data, properties and a display label.
+fn read_source(source: &Source) -> Result<(Vec<u8>, Properties, String)> {
+    match source {
+        Source::File { path } => {
+            let data = std::fs::read(path).context("Failed to read input file")?;
+            Ok((data, source.to_properties(), path.clone()))
+        }
+        Source::Glob { .. } => bail!("Glob sources not yet implemented"),
+        #[cfg(feature = "http")]
+        Source::Http {
+            url,
+            method,
+            headers,
+            format,
+        } => {
+            let (data, content_format) = http::fetch(url, method, headers)?;
+            let mut props = source.to_properties();
+            // An explicit format wins over Content-Type, which wins over the URL
+            if let Some(format) = format.clone().or(content_format) {
+                props.insert("format".into(), format.into());
+            }
+            Ok((data, props, url.clone()))
+        }
+        #[cfg(not(feature = "http"))]
+        Source::Http { .. } => bail!("HTTP sources require the 'http' feature"),
+        Source::Properties { .. } => bail!("Properties-only source cannot be executed"),
+    }
+}
+
+/// Check that a workflow sink can be written to, returning its display label.
+fn sink_label(sink: &Sink) -> Result<String> {
+    match sink {
+        Sink::File { path } => Ok(path.clone()),
+        Sink::Directory { .. } => bail!("Directory sinks not yet implemented"),
+        #[cfg(feature = "http")]
+        Sink::Http { url, .. } => Ok(url.clone()),
+        #[cfg(not(feature = "http"))]
+        Sink::Http { .. } => bail!("HTTP sinks require the 'http' feature"),
+        Sink::Properties { .. } => bail!("Properties-only sink cannot be executed"),
+    }
+}
+
+/// Write output to a sink already checked by [`sink_label`].
+fn write_sink(sink: &Sink, data: Vec<u8>) -> Result<()> {
+    match sink {
+        Sink::File { path } => std::fs::write(path, data).context("Failed to write output file"),
+        #[cfg(feature = "http")]
+        Sink::Http {
+            url,
+            method,
+            headers,
+            ..
+        } => http::send(url, method, headers, data),
+        _ => unreachable!("sink checked by sink_label"),
+    }
+}
+
 /// Check if a path looks like a workflow file.
 fn is_workflow_file(path: &str) -> bool {
     // Check if file exists and has workflow-like structure
@@ -1004,6 +1597,7 @@ fn is_archive_format(format: &str) -> bool {
 /// - "tar.gz" → ("tar", Some("gz"))
 /// - "tgz" → ("tar", Some("gz"))
 /// - "tar.zst" → ("tar", Some("zst"))
+/// - "tbz2" → ("tar", Some("bz2"))
 fn parse_compound_archive(format: &str) -> Result<(&str, Option<&str>)> {
     // Handle common aliases
     match format {
@@ -1019,7 +1613,7 @@ fn parse_compound_archive(format: &str) -> Result<(&str, Option<&str>)> {
             "gz" | "gzip" => return Ok(("tar", Some("gz"))),
             "zst" | "zstd" => return Ok(("tar", Some("zst"))),
             "br" | "brotli" => return Ok(("tar", Some("br"))),
-            "bz2" | "bzip2" => bail!("bzip2 compression not yet supported"),
+            "bz2" | "bzip2" => return Ok(("tar", Some("bz2"))),
             "xz" | "lzma" => bail!("xz/lzma compression not yet supported"),
             _ => bail!("Unknown compression format: {}", rest),
         }
@@ -1033,20 +1627,107 @@ fn parse_compound_archive(format: &str) -> Result<(&str, Option<&str>)> {
     }
 }
 
+/// Archive entry path for an input file: its file name, unless the
+/// directory structure is kept (the archiver strips the prefix).
+fn archive_entry_path(input_path: &str, opts: &ConvertOptions) -> String {
+    if opts.strip_prefix.is_some() {
+        return input_path.to_string();
+    }
+    Path::new(input_path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| input_path.to_string())
+}
+
+/// Write a tar archive straight to `output_path`, reading one input file
+/// at a time, so neither the inputs nor the archive are held in memory.
+#[cfg(feature = "tar")]
+fn stream_tar(
+    inputs: &[String],
+    output_path: &str,
+    target_format: &str,
+    opts: &ConvertOptions,
+    v: Verbosity,
+) -> Result<()> {
+    if parse_compound_archive(target_format)? != ("tar", None) {
+        bail!(
+            "--stream only writes uncompressed tar archives (got '{}'); \
+             compress the result separately",
+            target_format
+        );
+    }
+
+    let mut base_props = opts.from_props.clone();
+    base_props.extend(opts.to_props.clone());
+    if let Some(ref prefix) = opts.strip_prefix {
+        base_props.insert("tar_strip_prefix".into(), prefix.clone().into());
+    }
+    if let Some(ref base) = opts.base_dir {
+        base_props.insert("tar_base_dir".into(), base.clone().into());
+    }
+
+    let file = std::fs::File::create(output_path).context("Failed to create output")?;
+    let mut output = std::io::BufWriter::new(file);
+
+    // Files are opened as the archive reaches them; the first one that
+    // cannot be read stops the archive
+    let mut read_error = None;
+    let entries = inputs.iter().map_while(|input_path| {
+        let opened =
+            std::fs::File::open(input_path).and_then(|file| Ok((file.metadata()?.len(), file)));
+        match opened {
+            Ok((size, file)) => {
+                v.debug(&format!("  Adding: {} ({} bytes)", input_path, size));
+                let props = base_props
+                    .clone()
+                    .with("format", "raw")
+                    .with("path", archive_entry_path(input_path, opts))
+                    .with("size", size as i64);
+                Some((file, props))
+            }
+            Err(e) => {
+                read_error = Some(errors::file_read_error(input_path, &e));
+                None
+            }
+        }
+    });
+    let result = paraphase_serde::TarCreate::create_streaming(entries, &mut output);
+
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(output_path);
+        bail!("Aggregation failed: {}", errors::describe(&e));
+    }
+    if let Some(e) = read_error {
+        let _ = std::fs::remove_file(output_path);
+        bail!("{}", e);
+    }
+
+    let size = std::fs::metadata(output_path)
+        .map(|m| m.len())
+        .unwrap_or_default();
+    v.result(&format!(
+        "Created {} ({} bytes from {} files)",
+        output_path,
+        size,
+        inputs.len()
+    ));
+    Ok(())
+}
+
 /// Aggregate multiple files into a single output (N→1 conversion).
 #[allow(clippy::too_many_arguments)]
 fn cmd_convert_aggregate(
-    registry: &Registry,
+    registry: &Arc<Registry>,
     inputs: Vec<String>,
     output: Option<String>,
     from: Option<String>,
     to: Option<String>,
+    opts: &ConvertOptions,
     _optimize: Option<OptimizeTarget>,
     memory_limit: Option<usize>,
     v: Verbosity,
 ) -> Result<()> {
     use paraphase_core::{ExecutionContext, Executor, SimpleExecutor};
-    use std::sync::Arc;
 
     let target_format = to.context("Aggregation requires --to format")?;
     let output_path = output.context("Aggregation requires -o/--output file")?;
@@ -1058,6 +1739,14 @@ fn cmd_convert_aggregate(
         target_format
     ));
 
+    if opts.stream {
+        #[cfg(feature = "tar")]
+        return stream_tar(&inputs, &output_path, &target_format, opts, v);
+
+        #[cfg(not(feature = "tar"))]
+        bail!("--stream requires the 'tar' feature");
+    }
+
     // Read all input files with their properties
     let mut input_data: Vec<(Vec<u8>, Properties)> = Vec::new();
 
@@ -1072,15 +1761,11 @@ fn cmd_convert_aggregate(
             .or_else(|| detect_format(input_path))
             .unwrap_or_else(|| "raw".into());
 
-        // Use relative path for archive entry
-        let rel_path = PathBuf::from(input_path)
-            .file_name()
-            .map(|n| n.to_string_lossy().to_string())
-            .unwrap_or_else(|| input_path.clone());
+        let rel_path = archive_entry_path(input_path, opts);
 
-        let props = Properties::new()
-            .with("format", format.as_str())
-            .with("path", rel_path.as_str());
+        let mut props = opts.from_props.clone();
+        props.insert("format".into(), format.as_str().into());
+        props.insert("path".into(), rel_path.as_str().into());
 
         input_data.push((data, props));
         v.debug(&format!("  Added: {} ({})", input_path, format));
@@ -1098,12 +1783,30 @@ fn cmd_convert_aggregate(
         ),
     };
 
+    // Archive layout options are consumed by the aggregator
+    let mut archive_props = opts.to_props.clone();
+    if let Some(ref prefix) = opts.strip_prefix {
+        archive_props.insert("tar_strip_prefix".into(), prefix.clone().into());
+    }
+    if let Some(ref base) = opts.base_dir {
+        archive_props.insert("tar_base_dir".into(), base.clone().into());
+    }
+    if let Some(ref method) = opts.zip_method {
+        archive_props.insert("zip_method".into(), method.clone().into());
+    }
+    if let Some(level) = opts.zip_level {
+        archive_props.insert("zip_level".into(), level.into());
+    }
+
     // Build plan: aggregate step + optional compression step
     let mut steps = vec![paraphase_core::PlanStep {
-        converter_id: aggregator_id.into(),
-        input_port: "in".into(),
-        output_port: "out".into(),
-        output_properties: Properties::new().with("format", archive_format),
+        input_properties: archive_props,
+        ..paraphase_core::PlanStep::new(
+            aggregator_id,
+            "in",
+            "out",
+            Properties::new().with("format", archive_format),
+        )
     }];
 
     // Add compression step if needed
@@ -1112,29 +1815,31 @@ fn cmd_convert_aggregate(
             "gz" | "gzip" => "compression.gzip",
             "zst" | "zstd" => "compression.zstd",
             "br" | "brotli" => "compression.brotli",
+            "bz2" | "bzip2" => "compression.bzip2",
             _ => bail!("Unknown compression format: {}", comp),
         };
-        steps.push(paraphase_core::PlanStep {
-            converter_id: compressor_id.into(),
-            input_port: "in".into(),
-            output_port: "out".into(),
-            output_properties: Properties::new().with("format", comp),
-        });
+        steps.push(paraphase_core::PlanStep::new(
+            compressor_id,
+            "in",
+            "out",
+            Properties::new().with("format", comp),
+        ));
     }
 
     let plan = paraphase_core::Plan { steps, cost: 1.0 };
 
     // Execute aggregation
-    let ctx = ExecutionContext::new(Arc::new(registry.clone()))
+    let ctx = ExecutionContext::new(Arc::clone(registry))
         .with_memory_limit(memory_limit.unwrap_or(usize::MAX));
 
     let executor = SimpleExecutor::new();
     let result = executor
         .execute_aggregating(&ctx, &plan, input_data)
-        .map_err(|e| anyhow::anyhow!("Aggregation failed: {}", e))?;
+        .map_err(|e| anyhow::anyhow!("Aggregation failed: {}", errors::describe(&e)))?;
+    v.step_stats(&result.stats);
 
     // Write output
-    std::fs::write(&output_path, &result.data).context("Failed to write output")?;
+    write_output_file(Path::new(&output_path), &result.data, opts.no_atomic_write)?;
 
     v.result(&format!(
         "Created {} ({} bytes from {} files)",
@@ -1148,10 +1853,11 @@ fn cmd_convert_aggregate(
 
 #[allow(clippy::too_many_arguments)]
 fn cmd_convert(
-    registry: &Registry,
+    registry: &Arc<Registry>,
     inputs: Vec<String>,
     output: Option<String>,
     output_dir: Option<PathBuf>,
+    output_template: Option<String>,
     from: Option<String>,
     to: Option<String>,
     opts: ConvertOptions,
@@ -1168,6 +1874,7 @@ fn cmd_convert(
             output,
             from,
             to,
+            &opts,
             optimize,
             memory_limit,
             v,
@@ -1197,14 +1904,16 @@ fn cmd_convert(
             None
         };
 
-        for input in &inputs {
+        let template = output_template
+            .as_deref()
+            .unwrap_or(DEFAULT_OUTPUT_TEMPLATE);
+
+        for (idx, input) in inputs.iter().enumerate() {
             let input_path = PathBuf::from(input);
             let stem = input_path
                 .file_stem()
                 .and_then(|s| s.to_str())
                 .unwrap_or("output");
-            let output_name = format!("{}.{}", stem, target_format);
-            let output_path = out_dir.join(&output_name);
 
             if let Some(ref pb) = pb {
                 pb.set_message(stem.to_string());
@@ -1213,7 +1922,11 @@ fn cmd_convert(
             convert_single_file(
                 registry,
                 input,
-                &output_path.to_string_lossy(),
+                OutputTarget::Template {
+                    dir: &out_dir,
+                    template,
+                    seq: idx + 1,
+                },
                 from.clone(),
                 Some(target_format.clone()),
                 &opts,
@@ -1240,6 +1953,26 @@ fn cmd_convert(
 
     // Single file mode
     let input = inputs.into_iter().next().unwrap();
+    if let (None, Some(template)) = (&output, &output_template) {
+        if to.is_none() {
+            bail!("--output-template requires --to");
+        }
+        return convert_single_file(
+            registry,
+            &input,
+            OutputTarget::Template {
+                dir: Path::new("."),
+                template,
+                seq: 1,
+            },
+            from,
+            to,
+            &opts,
+            optimize,
+            memory_limit,
+            v,
+        );
+    }
     let output = output
         .or_else(|| {
             // If --to specified, derive output from input
@@ -1254,7 +1987,7 @@ fn cmd_convert(
     convert_single_file(
         registry,
         &input,
-        &output,
+        OutputTarget::Path(&output),
         from,
         to,
         &opts,
@@ -1264,11 +1997,153 @@ fn cmd_convert(
     )
 }
 
+/// Default `--output-template`.
+const DEFAULT_OUTPUT_TEMPLATE: &str = "{stem}.{ext}";
+
+/// Inputs larger than this get a per-step progress bar.
+const PROGRESS_MIN_INPUT_SIZE: usize = 1024 * 1024;
+
+/// Where a converted file is written.
+#[derive(Clone, Copy)]
+enum OutputTarget<'a> {
+    /// Fixed path ("-" for stdout).
+    Path(&'a str),
+    /// File name from a template, resolved once output properties are known.
+    Template {
+        dir: &'a Path,
+        template: &'a str,
+        seq: usize,
+    },
+}
+
+/// Expand an output file name template.
+///
+/// Supports `{stem}` (input file name without extension), `{ext}` (target
+/// format), `{width}` / `{height}` (from output properties), `{input_dir}`
+/// (directory of the input, see [`template_input_dir`]) and `{N}` (1-based
+/// sequence number). Unknown variables, and dimensions the output doesn't
+/// have, are left as-is.
+fn apply_template(
+    template: &str,
+    props: &Properties,
+    original_path: &Path,
+    seq: usize,
+) -> Result<String> {
+    let input_dir = if template.contains("{input_dir}") {
+        Some(template_input_dir(original_path)?)
+    } else {
+        None
+    };
+    let lookup = |name: &str| -> Option<String> {
+        match name {
+            "stem" => Some(
+                original_path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("output")
+                    .to_string(),
+            ),
+            "ext" => props
+                .get("format")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            "width" | "height" => props
+                .get(name)
+                .and_then(|v| v.as_i64())
+                .map(|n| n.to_string()),
+            "input_dir" => input_dir.clone(),
+            "N" => Some(seq.to_string()),
+            _ => None,
+        }
+    };
+
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start..];
+        match after
+            .find('}')
+            .and_then(|end| lookup(&after[1..end]).map(|v| (end, v)))
+        {
+            Some((end, value)) => {
+                out.push_str(&value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = &after[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// `{input_dir}` for an output template: the input's directory, kept
+/// relative so outputs stay inside the output directory.
+///
+/// A leading root (`/`, `C:\`) is dropped; paths with `..` are rejected.
+fn template_input_dir(input: &Path) -> Result<String> {
+    use std::path::Component;
+
+    let mut dir = PathBuf::new();
+    for component in input.parent().unwrap_or(Path::new("")).components() {
+        match component {
+            Component::Normal(part) => dir.push(part),
+            Component::ParentDir => bail!(
+                "{{input_dir}} can't be used with '{}': the path contains '..'",
+                input.display()
+            ),
+            Component::RootDir | Component::Prefix(_) | Component::CurDir => {}
+        }
+    }
+    if dir.as_os_str().is_empty() {
+        return Ok(".".to_string());
+    }
+    Ok(dir.to_string_lossy().into_owned())
+}
+
+/// Write an output file.
+///
+/// Unless `in_place`, the data goes to a `{path}.{pid}.tmp` sibling that
+/// is renamed over `path` once complete, so an interrupted write never
+/// leaves a partial file behind. The pid keeps concurrent runs apart.
+/// A symlinked `path` has its target replaced, and an existing file's
+/// permissions carry over. Special files such as `/dev/null` or a FIFO are
+/// always written in place.
+fn write_output_file(path: &Path, data: &[u8], in_place: bool) -> Result<()> {
+    let special = std::fs::metadata(path).is_ok_and(|m| !m.is_file() && !m.is_dir());
+    if in_place || special {
+        return std::fs::write(path, data).context("Failed to write output file");
+    }
+
+    // Renaming over a symlink would replace the link, not the file it points to
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let permissions = std::fs::metadata(&path).ok().map(|m| m.permissions());
+
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(".{}.tmp", std::process::id()));
+    let tmp = PathBuf::from(tmp);
+    let result = std::fs::write(&tmp, data)
+        .context("Failed to write output file")
+        .and_then(|()| match permissions {
+            Some(permissions) => std::fs::set_permissions(&tmp, permissions)
+                .context("Failed to copy output file permissions"),
+            None => Ok(()),
+        })
+        .and_then(|()| std::fs::rename(&tmp, &path).context("Failed to move output into place"));
+    if result.is_err() {
+        std::fs::remove_file(&tmp).ok();
+    }
+    result
+}
+
 #[allow(clippy::too_many_arguments)]
 fn convert_single_file(
-    registry: &Registry,
+    registry: &Arc<Registry>,
     input: &str,
-    output: &str,
+    output: OutputTarget,
     from: Option<String>,
     to: Option<String>,
     opts: &ConvertOptions,
@@ -1277,7 +2152,7 @@ fn convert_single_file(
     v: Verbosity,
 ) -> Result<()> {
     let is_stdin = input == "-";
-    let is_stdout = output == "-";
+    let is_stdout = matches!(output, OutputTarget::Path("-"));
 
     // Read input (from stdin or file)
     let mut current_data = if is_stdin {
@@ -1302,23 +2177,65 @@ fn convert_single_file(
 
     // Detect target format: --to flag > extension (no magic for output)
     let target_format = to
-        .or_else(|| {
-            if is_stdout {
-                None
-            } else {
-                detect_format(output)
-            }
+        .or_else(|| match output {
+            OutputTarget::Path(path) if !is_stdout => detect_format(path),
+            _ => None,
         })
         .ok_or_else(|| {
-            let path = if is_stdout { "<stdout>" } else { output };
+            let path = match output {
+                OutputTarget::Path(path) if !is_stdout => path,
+                _ => "<stdout>",
+            };
             anyhow::anyhow!("{}", errors::format_detection_error(path, false))
         })?;
 
     v.debug(&format!("Detected: {} -> {}", source_format, target_format));
-    let mut current_props = Properties::new().with("format", source_format.as_str());
+    let mut current_props = opts.from_props.clone();
+    current_props.insert("format".into(), source_format.as_str().into());
+
+    if let Some(time) = opts.frame_time {
+        current_props.insert("frame_time_secs".into(), time.into());
+    }
+    if opts.exif_auto_rotate {
+        current_props.insert("exif_auto_rotate".into(), true.into());
+    }
 
-    // Apply image transforms if any options are set
-    let needs_resize = opts.needs_resize();
+    // Extract a still frame first, so image transforms apply to it
+    #[cfg(feature = "video")]
+    let source_format = if opts.extract_frame {
+        let extractor = registry
+            .get("video.extract-frame")
+            .context("Frame extraction converter not available")?;
+
+        match extractor
+            .convert(&current_data, &current_props)
+            .map_err(|e| anyhow::anyhow!("Frame extraction failed: {}", errors::describe(&e)))?
+        {
+            ConvertOutput::Single(data, props) => {
+                current_data = data;
+                current_props = props;
+            }
+            _ => bail!("Unexpected output from frame extractor"),
+        }
+
+        current_props
+            .get("format")
+            .and_then(|v| v.as_str())
+            .unwrap_or("png")
+            .to_string()
+    } else {
+        source_format
+    };
+
+    #[cfg(not(feature = "video"))]
+    if opts.extract_frame {
+        bail!("Frame extraction requires the 'video' feature");
+    }
+
+    // Apply image transforms if any options are set. Same-format output is
+    // otherwise copied as-is, so rotate it with an identity resize.
+    let needs_resize = opts.needs_resize()
+        || (opts.exif_auto_rotate && source_format == target_format && opts.filter.is_none());
     let needs_crop = opts.aspect.is_some();
 
     if needs_resize || needs_crop {
@@ -1343,7 +2260,7 @@ fn convert_single_file(
 
             let result = crop_converter
                 .convert(&current_data, &current_props)
-                .map_err(|e| anyhow::anyhow!("Crop failed: {}", e))?;
+                .map_err(|e| anyhow::anyhow!("Crop failed: {}", errors::describe(&e)))?;
 
             match result {
                 ConvertOutput::Single(data, props) => {
@@ -1369,6 +2286,13 @@ fn convert_single_file(
             if let Some(s) = opts.eval_scale(&current_props) {
                 current_props.insert("scale".into(), s.into());
             }
+            if let Some(ref filter) = opts.resize_filter {
+                current_props.insert("resize_filter".into(), filter.clone().into());
+            }
+            if let Some(ref mode) = opts.resize_mode {
+                current_props.insert("resize_mode".into(), mode.clone().into());
+                current_props.insert("gravity".into(), opts.gravity.clone().into());
+            }
 
             let resize_converter = registry
                 .get("image.resize")
@@ -1376,7 +2300,7 @@ fn convert_single_file(
 
             let result = resize_converter
                 .convert(&current_data, &current_props)
-                .map_err(|e| anyhow::anyhow!("Resize failed: {}", e))?;
+                .map_err(|e| anyhow::anyhow!("Resize failed: {}", errors::describe(&e)))?;
 
             match result {
                 ConvertOutput::Single(data, props) => {
@@ -1390,6 +2314,9 @@ fn convert_single_file(
             current_props.shift_remove("max_width");
             current_props.shift_remove("max_height");
             current_props.shift_remove("scale");
+            current_props.shift_remove("resize_filter");
+            current_props.shift_remove("resize_mode");
+            current_props.shift_remove("gravity");
         }
     }
 
@@ -1450,7 +2377,7 @@ fn convert_single_file(
 
             let result = watermark_converter
                 .convert_multi(&inputs)
-                .map_err(|e| anyhow::anyhow!("Watermark failed: {}", e))?;
+                .map_err(|e| anyhow::anyhow!("Watermark failed: {}", errors::describe(&e)))?;
 
             match result {
                 ConvertOutput::Single(data, props) => {
@@ -1470,6 +2397,19 @@ fn convert_single_file(
         bail!("Watermark requires the 'image' feature");
     }
 
+    // Filter structured data (goes through JSON)
+    let source_format = if let Some(ref filter) = opts.filter {
+        (current_data, current_props) =
+            apply_filter(registry, current_data, current_props, filter, optimize)?;
+        "json".to_string()
+    } else {
+        source_format
+    };
+
+    opts.apply_serialization(&mut current_props);
+    // Target properties are options for the converters that produce it
+    current_props.extend(opts.to_props.clone());
+
     // Plan format conversion (if formats differ)
     if source_format != target_format {
         let target_pattern = PropertyPattern::new().eq("format", target_format.as_str());
@@ -1493,29 +2433,152 @@ fn convert_single_file(
             })?;
 
         // Execute format conversion plan using appropriate executor
-        let mut ctx = ExecutionContext::new(Arc::new(registry.clone()));
+        let mut ctx = ExecutionContext::new(Arc::clone(registry));
         if let Some(limit) = memory_limit {
             ctx = ctx.with_memory_limit(limit);
         }
+        if matches!(v, Verbosity::Verbose) {
+            ctx = ctx.with_debug_log(move |msg| v.debug(msg));
+        }
+
+        // Progress bar for slow single-file conversions
+        let pb = if current_data.len() > PROGRESS_MIN_INPUT_SIZE
+            && !matches!(v, Verbosity::Quiet)
+            && std::io::stdout().is_terminal()
+        {
+            let pb = ProgressBar::new(plan.steps.len() as u64);
+            pb.set_style(
+                ProgressStyle::default_bar()
+                    .template("{spinner:.green} [{bar:40.cyan/blue}] {msg}")
+                    .unwrap()
+                    .progress_chars("=> "),
+            );
+            let bar = pb.clone();
+            ctx = ctx.with_progress(move |step, total, converter_id| {
+                bar.set_position(step as u64 - 1);
+                bar.set_message(format!("Step {}/{}: {}", step, total, converter_id));
+            });
+            Some(pb)
+        } else {
+            None
+        };
 
         let result = if memory_limit.is_some() {
             BoundedExecutor::new().execute(&ctx, &plan, current_data, current_props)
         } else {
             SimpleExecutor::new().execute(&ctx, &plan, current_data, current_props)
+        };
+        if let Some(pb) = pb {
+            pb.finish_and_clear();
         }
-        .map_err(|e| anyhow::anyhow!("Conversion failed: {}", e))?;
+        let result =
+            result.map_err(|e| anyhow::anyhow!("Conversion failed: {}", errors::describe(&e)))?;
+        v.step_stats(&result.stats);
 
         current_data = result.data;
         current_props = result.props;
+    } else if opts.compact || opts.sorted_keys {
+        // Nothing to plan, but formatting options still need a re-serialize
+        (current_data, current_props) = reserialize(current_data, current_props, &target_format)?;
     }
 
+    // Pixel format conversion applies to the final encoding, so it knows
+    // whether the target format can hold the requested channels
+    if let Some(ref mode) = opts.color_mode {
+        #[cfg(feature = "image")]
+        {
+            current_props.insert("color_target".into(), mode.clone().into());
+            if current_props.get("width").is_none() {
+                let img = image::load_from_memory(&current_data)
+                    .context("Failed to decode image for color conversion")?;
+                current_props.insert("width".into(), (img.width() as i64).into());
+                current_props.insert("height".into(), (img.height() as i64).into());
+            }
+
+            let color_converter = registry
+                .get("image.color-convert")
+                .context("Color conversion converter not available")?;
+            match color_converter
+                .convert(&current_data, &current_props)
+                .map_err(|e| anyhow::anyhow!("Color conversion failed: {}", errors::describe(&e)))?
+            {
+                ConvertOutput::Single(data, props) => {
+                    current_data = data;
+                    current_props = props;
+                }
+                _ => bail!("Unexpected output from color converter"),
+            }
+        }
+
+        #[cfg(not(feature = "image"))]
+        bail!("--color-mode requires the 'image' feature");
+    }
+
+    // Palette quantization runs last so nothing re-encodes the indexed PNG
+    if let Some(colors) = opts.quantize {
+        #[cfg(feature = "image")]
+        {
+            if target_format != "png" {
+                bail!("--quantize requires PNG output");
+            }
+            current_props.insert("quantize_colors".into(), (colors as i64).into());
+
+            let quantizer = registry
+                .get("image.quantize")
+                .context("Quantize converter not available")?;
+            match quantizer
+                .convert(&current_data, &current_props)
+                .map_err(|e| anyhow::anyhow!("Quantization failed: {}", errors::describe(&e)))?
+            {
+                ConvertOutput::Single(data, props) => {
+                    current_data = data;
+                    current_props = props;
+                }
+                _ => bail!("Unexpected output from quantize converter"),
+            }
+        }
+
+        #[cfg(not(feature = "image"))]
+        bail!("--quantize requires the 'image' feature");
+    }
+
+    // Verify the output before anything is written
+    if let Some(ref expected) = opts.check {
+        check::check_output(registry, &current_data, expected)?;
+    }
+    if let Some(ref schema) = opts.check_schema {
+        check::check_schema(&current_data, schema)?;
+    }
+
+    // Resolve templated output names now that output properties are known
+    let output = match output {
+        OutputTarget::Path(path) => PathBuf::from(path),
+        OutputTarget::Template { dir, template, seq } => {
+            let path = dir.join(apply_template(
+                template,
+                &current_props,
+                Path::new(input),
+                seq,
+            )?);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).context("Failed to create output directory")?;
+            }
+            path
+        }
+    };
+    let output = output.to_string_lossy();
+
     // Write output (to stdout or file)
     if is_stdout {
         std::io::stdout()
             .write_all(&current_data)
             .context("Failed to write to stdout")?;
     } else {
-        std::fs::write(output, &current_data).context("Failed to write output file")?;
+        write_output_file(
+            Path::new(output.as_ref()),
+            &current_data,
+            opts.no_atomic_write,
+        )?;
     }
 
     // Report what was done (only if not using stdout for data)
@@ -1549,6 +2612,116 @@ fn convert_single_file(
     Ok(())
 }
 
+/// Round-trip same-format data through serde so serialization options apply.
+///
+/// Formats serde doesn't handle are returned unchanged.
+fn reserialize(data: Vec<u8>, props: Properties, format: &str) -> Result<(Vec<u8>, Properties)> {
+    #[cfg(feature = "serde")]
+    if let Some(format) = paraphase_serde::enabled_formats()
+        .into_iter()
+        .find(|f| *f == format)
+    {
+        use paraphase_core::Converter;
+
+        let converter = paraphase_serde::SerdeConverter::new(format, format);
+        return match converter
+            .convert(&data, &props)
+            .map_err(|e| anyhow::anyhow!("Re-serialization failed: {}", errors::describe(&e)))?
+        {
+            ConvertOutput::Single(data, props) => Ok((data, props)),
+            _ => bail!("Unexpected output from serde converter"),
+        };
+    }
+
+    #[cfg(not(feature = "serde"))]
+    let _ = format;
+    Ok((data, props))
+}
+
+/// Apply a `--filter` expression, converting the input to JSON first.
+///
+/// `filter` is either a JSONPath query (starting with `$`) or a
+/// comma-separated field list; fields prefixed with `-` are dropped
+/// instead of kept.
+fn apply_filter(
+    registry: &Arc<Registry>,
+    data: Vec<u8>,
+    props: Properties,
+    filter: &str,
+    optimize: Option<OptimizeTarget>,
+) -> Result<(Vec<u8>, Properties)> {
+    let source_format = props
+        .get("format")
+        .and_then(|v| v.as_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let (data, mut props) = if source_format == "json" {
+        (data, props)
+    } else {
+        let mut planner = Planner::new(registry);
+        if let Some(opt) = optimize {
+            planner = planner.optimize(opt);
+        }
+        let plan = planner
+            .plan(
+                &props,
+                &PropertyPattern::new().eq("format", "json"),
+                Cardinality::One,
+                Cardinality::One,
+            )
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "{}",
+                    errors::no_path_error(&source_format, "json", registry)
+                )
+            })?;
+
+        let ctx = ExecutionContext::new(Arc::clone(registry));
+        let result = SimpleExecutor::new()
+            .execute(&ctx, &plan, data, props)
+            .map_err(|e| anyhow::anyhow!("Conversion to JSON failed: {}", errors::describe(&e)))?;
+        (result.data, result.props)
+    };
+
+    let converter_id = if filter.starts_with('$') {
+        props.insert("jsonpath".into(), filter.into());
+        "serde.jsonpath-filter"
+    } else {
+        let fields: Vec<&str> = filter
+            .split(',')
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+            .collect();
+        let excluded: Vec<&str> = fields.iter().filter_map(|f| f.strip_prefix('-')).collect();
+
+        if excluded.is_empty() {
+            props.insert("json_include_fields".into(), fields.join(",").into());
+        } else if excluded.len() == fields.len() {
+            props.insert("json_exclude_fields".into(), excluded.join(",").into());
+        } else {
+            bail!("--filter cannot mix kept and dropped ('-') fields");
+        }
+        "serde.json-project"
+    };
+
+    let converter = registry.get(converter_id).with_context(|| {
+        if converter_id == "serde.jsonpath-filter" {
+            "JSONPath filters require the 'jsonpath' feature".to_string()
+        } else {
+            "Field filters require the 'json' feature".to_string()
+        }
+    })?;
+
+    match converter
+        .convert(&data, &props)
+        .map_err(|e| anyhow::anyhow!("Filter failed: {}", errors::describe(&e)))?
+    {
+        ConvertOutput::Single(data, props) => Ok((data, props)),
+        _ => bail!("Unexpected output from filter converter"),
+    }
+}
+
 /// Detect format from magic bytes using pure-magic.
 fn detect_format_from_magic(data: &[u8]) -> Option<String> {
     let db = magic_db::load().ok()?;
@@ -1573,6 +2746,7 @@ fn mime_to_format(mime: &str) -> Option<String> {
         "image/avif" => Some("avif".into()),
         "image/x-exr" => Some("exr".into()),
         "image/vnd.radiance" => Some("hdr".into()),
+        "image/svg+xml" => Some("svg".into()),
         // Audio
         "audio/x-wav" | "audio/wav" => Some("wav".into()),
         "audio/flac" | "audio/x-flac" => Some("flac".into()),
@@ -1592,20 +2766,57 @@ fn mime_to_format(mime: &str) -> Option<String> {
         "application/toml" | "text/x-toml" => Some("toml".into()),
         "application/cbor" => Some("cbor".into()),
         "application/msgpack" | "application/x-msgpack" => Some("msgpack".into()),
+        // Documents
+        "application/pdf" => Some("pdf".into()),
+        // Compression
+        "application/x-bzip2" => Some("bz2".into()),
+        "application/x-snappy-framed" => Some("snappy".into()),
         _ => None,
     }
 }
 
+/// Pick the target format from `--to` and the output path.
+///
+/// `--to` is authoritative; the output extension is the fallback. Warns if
+/// both are given and name different formats.
+fn resolve_target_format(to: Option<String>, output: Option<&str>, v: Verbosity) -> Option<String> {
+    let detected = output.filter(|path| *path != "-").and_then(detect_format);
+    match (to, detected) {
+        (Some(to), Some(detected)) => {
+            // Normalize aliases ("jpeg", "tgz") the same way extensions are
+            let normalized = detect_format(&format!(".{}", to)).unwrap_or_else(|| to.clone());
+            if normalized != detected {
+                v.warn(&format!(
+                    "--to {} disagrees with the output extension ({}); using {}",
+                    to, detected, to
+                ));
+            }
+            Some(to)
+        }
+        (to, detected) => to.or(detected),
+    }
+}
+
 /// Detect format from file extension.
+///
+/// Compound archive extensions like `.tar.gz` are recognized as a whole.
 fn detect_format(path: &str) -> Option<String> {
-    let ext = path.rsplit('.').next()?;
-    match ext.to_lowercase().as_str() {
+    let name = path.rsplit(['/', '\\']).next()?.to_lowercase();
+    let mut parts = name.rsplit('.');
+    let ext = parts.next()?;
+    if parts.next() == Some("tar") && matches!(ext, "gz" | "zst" | "bz2" | "br") {
+        return Some(format!("tar.{}", ext));
+    }
+    match ext {
         // Serde text formats
         "json" => Some("json".into()),
         "yaml" | "yml" => Some("yaml".into()),
         "toml" => Some("toml".into()),
         "ron" => Some("ron".into()),
         "json5" => Some("json5".into()),
+        "hcl" | "tf" => Some("hcl".into()),
+        "edn" => Some("edn".into()),
+        "kdl" => Some("kdl".into()),
         "xml" => Some("xml".into()),
         "lisp" | "sexp" | "lexpr" => Some("lexpr".into()),
         "csv" => Some("csv".into()),
@@ -1634,6 +2845,7 @@ fn detect_format(path: &str) -> Option<String> {
         "avif" => Some("avif".into()),
         "exr" => Some("exr".into()),
         "hdr" => Some("hdr".into()),
+        "svg" => Some("svg".into()),
         // Video formats
         "mp4" | "m4v" => Some("mp4".into()),
         "webm" => Some("webm".into()),
@@ -1646,6 +2858,550 @@ fn detect_format(path: &str) -> Option<String> {
         "mp3" => Some("mp3".into()),
         "ogg" | "oga" => Some("ogg".into()),
         "aac" | "m4a" => Some("aac".into()),
+        // Documents
+        "pdf" => Some("pdf".into()),
+        // Archives and compression
+        "tar" => Some("tar".into()),
+        "zip" => Some("zip".into()),
+        "bz2" => Some("bz2".into()),
+        "sz" => Some("snappy".into()),
+        "tgz" => Some("tar.gz".into()),
+        "tbz" | "tbz2" => Some("tar.bz2".into()),
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_list_filters() {
+        let mut registry = Registry::new();
+        paraphase_serde::register_all(&mut registry);
+
+        let ids = |decls: Vec<&ConverterDecl>| -> Vec<String> {
+            decls.into_iter().map(|decl| decl.id.clone()).collect()
+        };
+        let from_json = ids(filter_converters(&registry, Some("json"), None));
+        assert!(from_json.contains(&"serde.json-to-yaml".to_string()));
+        assert!(!from_json.contains(&"serde.yaml-to-json".to_string()));
+
+        let json_to_yaml = ids(filter_converters(&registry, Some("json"), Some("yaml")));
+        assert_eq!(json_to_yaml, ["serde.json-to-yaml"]);
+
+        let paths = conversion_paths(&registry, "yaml", "toml");
+        assert_eq!(paths[0].steps[0].converter_id, "serde.yaml-to-toml");
+        assert!(paths.len() > 1);
+        assert!(paths.windows(2).all(|w| w[0].cost <= w[1].cost));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_plan_json() {
+        let mut registry = Registry::new();
+        paraphase_serde::register_all(&mut registry);
+
+        let plan = plan_formats(&registry, "json", "yaml", None, None);
+        let json = plan_json(plan.as_ref(), "json");
+        assert_eq!(json["found"], true);
+        assert_eq!(json["steps"][0]["id"], "serde.json-to-yaml");
+        assert_eq!(json["steps"][0]["from"], "json");
+        assert_eq!(json["steps"][0]["to"], "yaml");
+        // Output is a single line of valid JSON
+        let text = json.to_string();
+        assert!(!text.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&text).unwrap(),
+            json
+        );
+
+        let plan = plan_formats(&registry, "json", "no-such-format", None, None);
+        assert!(plan.is_none());
+        let json = plan_json(plan.as_ref(), "json");
+        assert_eq!(json["found"], false);
+        assert_eq!(json["steps"].as_array().unwrap().len(), 0);
+        assert!(json["cost"].is_null());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_plan_validate_only() {
+        let mut registry = Registry::new();
+        paraphase_serde::register_all(&mut registry);
+
+        let validate = |to: &str| {
+            cmd_plan(
+                &registry,
+                "data.json",
+                Some("out".to_string()),
+                None,
+                Some(to.to_string()),
+                None,
+                None,
+                PlanOutput::Validate,
+                Verbosity::Quiet,
+            )
+        };
+
+        // main turns an Err into exit status 1
+        assert!(validate("yaml").is_ok());
+        let err = validate("no-such-format").unwrap_err();
+        assert!(err.to_string().contains("no-such-format"), "{}", err);
+    }
+
+    #[test]
+    #[cfg(all(feature = "http", feature = "serde"))]
+    fn test_run_http_workflow() {
+        let mut registry = Registry::new();
+        paraphase_serde::register_all(&mut registry);
+        let registry = Arc::new(registry);
+
+        let mut server = mockito::Server::new();
+        let source = server
+            .mock("GET", "/config")
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"name": "paraphase"}"#)
+            .create();
+        let sink = server
+            .mock("POST", "/upload")
+            .match_body("name: paraphase\n")
+            .create();
+
+        let workflow = format!(
+            "source:\n  url: {url}/config\nsink:\n  url: {url}/upload\n  format: yaml\n",
+            url = server.url()
+        );
+        let dir = std::env::temp_dir().join(format!("paraphase-http-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("workflow.yaml");
+        std::fs::write(&path, workflow).unwrap();
+
+        let result = cmd_run(&registry, &path, None, Verbosity::Quiet);
+        std::fs::remove_dir_all(&dir).unwrap();
+        result.unwrap();
+
+        source.assert();
+        sink.assert();
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_run_workflow_jpeg_quality() {
+        let mut registry = Registry::new();
+        paraphase_image::register_all(&mut registry);
+        let registry = Arc::new(registry);
+
+        let dir = std::env::temp_dir().join(format!("paraphase-quality-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // Noisy gradient so quality has a measurable effect on size
+        let img = image::RgbImage::from_fn(64, 64, |x, y| {
+            let n = (x.wrapping_mul(7919) ^ y.wrapping_mul(104729)) as u8;
+            image::Rgb([(x * 4) as u8 ^ n, (y * 4) as u8, n])
+        });
+        let input = dir.join("in.png");
+        img.save(&input).unwrap();
+
+        let run = |quality: u32| {
+            let output = dir.join(format!("q{}.jpg", quality));
+            let workflow = format!(
+                "source:\n  path: {}\nsteps:\n  - converter: image.png-to-jpg\n    properties:\n      jpeg_quality: {}\nsink:\n  path: {}\n",
+                input.display(),
+                quality,
+                output.display()
+            );
+            let path = dir.join(format!("q{}.yaml", quality));
+            std::fs::write(&path, workflow).unwrap();
+            cmd_run(&registry, &path, None, Verbosity::Quiet).unwrap();
+            std::fs::read(&output).unwrap()
+        };
+
+        let low = run(10);
+        let high = run(90);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(low.starts_with(&[0xFF, 0xD8, 0xFF]));
+        assert!(
+            low.len() < high.len() / 2,
+            "quality 10 ({} bytes) should be much smaller than quality 90 ({} bytes)",
+            low.len(),
+            high.len()
+        );
+    }
+
+    #[test]
+    fn test_bzip2_formats() {
+        assert_eq!(detect_format("data.json.bz2").as_deref(), Some("bz2"));
+        assert_eq!(detect_format("src.TBZ2").as_deref(), Some("tar.bz2"));
+        assert_eq!(
+            parse_compound_archive("tar.bz2").unwrap(),
+            ("tar", Some("bz2"))
+        );
+        assert_eq!(parse_compound_archive("tbz").unwrap(), ("tar", Some("bz2")));
+    }
+
+    #[test]
+    #[cfg(feature = "tar")]
+    fn test_stream_tar() {
+        use paraphase_core::Converter;
+
+        let dir = std::env::temp_dir().join(format!("paraphase-stream-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let a = dir.join("a.txt");
+        let b = dir.join("b.bin");
+        std::fs::write(&a, "hello").unwrap();
+        std::fs::write(&b, vec![7u8; 100_000]).unwrap();
+        let inputs = [a, b].map(|p| p.to_string_lossy().to_string());
+        let output = dir.join("out.tar").to_string_lossy().to_string();
+
+        let opts = ConvertOptions {
+            base_dir: Some("files".into()),
+            ..Default::default()
+        };
+        stream_tar(&inputs, &output, "tar", &opts, Verbosity::Quiet).unwrap();
+
+        let archive = std::fs::read(&output).unwrap();
+        let props = Properties::new().with("format", "tar");
+        let entries: Vec<(String, usize)> = match paraphase_serde::TarExtract
+            .convert(&archive, &props)
+            .unwrap()
+        {
+            ConvertOutput::Multiple(files) => files
+                .into_iter()
+                .map(|(data, props)| (props["path"].as_str().unwrap().to_string(), data.len()))
+                .collect(),
+            _ => panic!("Expected multiple"),
+        };
+        assert_eq!(
+            entries,
+            [
+                ("files/a.txt".to_string(), 5),
+                ("files/b.bin".to_string(), 100_000)
+            ]
+        );
+
+        // Compressed targets need the in-memory path
+        assert!(stream_tar(&inputs, &output, "tar.gz", &opts, Verbosity::Quiet).is_err());
+
+        // A missing input removes the partial archive
+        let missing = [
+            inputs[0].clone(),
+            dir.join("nope").to_string_lossy().to_string(),
+        ];
+        assert!(stream_tar(&missing, &output, "tar", &opts, Verbosity::Quiet).is_err());
+        assert!(!Path::new(&output).exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_output_file() {
+        let dir = std::env::temp_dir().join(format!("paraphase-atomic-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let leftovers = |dir: &Path| {
+            std::fs::read_dir(dir)
+                .unwrap()
+                .filter(|e| e.as_ref().unwrap().path().extension() == Some("tmp".as_ref()))
+                .count()
+        };
+
+        // Complete output, no temp file left
+        let output = dir.join("out.json");
+        std::fs::write(&output, "old").unwrap();
+        write_output_file(&output, b"{\"new\": true}", false).unwrap();
+        assert_eq!(std::fs::read(&output).unwrap(), b"{\"new\": true}");
+        assert_eq!(leftovers(&dir), 0);
+
+        // A failed rename leaves neither a partial output nor the temp file
+        let blocked = dir.join("blocked");
+        std::fs::create_dir_all(blocked.join("child")).unwrap();
+        assert!(write_output_file(&blocked, b"data", false).is_err());
+        assert!(blocked.is_dir());
+        assert_eq!(leftovers(&dir), 0);
+
+        write_output_file(&output, b"in place", true).unwrap();
+        assert_eq!(std::fs::read(&output).unwrap(), b"in place");
+
+        // Devices are never replaced
+        #[cfg(unix)]
+        write_output_file(Path::new("/dev/null"), b"discarded", false).unwrap();
+
+        // Symlinks keep pointing at the rewritten target, which keeps its mode
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            std::fs::set_permissions(&output, std::fs::Permissions::from_mode(0o600)).unwrap();
+            let link = dir.join("link.json");
+            std::os::unix::fs::symlink(&output, &link).unwrap();
+            write_output_file(&link, b"via link", false).unwrap();
+            assert!(link.symlink_metadata().unwrap().file_type().is_symlink());
+            assert_eq!(std::fs::read(&output).unwrap(), b"via link");
+            let mode = std::fs::metadata(&output).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+            assert_eq!(leftovers(&dir), 0);
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_compound_archive_extensions() {
+        assert_eq!(detect_format("out.tar.gz").as_deref(), Some("tar.gz"));
+        assert_eq!(detect_format("out.tar.zst").as_deref(), Some("tar.zst"));
+        assert_eq!(
+            detect_format("dist/v1.2/SRC.TAR.BZ2").as_deref(),
+            Some("tar.bz2")
+        );
+        assert_eq!(detect_format("out.tgz").as_deref(), Some("tar.gz"));
+        assert_eq!(detect_format("out.tar").as_deref(), Some("tar"));
+        assert_eq!(detect_format("notes.bz2").as_deref(), Some("bz2"));
+
+        let resolve = |to: Option<&str>, output| {
+            resolve_target_format(to.map(String::from), output, Verbosity::Quiet)
+        };
+        assert_eq!(resolve(None, Some("out.tar.gz")).as_deref(), Some("tar.gz"));
+        assert_eq!(
+            resolve(Some("tgz"), Some("out.tar.gz")).as_deref(),
+            Some("tgz")
+        );
+        assert_eq!(
+            resolve(Some("yaml"), Some("out.json")).as_deref(),
+            Some("yaml")
+        );
+        assert_eq!(resolve(None, Some("-")), None);
+    }
+
+    #[test]
+    fn test_props_args() {
+        let cli = Cli::try_parse_from([
+            "paraphase",
+            "convert",
+            "book.xlsx",
+            "--from-props",
+            "sheet=Sheet1",
+            "headers=true",
+            "--to-props",
+            "format=json",
+            "bzip2_level=9",
+        ])
+        .unwrap();
+        let Commands::Convert {
+            input,
+            from_props,
+            to_props,
+            ..
+        } = cli.command
+        else {
+            panic!("Expected convert");
+        };
+        assert_eq!(input, ["book.xlsx"]);
+
+        let props = parse_props_args(&from_props).unwrap();
+        assert_eq!(props.get("sheet"), Some(&Value::from("Sheet1")));
+        assert_eq!(props.get("headers"), Some(&Value::Bool(true)));
+
+        let mut props = parse_props_args(&to_props).unwrap();
+        assert_eq!(take_format(&mut props).as_deref(), Some("json"));
+        assert_eq!(props, Properties::new().with("bzip2_level", 9));
+
+        assert_eq!(parse_prop_value("1.5"), Value::Float(1.5));
+        assert_eq!(parse_prop_value("nan"), Value::from("nan"));
+        assert!(parse_props_args(&["=1".into()]).is_err());
+        assert!(parse_props_args(&["sheet".into()]).is_err());
+
+        let path =
+            std::env::temp_dir().join(format!("paraphase-props-{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"sheet": "Sheet2", "headers": false}"#).unwrap();
+        let props =
+            parse_props_args(&[format!("@{}", path.display()), "headers=true".into()]).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(props.get("sheet"), Some(&Value::from("Sheet2")));
+        assert_eq!(props.get("headers"), Some(&Value::Bool(true)));
+    }
+
+    #[test]
+    fn test_apply_template() {
+        let props = Properties::new()
+            .with("format", "jpg")
+            .with("width", 320)
+            .with("height", 240);
+        let input = Path::new("photos/cat.png");
+
+        assert_eq!(
+            apply_template(DEFAULT_OUTPUT_TEMPLATE, &props, input, 1).unwrap(),
+            "cat.jpg"
+        );
+        assert_eq!(
+            apply_template("thumb_{width}x{height}_{stem}.jpg", &props, input, 1).unwrap(),
+            "thumb_320x240_cat.jpg"
+        );
+        assert_eq!(
+            apply_template("{input_dir}/{N}_{stem}_compressed.{ext}", &props, input, 3).unwrap(),
+            "photos/3_cat_compressed.jpg"
+        );
+
+        // Missing values and unknown variables are left alone
+        let props = Properties::new().with("format", "json");
+        assert_eq!(
+            apply_template("{stem}_{width}{nope}.{ext}", &props, Path::new("a.yaml"), 1).unwrap(),
+            "a_{width}{nope}.json"
+        );
+        assert_eq!(
+            apply_template("{input_dir}/{stem}", &props, Path::new("a.yaml"), 1).unwrap(),
+            "./a"
+        );
+
+        // {input_dir} never leaves the output directory
+        assert_eq!(
+            apply_template("{input_dir}/{stem}", &props, Path::new("/data/./a.yaml"), 1).unwrap(),
+            "data/a"
+        );
+        assert!(apply_template("{input_dir}/{stem}", &props, Path::new("../a.yaml"), 1).is_err());
+        assert!(apply_template("{stem}", &props, Path::new("../a.yaml"), 1).is_ok());
+    }
+
+    #[test]
+    #[cfg(all(unix, feature = "serde"))]
+    fn test_convert_output_template() {
+        let mut registry = Registry::new();
+        paraphase_serde::register_all(&mut registry);
+        let registry = Arc::new(registry);
+
+        let dir = std::env::temp_dir().join(format!("paraphase-template-{}", std::process::id()));
+        let input = dir.join("in").join("config.json");
+        std::fs::create_dir_all(input.parent().unwrap()).unwrap();
+        std::fs::write(&input, r#"{"name": "app"}"#).unwrap();
+        let out_dir = dir.join("out");
+
+        let convert = |input: &Path| {
+            cmd_convert(
+                &registry,
+                vec![input.to_string_lossy().into_owned()],
+                None,
+                Some(out_dir.clone()),
+                Some("{input_dir}/{N}_{stem}.{ext}".to_string()),
+                Some("json".to_string()),
+                Some("yaml".to_string()),
+                ConvertOptions::default(),
+                None,
+                None,
+                false,
+                Verbosity::Quiet,
+            )
+        };
+
+        // An absolute input path is re-rooted under the output directory
+        convert(&input).unwrap();
+        let relative = input.parent().unwrap().strip_prefix("/").unwrap();
+        let output = out_dir.join(relative).join("1_config.yaml");
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), "name: app\n");
+
+        // `..` is rejected rather than written outside the output directory
+        let err = convert(&dir.join("out").join("..").join("in").join("config.json")).unwrap_err();
+        assert!(err.to_string().contains("'..'"), "{}", err);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_same_format_serialization() {
+        let mut registry = Registry::new();
+        paraphase_serde::register_all(&mut registry);
+        let registry = Arc::new(registry);
+
+        let dir = std::env::temp_dir().join(format!("paraphase-reformat-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("in.json");
+        std::fs::write(&input, "{\n  \"b\": 1,\n  \"a\": [true]\n}").unwrap();
+
+        let convert = |name: &str, opts: ConvertOptions| {
+            let output = dir.join(name);
+            cmd_convert(
+                &registry,
+                vec![input.to_string_lossy().into_owned()],
+                Some(output.to_string_lossy().into_owned()),
+                None,
+                None,
+                None,
+                None,
+                opts,
+                None,
+                None,
+                false,
+                Verbosity::Quiet,
+            )
+            .unwrap();
+            std::fs::read_to_string(output).unwrap()
+        };
+
+        let opts = ConvertOptions {
+            compact: true,
+            ..Default::default()
+        };
+        assert_eq!(convert("compact.json", opts), r#"{"b":1,"a":[true]}"#);
+        let opts = ConvertOptions {
+            sorted_keys: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert("sorted.json", opts),
+            "{\n  \"a\": [\n    true\n  ],\n  \"b\": 1\n}"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_user_preset() {
+        let dir = std::env::temp_dir().join(format!("paraphase-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(
+            &path,
+            "[defaults]\nverbose = true\n\n\
+             [presets.blog]\nmax_width = 800\ngravity = \"top\"\nquality = \"low\"\n\n\
+             [presets.web]\nmax_width = 640\n",
+        )
+        .unwrap();
+        let config = Config::load_from_path(Some(path));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(config.defaults.verbose);
+
+        let options = |preset: &str, max_width: Option<u32>| {
+            build_convert_options(
+                &config,
+                Some(preset.into()),
+                max_width,
+                None,
+                None,
+                None,
+                "center".into(),
+                None,
+                "bottom-right".into(),
+                0.5,
+                10,
+                None,
+            )
+            .unwrap()
+        };
+        let blog = options("blog", None);
+        assert_eq!(blog.max_width.unwrap().as_literal(), Some(800.0));
+        assert_eq!(blog.gravity, "top");
+        assert_eq!(blog.quality.as_deref(), Some("low"));
+
+        // CLI flags win over the preset
+        let blog = options("blog", Some(400));
+        assert_eq!(blog.max_width.unwrap().as_literal(), Some(400.0));
+
+        // User presets shadow built-ins; other built-ins stay available
+        let web = options("web", None);
+        assert_eq!(web.max_width.unwrap().as_literal(), Some(640.0));
+        assert!(web.max_height.is_none());
+        assert!(config.get_preset("thumbnail").is_some());
+        assert!(config.get_preset("missing").is_none());
+    }
+}
@@ -0,0 +1,588 @@
+//! Async conversion API (requires the `tokio` feature).
+//!
+//! Mirrors the synchronous [`Converter`] / [`Registry`] / [`Executor`] stack
+//! for converters that wait on I/O (HTTP sources and sinks, databases, GPU
+//! queues). The synchronous API is unchanged; this module is purely additive.
+//!
+//! - [`AsyncConverter`]: converter whose `convert` returns a future
+//! - [`SyncToAsyncConverter`]: adapts any [`Converter`]
+//! - [`AsyncRegistry`]: holds async converters (and wrapped sync ones)
+//! - [`AsyncPlanner`]: plans over an [`AsyncRegistry`]
+//! - [`AsyncExecutor`] / [`AsyncSimpleExecutor`]: run plans, including
+//!   aggregating ones; wrapped sync converters run on tokio's blocking pool
+//!   so they don't stall the runtime
+//!
+//! # Migration
+//!
+//! Existing converters keep working unchanged. To run them from async code,
+//! wrap the registry you already build:
+//!
+//! ```ignore
+//! // Before
+//! let plan = Planner::new(&registry).plan(&from, &to, Cardinality::One, Cardinality::One)?;
+//! let ctx = ExecutionContext::new(Arc::new(registry));
+//! let result = SimpleExecutor::new().execute(&ctx, &plan, data, from)?;
+//!
+//! // After
+//! let registry = AsyncRegistry::from_registry(&registry);
+//! let plan = AsyncPlanner::new(&registry).plan(&from, &to, Cardinality::One, Cardinality::One)?;
+//! let result = AsyncSimpleExecutor::new().execute(&registry, &plan, data, from).await?;
+//! ```
+//!
+//! Native async converters implement [`AsyncConverter`] and are added with
+//! [`AsyncRegistry::register`]; sync ones can be mixed in with
+//! [`AsyncRegistry::register_sync`]. Trait methods return boxed futures so the
+//! registry can store `dyn AsyncConverter`; implement them with
+//! `Box::pin(async move { ... })`.
+//!
+//! [`Executor`]: crate::Executor
+
+use crate::converter::{ConvertError, ConvertOutput, Converter, ConverterDecl};
+use crate::executor::{
+    ExecuteError, ExecutionResult, ExecutionStats, StepStats, apply_output_overrides, run_step,
+};
+use crate::pattern::PropertyPattern;
+use crate::planner::{Cardinality, OptimizeTarget, Plan, PlanStep, Planner};
+use crate::properties::Properties;
+use crate::registry::Registry;
+use indexmap::IndexMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// A boxed, sendable future.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A converter that runs asynchronously.
+pub trait AsyncConverter: Send + Sync {
+    /// Get the declaration for this converter.
+    fn decl(&self) -> &ConverterDecl;
+
+    /// Convert a single input.
+    fn convert<'a>(
+        &'a self,
+        input: &'a [u8],
+        props: &'a Properties,
+    ) -> BoxFuture<'a, Result<ConvertOutput, ConvertError>>;
+
+    /// Convert a batch of inputs (for aggregating converters).
+    ///
+    /// Default implementation returns an error.
+    fn convert_batch<'a>(
+        &'a self,
+        inputs: &'a [(&'a [u8], &'a Properties)],
+    ) -> BoxFuture<'a, Result<ConvertOutput, ConvertError>> {
+        let _ = inputs;
+        Box::pin(async { Err(ConvertError::BatchNotSupported) })
+    }
+
+    /// The wrapped synchronous converter, if this is an adapter.
+    ///
+    /// Executors use it to move CPU-bound work onto the blocking pool.
+    fn as_sync(&self) -> Option<Arc<dyn Converter>> {
+        None
+    }
+}
+
+/// Adapts a synchronous [`Converter`] to [`AsyncConverter`].
+///
+/// Calling [`AsyncConverter::convert`] directly runs the converter on the
+/// current task; [`AsyncSimpleExecutor`] runs it via `spawn_blocking` instead.
+pub struct SyncToAsyncConverter<C: ?Sized = dyn Converter> {
+    inner: Arc<C>,
+}
+
+impl<C: Converter> SyncToAsyncConverter<C> {
+    /// Wrap a converter.
+    pub fn new(converter: C) -> Self {
+        Self {
+            inner: Arc::new(converter),
+        }
+    }
+}
+
+impl SyncToAsyncConverter {
+    /// Wrap a shared converter, e.g. from [`Registry::get`].
+    pub fn from_arc(converter: Arc<dyn Converter>) -> Self {
+        Self { inner: converter }
+    }
+}
+
+impl<C: Converter + 'static> AsyncConverter for SyncToAsyncConverter<C> {
+    fn decl(&self) -> &ConverterDecl {
+        self.inner.decl()
+    }
+
+    fn convert<'a>(
+        &'a self,
+        input: &'a [u8],
+        props: &'a Properties,
+    ) -> BoxFuture<'a, Result<ConvertOutput, ConvertError>> {
+        Box::pin(async move { self.inner.convert(input, props) })
+    }
+
+    fn convert_batch<'a>(
+        &'a self,
+        inputs: &'a [(&'a [u8], &'a Properties)],
+    ) -> BoxFuture<'a, Result<ConvertOutput, ConvertError>> {
+        Box::pin(async move { self.inner.convert_batch(inputs) })
+    }
+
+    fn as_sync(&self) -> Option<Arc<dyn Converter>> {
+        Some(self.inner.clone())
+    }
+}
+
+impl AsyncConverter for SyncToAsyncConverter<dyn Converter> {
+    fn decl(&self) -> &ConverterDecl {
+        self.inner.decl()
+    }
+
+    fn convert<'a>(
+        &'a self,
+        input: &'a [u8],
+        props: &'a Properties,
+    ) -> BoxFuture<'a, Result<ConvertOutput, ConvertError>> {
+        Box::pin(async move { self.inner.convert(input, props) })
+    }
+
+    fn convert_batch<'a>(
+        &'a self,
+        inputs: &'a [(&'a [u8], &'a Properties)],
+    ) -> BoxFuture<'a, Result<ConvertOutput, ConvertError>> {
+        Box::pin(async move { self.inner.convert_batch(inputs) })
+    }
+
+    fn as_sync(&self) -> Option<Arc<dyn Converter>> {
+        Some(self.inner.clone())
+    }
+}
+
+/// Registry of async converters.
+///
+/// Declarations live in a plain [`Registry`], so planning works exactly as
+/// in the synchronous API.
+#[derive(Clone, Default)]
+pub struct AsyncRegistry {
+    declarations: Registry,
+    implementations: IndexMap<String, Arc<dyn AsyncConverter>>,
+}
+
+impl AsyncRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap every converter of a synchronous registry.
+    ///
+    /// Declaration-only entries are kept for planning but can't be executed.
+    pub fn from_registry(registry: &Registry) -> Self {
+        let mut async_registry = Self::new();
+        for decl in registry.declarations() {
+            match registry.get(&decl.id) {
+                Some(converter) => {
+                    async_registry.register(SyncToAsyncConverter::from_arc(converter))
+                }
+                None => async_registry.declarations.register_decl(decl.clone()),
+            }
+        }
+        async_registry
+    }
+
+    /// Register an async converter.
+    pub fn register(&mut self, converter: impl AsyncConverter + 'static) {
+        let decl = converter.decl().clone();
+        self.implementations
+            .insert(decl.id.clone(), Arc::new(converter));
+        self.declarations.register_decl(decl);
+    }
+
+    /// Register a synchronous converter, wrapped in [`SyncToAsyncConverter`].
+    pub fn register_sync(&mut self, converter: impl Converter + 'static) {
+        self.register(SyncToAsyncConverter::new(converter));
+    }
+
+    /// Get a converter by ID.
+    pub fn get(&self, id: &str) -> Option<Arc<dyn AsyncConverter>> {
+        self.implementations.get(id).cloned()
+    }
+
+    /// The declarations, as a synchronous registry for planning.
+    pub fn declarations(&self) -> &Registry {
+        &self.declarations
+    }
+
+    /// Number of registered converters.
+    pub fn len(&self) -> usize {
+        self.declarations.len()
+    }
+
+    /// Check if registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+}
+
+/// Planner over an [`AsyncRegistry`].
+///
+/// Planning never blocks on I/O, so `plan` stays synchronous; this only
+/// saves reaching into [`AsyncRegistry::declarations`].
+pub struct AsyncPlanner<'a> {
+    inner: Planner<'a>,
+}
+
+impl<'a> AsyncPlanner<'a> {
+    /// Create a new planner with the given registry.
+    pub fn new(registry: &'a AsyncRegistry) -> Self {
+        Self {
+            inner: Planner::new(&registry.declarations),
+        }
+    }
+
+    /// Set maximum search depth.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.inner = self.inner.with_max_depth(depth);
+        self
+    }
+
+    /// Set optimization target for path selection.
+    pub fn optimize(mut self, target: OptimizeTarget) -> Self {
+        self.inner = self.inner.optimize(target);
+        self
+    }
+
+    /// Find a conversion path from source to target properties.
+    pub fn plan(
+        &self,
+        source: &Properties,
+        target: &PropertyPattern,
+        source_cardinality: Cardinality,
+        target_cardinality: Cardinality,
+    ) -> Option<Plan> {
+        self.inner
+            .plan(source, target, source_cardinality, target_cardinality)
+    }
+}
+
+/// Async counterpart of [`crate::Executor`].
+pub trait AsyncExecutor: Send + Sync {
+    /// Execute a conversion plan, returning the first output.
+    fn execute<'a>(
+        &'a self,
+        registry: &'a AsyncRegistry,
+        plan: &'a Plan,
+        input: Vec<u8>,
+        props: Properties,
+    ) -> BoxFuture<'a, Result<ExecutionResult, ExecuteError>>;
+
+    /// Execute a conversion plan with expansion support.
+    ///
+    /// Default implementation calls `execute` (single output only).
+    fn execute_expanding<'a>(
+        &'a self,
+        registry: &'a AsyncRegistry,
+        plan: &'a Plan,
+        input: Vec<u8>,
+        props: Properties,
+    ) -> BoxFuture<'a, Result<Vec<ExecutionResult>, ExecuteError>> {
+        Box::pin(async move {
+            self.execute(registry, plan, input, props)
+                .await
+                .map(|r| vec![r])
+        })
+    }
+
+    /// Execute an aggregating conversion (N inputs → 1 output).
+    ///
+    /// Same semantics as [`crate::Executor::execute_aggregating`]: steps
+    /// before the aggregating converter run on each input, the aggregator
+    /// combines them, and the remaining steps run on the combined output.
+    ///
+    /// Default implementation runs sequentially.
+    fn execute_aggregating<'a>(
+        &'a self,
+        registry: &'a AsyncRegistry,
+        plan: &'a Plan,
+        inputs: Vec<(Vec<u8>, Properties)>,
+    ) -> BoxFuture<'a, Result<ExecutionResult, ExecuteError>> {
+        Box::pin(async move {
+            if plan.steps.is_empty() {
+                return Err(ExecuteError::EmptyPlan);
+            }
+
+            let start = Instant::now();
+            let mut peak_memory = inputs.iter().map(|(d, _)| d.len()).sum::<usize>();
+
+            let aggregate_idx = plan
+                .steps
+                .iter()
+                .position(|step| {
+                    registry
+                        .get(&step.converter_id)
+                        .is_some_and(|c| c.decl().aggregates())
+                })
+                .unwrap_or(plan.steps.len() - 1);
+            let aggregate_step = &plan.steps[aggregate_idx];
+
+            let mut per_step_stats: Vec<StepStats> = plan
+                .steps
+                .iter()
+                .map(|step| StepStats::new(&step.converter_id))
+                .collect();
+
+            // Phase 1: Process each input through pre-aggregation steps
+            let mut processed = Vec::with_capacity(inputs.len());
+            for (data, props) in inputs {
+                processed.push(
+                    run_chain(
+                        registry,
+                        plan,
+                        0..aggregate_idx,
+                        (data, props),
+                        &mut per_step_stats,
+                        &mut peak_memory,
+                    )
+                    .await?,
+                );
+            }
+
+            // Phase 2: Run the aggregating step
+            let aggregator = registry.get(&aggregate_step.converter_id).ok_or_else(|| {
+                ExecuteError::ConverterNotFound(aggregate_step.converter_id.clone())
+            })?;
+
+            if !aggregate_step.input_properties.is_empty() {
+                for (_, props) in &mut processed {
+                    props.extend(aggregate_step.input_properties.clone());
+                }
+            }
+
+            let batch_bytes = processed.iter().map(|(d, _)| d.len()).sum();
+            let step_start = Instant::now();
+            let output = run_async_batch(aggregator.as_ref(), aggregate_step, processed)
+                .await
+                .map_err(|e| ExecuteError::ConversionFailed {
+                    step: aggregate_idx,
+                    source: e,
+                })?;
+            per_step_stats[aggregate_idx].record(batch_bytes, &output, step_start.elapsed());
+
+            let aggregated = match output {
+                ConvertOutput::Single(data, props) => (data, props),
+                ConvertOutput::Multiple(mut outputs) => {
+                    outputs.pop().ok_or(ExecuteError::EmptyPlan)?
+                }
+            };
+            peak_memory = peak_memory.max(aggregated.0.len());
+
+            // Phase 3: Process aggregated output through post-aggregation steps
+            let (data, props) = run_chain(
+                registry,
+                plan,
+                aggregate_idx + 1..plan.steps.len(),
+                aggregated,
+                &mut per_step_stats,
+                &mut peak_memory,
+            )
+            .await?;
+
+            Ok(ExecutionResult {
+                data,
+                props,
+                stats: ExecutionStats {
+                    duration: start.elapsed(),
+                    peak_memory,
+                    steps_executed: plan.steps.len(),
+                    per_step_stats,
+                },
+            })
+        })
+    }
+}
+
+/// Run a range of plan steps on one item, keeping the last output of any
+/// expanding step (as the synchronous aggregating executor does).
+async fn run_chain(
+    registry: &AsyncRegistry,
+    plan: &Plan,
+    steps: std::ops::Range<usize>,
+    item: (Vec<u8>, Properties),
+    per_step_stats: &mut [StepStats],
+    peak_memory: &mut usize,
+) -> Result<(Vec<u8>, Properties), ExecuteError> {
+    let (mut current_data, mut current_props) = item;
+    for step_idx in steps {
+        let step = &plan.steps[step_idx];
+        let converter = registry
+            .get(&step.converter_id)
+            .ok_or_else(|| ExecuteError::ConverterNotFound(step.converter_id.clone()))?;
+
+        let input_bytes = current_data.len();
+        let step_start = Instant::now();
+        let output = run_async_step(converter.as_ref(), step, current_data, current_props)
+            .await
+            .map_err(|e| ExecuteError::ConversionFailed {
+                step: step_idx,
+                source: e,
+            })?;
+        per_step_stats[step_idx].record(input_bytes, &output, step_start.elapsed());
+
+        (current_data, current_props) = match output {
+            ConvertOutput::Single(data, props) => (data, props),
+            ConvertOutput::Multiple(mut outputs) => outputs.pop().ok_or(ExecuteError::EmptyPlan)?,
+        };
+        *peak_memory = (*peak_memory).max(current_data.len());
+    }
+    Ok((current_data, current_props))
+}
+
+/// Sequential async executor.
+///
+/// Steps run one after another. Wrapped synchronous converters run via
+/// `tokio::task::spawn_blocking`, native async converters are awaited on
+/// the current task. Must be used from within a tokio runtime.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsyncSimpleExecutor;
+
+impl AsyncSimpleExecutor {
+    /// Create a new async executor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl AsyncExecutor for AsyncSimpleExecutor {
+    fn execute<'a>(
+        &'a self,
+        registry: &'a AsyncRegistry,
+        plan: &'a Plan,
+        input: Vec<u8>,
+        props: Properties,
+    ) -> BoxFuture<'a, Result<ExecutionResult, ExecuteError>> {
+        Box::pin(async move {
+            let results = self.execute_expanding(registry, plan, input, props).await?;
+            results.into_iter().next().ok_or(ExecuteError::EmptyPlan)
+        })
+    }
+
+    fn execute_expanding<'a>(
+        &'a self,
+        registry: &'a AsyncRegistry,
+        plan: &'a Plan,
+        input: Vec<u8>,
+        props: Properties,
+    ) -> BoxFuture<'a, Result<Vec<ExecutionResult>, ExecuteError>> {
+        Box::pin(async move {
+            let start = Instant::now();
+            let mut peak_memory = input.len();
+            let mut items: Vec<(Vec<u8>, Properties)> = vec![(input, props)];
+            let mut per_step_stats = Vec::with_capacity(plan.steps.len());
+
+            for (step_idx, step) in plan.steps.iter().enumerate() {
+                let converter = registry
+                    .get(&step.converter_id)
+                    .ok_or_else(|| ExecuteError::ConverterNotFound(step.converter_id.clone()))?;
+
+                let mut next_items = Vec::new();
+                let mut step_stats = StepStats::new(&step.converter_id);
+
+                for (data, props) in items {
+                    let input_bytes = data.len();
+                    let step_start = Instant::now();
+                    let output = run_async_step(converter.as_ref(), step, data, props)
+                        .await
+                        .map_err(|e| ExecuteError::ConversionFailed {
+                            step: step_idx,
+                            source: e,
+                        })?;
+                    step_stats.record(input_bytes, &output, step_start.elapsed());
+
+                    match output {
+                        ConvertOutput::Single(out_data, out_props) => {
+                            peak_memory = peak_memory.max(out_data.len());
+                            next_items.push((out_data, out_props));
+                        }
+                        ConvertOutput::Multiple(outputs) => {
+                            for (out_data, out_props) in outputs {
+                                peak_memory = peak_memory.max(out_data.len());
+                                next_items.push((out_data, out_props));
+                            }
+                        }
+                    }
+                }
+
+                if next_items.is_empty() {
+                    return Err(ExecuteError::EmptyPlan);
+                }
+
+                per_step_stats.push(step_stats);
+                items = next_items;
+            }
+
+            let duration = start.elapsed();
+            let steps_executed = plan.steps.len();
+
+            Ok(items
+                .into_iter()
+                .map(|(data, props)| ExecutionResult {
+                    data,
+                    props,
+                    stats: ExecutionStats {
+                        duration,
+                        peak_memory,
+                        steps_executed,
+                        per_step_stats: per_step_stats.clone(),
+                    },
+                })
+                .collect())
+        })
+    }
+}
+
+/// Run one step, moving synchronous converters onto the blocking pool.
+async fn run_async_step(
+    converter: &dyn AsyncConverter,
+    step: &PlanStep,
+    data: Vec<u8>,
+    props: Properties,
+) -> Result<ConvertOutput, ConvertError> {
+    if let Some(sync) = converter.as_sync() {
+        let step = step.clone();
+        return tokio::task::spawn_blocking(move || run_step(sync.as_ref(), &step, &data, &props))
+            .await
+            .map_err(|e| ConvertError::failed("blocking task failed", e))?;
+    }
+
+    let mut merged = props;
+    merged.extend(step.input_properties.clone());
+    let output = converter.convert(&data, &merged).await?;
+
+    Ok(apply_output_overrides(output, step))
+}
+
+/// Run an aggregating step, moving synchronous converters onto the
+/// blocking pool.
+async fn run_async_batch(
+    converter: &dyn AsyncConverter,
+    step: &PlanStep,
+    inputs: Vec<(Vec<u8>, Properties)>,
+) -> Result<ConvertOutput, ConvertError> {
+    if let Some(sync) = converter.as_sync() {
+        let step = step.clone();
+        return tokio::task::spawn_blocking(move || {
+            let batch: Vec<(&[u8], &Properties)> =
+                inputs.iter().map(|(d, p)| (d.as_slice(), p)).collect();
+            sync.convert_batch(&batch)
+                .map(|output| apply_output_overrides(output, &step))
+        })
+        .await
+        .map_err(|e| ConvertError::failed("blocking task failed", e))?;
+    }
+
+    let batch: Vec<(&[u8], &Properties)> = inputs.iter().map(|(d, p)| (d.as_slice(), p)).collect();
+    let output = converter.convert_batch(&batch).await?;
+
+    Ok(apply_output_overrides(output, step))
+}
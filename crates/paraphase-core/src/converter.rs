@@ -2,6 +2,7 @@
 
 use crate::pattern::PropertyPattern;
 use crate::properties::Properties;
+use crate::schema::{self, PropertyError, PropertySchema};
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +15,16 @@ pub struct PortDecl {
     /// If false, this port handles single items.
     #[serde(default)]
     pub list: bool,
+    /// Output properties restored from another input property, as
+    /// `key -> source`. When the input has `source`, the output's `key`
+    /// takes its value and `source` is removed; otherwise the pattern's
+    /// value stands.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub restore: IndexMap<String, String>,
+    /// Input property that turns `restore` off when set to `false`. The
+    /// property is consumed by the step either way.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restore_flag: Option<String>,
 }
 
 impl PortDecl {
@@ -22,6 +33,8 @@ impl PortDecl {
         Self {
             pattern,
             list: false,
+            restore: IndexMap::new(),
+            restore_flag: None,
         }
     }
 
@@ -30,8 +43,35 @@ impl PortDecl {
         Self {
             pattern,
             list: true,
+            restore: IndexMap::new(),
+            restore_flag: None,
         }
     }
+
+    /// Restore output property `key` from input property `source`.
+    ///
+    /// Used by converters that undo a wrapping step, e.g. decompressors
+    /// restoring `format` from the `inner_format` recorded on compression.
+    pub fn restore(mut self, key: impl Into<String>, source: impl Into<String>) -> Self {
+        self.restore.insert(key.into(), source.into());
+        self
+    }
+
+    /// Skip [`restore`](Self::restore) when the input has `property` set
+    /// to `false`.
+    pub fn restore_flag(mut self, property: impl Into<String>) -> Self {
+        self.restore_flag = Some(property.into());
+        self
+    }
+
+    /// Whether restored properties apply to a step with input `props`.
+    pub fn restores(&self, props: &Properties) -> bool {
+        self.restore_flag
+            .as_ref()
+            .and_then(|flag| props.get(flag))
+            .and_then(|v| v.as_bool())
+            != Some(false)
+    }
 }
 
 /// Declaration of a converter's interface.
@@ -52,6 +92,9 @@ pub struct ConverterDecl {
     /// Cost metrics for path optimization.
     #[serde(default)]
     pub costs: Properties,
+    /// Schemas for the option properties this converter reads.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub properties: IndexMap<String, PropertySchema>,
 }
 
 impl ConverterDecl {
@@ -63,6 +106,7 @@ impl ConverterDecl {
             inputs: IndexMap::new(),
             outputs: IndexMap::new(),
             costs: Properties::new(),
+            properties: IndexMap::new(),
         }
     }
 
@@ -95,6 +139,28 @@ impl ConverterDecl {
             .output("out", PortDecl::single(output_pattern))
     }
 
+    /// Restore property `key` from `source` on every output port.
+    ///
+    /// See [`PortDecl::restore`].
+    pub fn restore(mut self, key: impl Into<String>, source: impl Into<String>) -> Self {
+        let (key, source) = (key.into(), source.into());
+        for port in self.outputs.values_mut() {
+            port.restore.insert(key.clone(), source.clone());
+        }
+        self
+    }
+
+    /// Set the restore flag on every output port.
+    ///
+    /// See [`PortDecl::restore_flag`].
+    pub fn restore_flag(mut self, property: impl Into<String>) -> Self {
+        let property = property.into();
+        for port in self.outputs.values_mut() {
+            port.restore_flag = Some(property.clone());
+        }
+        self
+    }
+
     /// Set a cost property for path optimization.
     ///
     /// Common cost keys:
@@ -108,6 +174,54 @@ impl ConverterDecl {
         self
     }
 
+    /// Set the base cost of running this converter (default 1.0).
+    pub fn with_cost(self, cost: f64) -> Self {
+        self.cost("cost", cost)
+    }
+
+    /// Set how much quality this conversion loses, from 0.0 (lossless) up.
+    ///
+    /// The planner adds `loss * quality_weight` to the step cost, weighted
+    /// by [`OptimizeTarget::quality_weight`](crate::OptimizeTarget::quality_weight).
+    pub fn with_quality_loss(self, loss: f64) -> Self {
+        self.cost("quality_loss", loss)
+    }
+
+    /// Quality loss of this conversion (0.0 if not declared).
+    pub fn quality_loss(&self) -> f64 {
+        self.costs
+            .get("quality_loss")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0)
+    }
+
+    /// Check if this conversion loses no quality.
+    pub fn is_lossless(&self) -> bool {
+        self.quality_loss() == 0.0
+    }
+
+    /// Declare the schema of an option property this converter reads.
+    pub fn with_property_schema(mut self, name: impl Into<String>, schema: PropertySchema) -> Self {
+        self.properties.insert(name.into(), schema);
+        self
+    }
+
+    /// Check `props` against every declared property schema.
+    pub fn validate_props(&self, props: &Properties) -> Vec<PropertyError> {
+        schema::validate(&self.properties, props)
+    }
+
+    /// Like [`validate_props`](Self::validate_props), but as a `Result` for
+    /// use with `?` at the start of [`Converter::convert`].
+    pub fn check_props(&self, props: &Properties) -> Result<(), ConvertError> {
+        let errors = self.validate_props(props);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ConvertError::InvalidProperties(errors))
+        }
+    }
+
     /// Check if this converter can handle the given input properties.
     ///
     /// For single-input converters, checks the "in" port.
@@ -174,6 +288,32 @@ pub trait Converter: Send + Sync {
     /// Get the declaration for this converter.
     fn decl(&self) -> &ConverterDecl;
 
+    /// The converter's id, from its declaration.
+    fn name(&self) -> &str {
+        &self.decl().id
+    }
+
+    /// The converter's description, from its declaration.
+    fn description(&self) -> &str {
+        &self.decl().description
+    }
+
+    /// Whether [`convert_batch`](Self::convert_batch) is supported.
+    ///
+    /// Follows the declaration: aggregating converters (a list input port)
+    /// implement it.
+    fn supports_batch(&self) -> bool {
+        self.decl().aggregates()
+    }
+
+    /// Whether [`convert_multi`](Self::convert_multi) is supported.
+    ///
+    /// Follows the declaration: converters with several input ports
+    /// implement it.
+    fn supports_multi(&self) -> bool {
+        self.decl().has_multi_input()
+    }
+
     /// Convert a single input (for simple converters with one "in" port).
     fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError>;
 
@@ -199,13 +339,80 @@ pub trait Converter: Send + Sync {
         let _ = inputs;
         Err(ConvertError::BatchNotSupported)
     }
+
+    /// Estimate the size of the output of converting `input_len` bytes.
+    ///
+    /// The estimate becomes the next step's input size, so it should be
+    /// the encoded output, not any intermediate buffer (see
+    /// [`estimate_working_memory`](Self::estimate_working_memory)). Returns
+    /// `None` when unknown, in which case executors fall back to
+    /// [`crate::estimate_memory`] heuristics.
+    fn estimate_output_size(&self, input_len: usize, props: &Properties) -> Option<usize> {
+        let _ = (input_len, props);
+        None
+    }
+
+    /// Estimate the memory needed while converting `input_len` bytes, when
+    /// it exceeds the output, e.g. the raw pixels an image is decoded to.
+    ///
+    /// Counts toward peak memory but not toward the next step's input.
+    /// Returns `None` when there is no such intermediate buffer.
+    fn estimate_working_memory(&self, input_len: usize, props: &Properties) -> Option<usize> {
+        let _ = (input_len, props);
+        None
+    }
 }
 
+/// Shared converters, so an implementation taken out of a registry (see
+/// [`Registry::deregister`](crate::Registry::deregister)) can be registered again.
+impl<C: Converter + ?Sized> Converter for std::sync::Arc<C> {
+    fn decl(&self) -> &ConverterDecl {
+        (**self).decl()
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        (**self).convert(input, props)
+    }
+
+    fn convert_multi(
+        &self,
+        inputs: &IndexMap<String, NamedInput<'_>>,
+    ) -> Result<ConvertOutput, ConvertError> {
+        (**self).convert_multi(inputs)
+    }
+
+    fn convert_batch(
+        &self,
+        inputs: &[(&[u8], &Properties)],
+    ) -> Result<ConvertOutput, ConvertError> {
+        (**self).convert_batch(inputs)
+    }
+
+    fn estimate_output_size(&self, input_len: usize, props: &Properties) -> Option<usize> {
+        (**self).estimate_output_size(input_len, props)
+    }
+
+    fn estimate_working_memory(&self, input_len: usize, props: &Properties) -> Option<usize> {
+        (**self).estimate_working_memory(input_len, props)
+    }
+}
+
+/// Underlying cause attached to a [`ConvertError`].
+pub(crate) type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
 /// Errors that can occur during conversion.
+///
+/// `Failed` and `InvalidInput` keep the underlying error (if any) as their
+/// [`source`](std::error::Error::source). It is not repeated in the
+/// displayed text, so report it with [`describe_error`] or by walking the
+/// source chain.
 #[derive(Debug, thiserror::Error)]
 pub enum ConvertError {
-    #[error("conversion failed: {0}")]
-    Failed(String),
+    #[error("conversion failed: {message}")]
+    Failed {
+        message: String,
+        source: Option<BoxError>,
+    },
 
     #[error("batch conversion not supported by this converter")]
     BatchNotSupported,
@@ -216,20 +423,130 @@ pub enum ConvertError {
     #[error("missing required input port: {0}")]
     MissingInput(String),
 
-    #[error("invalid input: {0}")]
-    InvalidInput(String),
+    #[error("invalid input: {message}")]
+    InvalidInput {
+        message: String,
+        source: Option<BoxError>,
+    },
 
     #[error("missing required property: {0}")]
     MissingProperty(String),
 
+    #[error("invalid properties: {}", display_property_errors(.0))]
+    InvalidProperties(Vec<PropertyError>),
+
     #[error(transparent)]
-    Other(#[from] Box<dyn std::error::Error + Send + Sync>),
+    Other(#[from] BoxError),
+}
+
+impl ConvertError {
+    /// Invalid input caused by `source`, e.g. a parser error.
+    pub fn invalid_input(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        Self::InvalidInput {
+            message: message.into(),
+            source: Some(source.into()),
+        }
+    }
+
+    /// Invalid input with no underlying error.
+    pub fn invalid_input_msg(message: impl Into<String>) -> Self {
+        Self::InvalidInput {
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Conversion failure caused by `source`, e.g. an encoder or I/O error.
+    pub fn failed(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        Self::Failed {
+            message: message.into(),
+            source: Some(source.into()),
+        }
+    }
+
+    /// Conversion failure with no underlying error.
+    pub fn failed_msg(message: impl Into<String>) -> Self {
+        Self::Failed {
+            message: message.into(),
+            source: None,
+        }
+    }
+}
+
+impl From<PropertyError> for ConvertError {
+    fn from(error: PropertyError) -> Self {
+        Self::InvalidProperties(vec![error])
+    }
+}
+
+/// Join property errors into one line.
+fn display_property_errors(errors: &[PropertyError]) -> String {
+    errors
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Render an error and its chain of causes as `"error: cause: root cause"`.
+///
+/// Causes already spelled out by the outer message (as
+/// `ExecuteError::ConversionFailed` does) aren't repeated.
+pub fn describe_error(error: &(dyn std::error::Error + 'static)) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        let text = cause.to_string();
+        if !message.ends_with(&text) {
+            message.push_str(": ");
+            message.push_str(&text);
+        }
+        source = cause.source();
+    }
+    message
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_describe_error() {
+        use crate::ExecuteError;
+
+        let io = std::io::Error::other("truncated");
+        let err = ExecuteError::ConversionFailed {
+            step: 0,
+            source: ConvertError::invalid_input("Failed to read entry", io),
+        };
+        assert_eq!(
+            describe_error(&err),
+            "conversion failed at step 0: invalid input: Failed to read entry: truncated"
+        );
+    }
+
+    #[test]
+    fn test_convert_error_source() {
+        use std::error::Error;
+
+        let io = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "truncated");
+        let err = ConvertError::invalid_input("Failed to read entry", io);
+        assert_eq!(err.to_string(), "invalid input: Failed to read entry");
+        let source = err.source().expect("source is kept");
+        assert_eq!(source.to_string(), "truncated");
+        assert_eq!(
+            source.downcast_ref::<std::io::Error>().unwrap().kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+
+        let err = ConvertError::failed_msg("Frame buffer size mismatch");
+        assert_eq!(
+            err.to_string(),
+            "conversion failed: Frame buffer size mismatch"
+        );
+        assert!(err.source().is_none());
+    }
+
     #[test]
     fn test_simple_converter_decl() {
         let decl = ConverterDecl::simple(
@@ -244,6 +561,65 @@ mod tests {
         assert!(!decl.expands());
     }
 
+    #[test]
+    fn test_converter_accessors() {
+        struct Merge(ConverterDecl);
+
+        impl Converter for Merge {
+            fn decl(&self) -> &ConverterDecl {
+                &self.0
+            }
+
+            fn convert(&self, _: &[u8], _: &Properties) -> Result<ConvertOutput, ConvertError> {
+                Err(ConvertError::MultiInputNotSupported)
+            }
+        }
+
+        let any_json = || PortDecl::single(PropertyPattern::new().eq("format", "json"));
+        let merge = Merge(
+            ConverterDecl::new("json.merge")
+                .description("Merge two JSON documents")
+                .input("base", any_json())
+                .input("patch", any_json())
+                .output("out", any_json()),
+        );
+        assert_eq!(merge.name(), "json.merge");
+        assert_eq!(merge.description(), "Merge two JSON documents");
+        assert!(merge.supports_multi());
+        assert!(!merge.supports_batch());
+
+        let shared = std::sync::Arc::new(merge);
+        assert_eq!(shared.name(), "json.merge");
+    }
+
+    #[test]
+    fn test_check_props() {
+        use crate::PropertiesExt;
+
+        let decl = ConverterDecl::simple(
+            "png-to-jpg",
+            PropertyPattern::new().eq("format", "png"),
+            PropertyPattern::new().eq("format", "jpg"),
+        )
+        .with_property_schema("jpeg_quality", PropertySchema::integer().range(1.0, 100.0))
+        .with_property_schema("scale", PropertySchema::float().min(0.01));
+
+        let ok = Properties::new()
+            .with("format", "png")
+            .with("jpeg_quality", 90);
+        assert!(decl.check_props(&ok).is_ok());
+
+        let bad = Properties::new()
+            .with("jpeg_quality", 150)
+            .with("scale", "abc");
+        assert_eq!(decl.validate_props(&bad).len(), 2);
+        assert_eq!(
+            decl.check_props(&bad).unwrap_err().to_string(),
+            "invalid properties: jpeg_quality must be 1–100, got: 150; \
+             scale must be a number, got: \"abc\""
+        );
+    }
+
     #[test]
     fn test_aggregator_decl() {
         let decl = ConverterDecl::new("frames-to-video")
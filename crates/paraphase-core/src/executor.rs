@@ -6,14 +6,21 @@
 //!
 //! See ADR-0006 for design rationale.
 
-use crate::converter::ConvertError;
-use crate::planner::Plan;
-use crate::properties::Properties;
+use crate::converter::{ConvertError, ConvertOutput, Converter};
+use crate::planner::{Plan, PlanStep};
+use crate::properties::{Properties, PropertiesExt};
 use crate::registry::Registry;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
+/// Sink for debug messages from an executor.
+pub type DebugLog = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Progress callback, called with `(current_step, total_steps, converter_id)`
+/// as each step starts. `current_step` counts from 1.
+pub type ProgressCallback = Arc<dyn Fn(usize, usize, &str) + Send + Sync>;
+
 /// Context for executing conversion plans.
 #[derive(Clone)]
 pub struct ExecutionContext {
@@ -23,6 +30,10 @@ pub struct ExecutionContext {
     pub memory_limit: Option<usize>,
     /// Optional parallelism limit (max concurrent jobs).
     pub parallelism: Option<usize>,
+    /// Optional debug log; receives the property changes of each step.
+    pub debug_log: Option<DebugLog>,
+    /// Optional progress callback, called once per plan step.
+    pub progress: Option<ProgressCallback>,
 }
 
 impl ExecutionContext {
@@ -32,6 +43,8 @@ impl ExecutionContext {
             registry,
             memory_limit: None,
             parallelism: None,
+            debug_log: None,
+            progress: None,
         }
     }
 
@@ -46,6 +59,40 @@ impl ExecutionContext {
         self.parallelism = Some(jobs);
         self
     }
+
+    /// Enable debug logging.
+    pub fn with_debug_log(mut self, log: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.debug_log = Some(Arc::new(log));
+        self
+    }
+
+    /// Report progress as each step starts.
+    pub fn with_progress(
+        mut self,
+        progress: impl Fn(usize, usize, &str) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// Report that a step is starting, if a progress callback is set.
+    fn report_progress(&self, step_idx: usize, total: usize, step: &PlanStep) {
+        if let Some(progress) = &self.progress {
+            progress(step_idx + 1, total, &step.converter_id);
+        }
+    }
+
+    /// Log how a step changed the properties, if debug logging is enabled.
+    fn log_step(&self, step_idx: usize, step: &PlanStep, before: &Properties, after: &Properties) {
+        if let Some(log) = &self.debug_log {
+            log(&format!(
+                "step {} ({}):\n{}",
+                step_idx + 1,
+                step.converter_id,
+                before.diff(after).display()
+            ));
+        }
+    }
 }
 
 /// Result of executing a conversion plan.
@@ -68,6 +115,71 @@ pub struct ExecutionStats {
     pub peak_memory: usize,
     /// Number of converter steps executed.
     pub steps_executed: usize,
+    /// Timing and sizes of each plan step, in plan order.
+    pub per_step_stats: Vec<StepStats>,
+}
+
+impl ExecutionStats {
+    /// The step that spent the most time converting.
+    pub fn slowest_step(&self) -> Option<&StepStats> {
+        self.per_step_stats.iter().max_by_key(|s| s.duration)
+    }
+
+    /// The step with the highest output-to-input size ratio.
+    pub fn largest_expansion(&self) -> Option<&StepStats> {
+        self.per_step_stats
+            .iter()
+            .max_by(|a, b| a.expansion().total_cmp(&b.expansion()))
+    }
+}
+
+/// Statistics for a single plan step.
+///
+/// When a step runs once per item (after an expansion, or before an
+/// aggregation), durations and sizes are summed over all of its calls.
+#[derive(Debug, Clone, Default)]
+pub struct StepStats {
+    /// ID of the converter that ran.
+    pub converter_id: String,
+    /// Time spent inside the converter.
+    pub duration: Duration,
+    /// Bytes passed to the converter.
+    pub input_bytes: usize,
+    /// Bytes produced by the converter.
+    pub output_bytes: usize,
+    /// Memory estimate (bytes): the largest input plus output held by one call.
+    pub memory_estimate: usize,
+}
+
+impl StepStats {
+    pub(crate) fn new(converter_id: &str) -> Self {
+        Self {
+            converter_id: converter_id.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Output size divided by input size (an empty input counts as one byte).
+    pub fn expansion(&self) -> f64 {
+        self.output_bytes as f64 / self.input_bytes.max(1) as f64
+    }
+
+    /// Account for one converter call.
+    pub(crate) fn record(
+        &mut self,
+        input_bytes: usize,
+        output: &ConvertOutput,
+        duration: Duration,
+    ) {
+        let output_bytes = match output {
+            ConvertOutput::Single(data, _) => data.len(),
+            ConvertOutput::Multiple(outputs) => outputs.iter().map(|(data, _)| data.len()).sum(),
+        };
+        self.duration += duration;
+        self.input_bytes += input_bytes;
+        self.output_bytes += output_bytes;
+        self.memory_estimate = self.memory_estimate.max(input_bytes + output_bytes);
+    }
 }
 
 /// A conversion job for batch processing.
@@ -187,44 +299,56 @@ pub trait Executor: Send + Sync {
         let aggregate_step = &plan.steps[aggregate_idx];
         let post_aggregate_steps = &plan.steps[aggregate_idx + 1..];
 
+        let mut per_step_stats: Vec<StepStats> = plan
+            .steps
+            .iter()
+            .map(|step| StepStats::new(&step.converter_id))
+            .collect();
+
         // Phase 1: Process each input through pre-aggregation steps
-        let mut processed: Vec<(Vec<u8>, Properties)> = Vec::new();
+        let mut processed = inputs;
+
+        for (step_idx, step) in pre_aggregate_steps.iter().enumerate() {
+            let converter = ctx
+                .registry
+                .get(&step.converter_id)
+                .ok_or_else(|| ExecuteError::ConverterNotFound(step.converter_id.clone()))?;
 
-        for (input, props) in inputs {
-            let mut current_data = input;
-            let mut current_props = props;
+            ctx.report_progress(step_idx, plan.steps.len(), step);
 
-            for (step_idx, step) in pre_aggregate_steps.iter().enumerate() {
-                let converter = ctx
-                    .registry
-                    .get(&step.converter_id)
-                    .ok_or_else(|| ExecuteError::ConverterNotFound(step.converter_id.clone()))?;
+            let mut next_items = Vec::with_capacity(processed.len());
 
-                let output = converter
-                    .convert(&current_data, &current_props)
-                    .map_err(|e| ExecuteError::ConversionFailed {
+            for (data, props) in processed {
+                let step_start = Instant::now();
+                let output = run_step(converter.as_ref(), step, &data, &props).map_err(|e| {
+                    ExecuteError::ConversionFailed {
                         step: step_idx,
                         source: e,
-                    })?;
+                    }
+                })?;
+                per_step_stats[step_idx].record(data.len(), &output, step_start.elapsed());
 
                 match output {
-                    crate::ConvertOutput::Single(data, props) => {
-                        peak_memory = peak_memory.max(data.len());
-                        current_data = data;
-                        current_props = props;
+                    crate::ConvertOutput::Single(out_data, out_props) => {
+                        ctx.log_step(step_idx, step, &props, &out_props);
+                        peak_memory = peak_memory.max(out_data.len());
+                        next_items.push((out_data, out_props));
                     }
                     crate::ConvertOutput::Multiple(mut outputs) => {
                         // For aggregation, take just the first output from expansion
-                        if let Some((data, props)) = outputs.pop() {
-                            peak_memory = peak_memory.max(data.len());
-                            current_data = data;
-                            current_props = props;
+                        match outputs.pop() {
+                            Some((out_data, out_props)) => {
+                                ctx.log_step(step_idx, step, &props, &out_props);
+                                peak_memory = peak_memory.max(out_data.len());
+                                next_items.push((out_data, out_props));
+                            }
+                            None => next_items.push((data, props)),
                         }
                     }
                 }
             }
 
-            processed.push((current_data, current_props));
+            processed = next_items;
         }
 
         // Phase 2: Run the aggregating step
@@ -233,16 +357,27 @@ pub trait Executor: Send + Sync {
             .get(&aggregate_step.converter_id)
             .ok_or_else(|| ExecuteError::ConverterNotFound(aggregate_step.converter_id.clone()))?;
 
+        if !aggregate_step.input_properties.is_empty() {
+            for (_, props) in &mut processed {
+                props.extend(aggregate_step.input_properties.clone());
+            }
+        }
+
         let batch_input: Vec<(&[u8], &Properties)> =
             processed.iter().map(|(d, p)| (d.as_slice(), p)).collect();
 
-        let output =
-            aggregator
-                .convert_batch(&batch_input)
-                .map_err(|e| ExecuteError::ConversionFailed {
-                    step: aggregate_idx,
-                    source: e,
-                })?;
+        ctx.report_progress(aggregate_idx, plan.steps.len(), aggregate_step);
+
+        let step_start = Instant::now();
+        let output = aggregator
+            .convert_batch(&batch_input)
+            .map(|output| apply_output_overrides(output, aggregate_step))
+            .map_err(|e| ExecuteError::ConversionFailed {
+                step: aggregate_idx,
+                source: e,
+            })?;
+        let batch_bytes = processed.iter().map(|(d, _)| d.len()).sum();
+        per_step_stats[aggregate_idx].record(batch_bytes, &output, step_start.elapsed());
 
         let (mut current_data, mut current_props) = match output {
             crate::ConvertOutput::Single(data, props) => (data, props),
@@ -251,6 +386,10 @@ pub trait Executor: Send + Sync {
             }
         };
 
+        for (_, props) in &processed {
+            ctx.log_step(aggregate_idx, aggregate_step, props, &current_props);
+        }
+
         peak_memory = peak_memory.max(current_data.len());
 
         // Phase 3: Process aggregated output through post-aggregation steps
@@ -261,21 +400,26 @@ pub trait Executor: Send + Sync {
                 .get(&step.converter_id)
                 .ok_or_else(|| ExecuteError::ConverterNotFound(step.converter_id.clone()))?;
 
-            let output = converter
-                .convert(&current_data, &current_props)
+            ctx.report_progress(step_idx, plan.steps.len(), step);
+
+            let step_start = Instant::now();
+            let output = run_step(converter.as_ref(), step, &current_data, &current_props)
                 .map_err(|e| ExecuteError::ConversionFailed {
                     step: step_idx,
                     source: e,
                 })?;
+            per_step_stats[step_idx].record(current_data.len(), &output, step_start.elapsed());
 
             match output {
                 crate::ConvertOutput::Single(data, props) => {
+                    ctx.log_step(step_idx, step, &current_props, &props);
                     peak_memory = peak_memory.max(data.len());
                     current_data = data;
                     current_props = props;
                 }
                 crate::ConvertOutput::Multiple(mut outputs) => {
                     if let Some((data, props)) = outputs.pop() {
+                        ctx.log_step(step_idx, step, &current_props, &props);
                         peak_memory = peak_memory.max(data.len());
                         current_data = data;
                         current_props = props;
@@ -291,11 +435,56 @@ pub trait Executor: Send + Sync {
                 duration: start.elapsed(),
                 peak_memory,
                 steps_executed: plan.steps.len(),
+                per_step_stats,
             },
         })
     }
 }
 
+/// Run a single plan step.
+///
+/// The step's `input_properties` are merged into the incoming properties
+/// before conversion, and its `output_overrides` into every output.
+pub(crate) fn run_step(
+    converter: &dyn Converter,
+    step: &PlanStep,
+    data: &[u8],
+    props: &Properties,
+) -> Result<ConvertOutput, ConvertError> {
+    let output = if step.input_properties.is_empty() {
+        converter.convert(data, props)?
+    } else {
+        let mut merged = props.clone();
+        merged.extend(step.input_properties.clone());
+        converter.convert(data, &merged)?
+    };
+
+    Ok(apply_output_overrides(output, step))
+}
+
+/// Merge a step's `output_overrides` into its output properties.
+pub(crate) fn apply_output_overrides(output: ConvertOutput, step: &PlanStep) -> ConvertOutput {
+    if step.output_overrides.is_empty() {
+        return output;
+    }
+
+    match output {
+        ConvertOutput::Single(data, mut props) => {
+            props.extend(step.output_overrides.clone());
+            ConvertOutput::Single(data, props)
+        }
+        ConvertOutput::Multiple(outputs) => ConvertOutput::Multiple(
+            outputs
+                .into_iter()
+                .map(|(data, mut props)| {
+                    props.extend(step.output_overrides.clone());
+                    (data, props)
+                })
+                .collect(),
+        ),
+    }
+}
+
 /// Find the index of the aggregating step in a plan.
 ///
 /// Returns the index of the first step whose converter declares a list input.
@@ -303,7 +492,7 @@ fn find_aggregate_step_index(ctx: &ExecutionContext, plan: &Plan) -> Option<usiz
     #[allow(clippy::collapsible_if)]
     for (idx, step) in plan.steps.iter().enumerate() {
         if let Some(converter) = ctx.registry.get(&step.converter_id) {
-            if converter.decl().aggregates() {
+            if converter.supports_batch() {
                 return Some(idx);
             }
         }
@@ -333,8 +522,8 @@ impl Executor for SimpleExecutor {
         props: Properties,
     ) -> Result<ExecutionResult, ExecuteError> {
         // Use execute_expanding and take first result
-        let mut results = self.execute_expanding(ctx, plan, input, props)?;
-        results.pop().ok_or(ExecuteError::EmptyPlan)
+        let results = self.execute_expanding(ctx, plan, input, props)?;
+        results.into_iter().next().ok_or(ExecuteError::EmptyPlan)
     }
 
     fn execute_expanding(
@@ -350,6 +539,7 @@ impl Executor for SimpleExecutor {
         // Track all items flowing through the pipeline
         // Each item is (data, props)
         let mut items: Vec<(Vec<u8>, Properties)> = vec![(input, props)];
+        let mut per_step_stats = Vec::with_capacity(plan.steps.len());
 
         for (step_idx, step) in plan.steps.iter().enumerate() {
             let converter = ctx
@@ -357,23 +547,30 @@ impl Executor for SimpleExecutor {
                 .get(&step.converter_id)
                 .ok_or_else(|| ExecuteError::ConverterNotFound(step.converter_id.clone()))?;
 
+            ctx.report_progress(step_idx, plan.steps.len(), step);
+
             let mut next_items = Vec::new();
+            let mut step_stats = StepStats::new(&step.converter_id);
 
             for (data, props) in items {
-                let output = converter.convert(&data, &props).map_err(|e| {
+                let step_start = Instant::now();
+                let output = run_step(converter.as_ref(), step, &data, &props).map_err(|e| {
                     ExecuteError::ConversionFailed {
                         step: step_idx,
                         source: e,
                     }
                 })?;
+                step_stats.record(data.len(), &output, step_start.elapsed());
 
                 match output {
                     crate::ConvertOutput::Single(out_data, out_props) => {
+                        ctx.log_step(step_idx, step, &props, &out_props);
                         peak_memory = peak_memory.max(out_data.len());
                         next_items.push((out_data, out_props));
                     }
                     crate::ConvertOutput::Multiple(outputs) => {
                         for (out_data, out_props) in outputs {
+                            ctx.log_step(step_idx, step, &props, &out_props);
                             peak_memory = peak_memory.max(out_data.len());
                             next_items.push((out_data, out_props));
                         }
@@ -385,6 +582,7 @@ impl Executor for SimpleExecutor {
                 return Err(ExecuteError::EmptyPlan);
             }
 
+            per_step_stats.push(step_stats);
             items = next_items;
         }
 
@@ -400,6 +598,7 @@ impl Executor for SimpleExecutor {
                     duration,
                     peak_memory,
                     steps_executed,
+                    per_step_stats: per_step_stats.clone(),
                 },
             })
             .collect())
@@ -553,8 +752,8 @@ impl Executor for BoundedExecutor {
         props: Properties,
     ) -> Result<ExecutionResult, ExecuteError> {
         // Use execute_expanding and take first result
-        let mut results = self.execute_expanding(ctx, plan, input, props)?;
-        results.pop().ok_or(ExecuteError::EmptyPlan)
+        let results = self.execute_expanding(ctx, plan, input, props)?;
+        results.into_iter().next().ok_or(ExecuteError::EmptyPlan)
     }
 
     fn execute_expanding(
@@ -566,7 +765,7 @@ impl Executor for BoundedExecutor {
     ) -> Result<Vec<ExecutionResult>, ExecuteError> {
         // Check memory limit before starting
         if let Some(limit) = ctx.memory_limit {
-            let estimated = estimate_memory(input.len(), plan);
+            let estimated = estimate_plan_memory(&ctx.registry, input.len(), plan, &props);
             if estimated > limit {
                 return Err(ExecuteError::MemoryLimitExceeded {
                     needed: estimated,
@@ -613,6 +812,16 @@ impl Executor for ParallelExecutor {
         BoundedExecutor.execute(ctx, plan, input, props)
     }
 
+    fn execute_expanding(
+        &self,
+        ctx: &ExecutionContext,
+        plan: &Plan,
+        input: Vec<u8>,
+        props: Properties,
+    ) -> Result<Vec<ExecutionResult>, ExecuteError> {
+        BoundedExecutor.execute_expanding(ctx, plan, input, props)
+    }
+
     fn execute_batch(
         &self,
         ctx: &ExecutionContext,
@@ -634,7 +843,8 @@ impl Executor for ParallelExecutor {
         };
 
         let execute_job = |job: Job| {
-            let estimated = estimate_memory(job.input.len(), &job.plan);
+            let estimated =
+                estimate_plan_memory(&ctx.registry, job.input.len(), &job.plan, &job.props);
 
             // Block until memory is available (backpressure)
             // Only fails if single job exceeds total budget
@@ -671,20 +881,65 @@ impl Executor for ParallelExecutor {
 /// - Audio: ~10x (compressed to PCM)
 /// - Images: ~4x (compressed to RGBA)
 /// - Video: ~100x (compressed to raw frames)
+/// - Decompression: ~10x (typical for text; binary data expands less)
 /// - Serde: ~1x (roughly same size)
 pub fn estimate_memory(input_size: usize, plan: &Plan) -> usize {
-    let mut estimate = input_size;
+    plan.steps.iter().fold(input_size, |size, step| {
+        heuristic_step_size(&step.converter_id, size)
+    })
+}
+
+/// Estimate peak memory for a conversion plan, asking each converter first.
+///
+/// Uses [`Converter::estimate_output_size`] where the registry has an
+/// implementation that provides one, and the [`estimate_memory`] heuristics
+/// otherwise. [`Converter::estimate_working_memory`] raises the peak
+/// without carrying over to the next step. `props` are the source
+/// properties.
+pub fn estimate_plan_memory(
+    registry: &Registry,
+    input_size: usize,
+    plan: &Plan,
+    props: &Properties,
+) -> usize {
+    let mut size = input_size;
+    let mut peak = input_size;
 
     for step in &plan.steps {
-        estimate = match step.converter_id.as_str() {
-            s if s.starts_with("audio.") => estimate.saturating_mul(10),
-            s if s.starts_with("image.") => estimate.saturating_mul(4),
-            s if s.starts_with("video.") => estimate.saturating_mul(100),
-            _ => estimate,
-        };
+        let converter = registry.get(&step.converter_id);
+        if let Some(working) = converter
+            .as_ref()
+            .and_then(|c| c.estimate_working_memory(size, props))
+        {
+            peak = peak.max(working);
+        }
+        size = converter
+            .as_ref()
+            .and_then(|c| c.estimate_output_size(size, props))
+            .unwrap_or_else(|| heuristic_step_size(&step.converter_id, size));
+        peak = peak.max(size);
     }
 
-    estimate
+    peak
+}
+
+/// Expansion factor by converter family, for converters without an estimate.
+fn heuristic_step_size(converter_id: &str, size: usize) -> usize {
+    match converter_id {
+        s if s.starts_with("audio.") => size.saturating_mul(10),
+        s if s.starts_with("image.") => size.saturating_mul(4),
+        s if s.starts_with("video.") => size.saturating_mul(100),
+        s if is_decompressor(s) => size.saturating_mul(10),
+        _ => size,
+    }
+}
+
+/// Decompressors are `compression.<codec>-to-raw` plus the format-sniffing
+/// `compression.auto-decompress`.
+fn is_decompressor(converter_id: &str) -> bool {
+    converter_id
+        .strip_prefix("compression.")
+        .is_some_and(|rest| rest.ends_with("-to-raw") || rest == "auto-decompress")
 }
 
 #[cfg(test)]
@@ -744,18 +999,18 @@ mod tests {
 
         let plan = Plan {
             steps: vec![
-                crate::PlanStep {
-                    converter_id: "test.a-to-b".into(),
-                    input_port: "in".into(),
-                    output_port: "out".into(),
-                    output_properties: Properties::new().with("format", "b"),
-                },
-                crate::PlanStep {
-                    converter_id: "test.b-to-c".into(),
-                    input_port: "in".into(),
-                    output_port: "out".into(),
-                    output_properties: Properties::new().with("format", "c"),
-                },
+                crate::PlanStep::new(
+                    "test.a-to-b",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "b"),
+                ),
+                crate::PlanStep::new(
+                    "test.b-to-c",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "c"),
+                ),
             ],
             cost: 2.0,
         };
@@ -801,17 +1056,124 @@ mod tests {
     #[test]
     fn test_estimate_memory() {
         let plan = Plan {
-            steps: vec![crate::PlanStep {
-                converter_id: "audio.mp3-to-wav".into(),
-                input_port: "in".into(),
-                output_port: "out".into(),
-                output_properties: Properties::new(),
-            }],
+            steps: vec![crate::PlanStep::new(
+                "audio.mp3-to-wav",
+                "in",
+                "out",
+                Properties::new(),
+            )],
             cost: 1.0,
         };
 
         let estimate = estimate_memory(1000, &plan);
         assert_eq!(estimate, 10000); // 10x for audio
+
+        let step = |id: &str| crate::PlanStep::new(id, "in", "out", Properties::new());
+        for (id, expected) in [
+            ("compression.gzip-to-raw", 10000),
+            ("compression.auto-decompress", 10000),
+            ("compression.gzip", 1000),
+            ("serde.json-to-yaml", 1000),
+        ] {
+            let plan = Plan {
+                steps: vec![step(id)],
+                cost: 1.0,
+            };
+            assert_eq!(estimate_memory(1000, &plan), expected, "{id}");
+        }
+    }
+
+    #[test]
+    fn test_estimate_plan_memory() {
+        /// Claims a 1000x expansion without ever being run.
+        struct Inflating(ConverterDecl);
+
+        impl Converter for Inflating {
+            fn decl(&self) -> &ConverterDecl {
+                &self.0
+            }
+
+            fn convert(&self, _: &[u8], _: &Properties) -> Result<ConvertOutput, ConvertError> {
+                panic!("should be rejected before conversion");
+            }
+
+            fn estimate_output_size(&self, input_len: usize, _: &Properties) -> Option<usize> {
+                Some(input_len * 1000)
+            }
+        }
+
+        let mut registry = Registry::new();
+        registry.register(Inflating(ConverterDecl::simple(
+            "test.inflate",
+            PropertyPattern::new().eq("format", "a"),
+            PropertyPattern::new().eq("format", "b"),
+        )));
+        registry.register(IdentityConverter::new("b", "c"));
+
+        let step = |id: &str| crate::PlanStep::new(id, "in", "out", Properties::new());
+        let plan = Plan {
+            steps: vec![step("test.inflate"), step("test.b-to-c")],
+            cost: 2.0,
+        };
+        let props = Properties::new().with("format", "a");
+
+        // The converter's estimate wins; unknown steps keep the size
+        assert_eq!(estimate_plan_memory(&registry, 100, &plan, &props), 100_000);
+        // The plain heuristic knows nothing about test converters
+        assert_eq!(estimate_memory(100, &plan), 100);
+
+        let ctx = ExecutionContext::new(Arc::new(registry)).with_memory_limit(10_000);
+        let result = BoundedExecutor::new().execute(&ctx, &plan, vec![0; 100], props);
+        assert!(matches!(
+            result,
+            Err(ExecuteError::MemoryLimitExceeded {
+                needed: 100_000,
+                limit: 10_000
+            })
+        ));
+    }
+
+    #[test]
+    fn test_estimate_working_memory() {
+        /// Decodes to a 10x buffer, then writes half the input.
+        struct Decoding(ConverterDecl);
+
+        impl Converter for Decoding {
+            fn decl(&self) -> &ConverterDecl {
+                &self.0
+            }
+
+            fn convert(&self, _: &[u8], _: &Properties) -> Result<ConvertOutput, ConvertError> {
+                unreachable!()
+            }
+
+            fn estimate_output_size(&self, input_len: usize, _: &Properties) -> Option<usize> {
+                Some(input_len / 2)
+            }
+
+            fn estimate_working_memory(&self, input_len: usize, _: &Properties) -> Option<usize> {
+                Some(input_len * 10)
+            }
+        }
+
+        let mut registry = Registry::new();
+        for (from, to) in [("a", "b"), ("b", "c")] {
+            registry.register(Decoding(ConverterDecl::simple(
+                format!("test.{}-to-{}", from, to),
+                PropertyPattern::new().eq("format", from),
+                PropertyPattern::new().eq("format", to),
+            )));
+        }
+        let step = |id: &str| crate::PlanStep::new(id, "in", "out", Properties::new());
+        let plan = Plan {
+            steps: vec![step("test.a-to-b"), step("test.b-to-c")],
+            cost: 2.0,
+        };
+
+        // The first step's buffer is the peak; the second starts from its
+        // 50-byte output, not the 1000-byte buffer
+        let props = Properties::new().with("format", "a");
+        assert_eq!(estimate_plan_memory(&registry, 100, &plan, &props), 1000);
     }
 
     #[test]
@@ -891,12 +1253,12 @@ mod tests {
         let ctx = ExecutionContext::new(Arc::new(registry)).with_memory_limit(1000);
 
         let plan = Plan {
-            steps: vec![crate::PlanStep {
-                converter_id: "test.a-to-b".into(),
-                input_port: "in".into(),
-                output_port: "out".into(),
-                output_properties: Properties::new().with("format", "b"),
-            }],
+            steps: vec![crate::PlanStep::new(
+                "test.a-to-b",
+                "in",
+                "out",
+                Properties::new().with("format", "b"),
+            )],
             cost: 1.0,
         };
 
@@ -908,6 +1270,179 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_bounded_executor_debug_log() {
+        use std::sync::Mutex;
+
+        let mut registry = Registry::new();
+        registry.register(IdentityConverter::new("a", "b"));
+
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let sink = messages.clone();
+        let ctx = ExecutionContext::new(Arc::new(registry))
+            .with_memory_limit(1000)
+            .with_debug_log(move |msg| sink.lock().unwrap().push(msg.to_string()));
+
+        let plan = Plan {
+            steps: vec![crate::PlanStep {
+                output_overrides: Properties::new().with("label", "x"),
+                ..crate::PlanStep::new(
+                    "test.a-to-b",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "b"),
+                )
+            }],
+            cost: 1.0,
+        };
+
+        let props = Properties::new().with("format", "a");
+        BoundedExecutor::new()
+            .execute(&ctx, &plan, b"small".to_vec(), props)
+            .unwrap();
+
+        assert_eq!(
+            *messages.lock().unwrap(),
+            ["step 1 (test.a-to-b):\n+ label: \"x\"\n~ format: \"a\" -> \"b\""]
+        );
+    }
+
+    #[test]
+    fn test_executor_progress() {
+        use std::sync::Mutex;
+
+        let mut registry = Registry::new();
+        registry.register(IdentityConverter::new("a", "b"));
+        registry.register(IdentityConverter::new("b", "c"));
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let sink = calls.clone();
+        let ctx = ExecutionContext::new(Arc::new(registry))
+            .with_memory_limit(1000)
+            .with_progress(move |step, total, id| {
+                sink.lock().unwrap().push((step, total, id.to_string()))
+            });
+
+        let step = |from: &str, to: &str| {
+            crate::PlanStep::new(
+                format!("test.{}-to-{}", from, to),
+                "in",
+                "out",
+                Properties::new().with("format", to),
+            )
+        };
+        let plan = Plan {
+            steps: vec![step("a", "b"), step("b", "c")],
+            cost: 2.0,
+        };
+
+        let props = Properties::new().with("format", "a");
+        BoundedExecutor::new()
+            .execute(&ctx, &plan, b"small".to_vec(), props)
+            .unwrap();
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                (1, 2, "test.a-to-b".to_string()),
+                (2, 2, "test.b-to-c".to_string())
+            ]
+        );
+    }
+
+    /// Test converter that sleeps, then repeats its input `factor` times.
+    struct SlowConverter {
+        decl: ConverterDecl,
+        delay: Duration,
+        factor: usize,
+    }
+
+    impl SlowConverter {
+        fn new(from: &str, to: &str, delay_ms: u64, factor: usize) -> Self {
+            let decl = ConverterDecl::simple(
+                format!("test.slow-{}-to-{}", from, to),
+                PropertyPattern::new().eq("format", from),
+                PropertyPattern::new().eq("format", to),
+            );
+            Self {
+                decl,
+                delay: Duration::from_millis(delay_ms),
+                factor,
+            }
+        }
+    }
+
+    impl Converter for SlowConverter {
+        fn decl(&self) -> &ConverterDecl {
+            &self.decl
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            std::thread::sleep(self.delay);
+            Ok(ConvertOutput::Single(
+                input.repeat(self.factor),
+                props.clone(),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_per_step_stats() {
+        let mut registry = Registry::new();
+        registry.register(SlowConverter::new("a", "b", 30, 1));
+        registry.register(SlowConverter::new("b", "c", 60, 4));
+        registry.register(SlowConverter::new("c", "d", 10, 1));
+        let ctx = ExecutionContext::new(Arc::new(registry));
+
+        let step = |from: &str, to: &str| {
+            crate::PlanStep::new(
+                format!("test.slow-{}-to-{}", from, to),
+                "in",
+                "out",
+                Properties::new().with("format", to),
+            )
+        };
+        let plan = Plan {
+            steps: vec![step("a", "b"), step("b", "c"), step("c", "d")],
+            cost: 3.0,
+        };
+
+        let props = Properties::new().with("format", "a");
+        let stats = SimpleExecutor::new()
+            .execute(&ctx, &plan, b"0123456789".to_vec(), props)
+            .unwrap()
+            .stats;
+
+        let ids: Vec<_> = stats
+            .per_step_stats
+            .iter()
+            .map(|s| s.converter_id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            ["test.slow-a-to-b", "test.slow-b-to-c", "test.slow-c-to-d"]
+        );
+
+        let second = &stats.per_step_stats[1];
+        assert_eq!(second.input_bytes, 10);
+        assert_eq!(second.output_bytes, 40);
+        assert_eq!(second.memory_estimate, 50);
+        assert_eq!(stats.per_step_stats[2].input_bytes, 40);
+
+        assert_eq!(
+            stats.slowest_step().unwrap().converter_id,
+            "test.slow-b-to-c"
+        );
+        assert_eq!(
+            stats.largest_expansion().unwrap().converter_id,
+            "test.slow-b-to-c"
+        );
+
+        let total: Duration = stats.per_step_stats.iter().map(|s| s.duration).sum();
+        assert!(total <= stats.duration);
+        assert!(total.as_secs_f64() >= stats.duration.as_secs_f64() * 0.9);
+    }
+
     #[test]
     fn test_bounded_executor_exceeds_limit() {
         let mut registry = Registry::new();
@@ -917,12 +1452,12 @@ mod tests {
         let ctx = ExecutionContext::new(Arc::new(registry)).with_memory_limit(1);
 
         let plan = Plan {
-            steps: vec![crate::PlanStep {
-                converter_id: "test.a-to-b".into(),
-                input_port: "in".into(),
-                output_port: "out".into(),
-                output_properties: Properties::new().with("format", "b"),
-            }],
+            steps: vec![crate::PlanStep::new(
+                "test.a-to-b",
+                "in",
+                "out",
+                Properties::new().with("format", "b"),
+            )],
             cost: 1.0,
         };
 
@@ -938,7 +1473,7 @@ mod tests {
     }
 
     #[test]
-    fn test_execute_batch() {
+    fn test_step_property_injection() {
         let mut registry = Registry::new();
         registry.register(IdentityConverter::new("a", "b"));
 
@@ -946,14 +1481,50 @@ mod tests {
 
         let plan = Plan {
             steps: vec![crate::PlanStep {
-                converter_id: "test.a-to-b".into(),
-                input_port: "in".into(),
-                output_port: "out".into(),
-                output_properties: Properties::new().with("format", "b"),
+                input_properties: Properties::new().with("level", 19i64),
+                output_overrides: Properties::new().with("label", "done"),
+                ..crate::PlanStep::new(
+                    "test.a-to-b",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "b"),
+                )
             }],
             cost: 1.0,
         };
 
+        let result = SimpleExecutor::new()
+            .execute(
+                &ctx,
+                &plan,
+                b"data".to_vec(),
+                Properties::new().with("format", "a").with("level", 3i64),
+            )
+            .unwrap();
+
+        // Injected input properties reach the converter (and override existing ones)
+        assert_eq!(result.props.get("level").unwrap().as_i64(), Some(19));
+        assert_eq!(result.props.get("label").unwrap().as_str(), Some("done"));
+        assert_eq!(result.props.get("format").unwrap().as_str(), Some("b"));
+    }
+
+    #[test]
+    fn test_execute_batch() {
+        let mut registry = Registry::new();
+        registry.register(IdentityConverter::new("a", "b"));
+
+        let ctx = ExecutionContext::new(Arc::new(registry));
+
+        let plan = Plan {
+            steps: vec![crate::PlanStep::new(
+                "test.a-to-b",
+                "in",
+                "out",
+                Properties::new().with("format", "b"),
+            )],
+            cost: 1.0,
+        };
+
         let jobs = vec![
             Job::new(
                 plan.clone(),
@@ -1034,12 +1605,12 @@ mod tests {
         let ctx = ExecutionContext::new(Arc::new(registry));
 
         let plan = Plan {
-            steps: vec![crate::PlanStep {
-                converter_id: "test.expander".into(),
-                input_port: "in".into(),
-                output_port: "out".into(),
-                output_properties: Properties::new().with("format", "file"),
-            }],
+            steps: vec![crate::PlanStep::new(
+                "test.expander",
+                "in",
+                "out",
+                Properties::new().with("format", "file"),
+            )],
             cost: 1.0,
         };
 
@@ -1073,18 +1644,18 @@ mod tests {
 
         let plan = Plan {
             steps: vec![
-                crate::PlanStep {
-                    converter_id: "test.expander".into(),
-                    input_port: "in".into(),
-                    output_port: "out".into(),
-                    output_properties: Properties::new().with("format", "raw"),
-                },
-                crate::PlanStep {
-                    converter_id: "test.raw-to-processed".into(),
-                    input_port: "in".into(),
-                    output_port: "out".into(),
-                    output_properties: Properties::new().with("format", "processed"),
-                },
+                crate::PlanStep::new(
+                    "test.expander",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "raw"),
+                ),
+                crate::PlanStep::new(
+                    "test.raw-to-processed",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "processed"),
+                ),
             ],
             cost: 2.0,
         };
@@ -1118,12 +1689,12 @@ mod tests {
         let ctx = ExecutionContext::new(Arc::new(registry));
 
         let plan = Plan {
-            steps: vec![crate::PlanStep {
-                converter_id: "test.expander".into(),
-                input_port: "in".into(),
-                output_port: "out".into(),
-                output_properties: Properties::new().with("format", "file"),
-            }],
+            steps: vec![crate::PlanStep::new(
+                "test.expander",
+                "in",
+                "out",
+                Properties::new().with("format", "file"),
+            )],
             cost: 1.0,
         };
 
@@ -1131,12 +1702,59 @@ mod tests {
         let input = b"content".to_vec();
         let props = Properties::new().with("format", "archive");
 
-        // execute() should return single result (last one due to pop())
+        // execute() should return the first expanded result
         let result = executor
             .execute(&ctx, &plan, input, props)
             .expect("should succeed");
 
         assert_eq!(result.props.get("format").unwrap().as_str(), Some("file"));
+        assert_eq!(result.props.get("index").unwrap().as_i64(), Some(0));
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_parallel_execute_expanding() {
+        let mut registry = Registry::new();
+        registry.register(ExpanderConverter::new("archive", "raw", 2));
+        registry.register(IdentityConverter::new("raw", "processed"));
+
+        let ctx = ExecutionContext::new(Arc::new(registry));
+
+        let plan = Plan {
+            steps: vec![
+                crate::PlanStep::new(
+                    "test.expander",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "raw"),
+                ),
+                crate::PlanStep::new(
+                    "test.raw-to-processed",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "processed"),
+                ),
+            ],
+            cost: 2.0,
+        };
+
+        let results = ParallelExecutor::new()
+            .execute_expanding(
+                &ctx,
+                &plan,
+                b"data".to_vec(),
+                Properties::new().with("format", "archive"),
+            )
+            .expect("should succeed");
+
+        // Should not collapse to a single result
+        assert_eq!(results.len(), 2);
+        for result in &results {
+            assert_eq!(
+                result.props.get("format").unwrap().as_str(),
+                Some("processed")
+            );
+        }
     }
 
     /// Test converter that aggregates multiple inputs into one output.
@@ -1197,12 +1815,12 @@ mod tests {
         let ctx = ExecutionContext::new(Arc::new(registry));
 
         let plan = Plan {
-            steps: vec![crate::PlanStep {
-                converter_id: "test.aggregator".into(),
-                input_port: "in".into(),
-                output_port: "out".into(),
-                output_properties: Properties::new().with("format", "bundle"),
-            }],
+            steps: vec![crate::PlanStep::new(
+                "test.aggregator",
+                "in",
+                "out",
+                Properties::new().with("format", "bundle"),
+            )],
             cost: 1.0,
         };
 
@@ -1236,18 +1854,18 @@ mod tests {
 
         let plan = Plan {
             steps: vec![
-                crate::PlanStep {
-                    converter_id: "test.raw-to-item".into(),
-                    input_port: "in".into(),
-                    output_port: "out".into(),
-                    output_properties: Properties::new().with("format", "item"),
-                },
-                crate::PlanStep {
-                    converter_id: "test.aggregator".into(),
-                    input_port: "in".into(),
-                    output_port: "out".into(),
-                    output_properties: Properties::new().with("format", "bundle"),
-                },
+                crate::PlanStep::new(
+                    "test.raw-to-item",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "item"),
+                ),
+                crate::PlanStep::new(
+                    "test.aggregator",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "bundle"),
+                ),
             ],
             cost: 2.0,
         };
@@ -1268,6 +1886,51 @@ mod tests {
         assert_eq!(result.props.get("count").unwrap().as_i64(), Some(2));
     }
 
+    #[test]
+    fn test_execute_aggregating_progress() {
+        use std::sync::Mutex;
+
+        let mut registry = Registry::new();
+        registry.register(IdentityConverter::new("raw", "item"));
+        registry.register(AggregatorConverter::new());
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let sink = calls.clone();
+        let ctx =
+            ExecutionContext::new(Arc::new(registry)).with_progress(move |step, total, id| {
+                sink.lock().unwrap().push((step, total, id.to_string()))
+            });
+
+        let step = |id: &str, to: &str| {
+            crate::PlanStep::new(id, "in", "out", Properties::new().with("format", to))
+        };
+        let plan = Plan {
+            steps: vec![
+                step("test.raw-to-item", "item"),
+                step("test.aggregator", "bundle"),
+            ],
+            cost: 2.0,
+        };
+
+        let inputs = vec![
+            (b"a".to_vec(), Properties::new().with("format", "raw")),
+            (b"b".to_vec(), Properties::new().with("format", "raw")),
+        ];
+
+        SimpleExecutor::new()
+            .execute_aggregating(&ctx, &plan, inputs)
+            .unwrap();
+
+        // Each step is reported once, however many inputs it processes
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                (1, 2, "test.raw-to-item".to_string()),
+                (2, 2, "test.aggregator".to_string())
+            ]
+        );
+    }
+
     #[test]
     fn test_execute_aggregating_empty_plan() {
         let registry = Registry::new();
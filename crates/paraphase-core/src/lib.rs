@@ -3,23 +3,37 @@
 //! Paraphase is a route planner for data conversion. Given source and target
 //! properties, it finds a path through available converters.
 
+#[cfg(feature = "tokio")]
+mod async_exec;
 mod converter;
 mod executor;
 mod pattern;
 mod planner;
 mod properties;
 mod registry;
+mod schema;
 mod workflow;
 
-pub use converter::{ConvertError, ConvertOutput, Converter, ConverterDecl, NamedInput, PortDecl};
+#[cfg(feature = "tokio")]
+pub use async_exec::{
+    AsyncConverter, AsyncExecutor, AsyncPlanner, AsyncRegistry, AsyncSimpleExecutor, BoxFuture,
+    SyncToAsyncConverter,
+};
+pub use converter::{
+    ConvertError, ConvertOutput, Converter, ConverterDecl, NamedInput, PortDecl, describe_error,
+};
 #[cfg(feature = "parallel")]
 pub use executor::ParallelExecutor;
 pub use executor::{
-    BoundedExecutor, ExecuteError, ExecutionContext, ExecutionResult, ExecutionStats, Executor,
-    Job, MemoryBudget, MemoryPermit, SimpleExecutor, estimate_memory,
+    BoundedExecutor, DebugLog, ExecuteError, ExecutionContext, ExecutionResult, ExecutionStats,
+    Executor, Job, MemoryBudget, MemoryPermit, ProgressCallback, SimpleExecutor, StepStats,
+    estimate_memory, estimate_plan_memory,
 };
 pub use pattern::{Predicate, PropertyPattern};
-pub use planner::{Cardinality, OptimizeTarget, Plan, PlanStep, Planner};
-pub use properties::{Properties, PropertiesExt, Value};
+pub use planner::{Cardinality, DEFAULT_MAX_DEPTH, OptimizeTarget, Plan, PlanStep, Planner};
+pub use properties::{Properties, PropertiesDiff, PropertiesExt, VALUE_KEY, Value};
 pub use registry::Registry;
+#[cfg(feature = "plugins")]
+pub use registry::{PARAPHASE_ABI_VERSION, PluginError};
+pub use schema::{PropertyError, PropertySchema, PropertyType};
 pub use workflow::{Sink, Source, Step, Workflow, WorkflowError};
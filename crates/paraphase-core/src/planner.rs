@@ -8,7 +8,7 @@ use crate::pattern::PropertyPattern;
 use crate::properties::Properties;
 use crate::registry::Registry;
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
 /// Optimization target for path selection.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -22,6 +22,19 @@ pub enum OptimizeTarget {
     Size,
 }
 
+impl OptimizeTarget {
+    /// Weight of a converter's quality loss in its step cost.
+    ///
+    /// Only [`OptimizeTarget::Quality`] penalizes lossy steps; speed and
+    /// size don't care how much quality is lost.
+    pub fn quality_weight(self) -> f64 {
+        match self {
+            OptimizeTarget::Quality => 10.0,
+            OptimizeTarget::Speed | OptimizeTarget::Size => 0.0,
+        }
+    }
+}
+
 /// A planned conversion path.
 #[derive(Debug, Clone)]
 pub struct Plan {
@@ -31,8 +44,41 @@ pub struct Plan {
     pub cost: f64,
 }
 
+impl Plan {
+    /// Machine-readable form of the plan.
+    ///
+    /// `{"steps": [{"id", "from", "to", "output"}], "cost", "found": true}`,
+    /// where `from` / `to` are the formats going into and coming out of the
+    /// step (starting from `source`), and `output` the expected properties
+    /// after it. See `schemas/plan.json`.
+    pub fn to_json(&self, source: &Properties) -> serde_json::Value {
+        let mut from = source.get("format").cloned();
+        let steps: Vec<serde_json::Value> = self
+            .steps
+            .iter()
+            .map(|step| {
+                let to = step.output_properties.get("format").cloned();
+                let json = serde_json::json!({
+                    "id": step.converter_id,
+                    "from": from,
+                    "to": to,
+                    "output": serde_json::to_value(&step.output_properties)
+                        .unwrap_or_default(),
+                });
+                from = to;
+                json
+            })
+            .collect();
+        serde_json::json!({
+            "steps": steps,
+            "cost": self.cost,
+            "found": true,
+        })
+    }
+}
+
 /// A single step in a conversion plan.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct PlanStep {
     /// Converter ID.
     pub converter_id: String,
@@ -42,6 +88,31 @@ pub struct PlanStep {
     pub output_port: String,
     /// Expected output properties after this step.
     pub output_properties: Properties,
+    /// Properties merged into the input properties before this step runs.
+    pub input_properties: Properties,
+    /// Properties merged into this step's output, overriding the converter's.
+    pub output_overrides: Properties,
+}
+
+impl PlanStep {
+    /// Create a step with no injected input properties or output overrides.
+    ///
+    /// Use struct-update syntax to set the rest:
+    /// `PlanStep { input_properties, ..PlanStep::new(id, "in", "out", props) }`.
+    pub fn new(
+        converter_id: impl Into<String>,
+        input_port: impl Into<String>,
+        output_port: impl Into<String>,
+        output_properties: Properties,
+    ) -> Self {
+        Self {
+            converter_id: converter_id.into(),
+            input_port: input_port.into(),
+            output_port: output_port.into(),
+            output_properties,
+            ..Default::default()
+        }
+    }
 }
 
 /// Cardinality of the data flowing through the plan.
@@ -92,38 +163,97 @@ impl Ord for SearchNode {
     }
 }
 
+/// Default output-size multipliers used by [`OptimizeTarget::Size`].
+///
+/// Values above 1.0 penalize steps producing verbose formats, values below
+/// 1.0 favor compact ones. Formats not listed use 1.0.
+const DEFAULT_SIZE_HINTS: &[(&str, f64)] = &[
+    // Verbose text formats
+    ("xml", 2.0),
+    ("yaml", 1.5),
+    ("ron", 1.5),
+    // Compact binary formats
+    ("cbor", 0.6),
+    ("msgpack", 0.6),
+    ("bincode", 0.5),
+    ("postcard", 0.5),
+    // Images: WebP < JPEG < PNG
+    ("webp", 0.6),
+    ("jpg", 0.8),
+    ("jpeg", 0.8),
+    ("png", 1.0),
+    ("bmp", 3.0),
+];
+
 /// Planner for finding conversion paths.
 pub struct Planner<'a> {
     registry: &'a Registry,
     max_depth: usize,
     optimize: OptimizeTarget,
+    size_hints: HashMap<&'a str, f64>,
+    banned: HashSet<String>,
 }
 
+/// Default [`Planner::with_max_depth`]: longer chains are almost always a
+/// detour through a cycle.
+pub const DEFAULT_MAX_DEPTH: usize = 5;
+
 impl<'a> Planner<'a> {
     /// Create a new planner with the given registry.
     pub fn new(registry: &'a Registry) -> Self {
         Self {
             registry,
-            max_depth: 10,
+            max_depth: DEFAULT_MAX_DEPTH,
             optimize: OptimizeTarget::default(),
+            size_hints: DEFAULT_SIZE_HINTS.iter().copied().collect(),
+            banned: HashSet::new(),
         }
     }
 
-    /// Set maximum search depth.
-    pub fn max_depth(mut self, depth: usize) -> Self {
+    /// Set the maximum number of steps in a plan (default
+    /// [`DEFAULT_MAX_DEPTH`]). No plan is returned if every path is longer.
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
         self.max_depth = depth;
         self
     }
 
+    /// Set the maximum number of steps in a plan in place.
+    pub fn set_max_depth(&mut self, depth: usize) {
+        self.max_depth = depth;
+    }
+
+    /// Set maximum search depth.
+    #[deprecated(note = "use `with_max_depth`")]
+    pub fn max_depth(self, depth: usize) -> Self {
+        self.with_max_depth(depth)
+    }
+
+    /// Never use these converters, e.g. to rule out lossy paths.
+    pub fn with_banned_converters(mut self, ids: &[&str]) -> Self {
+        self.banned.extend(ids.iter().map(|id| id.to_string()));
+        self
+    }
+
     /// Set optimization target for path selection.
     pub fn optimize(mut self, target: OptimizeTarget) -> Self {
         self.optimize = target;
         self
     }
 
+    /// Override the output-size multiplier for a format.
+    ///
+    /// Only used with [`OptimizeTarget::Size`]: each step's cost is
+    /// multiplied by the hint for the format it produces.
+    pub fn size_hint(mut self, format: &'a str, multiplier: f64) -> Self {
+        self.size_hints.insert(format, multiplier);
+        self
+    }
+
     /// Find a conversion path from source to target properties.
     ///
-    /// Uses A* search to find the lowest-cost path.
+    /// Uses A* search to find the lowest-cost path. Each state (format and
+    /// cardinality) is expanded at most once, so cycles in the converter
+    /// graph (see [`Registry::find_cycles`]) can't make the search loop.
     pub fn plan(
         &self,
         source: &Properties,
@@ -153,11 +283,6 @@ impl<'a> Planner<'a> {
         frontier.push(initial);
 
         while let Some(current) = frontier.pop() {
-            // Check depth limit
-            if current.steps.len() >= self.max_depth {
-                continue;
-            }
-
             // Create a state key for visited check
             let state_key = self.state_key(&current.properties, current.cardinality);
             if visited.contains(&state_key) {
@@ -173,8 +298,16 @@ impl<'a> Planner<'a> {
                 });
             }
 
+            // Check depth limit before going any deeper
+            if current.steps.len() >= self.max_depth {
+                continue;
+            }
+
             // Expand neighbors
             for decl in self.registry.declarations() {
+                if self.banned.contains(&decl.id) {
+                    continue;
+                }
                 if let Some(neighbor) = self.try_apply(decl, &current, target, target_cardinality) {
                     let neighbor_key = self.state_key(&neighbor.properties, neighbor.cardinality);
                     if !visited.contains(&neighbor_key) {
@@ -251,19 +384,29 @@ impl<'a> Planner<'a> {
                 output_props.insert(key.clone(), value.clone());
             }
         }
+        if output_decl.restores(&current.properties) {
+            for (key, source) in &output_decl.restore {
+                if let Some(value) = output_props.shift_remove(source) {
+                    output_props.insert(key.clone(), value);
+                }
+            }
+        }
+        if let Some(flag) = &output_decl.restore_flag {
+            output_props.shift_remove(flag);
+        }
 
         // Calculate step cost based on optimization target
-        let step_cost = self.cost_for_converter(decl);
+        let step_cost = self.cost_for_converter(decl) * self.size_multiplier(&output_props);
 
         let new_cost = current.cost + step_cost;
         let heuristic = self.heuristic(&output_props, target);
 
-        let step = PlanStep {
-            converter_id: decl.id.clone(),
-            input_port: input_port.clone(),
-            output_port: output_port.clone(),
-            output_properties: output_props.clone(),
-        };
+        let step = PlanStep::new(
+            decl.id.clone(),
+            input_port.clone(),
+            output_port.clone(),
+            output_props.clone(),
+        );
 
         let mut new_steps = current.steps.clone();
         new_steps.push(step);
@@ -279,7 +422,9 @@ impl<'a> Planner<'a> {
 
     /// Heuristic: estimate remaining cost to goal.
     ///
-    /// Currently just counts mismatched properties.
+    /// Currently just counts mismatched properties, scaled down by the
+    /// cheapest size hint so it never overestimates under
+    /// [`OptimizeTarget::Size`].
     fn heuristic(&self, current: &Properties, target: &PropertyPattern) -> f64 {
         let mut mismatches = 0;
         for (key, predicate) in &target.predicates {
@@ -287,7 +432,27 @@ impl<'a> Planner<'a> {
                 mismatches += 1;
             }
         }
-        mismatches as f64
+
+        let scale = match self.optimize {
+            OptimizeTarget::Size => self.size_hints.values().copied().fold(1.0, f64::min),
+            _ => 1.0,
+        };
+        mismatches as f64 * scale
+    }
+
+    /// Size multiplier for a step producing `output`.
+    ///
+    /// Always 1.0 unless optimizing for size.
+    fn size_multiplier(&self, output: &Properties) -> f64 {
+        if self.optimize != OptimizeTarget::Size {
+            return 1.0;
+        }
+        output
+            .get("format")
+            .and_then(|v| v.as_str())
+            .and_then(|format| self.size_hints.get(format))
+            .copied()
+            .unwrap_or(1.0)
     }
 
     /// Create a state key for visited tracking.
@@ -303,25 +468,28 @@ impl<'a> Planner<'a> {
 
     /// Get the cost for a converter based on optimization target.
     ///
-    /// Cost properties:
-    /// - `quality_loss`: higher = more quality degradation (used for Quality optimization)
+    /// The step cost is `base + quality_loss * quality_weight`, where the
+    /// base cost is:
     /// - `speed`: higher = slower (used for Speed optimization)
     /// - `size`: higher = larger output (used for Size optimization)
     ///
-    /// Falls back to generic `cost` property, then to 1.0.
+    /// falling back to the generic `cost` property, then to 1.0. See
+    /// [`OptimizeTarget::quality_weight`].
     fn cost_for_converter(&self, decl: &ConverterDecl) -> f64 {
         let cost_key = match self.optimize {
-            OptimizeTarget::Quality => "quality_loss",
-            OptimizeTarget::Speed => "speed",
-            OptimizeTarget::Size => "size",
+            OptimizeTarget::Quality => None,
+            OptimizeTarget::Speed => Some("speed"),
+            OptimizeTarget::Size => Some("size"),
         };
 
         // Try optimization-specific cost, fall back to generic "cost", then 1.0
-        decl.costs
-            .get(cost_key)
+        let base = cost_key
+            .and_then(|key| decl.costs.get(key))
             .and_then(|v| v.as_f64())
             .or_else(|| decl.costs.get("cost").and_then(|v| v.as_f64()))
-            .unwrap_or(1.0)
+            .unwrap_or(1.0);
+
+        base + decl.quality_loss() * self.optimize.quality_weight()
     }
 }
 
@@ -407,6 +575,30 @@ mod tests {
         assert_eq!(plan.steps[1].converter_id, "webp-to-gif");
     }
 
+    #[test]
+    fn test_plan_to_json() {
+        let registry = make_test_registry();
+        let plan = Planner::new(&registry)
+            .plan(
+                &Properties::new().with("format", "png"),
+                &PropertyPattern::new().eq("format", "gif"),
+                Cardinality::One,
+                Cardinality::One,
+            )
+            .expect("should find plan");
+
+        let json = plan.to_json(&Properties::new().with("format", "png"));
+        assert_eq!(json["found"], true);
+        assert_eq!(json["cost"], plan.cost);
+        assert_eq!(json["steps"].as_array().unwrap().len(), 2);
+        assert_eq!(json["steps"][0]["id"], "png-to-webp");
+        assert_eq!(json["steps"][0]["from"], "png");
+        assert_eq!(json["steps"][0]["to"], "webp");
+        assert_eq!(json["steps"][1]["from"], "webp");
+        assert_eq!(json["steps"][1]["to"], "gif");
+        assert_eq!(json["steps"][1]["output"]["format"], "gif");
+    }
+
     #[test]
     fn test_already_at_goal() {
         let registry = make_test_registry();
@@ -434,6 +626,162 @@ mod tests {
         assert!(plan.is_none());
     }
 
+    #[test]
+    fn test_max_depth() {
+        let registry = make_test_registry();
+        let source = Properties::new().with("format", "png");
+        let target = PropertyPattern::new().eq("format", "gif");
+
+        // png -> webp -> gif needs two steps
+        let planner = Planner::new(&registry).with_max_depth(1);
+        assert!(
+            planner
+                .plan(&source, &target, Cardinality::One, Cardinality::One)
+                .is_none()
+        );
+
+        let mut planner = Planner::new(&registry);
+        planner.set_max_depth(2);
+        let plan = planner
+            .plan(&source, &target, Cardinality::One, Cardinality::One)
+            .expect("should find plan");
+        assert_eq!(plan.steps.len(), 2);
+    }
+
+    #[test]
+    fn test_banned_converters() {
+        let registry = make_test_registry();
+        let source = Properties::new().with("format", "png");
+        let target = PropertyPattern::new().eq("format", "webp");
+
+        let plan = Planner::new(&registry)
+            .with_banned_converters(&["png-to-webp"])
+            .plan(&source, &target, Cardinality::One, Cardinality::One)
+            .expect("should find plan");
+        let ids: Vec<_> = plan.steps.iter().map(|s| s.converter_id.as_str()).collect();
+        assert_eq!(ids, ["png-to-jpg", "jpg-to-webp"]);
+
+        let planner =
+            Planner::new(&registry).with_banned_converters(&["png-to-webp", "png-to-jpg"]);
+        assert!(
+            planner
+                .plan(&source, &target, Cardinality::One, Cardinality::One)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_restored_output_property() {
+        let mut registry = make_test_registry();
+        registry.register_decl(
+            ConverterDecl::simple(
+                "gzip-to-raw",
+                PropertyPattern::new().eq("format", "gzip"),
+                PropertyPattern::new().eq("format", "raw"),
+            )
+            .restore("format", "inner_format"),
+        );
+        let planner = Planner::new(&registry);
+        let target = PropertyPattern::new().eq("format", "webp");
+
+        let source = Properties::new()
+            .with("format", "gzip")
+            .with("inner_format", "png");
+        let plan = planner
+            .plan(&source, &target, Cardinality::One, Cardinality::One)
+            .expect("should find plan");
+        assert_eq!(plan.steps.len(), 2);
+        assert_eq!(plan.steps[0].converter_id, "gzip-to-raw");
+        let restored = &plan.steps[0].output_properties;
+        assert_eq!(restored.get("format").unwrap().as_str(), Some("png"));
+        assert!(!restored.contains_key("inner_format"));
+
+        // Nothing to restore: the pattern's value stands
+        let source = Properties::new().with("format", "gzip");
+        assert!(
+            planner
+                .plan(&source, &target, Cardinality::One, Cardinality::One)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_restore_flag() {
+        let mut registry = make_test_registry();
+        registry.register_decl(
+            ConverterDecl::simple(
+                "gzip-to-raw",
+                PropertyPattern::new().eq("format", "gzip"),
+                PropertyPattern::new().eq("format", "raw"),
+            )
+            .restore("format", "inner_format")
+            .restore_flag("restore_inner_format"),
+        );
+        let planner = Planner::new(&registry);
+        let source = Properties::new()
+            .with("format", "gzip")
+            .with("inner_format", "png")
+            .with("restore_inner_format", false);
+
+        // Turned off, the step produces raw bytes and can't reach webp
+        let target = PropertyPattern::new().eq("format", "webp");
+        assert!(
+            planner
+                .plan(&source, &target, Cardinality::One, Cardinality::One)
+                .is_none()
+        );
+        let target = PropertyPattern::new().eq("format", "raw");
+        let plan = planner
+            .plan(&source, &target, Cardinality::One, Cardinality::One)
+            .expect("should find plan");
+        let output = &plan.steps[0].output_properties;
+        assert_eq!(output.get("inner_format").unwrap().as_str(), Some("png"));
+        assert!(!output.contains_key("restore_inner_format"));
+
+        // Explicitly on behaves like the default
+        let source = source.with("restore_inner_format", true);
+        let target = PropertyPattern::new().eq("format", "webp");
+        assert!(
+            planner
+                .plan(&source, &target, Cardinality::One, Cardinality::One)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_converter_cycle() {
+        let mut registry = Registry::new();
+        for (from, to) in [("a", "b"), ("b", "a"), ("b", "c")] {
+            registry.register_decl(ConverterDecl::simple(
+                format!("{}-to-{}", from, to),
+                PropertyPattern::new().eq("format", from),
+                PropertyPattern::new().eq("format", to),
+            ));
+        }
+        let planner = Planner::new(&registry).with_max_depth(100);
+        let source = Properties::new().with("format", "a");
+
+        let plan = planner
+            .plan(
+                &source,
+                &PropertyPattern::new().eq("format", "c"),
+                Cardinality::One,
+                Cardinality::One,
+            )
+            .expect("should find plan");
+        let ids: Vec<_> = plan.steps.iter().map(|s| s.converter_id.as_str()).collect();
+        assert_eq!(ids, ["a-to-b", "b-to-c"]);
+
+        // Unreachable target: the search still terminates
+        let plan = planner.plan(
+            &source,
+            &PropertyPattern::new().eq("format", "d"),
+            Cardinality::One,
+            Cardinality::One,
+        );
+        assert!(plan.is_none());
+    }
+
     #[test]
     fn test_aggregation() {
         let registry = make_test_registry();
@@ -512,4 +860,133 @@ mod tests {
         assert_eq!(quality_plan.steps.len(), 1);
         assert_eq!(quality_plan.steps[0].converter_id, "a-to-c-slow");
     }
+
+    #[test]
+    fn test_optimize_quality_avoids_lossy_steps() {
+        let mut registry = Registry::new();
+        let simple = |id: &str, from: &str, to: &str| {
+            ConverterDecl::simple(
+                id,
+                PropertyPattern::new().eq("format", from),
+                PropertyPattern::new().eq("format", to),
+            )
+        };
+
+        registry.register_decl(simple("png-to-jpg", "png", "jpg").with_quality_loss(0.2));
+        registry.register_decl(simple("jpg-to-webp", "jpg", "webp").with_quality_loss(0.2));
+        registry.register_decl(simple("png-to-webp", "png", "webp").with_cost(1.5));
+        // A lossy shortcut to gif and a lossless detour through bmp
+        registry.register_decl(simple("png-to-gif", "png", "gif").with_quality_loss(0.2));
+        registry.register_decl(simple("png-to-bmp", "png", "bmp"));
+        registry.register_decl(simple("bmp-to-gif", "bmp", "gif"));
+
+        assert!(registry.get_decl("png-to-webp").unwrap().is_lossless());
+        assert!(!registry.get_decl("png-to-jpg").unwrap().is_lossless());
+
+        let source = Properties::new().with("format", "png");
+        let plan = |target: OptimizeTarget, format: &str| {
+            Planner::new(&registry)
+                .optimize(target)
+                .plan(
+                    &source,
+                    &PropertyPattern::new().eq("format", format),
+                    Cardinality::One,
+                    Cardinality::One,
+                )
+                .expect("should find plan")
+        };
+
+        // png -> webp stays lossless instead of going through JPEG
+        let webp = plan(OptimizeTarget::Quality, "webp");
+        assert_eq!(webp.steps.len(), 1);
+        assert_eq!(webp.steps[0].converter_id, "png-to-webp");
+        assert_eq!(webp.cost, 1.5);
+
+        // Quality takes the longer lossless route; speed takes the shortcut
+        let gif = plan(OptimizeTarget::Quality, "gif");
+        assert_eq!(gif.steps.len(), 2);
+        assert_eq!(gif.steps[0].converter_id, "png-to-bmp");
+        let gif = plan(OptimizeTarget::Speed, "gif");
+        assert_eq!(gif.steps.len(), 1);
+        assert_eq!(gif.steps[0].converter_id, "png-to-gif");
+    }
+
+    #[test]
+    fn test_optimize_size_avoids_verbose_intermediates() {
+        let mut registry = Registry::new();
+
+        // Direct but expensive: json -> cbor
+        registry.register_decl(
+            ConverterDecl::simple(
+                "json-to-cbor",
+                PropertyPattern::new().eq("format", "json"),
+                PropertyPattern::new().eq("format", "cbor"),
+            )
+            .cost("cost", 1.8),
+        );
+
+        // Cheap but via XML: json -> xml -> cbor
+        registry.register_decl(
+            ConverterDecl::simple(
+                "json-to-xml",
+                PropertyPattern::new().eq("format", "json"),
+                PropertyPattern::new().eq("format", "xml"),
+            )
+            .cost("cost", 0.5),
+        );
+        registry.register_decl(
+            ConverterDecl::simple(
+                "xml-to-cbor",
+                PropertyPattern::new().eq("format", "xml"),
+                PropertyPattern::new().eq("format", "cbor"),
+            )
+            .cost("cost", 0.5),
+        );
+
+        let source = Properties::new().with("format", "json");
+        let target = PropertyPattern::new().eq("format", "cbor");
+
+        // Speed ignores size hints: 0.5 + 0.5 < 1.8
+        let speed_plan = Planner::new(&registry)
+            .optimize(OptimizeTarget::Speed)
+            .plan(&source, &target, Cardinality::One, Cardinality::One)
+            .expect("should find plan");
+        assert_eq!(speed_plan.steps.len(), 2);
+
+        // Size: json -> cbor costs 1.8 * 0.6, while the XML route costs
+        // 0.5 * 2.0 + 0.5 * 0.6
+        let size_plan = Planner::new(&registry)
+            .optimize(OptimizeTarget::Size)
+            .plan(&source, &target, Cardinality::One, Cardinality::One)
+            .expect("should find plan");
+        assert_eq!(size_plan.steps.len(), 1);
+        assert_eq!(size_plan.steps[0].converter_id, "json-to-cbor");
+        assert!(size_plan.cost < 0.5 * 2.0 + 0.5 * 0.6);
+
+        // Hints can be overridden
+        let neutral_plan = Planner::new(&registry)
+            .optimize(OptimizeTarget::Size)
+            .size_hint("xml", 1.0)
+            .plan(&source, &target, Cardinality::One, Cardinality::One)
+            .expect("should find plan");
+        assert_eq!(neutral_plan.steps.len(), 2);
+    }
+
+    #[test]
+    fn test_optimize_speed_prefers_direct() {
+        let registry = make_test_registry();
+
+        // png -> webp directly beats png -> jpg -> webp at default cost
+        let plan = Planner::new(&registry)
+            .optimize(OptimizeTarget::Speed)
+            .plan(
+                &Properties::new().with("format", "png"),
+                &PropertyPattern::new().eq("format", "webp"),
+                Cardinality::One,
+                Cardinality::One,
+            )
+            .expect("should find plan");
+        assert_eq!(plan.steps.len(), 1);
+        assert_eq!(plan.cost, 1.0);
+    }
 }
@@ -3,7 +3,10 @@
 //! Data is described by property bags, not hierarchical types.
 //! Format is just another property.
 
+use crate::converter::ConvertError;
+use crate::schema::PropertyError;
 use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 /// A JSON-like value that can represent any property.
@@ -110,15 +113,104 @@ impl<T: Into<Value>> From<Vec<T>> for Value {
     }
 }
 
+impl From<serde_json::Value> for Value {
+    fn from(v: serde_json::Value) -> Self {
+        match v {
+            serde_json::Value::Null => Value::Null,
+            serde_json::Value::Bool(b) => Value::Bool(b),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => Value::Int(i),
+                None => n.as_f64().map(Value::Float).unwrap_or(Value::Null),
+            },
+            serde_json::Value::String(s) => Value::String(s),
+            serde_json::Value::Array(arr) => {
+                Value::Array(arr.into_iter().map(Into::into).collect())
+            }
+            serde_json::Value::Object(obj) => {
+                Value::Object(obj.into_iter().map(|(k, v)| (k, v.into())).collect())
+            }
+        }
+    }
+}
+
+impl From<Value> for serde_json::Value {
+    fn from(v: Value) -> Self {
+        match v {
+            Value::Null => serde_json::Value::Null,
+            Value::Bool(b) => serde_json::Value::Bool(b),
+            Value::Int(n) => serde_json::Value::from(n),
+            // NaN and infinities have no JSON representation
+            Value::Float(n) => serde_json::Number::from_f64(n)
+                .map(serde_json::Value::Number)
+                .unwrap_or(serde_json::Value::Null),
+            Value::String(s) => serde_json::Value::String(s),
+            Value::Array(arr) => {
+                serde_json::Value::Array(arr.into_iter().map(Into::into).collect())
+            }
+            Value::Object(obj) => {
+                serde_json::Value::Object(obj.into_iter().map(|(k, v)| (k, v.into())).collect())
+            }
+        }
+    }
+}
+
 /// A bag of properties describing some data.
 ///
 /// Properties are flat by default. Use namespacing only when
 /// semantics differ (e.g., `image.compression` vs `archive.compression`).
 pub type Properties = IndexMap<String, Value>;
 
+/// Key used by [`PropertiesExt::from_json_value`] for non-object JSON.
+pub const VALUE_KEY: &str = "_value";
+
 /// Extension trait for building Properties ergonomically.
-pub trait PropertiesExt {
+///
+/// `Properties` is an alias for a foreign map type, so conversions live
+/// here rather than in `From` impls.
+pub trait PropertiesExt: Sized {
     fn with(self, key: impl Into<String>, value: impl Into<Value>) -> Self;
+
+    /// Read `key` as any deserializable type, e.g. `get_typed::<u32>("jpeg_quality")`.
+    ///
+    /// A missing key reads as null, so `Option<T>` gives `None`; other
+    /// types fail with [`PropertyError::Missing`]. Use
+    /// [`require`](Self::require) when the key must be set.
+    fn get_typed<T: DeserializeOwned>(&self, key: &str) -> Result<T, PropertyError>;
+
+    /// Like [`get_typed`](Self::get_typed), but fails with
+    /// [`PropertyError::Missing`] if the key is absent, even for `Option<T>`.
+    fn require<T: DeserializeOwned>(&self, key: &str) -> Result<T, PropertyError>;
+
+    /// Build properties from a JSON object.
+    ///
+    /// Fails with [`ConvertError::InvalidInput`] if `v` is not an object.
+    fn from_json(v: &serde_json::Value) -> Result<Self, ConvertError>;
+
+    /// Build properties from any JSON value.
+    ///
+    /// Objects become one property per key; anything else is stored
+    /// under [`VALUE_KEY`].
+    fn from_json_value(v: serde_json::Value) -> Self;
+
+    /// Build properties from string pairs (e.g. a `HashMap<String, String>`).
+    fn from_strings<K, V>(pairs: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>;
+
+    /// Convert to a JSON object.
+    fn to_json(&self) -> serde_json::Value;
+
+    /// Compare with `other`, treating `self` as the old properties.
+    fn diff(&self, other: &Self) -> PropertiesDiff;
+
+    /// Copy with keys in alphabetical order, including nested objects.
+    fn with_sorted_keys(&self) -> Self;
+
+    /// Render as `key1=value1;key2=value2` with keys sorted and values as
+    /// compact JSON, so equal properties give the same string whatever
+    /// their insertion order. Suitable for cache keys and golden files.
+    fn to_canonical_string(&self) -> String;
 }
 
 impl PropertiesExt for Properties {
@@ -126,12 +218,228 @@ impl PropertiesExt for Properties {
         self.insert(key.into(), value.into());
         self
     }
+
+    fn get_typed<T: DeserializeOwned>(&self, key: &str) -> Result<T, PropertyError> {
+        let value = self
+            .get(key)
+            .map_or(serde_json::Value::Null, |v| v.clone().into());
+        serde_json::from_value(value).map_err(|e| {
+            if self.contains_key(key) {
+                PropertyError::Invalid {
+                    property: key.to_string(),
+                    message: format!("{} is invalid: {}", key, e),
+                }
+            } else {
+                PropertyError::Missing { key: key.into() }
+            }
+        })
+    }
+
+    fn require<T: DeserializeOwned>(&self, key: &str) -> Result<T, PropertyError> {
+        if !self.contains_key(key) {
+            return Err(PropertyError::Missing { key: key.into() });
+        }
+        self.get_typed(key)
+    }
+
+    fn from_json(v: &serde_json::Value) -> Result<Self, ConvertError> {
+        match v {
+            serde_json::Value::Object(obj) => Ok(obj
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone().into()))
+                .collect()),
+            other => Err(ConvertError::invalid_input_msg(format!(
+                "Expected JSON object for properties, got {}",
+                json_type_name(other)
+            ))),
+        }
+    }
+
+    fn from_json_value(v: serde_json::Value) -> Self {
+        match v {
+            serde_json::Value::Object(obj) => obj.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            other => Properties::new().with(VALUE_KEY, other),
+        }
+    }
+
+    fn from_strings<K, V>(pairs: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        pairs
+            .into_iter()
+            .map(|(k, v)| (k.into(), Value::String(v.into())))
+            .collect()
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Object(
+            self.iter()
+                .map(|(k, v)| (k.clone(), v.clone().into()))
+                .collect(),
+        )
+    }
+
+    fn diff(&self, other: &Self) -> PropertiesDiff {
+        let mut diff = PropertiesDiff::default();
+        for (key, old) in self {
+            match other.get(key) {
+                None => diff.removed.push(key.clone()),
+                Some(new) if new != old => {
+                    diff.changed.insert(key.clone(), (old.clone(), new.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, new) in other {
+            if !self.contains_key(key) {
+                diff.added.insert(key.clone(), new.clone());
+            }
+        }
+        diff
+    }
+
+    fn with_sorted_keys(&self) -> Self {
+        let mut sorted: Properties = self
+            .iter()
+            .map(|(k, v)| (k.clone(), sort_value_keys(v.clone())))
+            .collect();
+        sorted.sort_keys();
+        sorted
+    }
+
+    fn to_canonical_string(&self) -> String {
+        self.with_sorted_keys()
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, serde_json::Value::from(v.clone())))
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+}
+
+fn sort_value_keys(value: Value) -> Value {
+    match value {
+        Value::Array(items) => Value::Array(items.into_iter().map(sort_value_keys).collect()),
+        Value::Object(map) => {
+            let mut map: IndexMap<String, Value> = map
+                .into_iter()
+                .map(|(k, v)| (k, sort_value_keys(v)))
+                .collect();
+            map.sort_keys();
+            Value::Object(map)
+        }
+        other => other,
+    }
+}
+
+/// What changed between two property sets; see [`PropertiesExt::diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropertiesDiff {
+    /// Keys only present in the new properties.
+    pub added: IndexMap<String, Value>,
+    /// Keys only present in the old properties.
+    pub removed: Vec<String>,
+    /// Keys present in both with different values, as `(old, new)`.
+    pub changed: IndexMap<String, (Value, Value)>,
+}
+
+impl PropertiesDiff {
+    /// True if the two property sets were equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Human-readable summary, one change per line:
+    /// `+ key: value`, `- key` and `~ key: old -> new`.
+    pub fn display(&self) -> String {
+        if self.is_empty() {
+            return "(no changes)".into();
+        }
+
+        let json = |v: &Value| serde_json::Value::from(v.clone()).to_string();
+        let mut lines = Vec::new();
+        for (key, value) in &self.added {
+            lines.push(format!("+ {}: {}", key, json(value)));
+        }
+        for key in &self.removed {
+            lines.push(format!("- {}", key));
+        }
+        for (key, (old, new)) in &self.changed {
+            lines.push(format!("~ {}: {} -> {}", key, json(old), json(new)));
+        }
+        lines.join("\n")
+    }
+}
+
+fn json_type_name(v: &serde_json::Value) -> &'static str {
+    match v {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_get_typed() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Crop {
+            x: u32,
+            y: u32,
+            label: Option<String>,
+        }
+
+        let props = Properties::new()
+            .with("jpeg_quality", 85i64)
+            .with("scale", 0.5)
+            .with("int_scale", 2i64)
+            .with("strip", true)
+            .with("format", "png")
+            .with("crop", serde_json::json!({"x": 10, "y": 20}));
+
+        assert_eq!(props.get_typed::<u32>("jpeg_quality").unwrap(), 85);
+        assert_eq!(props.get_typed::<f64>("scale").unwrap(), 0.5);
+        assert_eq!(props.get_typed::<f64>("int_scale").unwrap(), 2.0);
+        assert!(props.get_typed::<bool>("strip").unwrap());
+        assert_eq!(props.get_typed::<String>("format").unwrap(), "png");
+        assert_eq!(
+            props.get_typed::<Crop>("crop").unwrap(),
+            Crop {
+                x: 10,
+                y: 20,
+                label: None
+            }
+        );
+
+        // Absent keys are null: fine for Option, an error otherwise
+        assert_eq!(props.get_typed::<Option<u32>>("missing").unwrap(), None);
+        let missing = PropertyError::Missing {
+            key: "missing".into(),
+        };
+        assert_eq!(props.get_typed::<u32>("missing"), Err(missing.clone()));
+
+        let err = props.get_typed::<u32>("format").unwrap_err();
+        assert_eq!(err.property(), "format");
+        assert!(
+            err.to_string()
+                .starts_with("format is invalid: invalid type")
+        );
+        assert!(props.get_typed::<u8>("jpeg_quality").is_ok());
+        assert!(props.get_typed::<i8>("crop").is_err());
+
+        assert_eq!(props.require::<u32>("jpeg_quality").unwrap(), 85);
+        let err = props.require::<Option<u32>>("missing").unwrap_err();
+        assert_eq!(err, missing);
+        assert_eq!(err.to_string(), "missing is required");
+    }
+
     #[test]
     fn test_value_conversions() {
         assert_eq!(Value::from(true), Value::Bool(true));
@@ -151,6 +459,135 @@ mod tests {
         assert_eq!(props.get("width").and_then(Value::as_i64), Some(1024));
     }
 
+    #[test]
+    fn test_properties_diff() {
+        let old = Properties::new()
+            .with("format", "png")
+            .with("width", 1024i64)
+            .with("path", "in.png");
+
+        assert!(old.diff(&old).is_empty());
+        assert_eq!(old.diff(&old).display(), "(no changes)");
+
+        let new = Properties::new()
+            .with("format", "webp")
+            .with("width", 1024i64)
+            .with("quality", 80i64);
+        let diff = old.diff(&new);
+
+        assert!(!diff.is_empty());
+        assert_eq!(
+            diff.added,
+            IndexMap::from([("quality".to_string(), Value::Int(80))])
+        );
+        assert_eq!(diff.removed, ["path"]);
+        assert_eq!(
+            diff.changed,
+            IndexMap::from([(
+                "format".to_string(),
+                (Value::from("png"), Value::from("webp"))
+            )])
+        );
+        assert_eq!(
+            diff.display(),
+            "+ quality: 80\n- path\n~ format: \"png\" -> \"webp\""
+        );
+    }
+
+    #[test]
+    fn test_properties_canonical_string() {
+        let a = Properties::new()
+            .with("width", 1024i64)
+            .with("format", "png")
+            .with(
+                "meta",
+                Value::Object(IndexMap::from([
+                    ("z".to_string(), Value::Bool(true)),
+                    ("a".to_string(), Value::Null),
+                ])),
+            );
+        let b = Properties::new()
+            .with(
+                "meta",
+                Value::Object(IndexMap::from([
+                    ("a".to_string(), Value::Null),
+                    ("z".to_string(), Value::Bool(true)),
+                ])),
+            )
+            .with("format", "png")
+            .with("width", 1024i64);
+
+        assert_eq!(a.to_canonical_string(), b.to_canonical_string());
+        assert_eq!(
+            a.to_canonical_string(),
+            r#"format="png";meta={"a":null,"z":true};width=1024"#
+        );
+        assert_eq!(
+            a.with_sorted_keys().keys().collect::<Vec<_>>(),
+            ["format", "meta", "width"]
+        );
+        let meta = b.with_sorted_keys()["meta"].clone();
+        assert_eq!(
+            meta.as_object().unwrap().keys().collect::<Vec<_>>(),
+            ["a", "z"]
+        );
+    }
+
+    #[test]
+    fn test_properties_json_roundtrip() {
+        let json = serde_json::json!({
+            "format": "png",
+            "width": 1024,
+            "ratio": 1.5,
+            "exif": {"camera": "X100", "iso": 200},
+            "tags": ["a", "b"]
+        });
+
+        let props = Properties::from_json(&json).unwrap();
+        assert_eq!(props.get("format").and_then(Value::as_str), Some("png"));
+        assert_eq!(props.get("width").and_then(Value::as_i64), Some(1024));
+        assert_eq!(props.get("ratio").and_then(Value::as_f64), Some(1.5));
+
+        // Nested values are kept structured, not stringified
+        let exif = props.get("exif").and_then(Value::as_object).unwrap();
+        assert_eq!(exif.get("iso").and_then(Value::as_i64), Some(200));
+        assert_eq!(
+            props.get("tags").and_then(Value::as_array).unwrap().len(),
+            2
+        );
+
+        assert_eq!(props.to_json(), json);
+    }
+
+    #[test]
+    fn test_properties_from_non_object_json() {
+        assert!(matches!(
+            Properties::from_json(&serde_json::json!([1, 2])),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+
+        let props = Properties::from_json_value(serde_json::json!(42));
+        assert_eq!(props.len(), 1);
+        assert_eq!(props.get(VALUE_KEY).and_then(Value::as_i64), Some(42));
+    }
+
+    #[test]
+    fn test_properties_from_strings() {
+        let mut map = std::collections::HashMap::new();
+        map.insert("format".to_string(), "json".to_string());
+        let props = Properties::from_strings(map);
+        assert_eq!(props.get("format").and_then(Value::as_str), Some("json"));
+
+        let ordered: IndexMap<String, String> = [
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "2".to_string()),
+        ]
+        .into();
+        let props = Properties::from_strings(ordered);
+        assert_eq!(props.keys().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(props.get("a"), Some(&Value::String("1".into())));
+    }
+
     #[test]
     fn test_value_accessors() {
         let v = Value::Int(42);
@@ -1,8 +1,11 @@
 //! Registry for converters.
 
-use crate::converter::{Converter, ConverterDecl};
-use crate::properties::Properties;
+use crate::converter::{Converter, ConverterDecl, PortDecl};
+use crate::pattern::Predicate;
+use crate::properties::{Properties, Value};
 use indexmap::IndexMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
 use std::sync::Arc;
 
 /// Registry of available converters.
@@ -47,6 +50,29 @@ impl Registry {
         self.implementations.insert(id, Arc::new(converter));
     }
 
+    /// Remove a converter by ID, returning its implementation.
+    ///
+    /// Returns `None` if no converter has this ID, or it was registered
+    /// with [`register_decl`](Self::register_decl) only (the declaration is
+    /// still removed). The implementation is shared with any executor that
+    /// already holds it, hence the `Arc`.
+    pub fn deregister(&mut self, id: &str) -> Option<Arc<dyn Converter>> {
+        self.declarations.shift_remove(id);
+        self.implementations.shift_remove(id)
+    }
+
+    /// Register a converter in place of the one with the same ID, returning
+    /// the previous implementation.
+    ///
+    /// The converter keeps its predecessor's position in registration
+    /// order. With no predecessor this is [`register`](Self::register).
+    pub fn replace(&mut self, converter: impl Converter + 'static) -> Option<Arc<dyn Converter>> {
+        let decl = converter.decl().clone();
+        let id = decl.id.clone();
+        self.declarations.insert(id.clone(), decl);
+        self.implementations.insert(id, Arc::new(converter))
+    }
+
     /// Get a converter declaration by ID.
     pub fn get_decl(&self, id: &str) -> Option<&ConverterDecl> {
         self.declarations.get(id)
@@ -57,11 +83,68 @@ impl Registry {
         self.implementations.get(id).cloned()
     }
 
-    /// Iterate over all declarations.
+    /// Iterate over all declarations, in registration order.
     pub fn declarations(&self) -> impl Iterator<Item = &ConverterDecl> {
         self.declarations.values()
     }
 
+    /// All declarations, sorted by converter ID.
+    pub fn declarations_sorted(&self) -> Vec<&ConverterDecl> {
+        let mut decls: Vec<_> = self.declarations.values().collect();
+        decls.sort_by(|a, b| a.id.cmp(&b.id));
+        decls
+    }
+
+    /// Declarations grouped by namespace, the ID component before the
+    /// first `.` (`serde`, `image`, ...), each group sorted by ID.
+    ///
+    /// Converters whose ID has no `.` are grouped under `""`.
+    pub fn namespaced(&self) -> BTreeMap<String, Vec<&ConverterDecl>> {
+        let mut groups: BTreeMap<String, Vec<&ConverterDecl>> = BTreeMap::new();
+        for decl in self.declarations_sorted() {
+            let namespace = decl.id.split_once('.').map_or("", |(prefix, _)| prefix);
+            groups.entry(namespace.to_string()).or_default().push(decl);
+        }
+        groups
+    }
+
+    /// Declarations whose ID starts with `prefix.`, sorted by ID.
+    pub fn get_namespace(&self, prefix: &str) -> Vec<&ConverterDecl> {
+        let prefix = format!("{}.", prefix);
+        self.declarations_sorted()
+            .into_iter()
+            .filter(|decl| decl.id.starts_with(&prefix))
+            .collect()
+    }
+
+    /// Declarations with an input port that explicitly accepts `format`,
+    /// sorted by converter ID.
+    ///
+    /// Format-agnostic converters (no `format` predicate) are not included.
+    pub fn declarations_by_input_format(&self, format: &str) -> Vec<&ConverterDecl> {
+        self.declarations_sorted()
+            .into_iter()
+            .filter(|decl| {
+                decl.inputs
+                    .values()
+                    .any(|port| port_has_format(port, format))
+            })
+            .collect()
+    }
+
+    /// Declarations with an output port that explicitly produces `format`,
+    /// sorted by converter ID.
+    pub fn declarations_by_output_format(&self, format: &str) -> Vec<&ConverterDecl> {
+        self.declarations_sorted()
+            .into_iter()
+            .filter(|decl| {
+                decl.outputs
+                    .values()
+                    .any(|port| port_has_format(port, format))
+            })
+            .collect()
+    }
+
     /// Find all converters that can handle the given input properties.
     ///
     /// Returns converter IDs and the name of the matching input port.
@@ -80,6 +163,120 @@ impl Registry {
             .collect()
     }
 
+    /// Render the converter graph in Graphviz DOT format.
+    ///
+    /// Formats are nodes and converters are edges labeled with their ID.
+    /// Converters sharing an ID prefix (`serde.`, `image.`, ...) are grouped
+    /// into a cluster. Converters without an explicit input and output
+    /// format (e.g. format-preserving transforms) are left out.
+    pub fn to_dot(&self) -> String {
+        let mut nodes = BTreeSet::new();
+        let mut clusters: BTreeMap<Option<&str>, Vec<(String, String, &str)>> = BTreeMap::new();
+
+        for decl in self.declarations_sorted() {
+            let inputs: BTreeSet<_> = decl.inputs.values().flat_map(port_formats).collect();
+            let outputs: BTreeSet<_> = decl.outputs.values().flat_map(port_formats).collect();
+            let prefix = decl.id.split_once('.').map(|(prefix, _)| prefix);
+
+            for from in &inputs {
+                for to in &outputs {
+                    clusters
+                        .entry(prefix)
+                        .or_default()
+                        .push((from.clone(), to.clone(), &decl.id));
+                }
+            }
+            nodes.extend(inputs);
+            nodes.extend(outputs);
+        }
+
+        let mut dot =
+            String::from("digraph converters {\n    rankdir=LR;\n    node [shape=box];\n");
+        if !nodes.is_empty() {
+            dot.push('\n');
+        }
+        for node in &nodes {
+            let _ = writeln!(dot, "    {};", dot_quote(node));
+        }
+
+        for (prefix, edges) in &clusters {
+            dot.push('\n');
+            let indent = match prefix {
+                Some(prefix) => {
+                    let _ = writeln!(
+                        dot,
+                        "    subgraph {} {{",
+                        dot_quote(&format!("cluster_{}", prefix))
+                    );
+                    let _ = writeln!(dot, "        label={};", dot_quote(prefix));
+                    "        "
+                }
+                None => "    ",
+            };
+            for (from, to, id) in edges {
+                let _ = writeln!(
+                    dot,
+                    "{}{} -> {} [label={}];",
+                    indent,
+                    dot_quote(from),
+                    dot_quote(to),
+                    dot_quote(id)
+                );
+            }
+            if prefix.is_some() {
+                dot.push_str("    }\n");
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Find cycles in the converter graph.
+    ///
+    /// Uses the graph of [`to_dot`](Self::to_dot), with formats as nodes.
+    /// Each strongly connected component of two or more formats is a cycle,
+    /// reported as the sorted IDs of the converters between its formats.
+    /// Format-preserving converters are not reported: the planner never
+    /// chains them, since they don't change its search state.
+    pub fn find_cycles(&self) -> Vec<Vec<String>> {
+        let mut edges = Vec::new();
+        for decl in self.declarations_sorted() {
+            let inputs: BTreeSet<_> = decl.inputs.values().flat_map(port_formats).collect();
+            let outputs: BTreeSet<_> = decl.outputs.values().flat_map(port_formats).collect();
+            for from in &inputs {
+                for to in outputs.iter().filter(|to| *to != from) {
+                    edges.push((from.clone(), to.clone(), decl.id.as_str()));
+                }
+            }
+        }
+
+        let mut graph: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (from, to, _) in &edges {
+            graph.entry(from).or_default().push(to);
+            graph.entry(to).or_default();
+        }
+
+        let mut cycles: Vec<Vec<String>> = strongly_connected(&graph)
+            .into_iter()
+            .filter(|component| component.len() > 1)
+            .map(|component| {
+                let mut ids: Vec<String> = edges
+                    .iter()
+                    .filter(|(from, to, _)| {
+                        component.contains(from.as_str()) && component.contains(to.as_str())
+                    })
+                    .map(|(_, _, id)| id.to_string())
+                    .collect();
+                // Edges are in ID order, so repeats are adjacent
+                ids.dedup();
+                ids
+            })
+            .collect();
+        cycles.sort();
+        cycles
+    }
+
     /// Number of registered converters.
     pub fn len(&self) -> usize {
         self.declarations.len()
@@ -91,10 +288,179 @@ impl Registry {
     }
 }
 
+/// Version of the plugin ABI: the `paraphase_register` signature and the
+/// [`Registry`] layout it relies on.
+///
+/// A plugin's `paraphase_plugin_version` must return this value for
+/// [`Registry::load_plugin`] to accept it.
+#[cfg(feature = "plugins")]
+pub const PARAPHASE_ABI_VERSION: u32 = 1;
+
+/// Errors that can occur while loading a plugin.
+#[cfg(feature = "plugins")]
+#[derive(Debug, thiserror::Error)]
+pub enum PluginError {
+    #[error("failed to load plugin {path}: {source}")]
+    Load {
+        path: std::path::PathBuf,
+        #[source]
+        source: libloading::Error,
+    },
+
+    #[error("plugin does not export `{symbol}`: {source}")]
+    MissingSymbol {
+        symbol: &'static str,
+        #[source]
+        source: libloading::Error,
+    },
+
+    #[error("plugin ABI version {found} does not match {expected}")]
+    VersionMismatch { expected: u32, found: u32 },
+}
+
+#[cfg(feature = "plugins")]
+impl Registry {
+    /// Load a converter plugin from a shared library (`.so`, `.dylib`, `.dll`).
+    ///
+    /// The library must export two C functions:
+    /// - `paraphase_plugin_version() -> u32`, returning [`PARAPHASE_ABI_VERSION`]
+    /// - `paraphase_register(registry: *mut Registry)`, registering its converters
+    ///
+    /// `paraphase_plugin::export_plugin!` generates both. The library stays
+    /// loaded for the rest of the process, since its converters may outlive
+    /// this registry through [`get`](Self::get).
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs its initialisation code, and `paraphase_register`
+    /// receives a Rust type: the plugin must be built with the same compiler
+    /// and `paraphase-core` version as the host. The version check only
+    /// catches ABI bumps, not toolchain differences.
+    pub unsafe fn load_plugin(&mut self, path: &std::path::Path) -> Result<(), PluginError> {
+        type VersionFn = unsafe extern "C" fn() -> u32;
+        type RegisterFn = unsafe extern "C" fn(*mut Registry);
+
+        let library =
+            unsafe { libloading::Library::new(path) }.map_err(|source| PluginError::Load {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        let symbol =
+            |symbol: &'static str| move |source| PluginError::MissingSymbol { symbol, source };
+
+        let version = unsafe { library.get::<VersionFn>(b"paraphase_plugin_version\0") }
+            .map_err(symbol("paraphase_plugin_version"))?;
+        let found = unsafe { version() };
+        if found != PARAPHASE_ABI_VERSION {
+            return Err(PluginError::VersionMismatch {
+                expected: PARAPHASE_ABI_VERSION,
+                found,
+            });
+        }
+
+        let register = unsafe { library.get::<RegisterFn>(b"paraphase_register\0") }
+            .map_err(symbol("paraphase_register"))?;
+        unsafe { register(self) };
+
+        // Registered converters point into the library; never unload it
+        std::mem::forget(library);
+        Ok(())
+    }
+}
+
+/// Check whether a port's pattern constrains `format` to a matching value.
+fn port_has_format(port: &PortDecl, format: &str) -> bool {
+    port.pattern
+        .predicates
+        .get("format")
+        .is_some_and(|predicate| predicate.matches(&Value::from(format)))
+}
+
+/// Formats a port explicitly accepts or produces.
+fn port_formats(port: &PortDecl) -> Vec<String> {
+    match port.pattern.predicates.get("format") {
+        Some(Predicate::Eq(value)) => value.as_str().map(String::from).into_iter().collect(),
+        Some(Predicate::OneOf(values)) => values
+            .iter()
+            .filter_map(|v| v.as_str().map(String::from))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Strongly connected components of `graph`, using Tarjan's algorithm.
+fn strongly_connected<'a>(graph: &BTreeMap<&'a str, Vec<&'a str>>) -> Vec<BTreeSet<&'a str>> {
+    struct Tarjan<'a, 'g> {
+        graph: &'g BTreeMap<&'a str, Vec<&'a str>>,
+        index: HashMap<&'a str, usize>,
+        lowlink: HashMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: HashSet<&'a str>,
+        components: Vec<BTreeSet<&'a str>>,
+    }
+
+    impl<'a> Tarjan<'a, '_> {
+        fn visit(&mut self, node: &'a str) {
+            let index = self.index.len();
+            self.index.insert(node, index);
+            self.lowlink.insert(node, index);
+            self.stack.push(node);
+            self.on_stack.insert(node);
+
+            let graph = self.graph;
+            for &next in &graph[node] {
+                let low = if !self.index.contains_key(next) {
+                    self.visit(next);
+                    self.lowlink[next]
+                } else if self.on_stack.contains(next) {
+                    self.index[next]
+                } else {
+                    continue;
+                };
+                if low < self.lowlink[node] {
+                    self.lowlink.insert(node, low);
+                }
+            }
+
+            // `node` is the root of a component: pop it off the stack
+            if self.lowlink[node] == index {
+                let mut component = BTreeSet::new();
+                while let Some(top) = self.stack.pop() {
+                    self.on_stack.remove(top);
+                    component.insert(top);
+                    if top == node {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        graph,
+        index: HashMap::new(),
+        lowlink: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: HashSet::new(),
+        components: Vec::new(),
+    };
+    for &node in graph.keys() {
+        if !tarjan.index.contains_key(node) {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components
+}
+
+/// Quote a DOT identifier.
+fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::converter::PortDecl;
     use crate::pattern::PropertyPattern;
     use crate::properties::PropertiesExt;
 
@@ -169,6 +535,275 @@ mod tests {
         assert!(matches.is_empty());
     }
 
+    #[test]
+    fn test_declarations_sorted() {
+        let registry = make_test_registry();
+
+        // Registered as png-to-webp, png-to-jpg, jpg-to-webp, frames-to-gif
+        let ids: Vec<_> = registry
+            .declarations_sorted()
+            .iter()
+            .map(|d| d.id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            ["frames-to-gif", "jpg-to-webp", "png-to-jpg", "png-to-webp"]
+        );
+    }
+
+    #[test]
+    fn test_declarations_by_format() {
+        let mut registry = make_test_registry();
+        registry.register_decl(ConverterDecl::simple(
+            "compress",
+            PropertyPattern::new(),
+            PropertyPattern::new().eq("format", "gzip"),
+        ));
+
+        let ids = |decls: Vec<&ConverterDecl>| -> Vec<String> {
+            decls.iter().map(|d| d.id.clone()).collect()
+        };
+
+        // List ports count; format-agnostic inputs don't
+        assert_eq!(
+            ids(registry.declarations_by_input_format("png")),
+            ["frames-to-gif", "png-to-jpg", "png-to-webp"]
+        );
+        assert_eq!(
+            ids(registry.declarations_by_output_format("webp")),
+            ["jpg-to-webp", "png-to-webp"]
+        );
+        assert!(registry.declarations_by_output_format("bmp").is_empty());
+    }
+
+    #[test]
+    fn test_namespaces() {
+        let mut registry = Registry::new();
+        for id in [
+            "serde.yaml-to-json",
+            "image.resize",
+            "serde.json-to-yaml",
+            "serdex.other",
+            "png-to-webp",
+        ] {
+            registry.register_decl(ConverterDecl::simple(
+                id,
+                PropertyPattern::new(),
+                PropertyPattern::new(),
+            ));
+        }
+
+        let ids = |decls: Vec<&ConverterDecl>| -> Vec<String> {
+            decls.into_iter().map(|d| d.id.clone()).collect()
+        };
+
+        let groups = registry.namespaced();
+        assert_eq!(
+            groups.keys().collect::<Vec<_>>(),
+            ["", "image", "serde", "serdex"]
+        );
+        assert_eq!(
+            ids(groups["serde"].clone()),
+            ["serde.json-to-yaml", "serde.yaml-to-json"]
+        );
+        assert_eq!(ids(groups[""].clone()), ["png-to-webp"]);
+
+        // The dot is part of the prefix, so "serde" doesn't match "serdex.*"
+        assert_eq!(
+            ids(registry.get_namespace("serde")),
+            ["serde.json-to-yaml", "serde.yaml-to-json"]
+        );
+        assert!(registry.get_namespace("video").is_empty());
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut registry = Registry::new();
+        registry.register_decl(ConverterDecl::simple(
+            "serde.json-to-yaml",
+            PropertyPattern::new().eq("format", "json"),
+            PropertyPattern::new().eq("format", "yaml"),
+        ));
+        registry.register_decl(ConverterDecl::simple(
+            "serde.yaml-to-json",
+            PropertyPattern::new().eq("format", "yaml"),
+            PropertyPattern::new().eq("format", "json"),
+        ));
+        registry.register_decl(ConverterDecl::simple(
+            "png-to-webp",
+            PropertyPattern::new().eq("format", "png"),
+            PropertyPattern::new().eq("format", "webp"),
+        ));
+        // No explicit formats, so no edge
+        registry.register_decl(ConverterDecl::simple(
+            "image.resize",
+            PropertyPattern::new().exists("width"),
+            PropertyPattern::new(),
+        ));
+
+        let dot = registry.to_dot();
+        assert!(dot.starts_with("digraph converters {"));
+
+        let labels: Vec<_> = dot
+            .lines()
+            .filter_map(|line| line.split_once("[label=\""))
+            .map(|(_, rest)| rest.trim_end_matches("\"];"))
+            .collect();
+        assert_eq!(
+            labels,
+            ["png-to-webp", "serde.json-to-yaml", "serde.yaml-to-json"]
+        );
+
+        for node in ["json", "yaml", "png", "webp"] {
+            assert!(dot.contains(&format!("    \"{}\";", node)));
+        }
+        assert!(dot.contains("subgraph \"cluster_serde\""));
+        assert!(dot.contains("\"json\" -> \"yaml\" [label=\"serde.json-to-yaml\"];"));
+    }
+
+    #[test]
+    fn test_find_cycles() {
+        let mut registry = make_test_registry();
+        assert!(registry.find_cycles().is_empty());
+
+        for (id, from, to) in [
+            ("serde.json-to-yaml", "json", "yaml"),
+            ("serde.yaml-to-json", "yaml", "json"),
+            ("a-to-b", "a", "b"),
+            ("b-to-c", "b", "c"),
+            ("c-to-a", "c", "a"),
+            ("c-to-d", "c", "d"),
+            // Format-preserving, not a cycle
+            ("text.trim", "text", "text"),
+        ] {
+            registry.register_decl(ConverterDecl::simple(
+                id,
+                PropertyPattern::new().eq("format", from),
+                PropertyPattern::new().eq("format", to),
+            ));
+        }
+
+        assert_eq!(
+            registry.find_cycles(),
+            [
+                vec!["a-to-b", "b-to-c", "c-to-a"],
+                vec!["serde.json-to-yaml", "serde.yaml-to-json"],
+            ]
+        );
+    }
+
+    struct Recording {
+        decl: ConverterDecl,
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl Converter for Recording {
+        fn decl(&self) -> &ConverterDecl {
+            &self.decl
+        }
+
+        fn convert(
+            &self,
+            _input: &[u8],
+            props: &Properties,
+        ) -> Result<crate::ConvertOutput, crate::ConvertError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let props = props.clone().with("format", "webp");
+            Ok(crate::ConvertOutput::Single(b"mock".to_vec(), props))
+        }
+    }
+
+    fn recording(id: &str) -> (Recording, Arc<std::sync::atomic::AtomicUsize>) {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let converter = Recording {
+            decl: ConverterDecl::simple(
+                id,
+                PropertyPattern::new().eq("format", "png"),
+                PropertyPattern::new().eq("format", "webp"),
+            ),
+            calls: calls.clone(),
+        };
+        (converter, calls)
+    }
+
+    #[test]
+    fn test_deregister() {
+        use crate::planner::{Cardinality, Planner};
+
+        let mut registry = make_test_registry();
+        let (converter, _) = recording("png-to-webp");
+        registry.replace(converter);
+
+        let plan = |registry: &Registry| {
+            Planner::new(registry).plan(
+                &Properties::new().with("format", "png"),
+                &PropertyPattern::new().eq("format", "webp"),
+                Cardinality::One,
+                Cardinality::One,
+            )
+        };
+        assert_eq!(plan(&registry).unwrap().steps.len(), 1);
+
+        let removed = registry.deregister("png-to-webp").unwrap();
+        assert_eq!(removed.name(), "png-to-webp");
+        assert!(registry.get_decl("png-to-webp").is_none());
+        assert!(registry.get("png-to-webp").is_none());
+        assert_eq!(registry.len(), 3);
+        // Only the longer route through jpg is left
+        assert_eq!(plan(&registry).unwrap().steps.len(), 2);
+        registry.deregister("jpg-to-webp");
+        assert!(plan(&registry).is_none());
+
+        // Declaration-only converters are removed too, with nothing returned
+        assert!(registry.deregister("png-to-jpg").is_none());
+        assert!(registry.get_decl("png-to-jpg").is_none());
+        assert!(registry.deregister("nonexistent").is_none());
+
+        registry.register(removed);
+        assert_eq!(
+            plan(&registry).unwrap().steps[0].converter_id,
+            "png-to-webp"
+        );
+        assert!(registry.get("png-to-webp").is_some());
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut registry = Registry::new();
+        let (original, original_calls) = recording("png-to-webp");
+        assert!(registry.replace(original).is_none());
+        registry.register_decl(ConverterDecl::simple(
+            "png-to-jpg",
+            PropertyPattern::new().eq("format", "png"),
+            PropertyPattern::new().eq("format", "jpg"),
+        ));
+
+        let (mock, mock_calls) = recording("png-to-webp");
+        let previous = registry.replace(mock).unwrap();
+
+        let props = Properties::new().with("format", "png");
+        registry
+            .get("png-to-webp")
+            .unwrap()
+            .convert(b"png", &props)
+            .unwrap();
+        assert_eq!(mock_calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(original_calls.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        // Position in registration order is kept
+        let ids: Vec<_> = registry.declarations().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, ["png-to-webp", "png-to-jpg"]);
+
+        // Restore the original
+        registry.replace(previous);
+        registry
+            .get("png-to-webp")
+            .unwrap()
+            .convert(b"png", &props)
+            .unwrap();
+        assert_eq!(original_calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_get_decl() {
         let registry = make_test_registry();
@@ -0,0 +1,303 @@
+//! Property schemas for validating converter options.
+//!
+//! A converter declares the options it reads with [`PropertySchema`]s, so
+//! bad values are rejected up front with a readable message instead of
+//! being ignored or failing halfway through a conversion.
+
+use crate::properties::{Properties, Value};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The type a property value must have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PropertyType {
+    String,
+    /// Whole number.
+    Integer,
+    /// Any number; integers are accepted too.
+    Float,
+    Boolean,
+}
+
+impl PropertyType {
+    /// Check if a value has this type.
+    pub fn matches(self, value: &Value) -> bool {
+        match self {
+            PropertyType::String => value.as_str().is_some(),
+            PropertyType::Integer => value.as_i64().is_some(),
+            PropertyType::Float => value.as_f64().is_some(),
+            PropertyType::Boolean => value.as_bool().is_some(),
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            PropertyType::String => "a string",
+            PropertyType::Integer => "an integer",
+            PropertyType::Float => "a number",
+            PropertyType::Boolean => "true or false",
+        }
+    }
+}
+
+/// Describes one property a converter reads.
+///
+/// ```
+/// use paraphase_core::PropertySchema;
+///
+/// let quality = PropertySchema::integer().range(1.0, 100.0);
+/// let mode = PropertySchema::string().one_of(["fast", "best"]).required();
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PropertySchema {
+    /// Expected value type.
+    #[serde(rename = "type")]
+    pub ty: PropertyType,
+    /// If true, the property must be present.
+    #[serde(default)]
+    pub required: bool,
+    /// If non-empty, the value must be one of these.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed: Vec<Value>,
+    /// Inclusive lower bound for numeric values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    /// Inclusive upper bound for numeric values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+}
+
+impl PropertySchema {
+    /// Create an optional property of the given type.
+    pub fn new(ty: PropertyType) -> Self {
+        Self {
+            ty,
+            required: false,
+            allowed: Vec::new(),
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Optional string property.
+    pub fn string() -> Self {
+        Self::new(PropertyType::String)
+    }
+
+    /// Optional integer property.
+    pub fn integer() -> Self {
+        Self::new(PropertyType::Integer)
+    }
+
+    /// Optional numeric property.
+    pub fn float() -> Self {
+        Self::new(PropertyType::Float)
+    }
+
+    /// Optional boolean property.
+    pub fn boolean() -> Self {
+        Self::new(PropertyType::Boolean)
+    }
+
+    /// Mark the property as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Restrict the value to a fixed set.
+    pub fn one_of<V: Into<Value>>(mut self, values: impl IntoIterator<Item = V>) -> Self {
+        self.allowed = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Require a numeric value of at least `min`.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Require a numeric value of at most `max`.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Require a numeric value within `min..=max`.
+    pub fn range(self, min: f64, max: f64) -> Self {
+        self.min(min).max(max)
+    }
+
+    /// Check the value of property `name` (`None` if absent).
+    pub fn validate(&self, name: &str, value: Option<&Value>) -> Result<(), PropertyError> {
+        let error = |message: String| {
+            Err(PropertyError::Invalid {
+                property: name.to_string(),
+                message,
+            })
+        };
+
+        let value = match value {
+            None | Some(Value::Null) if self.required => {
+                return Err(PropertyError::Missing { key: name.into() });
+            }
+            None | Some(Value::Null) => return Ok(()),
+            Some(value) => value,
+        };
+
+        if !self.ty.matches(value) {
+            return error(format!(
+                "{} must be {}, got: {}",
+                name,
+                self.ty.describe(),
+                display_value(value)
+            ));
+        }
+
+        if !self.allowed.is_empty() && !self.allowed.contains(value) {
+            let allowed: Vec<String> = self.allowed.iter().map(display_value).collect();
+            return error(format!(
+                "{} must be one of {}, got: {}",
+                name,
+                allowed.join(", "),
+                display_value(value)
+            ));
+        }
+
+        if let Some(n) = value.as_f64() {
+            let out_of_range =
+                self.min.is_some_and(|min| n < min) || self.max.is_some_and(|max| n > max);
+            if out_of_range {
+                let bounds = match (self.min, self.max) {
+                    (Some(min), Some(max)) => format!("{}–{}", min, max),
+                    (Some(min), None) => format!("at least {}", min),
+                    (None, Some(max)) => format!("at most {}", max),
+                    (None, None) => unreachable!(),
+                };
+                return error(format!(
+                    "{} must be {}, got: {}",
+                    name,
+                    bounds,
+                    display_value(value)
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A property that failed its schema.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyError {
+    /// A required property is absent.
+    Missing {
+        /// Name of the property.
+        key: String,
+    },
+    /// A property is present but has the wrong type or value.
+    Invalid {
+        /// Name of the offending property.
+        property: String,
+        /// Human-readable description, e.g. "jpeg_quality must be 1–100, got: 150".
+        message: String,
+    },
+}
+
+impl PropertyError {
+    /// Name of the offending property.
+    pub fn property(&self) -> &str {
+        match self {
+            Self::Missing { key } => key,
+            Self::Invalid { property, .. } => property,
+        }
+    }
+}
+
+impl fmt::Display for PropertyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { key } => write!(f, "{} is required", key),
+            Self::Invalid { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for PropertyError {}
+
+/// Check every property in `schema` against `props`.
+pub(crate) fn validate<'a>(
+    schema: impl IntoIterator<Item = (&'a String, &'a PropertySchema)>,
+    props: &Properties,
+) -> Vec<PropertyError> {
+    schema
+        .into_iter()
+        .filter_map(|(name, schema)| schema.validate(name, props.get(name)).err())
+        .collect()
+}
+
+/// Render a value the way a user would have typed it.
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => "null".into(),
+        Value::Bool(b) => b.to_string(),
+        Value::Int(n) => n.to_string(),
+        Value::Float(n) => n.to_string(),
+        Value::String(s) => format!("\"{}\"", s),
+        Value::Array(_) => "an array".into(),
+        Value::Object(_) => "an object".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_messages() {
+        let quality = PropertySchema::integer().range(1.0, 100.0);
+        assert!(quality.validate("jpeg_quality", None).is_ok());
+        assert!(
+            quality
+                .validate("jpeg_quality", Some(&Value::Int(80)))
+                .is_ok()
+        );
+        assert_eq!(
+            quality
+                .validate("jpeg_quality", Some(&Value::Int(150)))
+                .unwrap_err()
+                .to_string(),
+            "jpeg_quality must be 1–100, got: 150"
+        );
+        assert_eq!(
+            quality
+                .validate("jpeg_quality", Some(&Value::from("high")))
+                .unwrap_err()
+                .to_string(),
+            "jpeg_quality must be an integer, got: \"high\""
+        );
+
+        let scale = PropertySchema::float().min(0.01);
+        assert!(scale.validate("scale", Some(&Value::Int(2))).is_ok());
+        assert_eq!(
+            scale
+                .validate("scale", Some(&Value::Float(0.0)))
+                .unwrap_err()
+                .to_string(),
+            "scale must be at least 0.01, got: 0"
+        );
+
+        let mode = PropertySchema::string().one_of(["rgb", "rgba"]).required();
+        assert_eq!(
+            mode.validate("mode", None),
+            Err(PropertyError::Missing { key: "mode".into() })
+        );
+        assert_eq!(
+            mode.validate("mode", Some(&Value::from("cmyk")))
+                .unwrap_err()
+                .to_string(),
+            "mode must be one of \"rgb\", \"rgba\", got: \"cmyk\""
+        );
+    }
+}
@@ -1,19 +1,22 @@
 //! Workflow definition and execution.
 //!
 //! Workflows are serializable pipelines that define:
-//! - Source: where input comes from (file, glob)
+//! - Source: where input comes from (file, glob, HTTP)
 //! - Steps: converters to apply (optional for auto-planning)
-//! - Sink: where output goes
+//! - Sink: where output goes (file, directory, HTTP)
 //!
 //! Incomplete workflows (missing steps) trigger auto-planning.
 
+use crate::converter::BoxError;
 use crate::pattern::PropertyPattern;
+use crate::planner::{Plan, PlanStep};
 use crate::properties::{Properties, Value};
+use crate::registry::Registry;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 /// A workflow definition.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct Workflow {
     /// Optional preset to apply.
     #[serde(default)]
@@ -37,17 +40,33 @@ pub struct Workflow {
 }
 
 /// Source specification - where input comes from.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Source {
     /// Single file path.
     File { path: String },
     /// Glob pattern for multiple files.
     Glob { glob: String },
+    /// HTTP endpoint, fetched with `method` (default `GET`).
+    ///
+    /// Without an explicit `format`, the response's `Content-Type` decides it.
+    Http {
+        url: String,
+        #[serde(default = "default_source_method")]
+        method: String,
+        #[serde(default)]
+        headers: IndexMap<String, String>,
+        #[serde(default)]
+        format: Option<String>,
+    },
     /// Inline properties (for planning without files).
     Properties { properties: Properties },
 }
 
+fn default_source_method() -> String {
+    "GET".into()
+}
+
 impl Source {
     /// Get source properties for planning.
     pub fn to_properties(&self) -> Properties {
@@ -69,6 +88,15 @@ impl Source {
                 }
                 props
             }
+            Source::Http { url, format, .. } => {
+                let mut props = Properties::new();
+                props.insert("url".into(), Value::String(url.clone()));
+                // Best guess until the response's Content-Type is known
+                if let Some(format) = format.clone().or_else(|| detect_format(url_path(url))) {
+                    props.insert("format".into(), Value::String(format));
+                }
+                props
+            }
             Source::Properties { properties } => properties.clone(),
         }
     }
@@ -80,17 +108,31 @@ impl Source {
 }
 
 /// Sink specification - where output goes.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Sink {
     /// Single file path.
     File { path: String },
     /// Directory (for batch output).
     Directory { directory: String },
+    /// HTTP endpoint the output is sent to with `method` (default `POST`).
+    Http {
+        url: String,
+        #[serde(default = "default_sink_method")]
+        method: String,
+        #[serde(default)]
+        headers: IndexMap<String, String>,
+        #[serde(default)]
+        format: Option<String>,
+    },
     /// Target properties (for planning).
     Properties { properties: Properties },
 }
 
+fn default_sink_method() -> String {
+    "POST".into()
+}
+
 impl Sink {
     /// Get target pattern for planning.
     pub fn to_pattern(&self) -> PropertyPattern {
@@ -107,6 +149,13 @@ impl Sink {
                 let _ = directory;
                 PropertyPattern::new()
             }
+            Sink::Http { url, format, .. } => {
+                let mut pattern = PropertyPattern::new();
+                if let Some(format) = format.clone().or_else(|| detect_format(url_path(url))) {
+                    pattern = pattern.eq("format", format);
+                }
+                pattern
+            }
             Sink::Properties { properties } => {
                 let mut pattern = PropertyPattern::new();
                 for (key, value) in properties {
@@ -119,7 +168,7 @@ impl Sink {
 }
 
 /// A step in the workflow.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Step {
     /// Converter ID to use.
     pub converter: String,
@@ -139,6 +188,39 @@ pub struct Step {
     /// Output port to use (defaults to first/only output).
     #[serde(default)]
     pub output: Option<String>,
+
+    /// Properties merged into the active properties before this step runs
+    /// (e.g. `jpeg_quality: 90`).
+    #[serde(default)]
+    pub properties: Properties,
+
+    /// Input property overrides, applied on top of `properties`.
+    #[serde(default)]
+    pub input_properties: Properties,
+
+    /// Properties merged into this step's output.
+    #[serde(default)]
+    pub output_properties: Properties,
+}
+
+impl Step {
+    /// Convert to a plan step, folding `options`, `properties` and
+    /// `input_properties` (in increasing precedence) into the properties
+    /// injected before conversion.
+    pub fn to_plan_step(&self) -> PlanStep {
+        let mut input_properties = self.options.clone();
+        input_properties.extend(self.properties.clone());
+        input_properties.extend(self.input_properties.clone());
+
+        PlanStep {
+            converter_id: self.converter.clone(),
+            input_port: self.input.clone().unwrap_or_else(|| "in".into()),
+            output_port: self.output.clone().unwrap_or_else(|| "out".into()),
+            output_properties: Properties::new(),
+            input_properties,
+            output_overrides: self.output_properties.clone(),
+        }
+    }
 }
 
 impl Workflow {
@@ -171,6 +253,9 @@ impl Workflow {
             id: None,
             input: None,
             output: None,
+            properties: Properties::new(),
+            input_properties: Properties::new(),
+            output_properties: Properties::new(),
         });
         self
     }
@@ -186,6 +271,14 @@ impl Workflow {
         self.sink(Sink::File { path: path.into() })
     }
 
+    /// Build a plan from the explicit steps.
+    pub fn to_plan(&self) -> Plan {
+        Plan {
+            steps: self.steps.iter().map(Step::to_plan_step).collect(),
+            cost: self.steps.len() as f64,
+        }
+    }
+
     /// Check if this workflow is complete (has source, sink, and steps).
     pub fn is_complete(&self) -> bool {
         self.source.is_some() && self.sink.is_some() && !self.steps.is_empty()
@@ -206,57 +299,164 @@ impl Workflow {
     }
 
     /// Parse workflow from bytes with explicit format.
+    ///
+    /// Steps are checked for an empty `converter`; whether converters
+    /// exist is left to [`validate`](Self::validate).
     pub fn from_bytes_format(data: &[u8], format: &str) -> Result<Self, WorkflowError> {
-        match format {
-            "json" => serde_json::from_slice(data).map_err(|e| WorkflowError::Parse(e.to_string())),
-            "yaml" | "yml" => {
-                serde_yaml::from_slice(data).map_err(|e| WorkflowError::Parse(e.to_string()))
-            }
+        let parse_error = |line: Option<usize>, source: BoxError| WorkflowError::ParseError {
+            format: format.to_string(),
+            line,
+            source,
+        };
+
+        let workflow: Workflow = match format {
+            "json" => serde_json::from_slice(data)
+                .map_err(|e| parse_error(Some(e.line()).filter(|&line| line > 0), e.into()))?,
+            "yaml" | "yml" => serde_yaml::from_slice(data)
+                .map_err(|e| parse_error(e.location().map(|l| l.line()), e.into()))?,
             "toml" => {
                 let s = std::str::from_utf8(data)
-                    .map_err(|e| WorkflowError::Parse(format!("Invalid UTF-8: {}", e)))?;
-                toml::from_str(s).map_err(|e| WorkflowError::Parse(e.to_string()))
+                    .map_err(|e| parse_error(Some(line_at(data, e.valid_up_to())), e.into()))?;
+                toml::from_str(s).map_err(|e| {
+                    parse_error(e.span().map(|span| line_at(data, span.start)), e.into())
+                })?
+            }
+            _ => return Err(WorkflowError::UnsupportedFormat(format.to_string())),
+        };
+
+        for (index, step) in workflow.steps.iter().enumerate() {
+            if step.converter.trim().is_empty() {
+                return Err(WorkflowError::InvalidStep {
+                    index,
+                    reason: "has an empty converter".into(),
+                });
             }
-            _ => Err(WorkflowError::Parse(format!(
-                "Unsupported workflow format: {}",
-                format
-            ))),
         }
+
+        Ok(workflow)
     }
 
     /// Serialize workflow to bytes.
     pub fn to_bytes(&self, format: &str) -> Result<Vec<u8>, WorkflowError> {
+        let serialize_error = |source: BoxError| WorkflowError::Serialize {
+            format: format.to_string(),
+            source,
+        };
+
         match format {
-            "json" => {
-                serde_json::to_vec_pretty(self).map_err(|e| WorkflowError::Parse(e.to_string()))
-            }
+            "json" => serde_json::to_vec_pretty(self).map_err(|e| serialize_error(e.into())),
             "yaml" | "yml" => serde_yaml::to_string(self)
                 .map(|s| s.into_bytes())
-                .map_err(|e| WorkflowError::Parse(e.to_string())),
+                .map_err(|e| serialize_error(e.into())),
             "toml" => toml::to_string_pretty(self)
                 .map(|s| s.into_bytes())
-                .map_err(|e| WorkflowError::Parse(e.to_string())),
-            _ => Err(WorkflowError::Parse(format!(
-                "Unsupported workflow format: {}",
-                format
-            ))),
+                .map_err(|e| serialize_error(e.into())),
+            _ => Err(WorkflowError::UnsupportedFormat(format.to_string())),
+        }
+    }
+
+    /// Workflow as a JSON value, for editing programmatically.
+    pub fn to_json(&self) -> serde_json::Value {
+        // Keys are strings and non-finite floats become null, so this
+        // cannot fail
+        serde_json::to_value(self).expect("workflows serialize to JSON")
+    }
+
+    /// Check that every explicit step names a converter in `registry`.
+    pub fn validate_steps(&self, registry: &Registry) -> Result<(), WorkflowError> {
+        for (index, step) in self.steps.iter().enumerate() {
+            if registry.get_decl(&step.converter).is_none() {
+                return Err(WorkflowError::UnknownConverter {
+                    index,
+                    converter: step.converter.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the workflow can run: it has a source and a sink that
+    /// fit together, and its explicit steps exist in `registry`.
+    pub fn validate(&self, registry: &Registry) -> Result<(), WorkflowError> {
+        let source = self.source.as_ref().ok_or(WorkflowError::MissingField {
+            field: "source".into(),
+        })?;
+        let sink = self.sink.as_ref().ok_or(WorkflowError::MissingField {
+            field: "sink".into(),
+        })?;
+
+        if let (Source::Glob { glob }, Sink::File { path }) = (source, sink) {
+            return Err(WorkflowError::IncompatibleSource {
+                message: format!(
+                    "glob source '{}' matches many files but sink '{}' is a single file",
+                    glob, path
+                ),
+            });
         }
+
+        self.validate_steps(registry)
     }
 }
 
+/// 1-based line number of byte offset `pos` in `data`.
+fn line_at(data: &[u8], pos: usize) -> usize {
+    data[..pos.min(data.len())]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count()
+        + 1
+}
+
 /// Errors related to workflow parsing and execution.
 #[derive(Debug, thiserror::Error)]
 pub enum WorkflowError {
-    #[error("failed to parse workflow: {0}")]
-    Parse(String),
+    /// The file is not valid YAML/TOML/JSON, or does not have the shape of
+    /// a workflow. `line` is 1-based, where the parser reports one.
+    #[error("invalid {format} workflow{}: {source}", display_line(.line))]
+    ParseError {
+        format: String,
+        line: Option<usize>,
+        source: BoxError,
+    },
 
-    #[error("incomplete workflow: {0}")]
-    Incomplete(String),
+    /// A field needed to run the workflow is missing.
+    #[error("workflow is missing a {field}")]
+    MissingField { field: String },
+
+    /// Step `index` (0-based) is unusable.
+    #[error("step {} {reason}", .index + 1)]
+    InvalidStep { index: usize, reason: String },
+
+    /// Step `index` (0-based) names a converter the registry does not have.
+    #[error("step {} references an unknown converter '{converter}'", .index + 1)]
+    UnknownConverter { index: usize, converter: String },
+
+    /// The source and sink do not fit together.
+    #[error("incompatible source and sink: {message}")]
+    IncompatibleSource { message: String },
+
+    #[error("unsupported workflow format: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("failed to serialize {format} workflow: {source}")]
+    Serialize { format: String, source: BoxError },
 
     #[error("workflow execution failed: {0}")]
     Execution(String),
 }
 
+fn display_line(line: &Option<usize>) -> String {
+    line.map(|line| format!(" at line {}", line))
+        .unwrap_or_default()
+}
+
+/// Path part of a URL, without query or fragment.
+fn url_path(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let rest = rest.split(['?', '#']).next().unwrap_or(rest);
+    rest.split_once('/').map_or("", |(_, path)| path)
+}
+
 /// Detect format from file path extension.
 fn detect_format(path: &str) -> Option<String> {
     let ext = path.rsplit('.').next()?;
@@ -328,6 +528,50 @@ mod tests {
         assert!(!pattern.matches(&props));
     }
 
+    #[test]
+    fn test_http_source_and_sink() {
+        let yaml = br#"
+source:
+  url: https://example.com/data.json?page=2
+  headers:
+    Authorization: Bearer token
+sink:
+  url: https://example.com/upload
+  format: yaml
+"#;
+        let workflow = Workflow::from_bytes_format(yaml, "yaml").unwrap();
+
+        let source = workflow.source.unwrap();
+        match &source {
+            Source::Http {
+                method, headers, ..
+            } => {
+                assert_eq!(method, "GET");
+                assert_eq!(headers["Authorization"], "Bearer token");
+            }
+            other => panic!("expected HTTP source, got {:?}", other),
+        }
+        let props = source.to_properties();
+        assert_eq!(props.get("format").unwrap().as_str(), Some("json"));
+
+        let sink = workflow.sink.unwrap();
+        assert!(matches!(&sink, Sink::Http { method, .. } if method == "POST"));
+        assert!(
+            sink.to_pattern()
+                .matches(&Properties::new().with("format", "yaml"))
+        );
+
+        // No extension in the URL: format is left to the Content-Type
+        assert_eq!(url_path("http://localhost:8080"), "");
+        let source = Source::Http {
+            url: "http://localhost:8080/api/items".into(),
+            method: "GET".into(),
+            headers: IndexMap::new(),
+            format: None,
+        };
+        assert!(source.to_properties().get("format").is_none());
+    }
+
     #[test]
     fn test_workflow_json_roundtrip() {
         let workflow = Workflow::new()
@@ -341,4 +585,147 @@ mod tests {
         assert_eq!(parsed.steps.len(), 1);
         assert_eq!(parsed.steps[0].converter, "serde.json-to-yaml");
     }
+
+    #[test]
+    fn test_workflow_serialize_roundtrip() {
+        let mut workflow = Workflow::new()
+            .source_file("photo.png")
+            .step("image.png-to-webp")
+            .sink(Sink::Http {
+                url: "https://example.com/upload".into(),
+                method: "PUT".into(),
+                headers: IndexMap::from([("X-Token".into(), "abc".into())]),
+                format: Some("webp".into()),
+            });
+        workflow.preset = Some("web".into());
+        workflow.steps[0].id = Some("encode".into());
+        workflow.steps[0]
+            .properties
+            .insert("webp_quality".into(), Value::Int(80));
+        workflow
+            .options
+            .insert("max_width".into(), Value::Float(1280.5));
+
+        for format in ["yaml", "toml", "json"] {
+            let bytes = workflow.to_bytes(format).unwrap();
+            let parsed = Workflow::from_bytes_format(&bytes, format).unwrap();
+            assert_eq!(parsed, workflow, "{} roundtrip", format);
+        }
+
+        let json = workflow.to_json();
+        assert_eq!(json["steps"][0]["converter"], "image.png-to-webp");
+        assert_eq!(json["sink"]["method"], "PUT");
+        assert!(matches!(
+            workflow.to_bytes("xml"),
+            Err(WorkflowError::UnsupportedFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_workflow_errors() {
+        use std::error::Error;
+
+        let yaml = b"source:\n  path: in.json\nsteps:\n  - converter: [oops\n";
+        let err = Workflow::from_bytes_format(yaml, "yaml").unwrap_err();
+        assert!(matches!(
+            &err,
+            WorkflowError::ParseError { format, line: Some(4), .. } if format == "yaml"
+        ));
+        assert!(
+            err.to_string()
+                .starts_with("invalid yaml workflow at line 4: ")
+        );
+        assert!(err.source().unwrap().is::<serde_yaml::Error>());
+
+        let toml = b"[source]\npath = \"in.json\"\n\n[sink\n";
+        let err = Workflow::from_bytes(toml, Some("flow.toml")).unwrap_err();
+        assert!(matches!(
+            err,
+            WorkflowError::ParseError { line: Some(4), .. }
+        ));
+        assert!(err.source().unwrap().is::<toml::de::Error>());
+
+        let err = Workflow::from_bytes_format(b"{\"steps\": 1}", "json").unwrap_err();
+        assert!(matches!(
+            err,
+            WorkflowError::ParseError { line: Some(1), .. }
+        ));
+
+        assert!(matches!(
+            Workflow::from_bytes_format(b"", "ini"),
+            Err(WorkflowError::UnsupportedFormat(_))
+        ));
+
+        let yaml = b"steps:\n  - converter: serde.json-to-yaml\n  - converter: ''\n";
+        let err = Workflow::from_bytes_format(yaml, "yaml").unwrap_err();
+        assert!(matches!(err, WorkflowError::InvalidStep { index: 1, .. }));
+        assert_eq!(err.to_string(), "step 2 has an empty converter");
+
+        let registry = Registry::new();
+        let err = Workflow::new()
+            .sink_file("out.yaml")
+            .validate(&registry)
+            .unwrap_err();
+        assert!(matches!(&err, WorkflowError::MissingField { field } if field == "source"));
+
+        let err = Workflow::new()
+            .source_glob("data/*.json")
+            .sink_file("out.yaml")
+            .validate(&registry)
+            .unwrap_err();
+        assert!(matches!(err, WorkflowError::IncompatibleSource { .. }));
+
+        let err = Workflow::new()
+            .source_file("in.json")
+            .step("serde.json-to-yaml")
+            .sink_file("out.yaml")
+            .validate(&registry)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            WorkflowError::UnknownConverter { index: 0, .. }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "step 1 references an unknown converter 'serde.json-to-yaml'"
+        );
+    }
+
+    #[test]
+    fn test_step_properties_to_plan() {
+        let yaml = br#"
+source:
+  path: input.png
+steps:
+  - converter: image.png-to-jpg
+    properties:
+      jpeg_quality: 90
+      strip: true
+    input_properties:
+      jpeg_quality: 75
+    output_properties:
+      label: thumbnail
+sink:
+  path: output.jpg
+"#;
+        let workflow = Workflow::from_bytes_format(yaml, "yaml").unwrap();
+        let plan = workflow.to_plan();
+
+        assert_eq!(plan.steps.len(), 1);
+        let step = &plan.steps[0];
+        assert_eq!(step.converter_id, "image.png-to-jpg");
+        // input_properties override properties
+        assert_eq!(
+            step.input_properties.get("jpeg_quality").unwrap().as_i64(),
+            Some(75)
+        );
+        assert_eq!(
+            step.input_properties.get("strip").unwrap().as_bool(),
+            Some(true)
+        );
+        assert_eq!(
+            step.output_overrides.get("label").unwrap().as_str(),
+            Some("thumbnail")
+        );
+    }
 }
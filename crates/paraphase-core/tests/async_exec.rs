@@ -0,0 +1,255 @@
+#![cfg(feature = "tokio")]
+
+use paraphase_core::{
+    AsyncConverter, AsyncExecutor, AsyncPlanner, AsyncRegistry, AsyncSimpleExecutor, BoxFuture,
+    Cardinality, ConvertError, ConvertOutput, Converter, ConverterDecl, ExecuteError, PortDecl,
+    Properties, PropertiesExt, PropertyPattern, Registry,
+};
+
+/// Sync converter: uppercases ASCII text.
+struct Upper(ConverterDecl);
+
+impl Upper {
+    fn new() -> Self {
+        Self(ConverterDecl::simple(
+            "text.upper",
+            PropertyPattern::new().eq("format", "text"),
+            PropertyPattern::new().eq("format", "upper"),
+        ))
+    }
+}
+
+impl Converter for Upper {
+    fn decl(&self) -> &ConverterDecl {
+        &self.0
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        let mut out = props.clone();
+        out.insert("format".into(), "upper".into());
+        Ok(ConvertOutput::Single(input.to_ascii_uppercase(), out))
+    }
+}
+
+/// Async converter: waits a tick, then wraps text in brackets.
+struct Bracket(ConverterDecl);
+
+impl Bracket {
+    fn new() -> Self {
+        Self(ConverterDecl::simple(
+            "text.bracket",
+            PropertyPattern::new().eq("format", "upper"),
+            PropertyPattern::new().eq("format", "bracketed"),
+        ))
+    }
+}
+
+impl AsyncConverter for Bracket {
+    fn decl(&self) -> &ConverterDecl {
+        &self.0
+    }
+
+    fn convert<'a>(
+        &'a self,
+        input: &'a [u8],
+        props: &'a Properties,
+    ) -> BoxFuture<'a, Result<ConvertOutput, ConvertError>> {
+        Box::pin(async move {
+            tokio::task::yield_now().await;
+            let mut data = b"[".to_vec();
+            data.extend_from_slice(input);
+            data.push(b']');
+            let mut out = props.clone();
+            out.insert("format".into(), "bracketed".into());
+            Ok(ConvertOutput::Single(data, out))
+        })
+    }
+}
+
+/// Aggregating converter: joins uppercased inputs with commas.
+///
+/// Implements both traits so it can be registered sync or native async.
+struct Join(ConverterDecl);
+
+impl Join {
+    fn new() -> Self {
+        Self(
+            ConverterDecl::new("text.join")
+                .input(
+                    "in",
+                    PortDecl::list(PropertyPattern::new().eq("format", "upper")),
+                )
+                .output(
+                    "out",
+                    PortDecl::single(PropertyPattern::new().eq("format", "joined")),
+                ),
+        )
+    }
+
+    fn join(inputs: &[(&[u8], &Properties)]) -> ConvertOutput {
+        let parts: Vec<&[u8]> = inputs.iter().map(|(data, _)| *data).collect();
+        ConvertOutput::Single(
+            parts.join(&b","[..]),
+            Properties::new().with("format", "joined"),
+        )
+    }
+}
+
+impl Converter for Join {
+    fn decl(&self) -> &ConverterDecl {
+        &self.0
+    }
+
+    fn convert(&self, _input: &[u8], _props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        Err(ConvertError::BatchNotSupported)
+    }
+
+    fn convert_batch(
+        &self,
+        inputs: &[(&[u8], &Properties)],
+    ) -> Result<ConvertOutput, ConvertError> {
+        Ok(Self::join(inputs))
+    }
+}
+
+impl AsyncConverter for Join {
+    fn decl(&self) -> &ConverterDecl {
+        &self.0
+    }
+
+    fn convert<'a>(
+        &'a self,
+        _input: &'a [u8],
+        _props: &'a Properties,
+    ) -> BoxFuture<'a, Result<ConvertOutput, ConvertError>> {
+        Box::pin(async { Err(ConvertError::BatchNotSupported) })
+    }
+
+    fn convert_batch<'a>(
+        &'a self,
+        inputs: &'a [(&'a [u8], &'a Properties)],
+    ) -> BoxFuture<'a, Result<ConvertOutput, ConvertError>> {
+        Box::pin(async move {
+            tokio::task::yield_now().await;
+            Ok(Self::join(inputs))
+        })
+    }
+}
+
+fn plan_to(registry: &AsyncRegistry, to: &str) -> paraphase_core::Plan {
+    AsyncPlanner::new(registry)
+        .plan(
+            &Properties::new().with("format", "text"),
+            &PropertyPattern::new().eq("format", to),
+            Cardinality::One,
+            Cardinality::One,
+        )
+        .expect("should find plan")
+}
+
+#[tokio::test]
+async fn test_sync_registry_wrapped() {
+    let mut sync_registry = Registry::new();
+    sync_registry.register(Upper::new());
+    let registry = AsyncRegistry::from_registry(&sync_registry);
+    assert_eq!(registry.len(), 1);
+
+    let plan = plan_to(&registry, "upper");
+    let result = AsyncSimpleExecutor::new()
+        .execute(
+            &registry,
+            &plan,
+            b"hello".to_vec(),
+            Properties::new().with("format", "text"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.data, b"HELLO");
+    assert_eq!(result.props.get("format").unwrap().as_str(), Some("upper"));
+    assert_eq!(result.stats.steps_executed, 1);
+}
+
+#[tokio::test]
+async fn test_mixed_sync_and_async_steps() {
+    let mut registry = AsyncRegistry::new();
+    registry.register_sync(Upper::new());
+    registry.register(Bracket::new());
+
+    let plan = plan_to(&registry, "bracketed");
+    assert_eq!(plan.steps.len(), 2);
+
+    let result = AsyncSimpleExecutor::new()
+        .execute(
+            &registry,
+            &plan,
+            b"hi".to_vec(),
+            Properties::new().with("format", "text"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.data, b"[HI]");
+    assert_eq!(
+        result.props.get("format").unwrap().as_str(),
+        Some("bracketed")
+    );
+}
+
+#[tokio::test]
+async fn test_missing_converter() {
+    let mut registry = AsyncRegistry::new();
+    registry.register_sync(Upper::new());
+    let plan = plan_to(&registry, "upper");
+
+    let err = AsyncSimpleExecutor::new()
+        .execute(
+            &AsyncRegistry::new(),
+            &plan,
+            Vec::new(),
+            Properties::new().with("format", "text"),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ExecuteError::ConverterNotFound(id) if id == "text.upper"));
+}
+
+#[tokio::test]
+async fn test_aggregating() {
+    let mut sync_join = AsyncRegistry::new();
+    sync_join.register_sync(Upper::new());
+    sync_join.register_sync(Join::new());
+    let mut async_join = AsyncRegistry::new();
+    async_join.register_sync(Upper::new());
+    async_join.register(Join::new());
+
+    for registry in [sync_join, async_join] {
+        let plan = AsyncPlanner::new(&registry)
+            .plan(
+                &Properties::new().with("format", "text"),
+                &PropertyPattern::new().eq("format", "joined"),
+                Cardinality::Many,
+                Cardinality::One,
+            )
+            .expect("should find plan");
+        assert_eq!(plan.steps.len(), 2);
+
+        let inputs = ["a", "b", "c"]
+            .map(|s| {
+                (
+                    s.as_bytes().to_vec(),
+                    Properties::new().with("format", "text"),
+                )
+            })
+            .to_vec();
+        let result = AsyncSimpleExecutor::new()
+            .execute_aggregating(&registry, &plan, inputs)
+            .await
+            .unwrap();
+
+        assert_eq!(result.data, b"A,B,C");
+        assert_eq!(result.props.get("format").unwrap().as_str(), Some("joined"));
+        assert_eq!(result.stats.per_step_stats[0].input_bytes, 3);
+        assert_eq!(result.stats.per_step_stats[1].output_bytes, 5);
+    }
+}
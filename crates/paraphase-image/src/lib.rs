@@ -25,14 +25,19 @@
 //! - `openexr` - OpenEXR high dynamic range
 //! - `hdr` - Radiance HDR
 //!
+//! ## Vector input
+//! - `svg` - Rasterize SVG to PNG/JPEG (via `resvg`)
+//!
 //! ## Feature group
 //! - `all` - All image formats
 
+use image::imageops::FilterType;
+use image::metadata::Orientation;
 use image::{DynamicImage, GenericImageView, ImageFormat, Rgba};
 use indexmap::IndexMap;
 use paraphase_core::{
     ConvertError, ConvertOutput, Converter, ConverterDecl, NamedInput, PortDecl, Predicate,
-    Properties, PropertyPattern, Registry,
+    Properties, PropertyPattern, PropertySchema, Registry,
 };
 use std::io::Cursor;
 
@@ -53,6 +58,15 @@ pub fn register_all(registry: &mut Registry) {
     registry.register(ResizeConverter::new());
     registry.register(CropAspectConverter::new());
     registry.register(WatermarkConverter::new());
+    registry.register(SpriteSheetCreate::new());
+    registry.register(QuantizeConverter::new());
+    registry.register(ColorConvertConverter::new());
+
+    #[cfg(feature = "svg")]
+    {
+        registry.register(SvgToPng::new());
+        registry.register(SvgToJpeg::new());
+    }
 }
 
 /// Get list of enabled formats based on feature flags.
@@ -91,6 +105,12 @@ pub fn enabled_formats() -> Vec<(&'static str, ImageFormat)> {
 }
 
 /// A converter between two image formats.
+///
+/// Options (via properties):
+/// - `exif_auto_rotate`: rotate/flip the pixels as the EXIF `Orientation`
+///   tag says (default: false). Re-encoded output carries no EXIF, so no
+///   stale orientation is left behind.
+/// - `jpeg_quality`: see [`encode_image`]
 pub struct ImageConverter {
     decl: ConverterDecl,
     from_format: ImageFormat,
@@ -106,7 +126,7 @@ impl ImageConverter {
         to_format: ImageFormat,
     ) -> Self {
         let id = format!("image.{}-to-{}", from_name, to_name);
-        let decl = ConverterDecl::simple(
+        let mut decl = ConverterDecl::simple(
             &id,
             PropertyPattern::new().eq("format", from_name),
             PropertyPattern::new().eq("format", to_name),
@@ -115,7 +135,14 @@ impl ImageConverter {
             "Convert {} to {} via image crate",
             from_name.to_uppercase(),
             to_name.to_uppercase()
-        ));
+        ))
+        .with_property_schema("exif_auto_rotate", PropertySchema::boolean());
+        // JPEG encoding is lossy; the image crate's WebP encoder is lossless
+        if to_format == ImageFormat::Jpeg {
+            decl = decl
+                .with_quality_loss(0.2)
+                .with_property_schema("jpeg_quality", jpeg_quality_schema());
+        }
 
         Self {
             decl,
@@ -132,15 +159,17 @@ impl Converter for ImageConverter {
     }
 
     fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        self.decl.check_props(props)?;
+
         // Decode image
-        let img = image::load_from_memory_with_format(input, self.from_format)
-            .map_err(|e| ConvertError::InvalidInput(format!("Failed to decode image: {}", e)))?;
+        let img = decode_image(input, Some(self.from_format), props)?;
 
         // Encode to target format
-        let output = encode_image(&img, self.to_format)?;
+        let output = encode_image(&img, self.to_format, props)?;
 
         // Build output properties
         let mut out_props = props.clone();
+        out_props.shift_remove("exif_auto_rotate");
         out_props.insert("format".into(), self.to_name.into());
 
         // Add image metadata
@@ -149,14 +178,117 @@ impl Converter for ImageConverter {
 
         Ok(ConvertOutput::Single(output, out_props))
     }
+
+    /// The decoded size scaled by the target format's typical compression.
+    fn estimate_output_size(&self, input_len: usize, props: &Properties) -> Option<usize> {
+        Some(self.decoded_size(input_len, props) / compression_ratio(self.to_format))
+    }
+
+    /// Peak memory is the decoded RGBA buffer.
+    fn estimate_working_memory(&self, input_len: usize, props: &Properties) -> Option<usize> {
+        Some(self.decoded_size(input_len, props))
+    }
+}
+
+impl ImageConverter {
+    /// Size of the decoded RGBA buffer: exact when `width` and `height` are
+    /// known, otherwise guessed from the source format.
+    fn decoded_size(&self, input_len: usize, props: &Properties) -> usize {
+        let dimension = |key| props.get(key).and_then(|v| v.as_i64());
+        if let (Some(width), Some(height)) = (dimension("width"), dimension("height")) {
+            let pixels = (width.max(0) as usize).saturating_mul(height.max(0) as usize);
+            return pixels.saturating_mul(4);
+        }
+        input_len.saturating_mul(compression_ratio(self.from_format))
+    }
+}
+
+/// Typical compression ratio of `format` relative to RGBA.
+fn compression_ratio(format: ImageFormat) -> usize {
+    match format {
+        ImageFormat::Bmp
+        | ImageFormat::Tga
+        | ImageFormat::Pnm
+        | ImageFormat::Farbfeld
+        | ImageFormat::Ico => 2,
+        ImageFormat::Png
+        | ImageFormat::Tiff
+        | ImageFormat::Qoi
+        | ImageFormat::OpenExr
+        | ImageFormat::Hdr => 4,
+        ImageFormat::Gif => 8,
+        _ => 10,
+    }
+}
+
+/// Decode an image, in `format` or auto-detected.
+///
+/// If `exif_auto_rotate` is set, the EXIF `Orientation` tag is applied so
+/// the pixels come out upright.
+fn decode_image(
+    input: &[u8],
+    format: Option<ImageFormat>,
+    props: &Properties,
+) -> Result<DynamicImage, ConvertError> {
+    let mut img = match format {
+        Some(format) => image::load_from_memory_with_format(input, format),
+        None => image::load_from_memory(input),
+    }
+    .map_err(|e| ConvertError::invalid_input("Failed to decode image", e))?;
+
+    let auto_rotate = props
+        .get("exif_auto_rotate")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if let Some(orientation) = auto_rotate
+        .then(|| exif_orientation(input))
+        .flatten()
+        .and_then(Orientation::from_exif)
+    {
+        img.apply_orientation(orientation);
+    }
+
+    Ok(img)
+}
+
+/// Read the EXIF `Orientation` tag (1-8) from an encoded image, if present.
+fn exif_orientation(input: &[u8]) -> Option<u8> {
+    let exif = exif::Reader::new()
+        .read_from_container(&mut Cursor::new(input))
+        .ok()?;
+    let field = exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)?;
+    field.value.get_uint(0).and_then(|v| u8::try_from(v).ok())
+}
+
+/// Schema for the `jpeg_quality` encoder option.
+fn jpeg_quality_schema() -> PropertySchema {
+    PropertySchema::integer().range(1.0, 100.0)
 }
 
 /// Encode a DynamicImage to bytes in the specified format.
-fn encode_image(img: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, ConvertError> {
+///
+/// Encoder options (via properties):
+/// - `jpeg_quality`: JPEG quality, 1-100 (default: encoder default)
+fn encode_image(
+    img: &DynamicImage,
+    format: ImageFormat,
+    props: &Properties,
+) -> Result<Vec<u8>, ConvertError> {
     let mut buf = Cursor::new(Vec::new());
 
-    img.write_to(&mut buf, format)
-        .map_err(|e| ConvertError::Failed(format!("Failed to encode image: {}", e)))?;
+    let jpeg_quality = props.get("jpeg_quality").and_then(|v| v.as_i64());
+
+    match (format, jpeg_quality) {
+        (ImageFormat::Jpeg, Some(quality)) => {
+            let quality = quality.clamp(1, 100) as u8;
+            let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut buf, quality);
+            // JPEG has no alpha channel
+            let rgb = DynamicImage::ImageRgb8(img.to_rgb8());
+            rgb.write_with_encoder(encoder)
+        }
+        _ => img.write_to(&mut buf, format),
+    }
+    .map_err(|e| ConvertError::failed("Failed to encode image", e))?;
 
     Ok(buf.into_inner())
 }
@@ -221,6 +353,12 @@ impl Gravity {
 /// - `target_width`: exact target width
 /// - `target_height`: exact target height
 /// - `scale`: scale factor (e.g., 0.5 for half size)
+/// - `resize_filter`: `nearest`, `linear`, `cubic` or `lanczos3` (default)
+/// - `resize_mode`: how to fill a `target_width` × `target_height` (or
+///   `max_width` × `max_height`) box: `fit` within it preserving aspect
+///   ratio, `fill` it exactly by scaling and cropping around `gravity`, or
+///   `stretch` to it ignoring aspect ratio. Without a mode, max bounds fit
+///   and target dimensions stretch.
 pub struct ResizeConverter {
     decl: ConverterDecl,
 }
@@ -237,7 +375,22 @@ impl ResizeConverter {
                 .with("width", Predicate::Any)
                 .with("height", Predicate::Any),
         )
-        .description("Resize image to target dimensions or within bounds");
+        .description("Resize image to target dimensions or within bounds")
+        .with_property_schema("max_width", PropertySchema::integer().min(1.0))
+        .with_property_schema("max_height", PropertySchema::integer().min(1.0))
+        .with_property_schema("target_width", PropertySchema::integer().min(1.0))
+        .with_property_schema("target_height", PropertySchema::integer().min(1.0))
+        .with_property_schema("scale", PropertySchema::float())
+        .with_property_schema(
+            "resize_filter",
+            PropertySchema::string().one_of(["nearest", "linear", "cubic", "lanczos3"]),
+        )
+        .with_property_schema(
+            "resize_mode",
+            PropertySchema::string().one_of(["fit", "fill", "stretch"]),
+        )
+        .with_property_schema("jpeg_quality", jpeg_quality_schema())
+        .with_property_schema("exif_auto_rotate", PropertySchema::boolean());
 
         Self { decl }
     }
@@ -255,20 +408,21 @@ impl Converter for ResizeConverter {
     }
 
     fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        self.decl.check_props(props)?;
+
         // Decode image (auto-detect format)
-        let img = image::load_from_memory(input)
-            .map_err(|e| ConvertError::InvalidInput(format!("Failed to decode image: {}", e)))?;
+        let img = decode_image(input, None, props)?;
 
         let (orig_w, orig_h) = img.dimensions();
-
-        // Determine target dimensions from options
-        let (new_w, new_h) = compute_resize_dimensions(orig_w, orig_h, props)?;
-
-        // Skip resize if dimensions unchanged
-        let resized = if new_w == orig_w && new_h == orig_h {
-            img
-        } else {
-            img.resize_exact(new_w, new_h, image::imageops::FilterType::Lanczos3)
+        let filter = resize_filter(props);
+
+        let resized = match props.get("resize_mode").and_then(|v| v.as_str()) {
+            Some(mode) => resize_to_bounds(img, mode, filter, props)?,
+            None => {
+                // Determine target dimensions from options
+                let (new_w, new_h) = compute_resize_dimensions(orig_w, orig_h, props)?;
+                resize_if_changed(img, new_w, new_h, filter)
+            }
         };
 
         // Re-encode in original format
@@ -281,9 +435,10 @@ impl Converter for ResizeConverter {
             })
             .unwrap_or(ImageFormat::Png);
 
-        let output = encode_image(&resized, format)?;
+        let output = encode_image(&resized, format, props)?;
 
         let mut out_props = props.clone();
+        out_props.shift_remove("exif_auto_rotate");
         out_props.insert("width".into(), (resized.width() as i64).into());
         out_props.insert("height".into(), (resized.height() as i64).into());
 
@@ -291,6 +446,90 @@ impl Converter for ResizeConverter {
     }
 }
 
+/// Resampling filter from the `resize_filter` option.
+fn resize_filter(props: &Properties) -> FilterType {
+    match props.get("resize_filter").and_then(|v| v.as_str()) {
+        Some("nearest") => FilterType::Nearest,
+        Some("linear") => FilterType::Triangle,
+        Some("cubic") => FilterType::CatmullRom,
+        _ => FilterType::Lanczos3,
+    }
+}
+
+/// Resize to exactly `width` × `height`, skipping no-op resizes.
+fn resize_if_changed(
+    img: DynamicImage,
+    width: u32,
+    height: u32,
+    filter: FilterType,
+) -> DynamicImage {
+    if img.dimensions() == (width, height) {
+        img
+    } else {
+        img.resize_exact(width, height, filter)
+    }
+}
+
+/// Resize into the target (or max) bounds according to `resize_mode`.
+fn resize_to_bounds(
+    img: DynamicImage,
+    mode: &str,
+    filter: FilterType,
+    props: &Properties,
+) -> Result<DynamicImage, ConvertError> {
+    let dimension = |key: &str| props.get(key).and_then(|v| v.as_i64()).map(|v| v as u32);
+    // Max bounds never upscale, target dimensions may
+    let (box_w, box_h, upscale) = match (
+        dimension("target_width"),
+        dimension("target_height"),
+        dimension("max_width"),
+        dimension("max_height"),
+    ) {
+        (Some(w), Some(h), _, _) => (w, h, true),
+        (_, _, Some(w), Some(h)) => (w, h, false),
+        _ => {
+            return Err(ConvertError::invalid_input_msg(
+                "resize_mode requires target_width and target_height, or max_width and max_height",
+            ));
+        }
+    };
+
+    let (orig_w, orig_h) = img.dimensions();
+    let scale_x = box_w as f64 / orig_w as f64;
+    let scale_y = box_h as f64 / orig_h as f64;
+    let scaled = |scale: f64| {
+        (
+            ((orig_w as f64 * scale).round() as u32).max(1),
+            ((orig_h as f64 * scale).round() as u32).max(1),
+        )
+    };
+
+    match mode {
+        "stretch" => Ok(resize_if_changed(img, box_w, box_h, filter)),
+        "fill" => {
+            // Cover the box, then crop the overflow around the gravity anchor
+            let (w, h) = scaled(scale_x.max(scale_y));
+            let (w, h) = (w.max(box_w), h.max(box_h));
+            let covered = resize_if_changed(img, w, h, filter);
+
+            let gravity = props
+                .get("gravity")
+                .and_then(|v| v.as_str())
+                .and_then(Gravity::parse)
+                .unwrap_or_default();
+            let (fx, fy) = gravity.offset_factors();
+            let x = ((w - box_w) as f64 * fx).round() as u32;
+            let y = ((h - box_h) as f64 * fy).round() as u32;
+            Ok(covered.crop_imm(x, y, box_w, box_h))
+        }
+        _ => {
+            let scale = scale_x.min(scale_y);
+            let (w, h) = scaled(if upscale { scale } else { scale.min(1.0) });
+            Ok(resize_if_changed(img, w, h, filter))
+        }
+    }
+}
+
 /// Compute target dimensions from resize options.
 fn compute_resize_dimensions(
     orig_w: u32,
@@ -303,7 +542,7 @@ fn compute_resize_dimensions(
     // Check for scale factor first
     if let Some(scale) = props.get("scale").and_then(|v| v.as_f64()) {
         if scale <= 0.0 {
-            return Err(ConvertError::InvalidInput("Scale must be positive".into()));
+            return Err(ConvertError::invalid_input_msg("Scale must be positive"));
         }
         return Ok((
             (orig_w_f * scale).round() as u32,
@@ -398,7 +637,8 @@ impl CropAspectConverter {
                 .with("width", Predicate::Any)
                 .with("height", Predicate::Any),
         )
-        .description("Crop image to target aspect ratio");
+        .description("Crop image to target aspect ratio")
+        .with_property_schema("exif_auto_rotate", PropertySchema::boolean());
 
         Self { decl }
     }
@@ -416,8 +656,8 @@ impl Converter for CropAspectConverter {
     }
 
     fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-        let img = image::load_from_memory(input)
-            .map_err(|e| ConvertError::InvalidInput(format!("Failed to decode image: {}", e)))?;
+        self.decl.check_props(props)?;
+        let img = decode_image(input, None, props)?;
 
         let (orig_w, orig_h) = img.dimensions();
 
@@ -448,9 +688,10 @@ impl Converter for CropAspectConverter {
             })
             .unwrap_or(ImageFormat::Png);
 
-        let output = encode_image(&cropped, format)?;
+        let output = encode_image(&cropped, format, props)?;
 
         let mut out_props = props.clone();
+        out_props.shift_remove("exif_auto_rotate");
         out_props.insert("width".into(), (cropped.width() as i64).into());
         out_props.insert("height".into(), (cropped.height() as i64).into());
 
@@ -467,8 +708,8 @@ fn parse_aspect_ratio(props: &Properties) -> Result<f64, ConvertError> {
     // Try as float first
     if let Some(f) = aspect_val.as_f64() {
         if f <= 0.0 {
-            return Err(ConvertError::InvalidInput(
-                "Aspect ratio must be positive".into(),
+            return Err(ConvertError::invalid_input_msg(
+                "Aspect ratio must be positive",
             ));
         }
         return Ok(f);
@@ -477,17 +718,15 @@ fn parse_aspect_ratio(props: &Properties) -> Result<f64, ConvertError> {
     // Try as string "W:H"
     if let Some(s) = aspect_val.as_str() {
         if let Some((w_str, h_str)) = s.split_once(':') {
-            let w: f64 = w_str
-                .trim()
-                .parse()
-                .map_err(|_| ConvertError::InvalidInput(format!("Invalid aspect ratio: {}", s)))?;
-            let h: f64 = h_str
-                .trim()
-                .parse()
-                .map_err(|_| ConvertError::InvalidInput(format!("Invalid aspect ratio: {}", s)))?;
+            let w: f64 = w_str.trim().parse().map_err(|_| {
+                ConvertError::invalid_input_msg(format!("Invalid aspect ratio: {}", s))
+            })?;
+            let h: f64 = h_str.trim().parse().map_err(|_| {
+                ConvertError::invalid_input_msg(format!("Invalid aspect ratio: {}", s))
+            })?;
             if w <= 0.0 || h <= 0.0 {
-                return Err(ConvertError::InvalidInput(
-                    "Aspect ratio components must be positive".into(),
+                return Err(ConvertError::invalid_input_msg(
+                    "Aspect ratio components must be positive",
                 ));
             }
             return Ok(w / h);
@@ -495,16 +734,16 @@ fn parse_aspect_ratio(props: &Properties) -> Result<f64, ConvertError> {
         // Try parsing as plain float string
         if let Ok(f) = s.parse::<f64>() {
             if f <= 0.0 {
-                return Err(ConvertError::InvalidInput(
-                    "Aspect ratio must be positive".into(),
+                return Err(ConvertError::invalid_input_msg(
+                    "Aspect ratio must be positive",
                 ));
             }
             return Ok(f);
         }
     }
 
-    Err(ConvertError::InvalidInput(
-        "Aspect must be a number or 'W:H' string".into(),
+    Err(ConvertError::invalid_input_msg(
+        "Aspect must be a number or 'W:H' string",
     ))
 }
 
@@ -610,13 +849,11 @@ impl Converter for WatermarkConverter {
 
         // Decode images
         let mut base_img = image::load_from_memory(image_input.data)
-            .map_err(|e| ConvertError::InvalidInput(format!("Failed to decode base image: {}", e)))?
+            .map_err(|e| ConvertError::invalid_input("Failed to decode base image", e))?
             .to_rgba8();
 
         let watermark_img = image::load_from_memory(watermark_input.data)
-            .map_err(|e| {
-                ConvertError::InvalidInput(format!("Failed to decode watermark image: {}", e))
-            })?
+            .map_err(|e| ConvertError::invalid_input("Failed to decode watermark image", e))?
             .to_rgba8();
 
         // Get options from base image properties
@@ -655,7 +892,7 @@ impl Converter for WatermarkConverter {
             })
             .unwrap_or(ImageFormat::Png);
 
-        let output = encode_image(&DynamicImage::ImageRgba8(base_img), format)?;
+        let output = encode_image(&DynamicImage::ImageRgba8(base_img), format, props)?;
 
         // Build output properties
         let mut out_props = props.clone();
@@ -743,6 +980,745 @@ fn composite_with_opacity(
     }
 }
 
+// ============================================================================
+// Sprite Sheets
+// ============================================================================
+
+/// Sprite sheet layout strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpriteLayout {
+    /// Left to right, wrapping at `sprite_max_width` if set.
+    Row,
+    /// Top to bottom.
+    Column,
+    /// Shelf bin-packing, tallest sprites first.
+    Pack,
+}
+
+impl SpriteLayout {
+    /// Parse layout from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "row" => Some(SpriteLayout::Row),
+            "column" | "col" => Some(SpriteLayout::Column),
+            "pack" => Some(SpriteLayout::Pack),
+            _ => None,
+        }
+    }
+}
+
+/// Pack many images into a single sprite sheet.
+///
+/// This is an aggregating converter: each input needs a `path` property,
+/// which is used as its key in the manifest. Produces two outputs:
+/// - `sheet`: the sprite sheet as PNG
+/// - `manifest`: JSON mapping each `path` to `{x, y, w, h}`
+///
+/// Options (via properties on the first input):
+/// - `sprite_layout`: "row" (default), "column", or "pack"
+/// - `sprite_padding`: pixels between sprites (default: 1)
+/// - `sprite_max_width`: maximum sheet width for "row" and "pack"
+pub struct SpriteSheetCreate {
+    decl: ConverterDecl,
+}
+
+impl SpriteSheetCreate {
+    pub fn new() -> Self {
+        let decl = ConverterDecl::new("image.sprite-sheet-create")
+            .description("Pack images into a sprite sheet with a JSON manifest")
+            .input(
+                "in",
+                PortDecl::list(
+                    PropertyPattern::new()
+                        .with("width", Predicate::Any)
+                        .with("height", Predicate::Any),
+                ),
+            )
+            .output(
+                "sheet",
+                PortDecl::single(
+                    PropertyPattern::new()
+                        .eq("format", "png")
+                        .with("width", Predicate::Any)
+                        .with("height", Predicate::Any),
+                ),
+            )
+            .output(
+                "manifest",
+                PortDecl::single(PropertyPattern::new().eq("format", "json")),
+            );
+
+        Self { decl }
+    }
+}
+
+impl Default for SpriteSheetCreate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter for SpriteSheetCreate {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, _input: &[u8], _props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        Err(ConvertError::BatchNotSupported)
+    }
+
+    fn convert_batch(
+        &self,
+        inputs: &[(&[u8], &Properties)],
+    ) -> Result<ConvertOutput, ConvertError> {
+        let Some((_, first_props)) = inputs.first() else {
+            return Err(ConvertError::invalid_input_msg(
+                "Sprite sheet needs at least one image",
+            ));
+        };
+
+        let layout = match first_props.get("sprite_layout").and_then(|v| v.as_str()) {
+            Some(s) => SpriteLayout::parse(s).ok_or_else(|| {
+                ConvertError::invalid_input_msg(format!("Unknown sprite_layout: {}", s))
+            })?,
+            None => SpriteLayout::Row,
+        };
+        let padding = first_props
+            .get("sprite_padding")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(1)
+            .max(0) as u32;
+        let max_width = first_props
+            .get("sprite_max_width")
+            .and_then(|v| v.as_i64())
+            .map(|w| w.max(1) as u32);
+
+        // Paths key the manifest, so check them all before decoding anything
+        let mut paths = Vec::with_capacity(inputs.len());
+        let mut seen = std::collections::HashSet::new();
+        for (_, props) in inputs {
+            let path = props.get("path").and_then(|v| v.as_str()).ok_or_else(|| {
+                ConvertError::invalid_input_msg("Missing 'path' property for sprite")
+            })?;
+            if !seen.insert(path) {
+                return Err(ConvertError::invalid_input_msg(format!(
+                    "Duplicate sprite path '{}': manifest entries would collide",
+                    path
+                )));
+            }
+            paths.push(path);
+        }
+
+        // Decode all sprites up front
+        let mut sprites = Vec::with_capacity(inputs.len());
+        for ((data, _), path) in inputs.iter().zip(paths) {
+            let img = image::load_from_memory(data)
+                .map_err(|e| {
+                    ConvertError::invalid_input(format!("Failed to decode '{}'", path), e)
+                })?
+                .to_rgba8();
+            sprites.push((path, img));
+        }
+
+        let sizes: Vec<(u32, u32)> = sprites.iter().map(|(_, img)| img.dimensions()).collect();
+        let (positions, sheet_w, sheet_h) = layout_sprites(&sizes, layout, padding, max_width);
+
+        let mut sheet = image::RgbaImage::new(sheet_w, sheet_h);
+        let mut manifest = serde_json::Map::new();
+        for ((path, img), (x, y)) in sprites.iter().zip(&positions) {
+            image::imageops::replace(&mut sheet, img, *x as i64, *y as i64);
+            manifest.insert(
+                path.to_string(),
+                serde_json::json!({
+                    "x": x,
+                    "y": y,
+                    "w": img.width(),
+                    "h": img.height(),
+                }),
+            );
+        }
+
+        let sheet_data = encode_image(
+            &DynamicImage::ImageRgba8(sheet),
+            ImageFormat::Png,
+            first_props,
+        )?;
+        let manifest_data = serde_json::to_vec_pretty(&serde_json::Value::Object(manifest))
+            .map_err(|e| ConvertError::failed("Failed to encode manifest", e))?;
+
+        let mut sheet_props = Properties::new();
+        sheet_props.insert("format".into(), "png".into());
+        sheet_props.insert("width".into(), (sheet_w as i64).into());
+        sheet_props.insert("height".into(), (sheet_h as i64).into());
+
+        let mut manifest_props = Properties::new();
+        manifest_props.insert("format".into(), "json".into());
+
+        Ok(ConvertOutput::Multiple(vec![
+            (sheet_data, sheet_props),
+            (manifest_data, manifest_props),
+        ]))
+    }
+}
+
+/// Compute sprite positions and the resulting sheet size.
+///
+/// Positions are returned in input order.
+fn layout_sprites(
+    sizes: &[(u32, u32)],
+    layout: SpriteLayout,
+    padding: u32,
+    max_width: Option<u32>,
+) -> (Vec<(u32, u32)>, u32, u32) {
+    match layout {
+        SpriteLayout::Row => {
+            let order: Vec<usize> = (0..sizes.len()).collect();
+            shelf_pack(sizes, &order, padding, max_width.unwrap_or(u32::MAX))
+        }
+        SpriteLayout::Column => {
+            let mut positions = Vec::with_capacity(sizes.len());
+            let mut y = 0;
+            for &(_, h) in sizes {
+                positions.push((0, y));
+                y += h + padding;
+            }
+            let width = sizes.iter().map(|&(w, _)| w).max().unwrap_or(0);
+            (positions, width, y.saturating_sub(padding))
+        }
+        SpriteLayout::Pack => {
+            // Tallest first keeps shelves tight
+            let mut order: Vec<usize> = (0..sizes.len()).collect();
+            order.sort_by(|&a, &b| sizes[b].1.cmp(&sizes[a].1));
+
+            // Default to a roughly square sheet
+            let area: u64 = sizes
+                .iter()
+                .map(|&(w, h)| (w + padding) as u64 * (h + padding) as u64)
+                .sum();
+            let widest = sizes.iter().map(|&(w, _)| w).max().unwrap_or(0);
+            let width =
+                max_width.unwrap_or_else(|| ((area as f64).sqrt().ceil() as u32).max(widest));
+            shelf_pack(sizes, &order, padding, width)
+        }
+    }
+}
+
+/// Place sprites left to right in `order`, starting a new shelf when the
+/// next sprite would exceed `max_width`.
+fn shelf_pack(
+    sizes: &[(u32, u32)],
+    order: &[usize],
+    padding: u32,
+    max_width: u32,
+) -> (Vec<(u32, u32)>, u32, u32) {
+    let mut positions = vec![(0, 0); sizes.len()];
+    let (mut x, mut y) = (0u32, 0u32);
+    let mut shelf_h = 0;
+    let (mut sheet_w, mut sheet_h) = (0, 0);
+
+    for &i in order {
+        let (w, h) = sizes[i];
+        if x > 0 && x + w > max_width {
+            x = 0;
+            y += shelf_h + padding;
+            shelf_h = 0;
+        }
+        positions[i] = (x, y);
+        sheet_w = sheet_w.max(x + w);
+        sheet_h = sheet_h.max(y + h);
+        shelf_h = shelf_h.max(h);
+        x += w + padding;
+    }
+
+    (positions, sheet_w, sheet_h)
+}
+
+// ============================================================================
+// Color Conversion
+// ============================================================================
+
+/// Convert an image's pixel format, e.g. to strip or add an alpha channel.
+///
+/// Options (via properties):
+/// - `color_target`: "rgb", "rgba", "grayscale" (or "luma") or
+///   "grayscale_alpha" (or "luma_alpha")
+/// - `alpha_value`: 0-255, opacity of an alpha channel added to an image
+///   that had none (default: 255)
+pub struct ColorConvertConverter {
+    decl: ConverterDecl,
+}
+
+impl ColorConvertConverter {
+    pub fn new() -> Self {
+        let decl = ConverterDecl::simple(
+            "image.color-convert",
+            PropertyPattern::new()
+                .with("width", Predicate::Any)
+                .with("height", Predicate::Any),
+            PropertyPattern::new()
+                .with("width", Predicate::Any)
+                .with("height", Predicate::Any),
+        )
+        .description("Convert image pixel format (RGB, RGBA, grayscale)")
+        .with_property_schema(
+            "color_target",
+            PropertySchema::string()
+                .one_of([
+                    "rgb",
+                    "rgba",
+                    "grayscale",
+                    "luma",
+                    "grayscale_alpha",
+                    "luma_alpha",
+                ])
+                .required(),
+        )
+        .with_property_schema("alpha_value", PropertySchema::integer().range(0.0, 255.0))
+        .with_property_schema("jpeg_quality", jpeg_quality_schema());
+
+        Self { decl }
+    }
+}
+
+impl Default for ColorConvertConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter for ColorConvertConverter {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        self.decl.check_props(props)?;
+        let target = props
+            .get("color_target")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        let alpha = props
+            .get("alpha_value")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(255) as u8;
+
+        let img = image::load_from_memory(input)
+            .map_err(|e| ConvertError::invalid_input("Failed to decode image", e))?;
+        let had_alpha = img.color().has_alpha();
+
+        let converted = match target {
+            "rgb" => DynamicImage::ImageRgb8(img.to_rgb8()),
+            "grayscale" | "luma" => DynamicImage::ImageLuma8(img.to_luma8()),
+            "rgba" => {
+                let mut rgba = img.to_rgba8();
+                if !had_alpha {
+                    rgba.pixels_mut().for_each(|p| p[3] = alpha);
+                }
+                DynamicImage::ImageRgba8(rgba)
+            }
+            "grayscale_alpha" | "luma_alpha" => {
+                let mut luma = img.to_luma_alpha8();
+                if !had_alpha {
+                    luma.pixels_mut().for_each(|p| p[1] = alpha);
+                }
+                DynamicImage::ImageLumaA8(luma)
+            }
+            other => {
+                return Err(ConvertError::invalid_input_msg(format!(
+                    "Unknown color_target '{}' (expected rgb, rgba, grayscale or grayscale_alpha)",
+                    other
+                )));
+            }
+        };
+
+        // Re-encode in original format
+        let format = detect_format_from_bytes(input)
+            .or_else(|| {
+                props
+                    .get("format")
+                    .and_then(|v| v.as_str())
+                    .and_then(format_from_name)
+            })
+            .unwrap_or(ImageFormat::Png);
+
+        let output = encode_image(&converted, format, props)?;
+
+        let mut out_props = props.clone();
+        out_props.insert("width".into(), (converted.width() as i64).into());
+        out_props.insert("height".into(), (converted.height() as i64).into());
+        out_props.shift_remove("color_target");
+        out_props.shift_remove("alpha_value");
+
+        Ok(ConvertOutput::Single(output, out_props))
+    }
+}
+
+// ============================================================================
+// Palette Quantization
+// ============================================================================
+
+/// Reduce an image to a palette of at most N colors, written as an 8-bit
+/// indexed PNG.
+///
+/// Uses NeuQuant (`color_quant`) with optional Floyd-Steinberg dithering.
+///
+/// Options (via properties):
+/// - `quantize_colors`: palette size, 2-256 (default: 256)
+/// - `quantize_dither`: diffuse quantization error (default: true)
+/// - `quantize_quality`: 1-100, higher samples more pixels when building
+///   the palette (default: 85)
+pub struct QuantizeConverter {
+    decl: ConverterDecl,
+}
+
+impl QuantizeConverter {
+    pub fn new() -> Self {
+        let decl = ConverterDecl::simple(
+            "image.quantize",
+            PropertyPattern::new().eq("format", "png"),
+            PropertyPattern::new().eq("format", "png"),
+        )
+        .description("Reduce image to an indexed color palette (PNG8)")
+        .with_quality_loss(0.3)
+        .with_property_schema(
+            "quantize_colors",
+            PropertySchema::integer().range(2.0, 256.0),
+        )
+        .with_property_schema("quantize_dither", PropertySchema::boolean())
+        .with_property_schema(
+            "quantize_quality",
+            PropertySchema::integer().range(1.0, 100.0),
+        );
+
+        Self { decl }
+    }
+}
+
+impl Default for QuantizeConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter for QuantizeConverter {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        self.decl.check_props(props)?;
+        let colors = props
+            .get("quantize_colors")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(256);
+        let dither = props
+            .get("quantize_dither")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        let quality = props
+            .get("quantize_quality")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(85);
+
+        let img = image::load_from_memory(input)
+            .map_err(|e| ConvertError::invalid_input("Failed to decode image", e))?
+            .to_rgba8();
+
+        // NeuQuant samples every n-th pixel: 1 (best) to 30 (fastest)
+        let sample_factor = 1 + (100 - quality as i32) * 29 / 99;
+        let quantizer = color_quant::NeuQuant::new(sample_factor, colors as usize, img.as_raw());
+        let indices = if dither {
+            dither_indices(&img, &quantizer)
+        } else {
+            img.pixels()
+                .map(|p| quantizer.index_of(&p.0) as u8)
+                .collect()
+        };
+
+        let output = encode_indexed_png(img.width(), img.height(), &quantizer, &indices)?;
+
+        let mut out_props = props.clone();
+        out_props.insert("format".into(), "png".into());
+        out_props.insert("width".into(), (img.width() as i64).into());
+        out_props.insert("height".into(), (img.height() as i64).into());
+        out_props.shift_remove("quantize_colors");
+        out_props.shift_remove("quantize_dither");
+        out_props.shift_remove("quantize_quality");
+
+        Ok(ConvertOutput::Single(output, out_props))
+    }
+}
+
+/// Map pixels to palette indices with Floyd-Steinberg error diffusion.
+fn dither_indices(img: &image::RgbaImage, quantizer: &color_quant::NeuQuant) -> Vec<u8> {
+    let width = img.width() as usize;
+    let mut indices = Vec::with_capacity(img.as_raw().len() / 4);
+    // Error carried into the current and next row, padded by one pixel each side
+    let mut current = vec![[0f32; 4]; width + 2];
+    let mut next = vec![[0f32; 4]; width + 2];
+
+    for row in img.rows() {
+        for (x, pixel) in row.enumerate() {
+            let mut wanted = [0u8; 4];
+            let mut target = [0f32; 4];
+            for c in 0..4 {
+                target[c] = (pixel[c] as f32 + current[x + 1][c]).clamp(0.0, 255.0);
+                wanted[c] = target[c].round() as u8;
+            }
+
+            let index = quantizer.index_of(&wanted);
+            let chosen = quantizer.lookup(index).unwrap_or(wanted);
+            indices.push(index as u8);
+
+            for c in 0..4 {
+                let error = target[c] - chosen[c] as f32;
+                current[x + 2][c] += error * 7.0 / 16.0;
+                next[x][c] += error * 3.0 / 16.0;
+                next[x + 1][c] += error * 5.0 / 16.0;
+                next[x + 2][c] += error / 16.0;
+            }
+        }
+        std::mem::swap(&mut current, &mut next);
+        next.fill([0.0; 4]);
+    }
+
+    indices
+}
+
+/// Encode palette indices as an 8-bit indexed PNG.
+fn encode_indexed_png(
+    width: u32,
+    height: u32,
+    quantizer: &color_quant::NeuQuant,
+    indices: &[u8],
+) -> Result<Vec<u8>, ConvertError> {
+    let palette = quantizer.color_map_rgba();
+    let rgb: Vec<u8> = palette
+        .chunks_exact(4)
+        .flat_map(|c| [c[0], c[1], c[2]])
+        .collect();
+    let alpha: Vec<u8> = palette.chunks_exact(4).map(|c| c[3]).collect();
+
+    let mut output = Vec::new();
+    let mut encoder = png::Encoder::new(&mut output, width, height);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(rgb);
+    // Only write transparency when some palette entry needs it
+    if alpha.iter().any(|&a| a < 255) {
+        encoder.set_trns(alpha);
+    }
+
+    let png_error = |e: png::EncodingError| ConvertError::failed("Failed to encode indexed PNG", e);
+    let mut writer = encoder.write_header().map_err(png_error)?;
+    writer.write_image_data(indices).map_err(png_error)?;
+    writer.finish().map_err(png_error)?;
+
+    Ok(output)
+}
+
+// ============================================================================
+// SVG Rasterization
+// ============================================================================
+
+#[cfg(feature = "svg")]
+mod svg_impl {
+    use super::*;
+    use resvg::{tiny_skia, usvg};
+
+    const OPTIONS: [&str; 4] = ["svg_width", "svg_height", "svg_scale", "svg_background"];
+
+    /// Schema shared by the SVG rasterizers.
+    fn svg_decl(id: &str, target: &str, description: &str) -> ConverterDecl {
+        ConverterDecl::simple(
+            id,
+            PropertyPattern::new().eq("format", "svg"),
+            PropertyPattern::new().eq("format", target),
+        )
+        .description(description)
+        .with_property_schema("svg_width", PropertySchema::integer().min(1.0))
+        .with_property_schema("svg_height", PropertySchema::integer().min(1.0))
+        .with_property_schema("svg_scale", PropertySchema::float().min(0.001))
+    }
+
+    /// Render an SVG document to RGBA pixels.
+    ///
+    /// The output size is the SVG's own size, overridden by `svg_width` and
+    /// `svg_height` (one of them alone keeps the aspect ratio), then
+    /// multiplied by `svg_scale`.
+    fn rasterize(input: &[u8], props: &Properties) -> Result<image::RgbaImage, ConvertError> {
+        let tree = usvg::Tree::from_data(input, &usvg::Options::default())
+            .map_err(|e| ConvertError::invalid_input("Invalid SVG", e))?;
+        let size = tree.size();
+        let (svg_w, svg_h) = (size.width() as f64, size.height() as f64);
+
+        let dim = |key: &str| props.get(key).and_then(|v| v.as_i64()).map(|n| n as f64);
+        let (width, height) = match (dim("svg_width"), dim("svg_height")) {
+            (Some(w), Some(h)) => (w, h),
+            (Some(w), None) => (w, svg_h * w / svg_w),
+            (None, Some(h)) => (svg_w * h / svg_h, h),
+            (None, None) => (svg_w, svg_h),
+        };
+        let scale = props
+            .get("svg_scale")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(1.0);
+        let width = (width * scale).round().max(1.0) as u32;
+        let height = (height * scale).round().max(1.0) as u32;
+
+        let mut pixmap = tiny_skia::Pixmap::new(width, height).ok_or_else(|| {
+            ConvertError::invalid_input_msg(format!("Cannot rasterize SVG at {}x{}", width, height))
+        })?;
+        let transform = tiny_skia::Transform::from_scale(
+            (width as f64 / svg_w) as f32,
+            (height as f64 / svg_h) as f32,
+        );
+        resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+        // tiny-skia stores premultiplied alpha
+        let pixels = pixmap
+            .pixels()
+            .iter()
+            .flat_map(|p| {
+                let c = p.demultiply();
+                [c.red(), c.green(), c.blue(), c.alpha()]
+            })
+            .collect();
+        Ok(image::RgbaImage::from_raw(width, height, pixels).expect("pixmap size matches"))
+    }
+
+    /// Parse `#rgb` or `#rrggbb` (the `#` is optional).
+    fn parse_hex_color(s: &str) -> Option<[u8; 3]> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let channel =
+            |i: usize, len: usize| u8::from_str_radix(hex.get(i * len..(i + 1) * len)?, 16).ok();
+        match hex.len() {
+            3 => Some([
+                channel(0, 1)? * 17,
+                channel(1, 1)? * 17,
+                channel(2, 1)? * 17,
+            ]),
+            6 => Some([channel(0, 2)?, channel(1, 2)?, channel(2, 2)?]),
+            _ => None,
+        }
+    }
+
+    fn output_props(props: &Properties, format: &str, img: &image::RgbaImage) -> Properties {
+        let mut out_props = props.clone();
+        for key in OPTIONS {
+            out_props.shift_remove(key);
+        }
+        out_props.insert("format".into(), format.into());
+        out_props.insert("width".into(), (img.width() as i64).into());
+        out_props.insert("height".into(), (img.height() as i64).into());
+        out_props
+    }
+
+    /// Rasterize SVG to PNG, keeping transparency.
+    ///
+    /// Options (via properties):
+    /// - `svg_width`, `svg_height`: output size in pixels (default: the
+    ///   SVG's own size; one alone keeps the aspect ratio)
+    /// - `svg_scale`: scale factor applied after sizing (default: 1.0)
+    pub struct SvgToPng {
+        decl: ConverterDecl,
+    }
+
+    impl SvgToPng {
+        pub fn new() -> Self {
+            Self {
+                decl: svg_decl("image.svg-to-png", "png", "Rasterize SVG to PNG"),
+            }
+        }
+    }
+
+    impl Default for SvgToPng {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Converter for SvgToPng {
+        fn decl(&self) -> &ConverterDecl {
+            &self.decl
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl.check_props(props)?;
+            let img = rasterize(input, props)?;
+            let out_props = output_props(props, "png", &img);
+            let output = encode_image(&DynamicImage::ImageRgba8(img), ImageFormat::Png, props)?;
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+
+    /// Rasterize SVG to JPEG over a solid background.
+    ///
+    /// Options (via properties): as for [`SvgToPng`], plus
+    /// - `svg_background`: hex color behind transparent areas (default: `#ffffff`)
+    /// - `jpeg_quality`: see [`encode_image`]
+    pub struct SvgToJpeg {
+        decl: ConverterDecl,
+    }
+
+    impl SvgToJpeg {
+        pub fn new() -> Self {
+            let decl = svg_decl("image.svg-to-jpg", "jpg", "Rasterize SVG to JPEG")
+                .with_property_schema("svg_background", PropertySchema::string())
+                .with_property_schema("jpeg_quality", jpeg_quality_schema())
+                .with_quality_loss(0.1);
+            Self { decl }
+        }
+    }
+
+    impl Default for SvgToJpeg {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Converter for SvgToJpeg {
+        fn decl(&self) -> &ConverterDecl {
+            &self.decl
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl.check_props(props)?;
+            let background = match props.get("svg_background").and_then(|v| v.as_str()) {
+                Some(color) => parse_hex_color(color).ok_or_else(|| {
+                    ConvertError::invalid_input_msg(format!(
+                        "svg_background must be a hex color like \"#ffffff\", got: \"{}\"",
+                        color
+                    ))
+                })?,
+                None => [255, 255, 255],
+            };
+
+            let img = rasterize(input, props)?;
+            let out_props = output_props(props, "jpg", &img);
+
+            // JPEG has no alpha channel: blend onto the background
+            let rgb = image::RgbImage::from_fn(img.width(), img.height(), |x, y| {
+                let [r, g, b, a] = img.get_pixel(x, y).0;
+                let blend = |c: u8, bg: u8| {
+                    ((c as u32 * a as u32 + bg as u32 * (255 - a as u32) + 127) / 255) as u8
+                };
+                image::Rgb([
+                    blend(r, background[0]),
+                    blend(g, background[1]),
+                    blend(b, background[2]),
+                ])
+            });
+
+            let output = encode_image(&DynamicImage::ImageRgb8(rgb), ImageFormat::Jpeg, props)?;
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+}
+
+#[cfg(feature = "svg")]
+pub use svg_impl::{SvgToJpeg, SvgToPng};
+
 /// Detect image format from magic bytes.
 fn detect_format_from_bytes(data: &[u8]) -> Option<ImageFormat> {
     image::guess_format(data).ok()
@@ -801,9 +1777,33 @@ mod tests {
         let mut registry = Registry::new();
         register_all(&mut registry);
 
-        // Should have n*(n-1) format converters + 3 transform converters
+        // Should have n*(n-1) format converters + 6 transform converters
+        // (+ 2 SVG rasterizers)
         let n = enabled_formats().len();
-        assert_eq!(registry.len(), n * (n - 1) + 3);
+        let svg = if cfg!(feature = "svg") { 2 } else { 0 };
+        assert_eq!(registry.len(), n * (n - 1) + 6 + svg);
+    }
+
+    #[test]
+    #[cfg(all(feature = "png", feature = "jpeg"))]
+    fn test_estimate_sizes() {
+        let converter = ImageConverter::new("png", ImageFormat::Png, "jpg", ImageFormat::Jpeg);
+
+        // Known dimensions give the exact RGBA buffer
+        let props = Properties::new()
+            .with("format", "png")
+            .with("width", 100)
+            .with("height", 100);
+        assert_eq!(
+            converter.estimate_working_memory(1000, &props),
+            Some(40_000)
+        );
+        assert_eq!(converter.estimate_output_size(1000, &props), Some(4_000));
+
+        // Otherwise from the formats' typical ratios
+        let props = Properties::new().with("format", "png");
+        assert_eq!(converter.estimate_working_memory(1000, &props), Some(4_000));
+        assert_eq!(converter.estimate_output_size(1000, &props), Some(400));
     }
 
     #[test]
@@ -829,6 +1829,113 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "svg")]
+    fn test_svg_rasterize() {
+        let svg = br##"<svg xmlns="http://www.w3.org/2000/svg" width="50" height="50">
+            <circle cx="25" cy="25" r="20" fill="#ff0000"/>
+        </svg>"##;
+        let props = Properties::new()
+            .with("format", "svg")
+            .with("svg_scale", 2.0);
+
+        let (output, out_props) = match SvgToPng::new().convert(svg, &props).unwrap() {
+            ConvertOutput::Single(output, out_props) => (output, out_props),
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("png"));
+        assert!(out_props.get("svg_scale").is_none());
+        let img = image::load_from_memory(&output).unwrap().to_rgba8();
+        assert_eq!(img.dimensions(), (100, 100));
+        assert_eq!(img.get_pixel(50, 50).0, [255, 0, 0, 255]);
+        assert_eq!(img.get_pixel(2, 2).0[3], 0);
+
+        // JPEG fills the transparent corners with the background
+        let props = props.with("svg_width", 40).with("svg_background", "#00f");
+        let (output, out_props) = match SvgToJpeg::new().convert(svg, &props).unwrap() {
+            ConvertOutput::Single(output, out_props) => (output, out_props),
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(out_props.get("width").unwrap().as_i64(), Some(80));
+        let img = image::load_from_memory(&output).unwrap().to_rgb8();
+        assert_eq!(img.dimensions(), (80, 80));
+        let [r, _, b] = img.get_pixel(40, 40).0;
+        assert!(r > 200 && b < 60);
+        let [r, _, b] = img.get_pixel(1, 1).0;
+        assert!(r < 60 && b > 200);
+
+        let Err(err) = SvgToJpeg::new().convert(svg, &props.with("svg_background", "blue")) else {
+            panic!("Expected an error");
+        };
+        assert!(
+            err.to_string()
+                .contains("svg_background must be a hex color")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "png", feature = "jpeg"))]
+    fn test_exif_auto_rotate() {
+        // 16x8 JPEG: left half red, right half blue
+        let img = image::RgbImage::from_fn(16, 8, |x, _| {
+            if x < 8 {
+                image::Rgb([255, 0, 0])
+            } else {
+                image::Rgb([0, 0, 255])
+            }
+        });
+        let mut jpeg = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(img)
+            .write_to(&mut jpeg, ImageFormat::Jpeg)
+            .unwrap();
+        let jpeg = jpeg.into_inner();
+
+        // Insert an APP1 segment after SOI holding one IFD entry:
+        // Orientation (0x0112), SHORT, count 1, value 6 ("rotate 90° CW")
+        let mut tiff = b"Exif\0\0MM\0\x2a\0\0\0\x08\0\x01".to_vec();
+        tiff.extend([0x01, 0x12, 0x00, 0x03, 0, 0, 0, 1, 0x00, 0x06, 0, 0]);
+        tiff.extend([0, 0, 0, 0]);
+        let mut with_exif = jpeg[..2].to_vec();
+        with_exif.extend([0xFF, 0xE1]);
+        with_exif.extend(((tiff.len() + 2) as u16).to_be_bytes());
+        with_exif.extend(&tiff);
+        with_exif.extend(&jpeg[2..]);
+        assert_eq!(exif_orientation(&with_exif), Some(6));
+
+        let converter = ImageConverter::new("jpg", ImageFormat::Jpeg, "png", ImageFormat::Png);
+        let convert = |props: Properties| match converter.convert(&with_exif, &props).unwrap() {
+            ConvertOutput::Single(data, props) => (image::load_from_memory(&data).unwrap(), props),
+            _ => panic!("Expected single output"),
+        };
+
+        // Off by default: pixels are left as stored
+        let (img, _) = convert(Properties::new().with("format", "jpg"));
+        assert_eq!(img.dimensions(), (16, 8));
+
+        let (img, out_props) = convert(
+            Properties::new()
+                .with("format", "jpg")
+                .with("exif_auto_rotate", true),
+        );
+        assert_eq!(img.dimensions(), (8, 16));
+        assert_eq!(out_props.get("width").unwrap().as_i64(), Some(8));
+        assert!(out_props.get("exif_auto_rotate").is_none());
+        // Turned clockwise, the left (red) half ends up on top
+        let top = img.get_pixel(4, 2);
+        let bottom = img.get_pixel(4, 13);
+        assert!(top[0] > 200 && top[2] < 60, "top is {:?}", top);
+        assert!(bottom[2] > 200 && bottom[0] < 60, "bottom is {:?}", bottom);
+    }
+
+    #[test]
+    #[cfg(all(feature = "png", feature = "jpeg"))]
+    fn test_jpeg_output_is_lossy() {
+        let to_jpeg = ImageConverter::new("png", ImageFormat::Png, "jpg", ImageFormat::Jpeg);
+        let to_png = ImageConverter::new("jpg", ImageFormat::Jpeg, "png", ImageFormat::Png);
+        assert!(!to_jpeg.decl().is_lossless());
+        assert!(to_png.decl().is_lossless());
+    }
+
     #[cfg(feature = "png")]
     fn create_test_png() -> Vec<u8> {
         use image::{ImageBuffer, Rgba};
@@ -871,6 +1978,94 @@ mod tests {
         buf.into_inner()
     }
 
+    #[test]
+    #[cfg(feature = "png")]
+    fn test_color_convert_alpha() {
+        use image::{ImageBuffer, Rgb, Rgba};
+
+        let convert = |data: &[u8], props: Properties| match ColorConvertConverter::new()
+            .convert(data, &props)
+            .unwrap()
+        {
+            ConvertOutput::Single(output, _) => image::load_from_memory(&output).unwrap(),
+            _ => panic!("Expected single output"),
+        };
+
+        let rgb: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::from_pixel(4, 4, Rgb([10, 20, 30]));
+        let mut buf = Cursor::new(Vec::new());
+        rgb.write_to(&mut buf, ImageFormat::Png).unwrap();
+        let rgb_png = buf.into_inner();
+
+        // Adding alpha fills in the requested value
+        let props = Properties::new()
+            .with("format", "png")
+            .with("color_target", "rgba")
+            .with("alpha_value", 128);
+        let img = convert(&rgb_png, props);
+        assert_eq!(img.color(), image::ColorType::Rgba8);
+        assert_eq!(img.to_rgba8().get_pixel(0, 0), &Rgba([10, 20, 30, 128]));
+
+        // Stripping alpha drops the channel
+        let rgba: ImageBuffer<Rgba<u8>, Vec<u8>> =
+            ImageBuffer::from_pixel(4, 4, Rgba([10, 20, 30, 40]));
+        let mut buf = Cursor::new(Vec::new());
+        rgba.write_to(&mut buf, ImageFormat::Png).unwrap();
+        let props = Properties::new()
+            .with("format", "png")
+            .with("color_target", "rgb");
+        let img = convert(&buf.into_inner(), props);
+        assert_eq!(img.color(), image::ColorType::Rgb8);
+
+        let props = Properties::new()
+            .with("color_target", "rgba")
+            .with("alpha_value", 300);
+        assert!(matches!(
+            ColorConvertConverter::new().convert(&rgb_png, &props),
+            Err(ConvertError::InvalidProperties(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "png")]
+    fn test_quantize_gradient() {
+        use image::{ImageBuffer, Rgb};
+
+        // 24-bit gradient: every pixel a different color
+        let img: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::from_fn(128, 128, |x, y| {
+            Rgb([(x * 2) as u8, (y * 2) as u8, ((x + y) % 256) as u8])
+        });
+        let mut buf = Cursor::new(Vec::new());
+        img.write_to(&mut buf, ImageFormat::Png).unwrap();
+        let png_data = buf.into_inner();
+
+        let converter = QuantizeConverter::new();
+        let props = Properties::new()
+            .with("format", "png")
+            .with("quantize_colors", 16i64);
+        let (output, out_props) = match converter.convert(&png_data, &props).unwrap() {
+            ConvertOutput::Single(output, out_props) => (output, out_props),
+            _ => panic!("Expected single output"),
+        };
+
+        assert!(output.len() < png_data.len());
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("png"));
+        assert!(out_props.get("quantize_colors").is_none());
+
+        let decoder = png::Decoder::new(Cursor::new(&output));
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        assert_eq!(info.color_type, png::ColorType::Indexed);
+        assert_eq!(info.bit_depth, png::BitDepth::Eight);
+        assert_eq!(info.palette.as_ref().unwrap().len(), 16 * 3);
+        assert_eq!((info.width, info.height), (128, 128));
+
+        let props = props.with("quantize_colors", 300i64);
+        assert!(matches!(
+            converter.convert(&png_data, &props),
+            Err(ConvertError::InvalidProperties(_))
+        ));
+    }
+
     #[test]
     #[cfg(feature = "png")]
     fn test_resize_scale() {
@@ -942,6 +2137,48 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "png")]
+    fn test_resize_modes() {
+        let png_data = create_test_png_sized(100, 50);
+        let converter = ResizeConverter::new();
+        let resize = |mode: &str| {
+            let props = Properties::new()
+                .with("format", "png")
+                .with("width", 100i64)
+                .with("height", 50i64)
+                .with("target_width", 40i64)
+                .with("target_height", 40i64)
+                .with("resize_mode", mode)
+                .with("resize_filter", "nearest")
+                .with("gravity", "left");
+            match converter.convert(&png_data, &props).unwrap() {
+                ConvertOutput::Single(data, out_props) => {
+                    let img = image::load_from_memory(&data).unwrap();
+                    assert_eq!(
+                        out_props.get("width").unwrap().as_i64(),
+                        Some(img.width() as i64)
+                    );
+                    img.dimensions()
+                }
+                _ => panic!("Expected single output"),
+            }
+        };
+
+        // Fill scales to 80x40 and crops to exactly the target
+        assert_eq!(resize("fill"), (40, 40));
+        assert_eq!(resize("fit"), (40, 20));
+        assert_eq!(resize("stretch"), (40, 40));
+
+        let props = Properties::new()
+            .with("format", "png")
+            .with("width", 100i64)
+            .with("height", 50i64)
+            .with("scale", 0.5)
+            .with("resize_mode", "fill");
+        assert!(converter.convert(&png_data, &props).is_err());
+    }
+
     #[test]
     #[cfg(feature = "png")]
     fn test_crop_aspect_16_9() {
@@ -996,6 +2233,79 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "png")]
+    fn test_sprite_sheet_row() {
+        let sprites: Vec<(Vec<u8>, Properties)> = (0..4)
+            .map(|i| {
+                let props = Properties::new()
+                    .with("format", "png")
+                    .with("path", format!("sprite_{}.png", i))
+                    .with("width", 10i64)
+                    .with("height", 10i64)
+                    .with("sprite_layout", "row")
+                    .with("sprite_padding", 0i64);
+                (create_test_png_sized(10, 10), props)
+            })
+            .collect();
+        let inputs: Vec<(&[u8], &Properties)> =
+            sprites.iter().map(|(d, p)| (d.as_slice(), p)).collect();
+
+        let result = SpriteSheetCreate::new().convert_batch(&inputs).unwrap();
+
+        let ConvertOutput::Multiple(outputs) = result else {
+            panic!("Expected multiple outputs");
+        };
+        assert_eq!(outputs.len(), 2);
+
+        let (sheet, sheet_props) = &outputs[0];
+        assert_eq!(sheet_props.get("width").unwrap().as_i64(), Some(40));
+        assert_eq!(sheet_props.get("height").unwrap().as_i64(), Some(10));
+        assert_eq!(
+            image::load_from_memory(sheet).unwrap().dimensions(),
+            (40, 10)
+        );
+
+        let (manifest, manifest_props) = &outputs[1];
+        assert_eq!(manifest_props.get("format").unwrap().as_str(), Some("json"));
+        let manifest: serde_json::Value = serde_json::from_slice(manifest).unwrap();
+        for i in 0..4 {
+            let entry = &manifest[format!("sprite_{}.png", i)];
+            assert_eq!(entry["x"], 10 * i);
+            assert_eq!(entry["y"], 0);
+            assert_eq!(entry["w"], 10);
+            assert_eq!(entry["h"], 10);
+        }
+
+        // Two sprites under one path would share a manifest entry
+        let duplicate = [inputs[0], inputs[1], inputs[0]];
+        match SpriteSheetCreate::new().convert_batch(&duplicate) {
+            Err(err @ ConvertError::InvalidInput { .. }) => {
+                assert!(err.to_string().contains("sprite_0.png"), "{}", err)
+            }
+            _ => panic!("Expected InvalidInput"),
+        }
+    }
+
+    #[test]
+    fn test_sprite_layouts() {
+        let sizes = [(10, 10), (10, 20), (10, 10)];
+
+        // Column: stacked with padding
+        let (pos, w, h) = layout_sprites(&sizes, SpriteLayout::Column, 1, None);
+        assert_eq!(pos, vec![(0, 0), (0, 11), (0, 32)]);
+        assert_eq!((w, h), (10, 42));
+
+        // Row wraps at max width
+        let (pos, w, h) = layout_sprites(&sizes, SpriteLayout::Row, 0, Some(20));
+        assert_eq!(pos, vec![(0, 0), (10, 0), (0, 20)]);
+        assert_eq!((w, h), (20, 30));
+
+        // Pack places the tallest sprite first
+        let (pos, _, _) = layout_sprites(&sizes, SpriteLayout::Pack, 0, Some(20));
+        assert_eq!(pos[1], (0, 0));
+    }
+
     #[test]
     fn test_gravity_parsing() {
         assert_eq!(Gravity::parse("center"), Some(Gravity::Center));
@@ -1033,4 +2343,49 @@ mod tests {
         // y should be at bottom: 100 - 56 = 44
         assert!((y as i32 - 44).abs() <= 1);
     }
+
+    #[test]
+    #[cfg(all(feature = "png", feature = "jpeg"))]
+    fn test_workflow_jpeg_quality() {
+        use image::{ImageBuffer, Rgb};
+        use paraphase_core::{ExecutionContext, Executor, SimpleExecutor, Workflow};
+        use std::sync::Arc;
+
+        // Noisy gradient so quality has a measurable effect on size
+        let img: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::from_fn(64, 64, |x, y| {
+            let n = (x.wrapping_mul(7919) ^ y.wrapping_mul(104729)) as u8;
+            Rgb([(x * 4) as u8 ^ n, (y * 4) as u8, n])
+        });
+        let mut buf = Cursor::new(Vec::new());
+        img.write_to(&mut buf, ImageFormat::Png).unwrap();
+        let png_data = buf.into_inner();
+
+        let mut registry = Registry::new();
+        register_all(&mut registry);
+        let registry = Arc::new(registry);
+
+        let run = |quality: i64| {
+            let yaml = format!(
+                "steps:\n  - converter: image.png-to-jpg\n    properties:\n      jpeg_quality: {}\n",
+                quality
+            );
+            let workflow = Workflow::from_bytes_format(yaml.as_bytes(), "yaml").unwrap();
+            let ctx = ExecutionContext::new(registry.clone());
+            let props = Properties::new().with("format", "png");
+            SimpleExecutor::new()
+                .execute(&ctx, &workflow.to_plan(), png_data.clone(), props)
+                .unwrap()
+        };
+
+        let low = run(10);
+        let high = run(95);
+
+        assert!(low.data.starts_with(&[0xFF, 0xD8, 0xFF]));
+        assert!(
+            low.data.len() < high.data.len() / 2,
+            "quality 10 ({} bytes) should be much smaller than quality 95 ({} bytes)",
+            low.data.len(),
+            high.data.len()
+        );
+    }
 }
@@ -0,0 +1,334 @@
+//! PDF text extraction for Paraphase
+//!
+//! Pure Rust text extraction via pdf-extract. This reads the text layer
+//! only; PDFs are never rendered, so scanned documents without a text layer
+//! come out empty.
+
+use paraphase_core::{
+    ConvertError, ConvertOutput, Converter, ConverterDecl, Properties, PropertyPattern, Registry,
+};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Extract the text of each page, in page order.
+fn extract_pages(input: &[u8]) -> Result<Vec<String>, ConvertError> {
+    // pdf-extract panics on some malformed documents instead of erroring
+    match panic::catch_unwind(AssertUnwindSafe(|| {
+        pdf_extract::extract_text_from_mem_by_pages(input)
+    })) {
+        Ok(Ok(pages)) => Ok(pages),
+        Ok(Err(e)) => Err(ConvertError::invalid_input("Failed to read PDF", e)),
+        Err(_) => Err(ConvertError::invalid_input_msg(
+            "Failed to read PDF: malformed document",
+        )),
+    }
+}
+
+/// Extract pages as `(page_number, text)` pairs, honouring `pdf_page` and
+/// `pdf_preserve_layout`.
+fn extract(input: &[u8], props: &Properties) -> Result<Vec<(usize, String)>, ConvertError> {
+    let preserve_layout = props
+        .get("pdf_preserve_layout")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let pages = extract_pages(input)?;
+    let count = pages.len();
+    let mut pages: Vec<(usize, String)> = pages
+        .into_iter()
+        .enumerate()
+        .map(|(i, text)| (i + 1, text))
+        .collect();
+
+    if let Some(page) = props.get("pdf_page").and_then(|v| v.as_i64()) {
+        if page < 1 || page as usize > count {
+            return Err(ConvertError::invalid_input_msg(format!(
+                "pdf_page {} out of range (document has {} pages)",
+                page, count
+            )));
+        }
+        pages = vec![pages.swap_remove(page as usize - 1)];
+    }
+
+    Ok(pages
+        .into_iter()
+        .map(|(n, text)| {
+            let text = if preserve_layout { text } else { reflow(&text) };
+            (n, text)
+        })
+        .collect())
+}
+
+/// Collapse the positional whitespace pdf-extract emits.
+///
+/// Runs of spaces become one space, lines are trimmed, and runs of blank
+/// lines become a single paragraph break.
+fn reflow(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut blank = false;
+
+    for line in text.lines() {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if line.is_empty() {
+            blank = !out.is_empty();
+            continue;
+        }
+        if !out.is_empty() {
+            out.push_str(if blank { "\n\n" } else { "\n" });
+        }
+        out.push_str(&line);
+        blank = false;
+    }
+
+    out
+}
+
+/// Strip the options consumed by the PDF converters from the output props.
+fn output_props(props: &Properties, format: &str, pages: usize) -> Properties {
+    let mut out_props = props.clone();
+    for key in ["pdf_page", "pdf_preserve_layout"] {
+        out_props.shift_remove(key);
+    }
+    out_props.insert("format".into(), format.into());
+    out_props.insert("pages".into(), (pages as i64).into());
+    out_props
+}
+
+/// PDF text extractor (PDF -> plain text)
+///
+/// Pages are separated by a blank line.
+///
+/// Options (via properties):
+/// - `pdf_page`: extract only this page (1-based)
+/// - `pdf_preserve_layout`: keep the spacing pdf-extract derives from glyph
+///   positions, which roughly preserves columns (default: false, reflow to
+///   plain lines)
+pub struct PdfToText {
+    decl: ConverterDecl,
+}
+
+impl PdfToText {
+    pub fn new() -> Self {
+        let decl = ConverterDecl::simple(
+            "pdf.to-text",
+            PropertyPattern::new().eq("format", "pdf"),
+            PropertyPattern::new().eq("format", "text"),
+        )
+        .description("Extract text from PDF");
+
+        Self { decl }
+    }
+}
+
+impl Default for PdfToText {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter for PdfToText {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        let pages = extract(input, props)?;
+        let separator = if props
+            .get("pdf_preserve_layout")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+        {
+            "\n"
+        } else {
+            "\n\n"
+        };
+
+        let mut text = pages
+            .iter()
+            .map(|(_, text)| text.as_str())
+            .collect::<Vec<_>>()
+            .join(separator);
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+
+        let out_props = output_props(props, "text", pages.len());
+        Ok(ConvertOutput::Single(text.into_bytes(), out_props))
+    }
+}
+
+/// PDF text extractor (PDF -> JSON)
+///
+/// Emits `{"pages": [{"page": 1, "text": "..."}, ...]}` with 1-based page
+/// numbers from the original document.
+///
+/// Takes the same options as [`PdfToText`].
+pub struct PdfToJson {
+    decl: ConverterDecl,
+}
+
+impl PdfToJson {
+    pub fn new() -> Self {
+        let decl = ConverterDecl::simple(
+            "pdf.to-json",
+            PropertyPattern::new().eq("format", "pdf"),
+            PropertyPattern::new().eq("format", "json"),
+        )
+        .description("Extract per-page text from PDF as JSON");
+
+        Self { decl }
+    }
+}
+
+impl Default for PdfToJson {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter for PdfToJson {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        let pages = extract(input, props)?;
+        let json = serde_json::json!({
+            "pages": pages
+                .iter()
+                .map(|(page, text)| serde_json::json!({ "page": page, "text": text }))
+                .collect::<Vec<_>>(),
+        });
+
+        let output = serde_json::to_vec_pretty(&json)
+            .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+        let out_props = output_props(props, "json", pages.len());
+        Ok(ConvertOutput::Single(output, out_props))
+    }
+}
+
+/// Register all PDF converters with a registry.
+pub fn register_all(registry: &mut Registry) {
+    registry.register(PdfToText::new());
+    registry.register(PdfToJson::new());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paraphase_core::PropertiesExt;
+
+    /// Build a minimal PDF with one Helvetica text line per page.
+    fn minimal_pdf(pages: &[&str]) -> Vec<u8> {
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                (0..pages.len())
+                    .map(|i| format!("{} 0 R", 4 + 2 * i))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                pages.len()
+            ),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+        ];
+        for (i, text) in pages.iter().enumerate() {
+            let content = format!("BT /F1 24 Tf 72 720 Td ({}) Tj ET", text);
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+                 /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                5 + 2 * i
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{}\nendstream",
+                content.len(),
+                content
+            ));
+        }
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::new();
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).bytes());
+        }
+
+        let xref = pdf.len();
+        pdf.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).bytes());
+        for offset in offsets {
+            pdf.extend(format!("{:010} 00000 n \n", offset).bytes());
+        }
+        pdf.extend(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref
+            )
+            .bytes(),
+        );
+        pdf
+    }
+
+    #[test]
+    fn test_pdf_to_text() {
+        let pdf = minimal_pdf(&["Hello PDF", "Second page"]);
+        let props = Properties::new().with("format", "pdf");
+
+        let (output, out_props) = match PdfToText::new().convert(&pdf, &props).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("text"));
+        assert_eq!(out_props.get("pages").unwrap().as_i64(), Some(2));
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Hello PDF\n\nSecond page\n"
+        );
+    }
+
+    #[test]
+    fn test_pdf_to_json_single_page() {
+        let pdf = minimal_pdf(&["Hello PDF", "Second page"]);
+        let props = Properties::new().with("format", "pdf").with("pdf_page", 2);
+
+        let (output, out_props) = match PdfToJson::new().convert(&pdf, &props).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+        assert!(out_props.get("pdf_page").is_none());
+
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "pages": [{ "page": 2, "text": "Second page" }] })
+        );
+
+        let props = Properties::new().with("format", "pdf").with("pdf_page", 3);
+        assert!(matches!(
+            PdfToJson::new().convert(&pdf, &props),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    fn test_malformed_pdf() {
+        let props = Properties::new().with("format", "pdf");
+        let pdf = minimal_pdf(&["Hello PDF"]);
+
+        for input in [&b"not a pdf"[..], &pdf[..pdf.len() / 2]] {
+            assert!(matches!(
+                PdfToText::new().convert(input, &props),
+                Err(ConvertError::InvalidInput { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_reflow() {
+        assert_eq!(
+            reflow("\n\n  Title   here \n\n\n\nBody  line\nnext\n\n"),
+            "Title here\n\nBody line\nnext"
+        );
+    }
+}
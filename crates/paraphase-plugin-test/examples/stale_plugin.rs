@@ -0,0 +1,19 @@
+//! Plugin built against a different ABI version.
+//!
+//! `Registry::load_plugin` must reject it before calling
+//! `paraphase_register`.
+
+use paraphase_plugin::{PARAPHASE_ABI_VERSION, Registry};
+
+#[unsafe(no_mangle)]
+pub extern "C" fn paraphase_plugin_version() -> u32 {
+    PARAPHASE_ABI_VERSION + 1
+}
+
+/// # Safety
+///
+/// Never called: the version check fails first.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn paraphase_register(_registry: *mut Registry) {
+    panic!("registered a plugin with a mismatched ABI version");
+}
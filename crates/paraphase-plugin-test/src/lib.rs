@@ -0,0 +1,35 @@
+//! Test plugin for `Registry::load_plugin`.
+//!
+//! Registers `plugin-test.identity`, which passes bytes through unchanged.
+
+use paraphase_plugin::{
+    ConvertError, ConvertOutput, Converter, ConverterDecl, Properties, PropertyPattern, Registry,
+    export_plugin,
+};
+
+/// Passes `format: bytes` input through unchanged.
+struct Identity;
+
+impl Converter for Identity {
+    fn decl(&self) -> &ConverterDecl {
+        static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+        DECL.get_or_init(|| {
+            ConverterDecl::simple(
+                "plugin-test.identity",
+                PropertyPattern::new().eq("format", "bytes"),
+                PropertyPattern::new().eq("format", "bytes"),
+            )
+            .description("Pass bytes through unchanged")
+        })
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        Ok(ConvertOutput::Single(input.to_vec(), props.clone()))
+    }
+}
+
+fn register(registry: &mut Registry) {
+    registry.register(Identity);
+}
+
+export_plugin!(register);
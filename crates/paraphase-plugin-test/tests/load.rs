@@ -0,0 +1,64 @@
+use paraphase_core::{ConvertOutput, Properties, PropertiesExt, Registry};
+use std::path::PathBuf;
+
+/// A shared library built next to this test binary, e.g. the plugin itself
+/// or one of its examples.
+fn library_path(name: &str) -> PathBuf {
+    let name = format!(
+        "{}{}{}",
+        std::env::consts::DLL_PREFIX,
+        name,
+        std::env::consts::DLL_SUFFIX
+    );
+    let deps = std::env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    let target = deps.parent().unwrap();
+    [
+        deps.join(&name),
+        target.join(&name),
+        target.join("examples").join(&name),
+    ]
+    .into_iter()
+    .find(|path| path.exists())
+    .unwrap_or_else(|| panic!("{} not built; plain `cargo test` builds examples", name))
+}
+
+#[test]
+fn test_load_plugin() {
+    let mut registry = Registry::new();
+    unsafe { registry.load_plugin(&library_path("paraphase_plugin_test")) }.unwrap();
+
+    let converter = registry.get("plugin-test.identity").unwrap();
+    assert_eq!(converter.description(), "Pass bytes through unchanged");
+
+    let props = Properties::new().with("format", "bytes");
+    match converter.convert(b"hello", &props).unwrap() {
+        ConvertOutput::Single(data, out_props) => {
+            assert_eq!(data, b"hello");
+            assert_eq!(out_props, props);
+        }
+        _ => panic!("Expected single output"),
+    }
+}
+
+#[test]
+fn test_load_missing_plugin() {
+    let mut registry = Registry::new();
+    let err = unsafe { registry.load_plugin("does-not-exist.so".as_ref()) }.unwrap_err();
+    assert!(matches!(err, paraphase_core::PluginError::Load { .. }));
+}
+
+#[test]
+fn test_load_plugin_version_mismatch() {
+    let mut registry = Registry::new();
+    let err = unsafe { registry.load_plugin(&library_path("stale_plugin")) }.unwrap_err();
+    assert!(matches!(
+        err,
+        paraphase_core::PluginError::VersionMismatch { expected, found }
+            if expected == paraphase_core::PARAPHASE_ABI_VERSION && found == expected + 1
+    ));
+    assert!(registry.is_empty());
+}
@@ -2,11 +2,51 @@
 //!
 //! This crate provides utilities for writing Cambium plugins,
 //! including the C ABI exports and procedural macros.
+//!
+//! A plugin is a `cdylib` that exports its registration function with
+//! [`export_plugin!`]:
+//!
+//! ```ignore
+//! use paraphase_plugin::{Registry, export_plugin};
+//!
+//! fn register(registry: &mut Registry) {
+//!     registry.register(MyConverter);
+//! }
+//!
+//! export_plugin!(register);
+//! ```
+//!
+//! Hosts load it with `Registry::load_plugin` (the `plugins` feature of
+//! `paraphase-core`).
 
 pub use paraphase_core::{
-    ConvertError, ConvertOutput, Converter, ConverterDecl, PortDecl, Predicate, Properties,
-    PropertiesExt, PropertyPattern, Value,
+    ConvertError, ConvertOutput, Converter, ConverterDecl, PARAPHASE_ABI_VERSION, PortDecl,
+    Predicate, Properties, PropertiesExt, PropertyPattern, Registry, Value,
 };
 
 // TODO: Add #[paraphase_converter] proc macro
-// TODO: Add C ABI export helpers
+
+/// Export the C ABI entry points of a plugin.
+///
+/// Generates `paraphase_plugin_version`, returning [`PARAPHASE_ABI_VERSION`],
+/// and `paraphase_register`, which passes the host's registry to
+/// `$register: fn(&mut Registry)`. Use it once, in a `cdylib` crate.
+#[macro_export]
+macro_rules! export_plugin {
+    ($register:path) => {
+        #[unsafe(no_mangle)]
+        pub extern "C" fn paraphase_plugin_version() -> u32 {
+            $crate::PARAPHASE_ABI_VERSION
+        }
+
+        /// # Safety
+        ///
+        /// `registry` must be a valid, exclusive pointer to a registry built
+        /// with the same `paraphase-core` as this plugin.
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn paraphase_register(registry: *mut $crate::Registry) {
+            let registry: &mut $crate::Registry = unsafe { &mut *registry };
+            $register(registry);
+        }
+    };
+}
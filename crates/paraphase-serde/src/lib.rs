@@ -13,6 +13,8 @@
 //! - `toml` (default) - TOML via toml
 //! - `ron` - Rusty Object Notation
 //! - `json5` - JSON5 (JSON with comments/trailing commas)
+//! - `hcl` - HashiCorp Configuration Language (Terraform, Packer, Nomad)
+//! - `edn` - Extensible Data Notation (Clojure, Datomic)
 //! - `xml` - XML via quick-xml
 //! - `lexpr` - S-expressions (Lisp-style)
 //! - `urlencoded` - URL-encoded form data
@@ -33,14 +35,24 @@
 //! ## Encoding formats (byte representations)
 //! - `base64` - Base64 encoding/decoding
 //! - `hex` - Hexadecimal encoding/decoding
+//! - `base32` - Base32 encoding/decoding (RFC 4648)
+//! - `base58` - Base58 encoding/decoding (Bitcoin, Flickr alphabets)
 //!
 //! ## Line-based formats
 //! - `ndjson` - Newline-delimited JSON (JSON Lines)
 //!
+//! ## Queries
+//! - `jsonpath` - JSONPath filtering (RFC 9535)
+//!
 //! ## Compression formats
-//! - `gzip` - Gzip compression/decompression
+//! - `gzip` - Gzip, zlib and raw deflate compression/decompression
 //! - `zstd` - Zstandard compression/decompression
 //! - `brotli` - Brotli compression/decompression
+//! - `bzip2` - Bzip2 compression/decompression
+//! - `snappy` - Snappy compression/decompression (framed and raw)
+//!
+//! ## Binary diff
+//! - `bsdiff` - Binary diff/patch (bsdiff)
 //!
 //! ## Config formats
 //! - `ini` - INI file format (bidirectional with JSON)
@@ -50,6 +62,10 @@
 //! - `markdown` - Markdown to HTML conversion
 //! - `html2text` - HTML to plain text conversion
 //!
+//! ## Templates
+//! - `tera` - Render JSON data with a Tera template
+//! - `handlebars` - Render JSON data with a Handlebars template
+//!
 //! ## Feature group
 //! - `all` - All formats
 
@@ -65,13 +81,30 @@ pub fn register_all(registry: &mut Registry) {
     // Register converters between all pairs of enabled formats
     for from in &formats {
         for to in &formats {
-            if from != to {
-                registry.register(SerdeConverter::new(from, to));
+            // YAML → TOML and CBOR ↔ MessagePack have dedicated transcoders
+            if from == to
+                || matches!(
+                    (*from, *to),
+                    ("yaml", "toml") | ("cbor", "msgpack") | ("msgpack", "cbor")
+                )
+            {
+                continue;
             }
+            registry.register(SerdeConverter::new(from, to));
         }
     }
 
-    // Register encoding converters (base64, hex)
+    #[cfg(all(feature = "yaml", feature = "toml"))]
+    {
+        registry.register(YamlToToml);
+    }
+    #[cfg(all(feature = "cbor", feature = "msgpack"))]
+    {
+        registry.register(CborToMsgpack);
+        registry.register(MsgpackToCbor);
+    }
+
+    // Register encoding converters (base64, hex, base32, base58)
     #[cfg(feature = "base64")]
     {
         registry.register(Base64Encoder);
@@ -82,6 +115,37 @@ pub fn register_all(registry: &mut Registry) {
         registry.register(HexEncoder);
         registry.register(HexDecoder);
     }
+    #[cfg(feature = "base32")]
+    {
+        registry.register(Base32Encoder);
+        registry.register(Base32Decoder);
+    }
+    #[cfg(feature = "base58")]
+    {
+        registry.register(Base58Encoder);
+        registry.register(Base58Decoder);
+    }
+    #[cfg(feature = "legacy-encoding")]
+    {
+        registry.register(QuotedPrintableEncode);
+        registry.register(QuotedPrintableDecode);
+        registry.register(UuEncode);
+        registry.register(UuDecode);
+    }
+
+    #[cfg(feature = "urlencoded")]
+    {
+        registry.register(UrlPercentEncode);
+        registry.register(UrlPercentDecode);
+        registry.register(HtmlEntityEncode);
+        registry.register(HtmlEntityDecode);
+    }
+
+    // Register CSV converters
+    #[cfg(feature = "csv")]
+    {
+        registry.register(CsvToJson);
+    }
 
     // Register NDJSON converters
     #[cfg(feature = "ndjson")]
@@ -90,11 +154,29 @@ pub fn register_all(registry: &mut Registry) {
         registry.register(NdjsonToJson);
     }
 
+    // Register query converters
+    #[cfg(feature = "jsonpath")]
+    {
+        registry.register(JsonPathFilter);
+    }
+    #[cfg(feature = "json")]
+    {
+        registry.register(JsonProjectConverter);
+        registry.register(JsonMergePatch);
+        registry.register(JsonMergeDiff);
+        registry.register(JsonPatchApply::new());
+        registry.register(JsonPatchGenerate);
+    }
+
     // Register compression converters
     #[cfg(feature = "gzip")]
     {
         registry.register(GzipCompress);
         registry.register(GzipDecompress);
+        registry.register(ZlibCompress);
+        registry.register(ZlibDecompress);
+        registry.register(DeflateCompress);
+        registry.register(DeflateDecompress);
     }
     #[cfg(feature = "zstd")]
     {
@@ -106,6 +188,30 @@ pub fn register_all(registry: &mut Registry) {
         registry.register(BrotliCompress);
         registry.register(BrotliDecompress);
     }
+    #[cfg(feature = "bzip2")]
+    {
+        registry.register(Bzip2Compress);
+        registry.register(Bzip2Decompress);
+    }
+    #[cfg(feature = "snappy")]
+    {
+        registry.register(SnappyCompress);
+        registry.register(SnappyDecompress);
+    }
+    #[cfg(any(
+        feature = "gzip",
+        feature = "zstd",
+        feature = "bzip2",
+        feature = "brotli"
+    ))]
+    registry.register(AutoDecompress);
+
+    // Register binary diff converters
+    #[cfg(feature = "bsdiff")]
+    {
+        registry.register(BinaryDiff);
+        registry.register(BinaryPatch);
+    }
 
     // Register config format converters
     #[cfg(feature = "ini")]
@@ -125,12 +231,23 @@ pub fn register_all(registry: &mut Registry) {
     #[cfg(feature = "markdown")]
     {
         registry.register(MarkdownToHtml);
+        registry.register(MarkdownToText);
     }
     #[cfg(feature = "html2text")]
     {
         registry.register(HtmlToText);
     }
 
+    // Register template converters
+    #[cfg(feature = "tera")]
+    {
+        registry.register(TeraRender);
+    }
+    #[cfg(feature = "handlebars")]
+    {
+        registry.register(HandlebarsRender);
+    }
+
     // Register archive converters
     #[cfg(feature = "tar")]
     {
@@ -147,16 +264,20 @@ pub fn register_all(registry: &mut Registry) {
     #[cfg(feature = "spreadsheet")]
     {
         registry.register(SpreadsheetToJson);
+        registry.register(JsonToXlsx);
     }
 
     // Register schema-based format converters
     #[cfg(feature = "avro")]
     {
         registry.register(AvroToJson);
+        registry.register(JsonToAvro);
+        registry.register(JsonToAvroFile);
     }
     #[cfg(feature = "parquet")]
     {
         registry.register(ParquetToJson);
+        registry.register(ParquetSchemaToJson);
     }
 }
 
@@ -174,6 +295,12 @@ pub fn enabled_formats() -> Vec<&'static str> {
         "ron",
         #[cfg(feature = "json5")]
         "json5",
+        #[cfg(feature = "hcl")]
+        "hcl",
+        #[cfg(feature = "edn")]
+        "edn",
+        #[cfg(feature = "kdl")]
+        "kdl",
         #[cfg(feature = "xml")]
         "xml",
         #[cfg(feature = "lexpr")]
@@ -201,6 +328,8 @@ pub fn enabled_formats() -> Vec<&'static str> {
         "pickle",
         #[cfg(feature = "plist")]
         "plist",
+        #[cfg(feature = "plist")]
+        "plist-xml",
         // CSV is special - only works with arrays of flat objects
         // Don't include in general conversion matrix
     ]
@@ -208,6 +337,22 @@ pub fn enabled_formats() -> Vec<&'static str> {
 }
 
 /// A converter between two serde-compatible formats.
+///
+/// Options (via properties):
+/// - `json_pretty`: indent JSON output (default: true)
+/// - `json_sorted_keys`: sort object keys recursively rather than keeping
+///   document order (default: false)
+/// - `cbor_canonical`: use deterministic CBOR encoding (default: false)
+/// - `msgpack_compact`: write small non-negative integer keys as integers (default: false)
+/// - `msgpack_named_keys`: always write string keys (default: true, false when compact)
+/// - `msgpack_binary_as_base64`: read MessagePack binary as base64 strings rather
+///   than byte arrays (default: true)
+/// - `toml_null`: write nulls to TOML as `"omit"`, `"empty_string"` or
+///   `"error"` (default: omit)
+/// - `bson_extended_json`: map BSON ObjectIds, dates and binary to and from
+///   MongoDB Extended JSON (default: true)
+/// - `plist_format`: write `plist` as `"binary"` or `"xml"` (default: binary);
+///   the `plist-xml` format is always XML
 pub struct SerdeConverter {
     decl: ConverterDecl,
     from: &'static str,
@@ -239,1892 +384,9279 @@ impl Converter for SerdeConverter {
 
     fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
         // Deserialize from source format
-        let value: serde_json::Value = deserialize(self.from, input)?;
+        let (mut value, source_root): (serde_json::Value, Option<String>) = match self.from {
+            #[cfg(feature = "msgpack")]
+            "msgpack" => (
+                msgpack_impl::from_slice(input, flag(props, "msgpack_binary_as_base64", true))?,
+                None,
+            ),
+            #[cfg(feature = "xml")]
+            "xml" => {
+                let s = std::str::from_utf8(input)
+                    .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+                let (value, root) =
+                    xml_impl::from_str(s, flag(props, "xml_preserve_namespaces", false))?;
+                (value, Some(root))
+            }
+            #[cfg(feature = "toml")]
+            "toml" => {
+                let s = std::str::from_utf8(input)
+                    .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+                let as_string = flag(props, "toml_datetime_as_string", true);
+                (toml_impl::from_str(s, as_string)?, None)
+            }
+            #[cfg(feature = "bson")]
+            "bson" => (
+                bson_impl::from_slice(input, flag(props, "bson_extended_json", true))?,
+                None,
+            ),
+            _ => (deserialize(self.from, input)?, None),
+        };
+
+        if flag(props, "json_sorted_keys", false) {
+            sort_keys(&mut value);
+        }
 
         // Serialize to target format
-        let output = serialize(self.to, &value)?;
+        let output = match self.to {
+            #[cfg(feature = "json")]
+            "json" if !flag(props, "json_pretty", true) => serde_json::to_vec(&value)
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?,
+            #[cfg(feature = "cbor")]
+            "cbor" if flag(props, "cbor_canonical", false) => {
+                canonicalize_cbor(&serialize("cbor", &value)?)?
+            }
+            #[cfg(feature = "msgpack")]
+            "msgpack" => {
+                let compact = flag(props, "msgpack_compact", false);
+                let named_keys = flag(props, "msgpack_named_keys", !compact);
+                msgpack_impl::to_vec(&value, compact && !named_keys)?
+            }
+            #[cfg(feature = "xml")]
+            "xml" => {
+                let root = props
+                    .get("xml_root_element")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or(xml_impl::DEFAULT_ROOT);
+                xml_impl::to_string(&value, root)?.into_bytes()
+            }
+            #[cfg(feature = "toml")]
+            "toml" => {
+                let null = toml_impl::NullStrategy::from_props(props)?;
+                toml_impl::to_string(&value, null)?.into_bytes()
+            }
+            #[cfg(feature = "bson")]
+            "bson" => bson_impl::to_vec(&value, flag(props, "bson_extended_json", true))?,
+            #[cfg(feature = "plist")]
+            "plist" => match props.get("plist_format").and_then(|v| v.as_str()) {
+                None | Some("binary") => serialize("plist", &value)?,
+                Some("xml") => serialize("plist-xml", &value)?,
+                Some(other) => {
+                    return Err(ConvertError::invalid_input_msg(format!(
+                        "Invalid plist_format '{}': expected 'binary' or 'xml'",
+                        other
+                    )));
+                }
+            },
+            _ => serialize(self.to, &value)?,
+        };
 
         // Update properties
         let mut out_props = props.clone();
         out_props.insert("format".into(), self.to.into());
+        if let Some(root) = source_root {
+            // Keep the root name for a later XML serialization
+            out_props
+                .entry("xml_root_element".to_string())
+                .or_insert(root.into());
+        }
+        if self.to == "xml" {
+            // Consumed by the XML serializer
+            out_props.shift_remove("xml_root_element");
+        }
 
         Ok(ConvertOutput::Single(output, out_props))
     }
 }
 
 // ============================================
-// Base64 encoding/decoding
+// TOML options
 // ============================================
 
-#[cfg(feature = "base64")]
-mod base64_impl {
+#[cfg(feature = "toml")]
+mod toml_impl {
     use super::*;
-    use base64::prelude::*;
+    use serde_json::{Number, Value};
+    use toml::Value as TomlValue;
+    use toml::value::{Datetime, Offset};
 
-    /// Encode raw bytes to base64 text.
-    pub struct Base64Encoder;
+    /// Parse TOML into a JSON value.
+    ///
+    /// Datetimes become RFC 3339 strings (`2023-01-15T10:30:00Z`), or Unix
+    /// timestamps when `datetime_as_string` is false. Timestamps treat
+    /// local datetimes as UTC and local dates as midnight; local times have
+    /// no timestamp and stay strings.
+    pub fn from_str(text: &str, datetime_as_string: bool) -> Result<Value, ConvertError> {
+        let value: TomlValue =
+            toml::from_str(text).map_err(|e| ConvertError::invalid_input("Invalid TOML", e))?;
+        Ok(to_json(value, datetime_as_string))
+    }
 
-    impl Converter for Base64Encoder {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "encoding.raw-to-base64",
-                    PropertyPattern::new().eq("format", "raw"),
-                    PropertyPattern::new().eq("format", "base64"),
-                )
-                .description("Encode raw bytes to base64")
-            })
+    /// How null values are written, since TOML has no null (`toml_null`).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum NullStrategy {
+        /// Leave the key (or array element) out.
+        Omit,
+        /// Write an empty string.
+        EmptyString,
+        /// Fail the conversion.
+        Error,
+    }
+
+    impl NullStrategy {
+        /// Read the `toml_null` property (default: omit).
+        pub fn from_props(props: &Properties) -> Result<Self, ConvertError> {
+            match props.get("toml_null").and_then(|v| v.as_str()) {
+                None | Some("omit") => Ok(Self::Omit),
+                Some("empty_string") => Ok(Self::EmptyString),
+                Some("error") => Ok(Self::Error),
+                Some(other) => Err(ConvertError::invalid_input_msg(format!(
+                    "Invalid toml_null '{}': expected 'omit', 'empty_string' or 'error'",
+                    other
+                ))),
+            }
         }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let encoded = BASE64_STANDARD.encode(input);
-            let mut out_props = props.clone();
-            out_props.insert("format".into(), "base64".into());
-            Ok(ConvertOutput::Single(encoded.into_bytes(), out_props))
+        /// The TOML value written for a null at `path`; `None` means omitted.
+        pub fn apply(self, path: &str) -> Result<Option<TomlValue>, ConvertError> {
+            match self {
+                Self::Omit => Ok(None),
+                Self::EmptyString => Ok(Some(TomlValue::String(String::new()))),
+                Self::Error => Err(ConvertError::invalid_input_msg(format!(
+                    "Null value at '{}' has no TOML equivalent",
+                    path
+                ))),
+            }
         }
     }
 
-    /// Decode base64 text to raw bytes.
-    pub struct Base64Decoder;
+    /// Write a JSON value as TOML.
+    ///
+    /// Goes through `toml::Value`, so arrays of objects are written as
+    /// arrays of tables (`[[servers]]`). TOML has no null, so null values
+    /// are handled by `null`. The root must be an object.
+    pub fn to_string(value: &Value, null: NullStrategy) -> Result<String, ConvertError> {
+        let table = match from_json(value, "", null)? {
+            Some(TomlValue::Table(table)) => table,
+            _ => {
+                return Err(ConvertError::invalid_input_msg(
+                    "TOML documents must be a table; the JSON root is not an object",
+                ));
+            }
+        };
+        toml::to_string_pretty(&table)
+            .map_err(|e| ConvertError::failed("TOML serialization failed", e))
+    }
 
-    impl Converter for Base64Decoder {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "encoding.base64-to-raw",
-                    PropertyPattern::new().eq("format", "base64"),
-                    PropertyPattern::new().eq("format", "raw"),
-                )
-                .description("Decode base64 to raw bytes")
-            })
+    /// Transcode a JSON value at `path`; `None` means it was omitted.
+    fn from_json(
+        value: &Value,
+        path: &str,
+        null: NullStrategy,
+    ) -> Result<Option<TomlValue>, ConvertError> {
+        let value = match value {
+            Value::Null => return null.apply(path),
+            Value::Bool(b) => TomlValue::Boolean(*b),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => TomlValue::Integer(i),
+                None if n.is_f64() => TomlValue::Float(n.as_f64().unwrap_or(f64::NAN)),
+                None => {
+                    return Err(ConvertError::invalid_input_msg(format!(
+                        "Integer {} at '{}' is out of TOML's 64-bit signed range",
+                        n, path
+                    )));
+                }
+            },
+            Value::String(s) => TomlValue::String(s.clone()),
+            Value::Array(items) => {
+                let mut array = Vec::with_capacity(items.len());
+                for (i, item) in items.iter().enumerate() {
+                    array.extend(from_json(item, &format!("{}[{}]", path, i), null)?);
+                }
+                TomlValue::Array(array)
+            }
+            Value::Object(map) => {
+                let mut table = toml::Table::new();
+                for (key, item) in map {
+                    let path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    if let Some(item) = from_json(item, &path, null)? {
+                        table.insert(key.clone(), item);
+                    }
+                }
+                TomlValue::Table(table)
+            }
+        };
+        Ok(Some(value))
+    }
+
+    fn to_json(value: TomlValue, datetime_as_string: bool) -> Value {
+        match value {
+            TomlValue::String(s) => Value::String(s),
+            TomlValue::Integer(i) => Value::from(i),
+            TomlValue::Float(f) => Number::from_f64(f).map_or(Value::Null, Value::Number),
+            TomlValue::Boolean(b) => Value::Bool(b),
+            TomlValue::Datetime(dt) if datetime_as_string => Value::String(dt.to_string()),
+            TomlValue::Datetime(dt) => {
+                unix_timestamp(&dt).unwrap_or_else(|| Value::String(dt.to_string()))
+            }
+            TomlValue::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .map(|v| to_json(v, datetime_as_string))
+                    .collect(),
+            ),
+            TomlValue::Table(table) => Value::Object(
+                table
+                    .into_iter()
+                    .map(|(k, v)| (k, to_json(v, datetime_as_string)))
+                    .collect(),
+            ),
         }
+    }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            // Handle input as text (trim whitespace)
-            let text = std::str::from_utf8(input)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?
-                .trim();
-            let decoded = BASE64_STANDARD
-                .decode(text)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid base64: {}", e)))?;
-            let mut out_props = props.clone();
-            out_props.insert("format".into(), "raw".into());
-            Ok(ConvertOutput::Single(decoded, out_props))
+    /// Seconds since the Unix epoch; fractional if the datetime has them.
+    fn unix_timestamp(dt: &Datetime) -> Option<Value> {
+        let date = dt.date?;
+        let days = days_from_civil(date.year as i64, date.month as i64, date.day as i64);
+        let (seconds, nanos) = match dt.time {
+            Some(t) => (
+                t.hour as i64 * 3600 + t.minute as i64 * 60 + t.second as i64,
+                t.nanosecond,
+            ),
+            None => (0, 0),
+        };
+        let offset = match dt.offset {
+            Some(Offset::Custom { minutes }) => minutes as i64 * 60,
+            Some(Offset::Z) | None => 0,
+        };
+        let timestamp = days * 86_400 + seconds - offset;
+        if nanos == 0 {
+            Some(Value::from(timestamp))
+        } else {
+            Number::from_f64(timestamp as f64 + nanos as f64 / 1e9).map(Value::Number)
         }
     }
-}
 
-#[cfg(feature = "base64")]
-pub use base64_impl::{Base64Decoder, Base64Encoder};
+    /// Days since 1970-01-01 in the proleptic Gregorian calendar.
+    fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+        let year = if month <= 2 { year - 1 } else { year };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+}
 
 // ============================================
-// Hex encoding/decoding
+// YAML → TOML
 // ============================================
 
-#[cfg(feature = "hex")]
-mod hex_impl {
+#[cfg(all(feature = "yaml", feature = "toml"))]
+mod yaml_toml_impl {
     use super::*;
+    use crate::toml_impl::NullStrategy;
+    use std::collections::HashMap;
+    use toml::Value as TomlValue;
+    use toml::value::Datetime;
+    use yaml_rust2::parser::{Event, EventReceiver, Parser, Tag};
+    use yaml_rust2::scanner::TScalarStyle;
+
+    /// Most nodes alias expansion may produce, guarding against
+    /// "billion laughs" documents.
+    const MAX_ALIAS_NODES: usize = 1 << 20;
+
+    /// Convert YAML to TOML without going through JSON.
+    ///
+    /// - Plain scalars that are YAML timestamps (`2024-01-15`,
+    ///   `2024-01-15T10:30:00Z`) become TOML datetimes; quoted or tagged
+    ///   ones (`"2024-01-15"`, `!!str 2024-01-15`) stay strings
+    /// - Tags are dropped, keeping the tagged value
+    /// - Non-string keys (numbers, booleans) are written as strings
+    ///
+    /// TOML has no null, so null values are omitted by default. Set
+    /// `toml_null` to `"empty_string"` to write `""`, or to `"error"` to
+    /// reject them; omitted keys are listed in the `toml_omitted` output
+    /// property.
+    ///
+    /// YAML 1.1 booleans (`yes`/`no`, `on`/`off`) are plain strings in YAML
+    /// 1.2; set `yaml_bool_aliases` to write unquoted ones as TOML booleans.
+    ///
+    /// The document root must be a mapping.
+    pub struct YamlToToml;
 
-    /// Encode raw bytes to hexadecimal text.
-    pub struct HexEncoder;
-
-    impl Converter for HexEncoder {
+    impl Converter for YamlToToml {
         fn decl(&self) -> &ConverterDecl {
             static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
             DECL.get_or_init(|| {
                 ConverterDecl::simple(
-                    "encoding.raw-to-hex",
-                    PropertyPattern::new().eq("format", "raw"),
-                    PropertyPattern::new().eq("format", "hex"),
+                    "serde.yaml-to-toml",
+                    PropertyPattern::new().eq("format", "yaml"),
+                    PropertyPattern::new().eq("format", "toml"),
                 )
-                .description("Encode raw bytes to hexadecimal")
+                .description("Convert YAML to TOML")
             })
         }
 
         fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let encoded = hex::encode(input);
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+
+            let mut transcoder = Transcoder {
+                null: NullStrategy::from_props(props)?,
+                bool_aliases: flag(props, "yaml_bool_aliases", false),
+                omitted: Vec::new(),
+            };
+            let table = match parse(text)? {
+                Node::Map(entries) => transcoder.table(entries, "")?,
+                _ => {
+                    return Err(ConvertError::invalid_input_msg(
+                        "YAML value is not a TOML-compatible table",
+                    ));
+                }
+            };
+
+            let output = toml::to_string_pretty(&TomlValue::Table(table))
+                .map_err(|e| ConvertError::failed("TOML serialization failed", e))?;
+
             let mut out_props = props.clone();
-            out_props.insert("format".into(), "hex".into());
-            Ok(ConvertOutput::Single(encoded.into_bytes(), out_props))
+            out_props.shift_remove("toml_null");
+            out_props.shift_remove("yaml_bool_aliases");
+            out_props.insert("format".into(), "toml".into());
+            if !transcoder.omitted.is_empty() {
+                out_props.insert("toml_omitted".into(), transcoder.omitted.join(",").into());
+            }
+            Ok(ConvertOutput::Single(output.into_bytes(), out_props))
         }
     }
 
-    /// Decode hexadecimal text to raw bytes.
-    pub struct HexDecoder;
+    /// A YAML node with its scalars resolved.
+    ///
+    /// `serde_yaml::Value` forgets how a string was written, so this keeps
+    /// untagged plain scalars apart: only those may become datetimes.
+    #[derive(Clone)]
+    enum Node {
+        Null,
+        Bool(bool),
+        Int(i128),
+        Float(f64),
+        /// A quoted, block or `!!str` scalar.
+        Str(String),
+        /// An untagged plain scalar that isn't a null, bool or number.
+        Plain(String),
+        Seq(Vec<Node>),
+        Map(Vec<(Node, Node)>),
+    }
 
-    impl Converter for HexDecoder {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "encoding.hex-to-raw",
-                    PropertyPattern::new().eq("format", "hex"),
-                    PropertyPattern::new().eq("format", "raw"),
-                )
-                .description("Decode hexadecimal to raw bytes")
-            })
+    impl Node {
+        /// Number of nodes in this subtree.
+        fn size(&self) -> usize {
+            match self {
+                Node::Seq(items) => 1 + items.iter().map(Node::size).sum::<usize>(),
+                Node::Map(entries) => {
+                    1 + entries
+                        .iter()
+                        .map(|(k, v)| k.size() + v.size())
+                        .sum::<usize>()
+                }
+                _ => 1,
+            }
         }
+    }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            // Handle input as text (trim whitespace, remove common separators)
-            let text = std::str::from_utf8(input)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?
-                .trim()
-                .replace([' ', ':', '-'], "");
-            let decoded = hex::decode(&text)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid hex: {}", e)))?;
-            let mut out_props = props.clone();
-            out_props.insert("format".into(), "raw".into());
-            Ok(ConvertOutput::Single(decoded, out_props))
+    /// Parse a single YAML document.
+    fn parse(text: &str) -> Result<Node, ConvertError> {
+        let mut builder = Builder::default();
+        Parser::new_from_str(text)
+            .load(&mut builder, true)
+            .map_err(|e| ConvertError::invalid_input("Invalid YAML", e))?;
+        if let Some(error) = builder.error {
+            return Err(ConvertError::invalid_input_msg(error));
+        }
+        if builder.documents.len() > 1 {
+            return Err(ConvertError::invalid_input_msg(
+                "YAML input contains more than one document",
+            ));
         }
+        Ok(builder.documents.pop().unwrap_or(Node::Null))
     }
-}
-
-#[cfg(feature = "hex")]
-pub use hex_impl::{HexDecoder, HexEncoder};
 
-// ============================================
-// NDJSON (Newline-delimited JSON)
-// ============================================
-
-#[cfg(feature = "ndjson")]
-mod ndjson_impl {
-    use super::*;
+    /// Builds [`Node`] trees from parser events, expanding aliases.
+    #[derive(Default)]
+    struct Builder {
+        /// Open sequences and mappings, innermost last.
+        frames: Vec<Frame>,
+        /// Anchored nodes with their sizes.
+        anchors: HashMap<usize, (Node, usize)>,
+        alias_nodes: usize,
+        documents: Vec<Node>,
+        error: Option<String>,
+    }
 
-    /// Convert JSON array to newline-delimited JSON.
-    pub struct JsonToNdjson;
+    struct Frame {
+        anchor: usize,
+        mapping: bool,
+        /// Keys and values alternate for mappings.
+        items: Vec<Node>,
+    }
 
-    impl Converter for JsonToNdjson {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "serde.json-to-ndjson",
-                    PropertyPattern::new().eq("format", "json"),
-                    PropertyPattern::new().eq("format", "ndjson"),
-                )
-                .description("Convert JSON array to newline-delimited JSON")
-            })
+    impl Builder {
+        fn push(&mut self, node: Node, anchor: usize) {
+            if anchor > 0 {
+                let size = node.size();
+                self.anchors.insert(anchor, (node.clone(), size));
+            }
+            match self.frames.last_mut() {
+                Some(frame) => frame.items.push(node),
+                None => self.documents.push(node),
+            }
         }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let value: serde_json::Value = serde_json::from_slice(input)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid JSON: {}", e)))?;
+        fn open(&mut self, anchor: usize, mapping: bool) {
+            self.frames.push(Frame {
+                anchor,
+                mapping,
+                items: Vec::new(),
+            });
+        }
 
-            let array = value
-                .as_array()
-                .ok_or_else(|| ConvertError::InvalidInput("JSON must be an array".into()))?;
+        fn close(&mut self) {
+            let Some(frame) = self.frames.pop() else {
+                return;
+            };
+            let node = if frame.mapping {
+                let mut items = frame.items.into_iter();
+                let mut entries = Vec::new();
+                while let (Some(key), Some(value)) = (items.next(), items.next()) {
+                    entries.push((key, value));
+                }
+                Node::Map(entries)
+            } else {
+                Node::Seq(frame.items)
+            };
+            self.push(node, frame.anchor);
+        }
 
-            let mut output = Vec::new();
-            for item in array {
-                serde_json::to_writer(&mut output, item).map_err(|e| {
-                    ConvertError::Failed(format!("JSON serialization failed: {}", e))
-                })?;
-                output.push(b'\n');
+        fn alias(&mut self, anchor: usize) {
+            // The parser rejects unknown anchors
+            let Some((node, size)) = self.anchors.get(&anchor) else {
+                return;
+            };
+            self.alias_nodes += size;
+            if self.alias_nodes > MAX_ALIAS_NODES {
+                self.error
+                    .get_or_insert_with(|| "YAML aliases expand to too many nodes".to_string());
+                return;
             }
-
-            let mut out_props = props.clone();
-            out_props.insert("format".into(), "ndjson".into());
-            Ok(ConvertOutput::Single(output, out_props))
+            let node = node.clone();
+            self.push(node, 0);
         }
     }
 
-    /// Convert newline-delimited JSON to JSON array.
-    pub struct NdjsonToJson;
-
-    impl Converter for NdjsonToJson {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "serde.ndjson-to-json",
-                    PropertyPattern::new().eq("format", "ndjson"),
-                    PropertyPattern::new().eq("format", "json"),
-                )
-                .description("Convert newline-delimited JSON to JSON array")
-            })
+    impl EventReceiver for Builder {
+        fn on_event(&mut self, event: Event) {
+            if self.error.is_some() {
+                return;
+            }
+            match event {
+                Event::Scalar(value, style, anchor, tag) => {
+                    self.push(scalar(value, style, tag.as_ref()), anchor)
+                }
+                Event::SequenceStart(anchor, _) => self.open(anchor, false),
+                Event::MappingStart(anchor, _) => self.open(anchor, true),
+                Event::SequenceEnd | Event::MappingEnd => self.close(),
+                Event::Alias(anchor) => self.alias(anchor),
+                _ => {}
+            }
         }
+    }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let text = std::str::from_utf8(input)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
+    fn scalar(value: String, style: TScalarStyle, tag: Option<&Tag>) -> Node {
+        let is_str_tag = tag.is_some_and(|t| t.handle == "tag:yaml.org,2002:" && t.suffix == "str");
+        if style != TScalarStyle::Plain || is_str_tag {
+            return Node::Str(value);
+        }
+        match resolve(value) {
+            Node::Plain(s) if tag.is_some() => Node::Str(s),
+            node => node,
+        }
+    }
 
-            let mut items = Vec::new();
-            for (line_num, line) in text.lines().enumerate() {
-                let line = line.trim();
-                if line.is_empty() {
-                    continue;
+    /// Resolve a plain scalar with the YAML 1.2 core schema.
+    fn resolve(value: String) -> Node {
+        match value.as_str() {
+            "" | "~" | "null" | "Null" | "NULL" => Node::Null,
+            "true" | "True" | "TRUE" => Node::Bool(true),
+            "false" | "False" | "FALSE" => Node::Bool(false),
+            ".inf" | ".Inf" | ".INF" | "+.inf" | "+.Inf" | "+.INF" => Node::Float(f64::INFINITY),
+            "-.inf" | "-.Inf" | "-.INF" => Node::Float(f64::NEG_INFINITY),
+            ".nan" | ".NaN" | ".NAN" => Node::Float(f64::NAN),
+            s => {
+                if let Some(i) = parse_int(s) {
+                    Node::Int(i)
+                } else if let Some(f) = parse_float(s) {
+                    Node::Float(f)
+                } else {
+                    Node::Plain(value)
                 }
-                let item: serde_json::Value = serde_json::from_str(line).map_err(|e| {
-                    ConvertError::InvalidInput(format!(
-                        "Invalid JSON at line {}: {}",
-                        line_num + 1,
-                        e
-                    ))
-                })?;
-                items.push(item);
             }
+        }
+    }
 
-            let array = serde_json::Value::Array(items);
-            let output = serde_json::to_vec_pretty(&array)
-                .map_err(|e| ConvertError::Failed(format!("JSON serialization failed: {}", e)))?;
-
-            let mut out_props = props.clone();
-            out_props.insert("format".into(), "json".into());
-            Ok(ConvertOutput::Single(output, out_props))
+    /// Decimal, `0x` hex or `0o` octal integers.
+    fn parse_int(s: &str) -> Option<i128> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (digits, radix) = if let Some(hex) = unsigned.strip_prefix("0x") {
+            (hex, 16)
+        } else if let Some(octal) = unsigned.strip_prefix("0o") {
+            (octal, 8)
+        } else {
+            (unsigned, 10)
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return None;
         }
+        let magnitude = i128::from_str_radix(digits, radix).ok()?;
+        Some(if negative { -magnitude } else { magnitude })
     }
-}
-
-#[cfg(feature = "ndjson")]
-pub use ndjson_impl::{JsonToNdjson, NdjsonToJson};
-
-// ============================================
-// Compression (gzip, zstd, brotli)
-// ============================================
 
-#[cfg(feature = "gzip")]
-mod gzip_impl {
-    use super::*;
-    use flate2::Compression;
-    use flate2::read::{GzDecoder, GzEncoder};
-    use std::io::Read;
+    fn parse_float(s: &str) -> Option<f64> {
+        // Rust also accepts `inf`, `nan` and `infinity`; YAML doesn't
+        if !s.bytes().any(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    }
 
-    /// Compress bytes with gzip.
-    pub struct GzipCompress;
+    struct Transcoder {
+        null: NullStrategy,
+        bool_aliases: bool,
+        /// Dotted paths of omitted null values.
+        omitted: Vec<String>,
+    }
 
-    impl Converter for GzipCompress {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                // Accept any format - compression is format-agnostic
-                ConverterDecl::simple(
-                    "compression.gzip",
-                    PropertyPattern::new(),
-                    PropertyPattern::new().eq("format", "gzip"),
-                )
-                .description("Compress with gzip")
-            })
+    impl Transcoder {
+        fn table(
+            &mut self,
+            entries: Vec<(Node, Node)>,
+            path: &str,
+        ) -> Result<toml::Table, ConvertError> {
+            let mut table = toml::Table::new();
+            for (key, value) in entries {
+                let key = self.key(key, path)?;
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                if table.contains_key(&key) {
+                    return Err(ConvertError::invalid_input_msg(format!(
+                        "Duplicate key '{}'",
+                        path
+                    )));
+                }
+                if let Some(value) = self.value(value, &path)? {
+                    table.insert(key, value);
+                }
+            }
+            Ok(table)
         }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let mut encoder = GzEncoder::new(input, Compression::default());
-            let mut output = Vec::new();
-            encoder
-                .read_to_end(&mut output)
-                .map_err(|e| ConvertError::Failed(format!("Gzip compression failed: {}", e)))?;
-            let mut out_props = props.clone();
-            // Track inner format for decompression
-            if let Some(inner) = props.get("format") {
-                out_props.insert("inner_format".into(), inner.clone());
+        fn key(&self, key: Node, path: &str) -> Result<String, ConvertError> {
+            match key {
+                Node::Str(s) | Node::Plain(s) => Ok(s),
+                Node::Bool(b) => Ok(b.to_string()),
+                Node::Int(i) => Ok(i.to_string()),
+                Node::Float(f) => Ok(f.to_string()),
+                _ => Err(ConvertError::invalid_input_msg(format!(
+                    "Unsupported map key under '{}': TOML keys must be scalars",
+                    if path.is_empty() { "<root>" } else { path }
+                ))),
             }
-            out_props.insert("format".into(), "gzip".into());
-            Ok(ConvertOutput::Single(output, out_props))
         }
-    }
 
-    /// Decompress gzip bytes.
-    pub struct GzipDecompress;
+        /// Transcode a value; `None` means it was omitted.
+        fn value(&mut self, value: Node, path: &str) -> Result<Option<TomlValue>, ConvertError> {
+            let value = match value {
+                Node::Null => {
+                    let value = self.null.apply(path)?;
+                    if value.is_none() {
+                        self.omitted.push(path.to_string());
+                    }
+                    return Ok(value);
+                }
+                Node::Bool(b) => TomlValue::Boolean(b),
+                Node::Int(i) => TomlValue::Integer(i64::try_from(i).map_err(|_| {
+                    ConvertError::invalid_input_msg(format!(
+                        "Integer {} at '{}' is out of TOML's 64-bit signed range",
+                        i, path
+                    ))
+                })?),
+                Node::Float(f) => TomlValue::Float(f),
+                Node::Str(s) => TomlValue::String(s),
+                Node::Plain(s) => match (parse_timestamp(&s), bool_alias(&s)) {
+                    (Some(datetime), _) => TomlValue::Datetime(datetime),
+                    (None, Some(b)) if self.bool_aliases => TomlValue::Boolean(b),
+                    (None, _) => TomlValue::String(s),
+                },
+                Node::Seq(items) => {
+                    let mut array = Vec::with_capacity(items.len());
+                    for (i, item) in items.into_iter().enumerate() {
+                        if let Some(item) = self.value(item, &format!("{}[{}]", path, i))? {
+                            array.push(item);
+                        }
+                    }
+                    TomlValue::Array(array)
+                }
+                Node::Map(entries) => TomlValue::Table(self.table(entries, path)?),
+            };
+            Ok(Some(value))
+        }
+    }
 
-    impl Converter for GzipDecompress {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "compression.gzip-to-raw",
-                    PropertyPattern::new().eq("format", "gzip"),
-                    PropertyPattern::new().eq("format", "raw"),
-                )
-                .description("Decompress gzip")
-            })
+    /// Parse a YAML 1.1 boolean spelling (`yes`, `Off`, ...).
+    fn bool_alias(s: &str) -> Option<bool> {
+        match s {
+            "yes" | "Yes" | "YES" | "on" | "On" | "ON" => Some(true),
+            "no" | "No" | "NO" | "off" | "Off" | "OFF" => Some(false),
+            _ => None,
         }
+    }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let mut decoder = GzDecoder::new(input);
-            let mut output = Vec::new();
-            decoder.read_to_end(&mut output).map_err(|e| {
-                ConvertError::InvalidInput(format!("Gzip decompression failed: {}", e))
-            })?;
-            let mut out_props = props.clone();
-            out_props.insert("format".into(), "raw".into());
-            Ok(ConvertOutput::Single(output, out_props))
+    /// Parse a YAML timestamp (date or date-time) as a TOML datetime.
+    ///
+    /// Only strings starting with a `YYYY-MM-DD` date qualify, so bare
+    /// times like `12:30:00` stay strings.
+    fn parse_timestamp(s: &str) -> Option<Datetime> {
+        let bytes = s.as_bytes();
+        let is_date = bytes.len() >= 10
+            && bytes[..4].iter().all(u8::is_ascii_digit)
+            && bytes[4] == b'-'
+            && bytes[7] == b'-';
+        if !is_date {
+            return None;
         }
+        s.parse().ok()
     }
 }
 
-#[cfg(feature = "gzip")]
-pub use gzip_impl::{GzipCompress, GzipDecompress};
+#[cfg(all(feature = "yaml", feature = "toml"))]
+pub use yaml_toml_impl::YamlToToml;
 
-#[cfg(feature = "zstd")]
-mod zstd_impl {
+// ============================================
+// EDN (Extensible Data Notation)
+// ============================================
+
+#[cfg(feature = "edn")]
+mod edn_impl {
     use super::*;
+    use edn_rs::Edn;
+    use std::str::FromStr;
 
-    /// Compress bytes with zstd.
-    pub struct ZstdCompress;
+    const KEYWORD_PREFIX: &str = "#keyword ";
+    const SYMBOL_PREFIX: &str = "#symbol ";
 
-    impl Converter for ZstdCompress {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                // Accept any format - compression is format-agnostic
-                ConverterDecl::simple(
-                    "compression.zstd",
-                    PropertyPattern::new(),
-                    PropertyPattern::new().eq("format", "zstd"),
-                )
-                .description("Compress with zstd")
-            })
+    /// Parse EDN into a JSON value.
+    ///
+    /// Lists, vectors and sets become arrays. Keywords and symbols become
+    /// `"#keyword :name"` / `"#symbol name"` strings (map keys included),
+    /// tagged literals become `"#tag value"` strings, and rationals and
+    /// characters become plain strings.
+    pub fn from_str(text: &str) -> Result<serde_json::Value, ConvertError> {
+        let edn = Edn::from_str(text)
+            .map_err(|e| ConvertError::invalid_input_msg(format!("Invalid EDN: {}", e)))?;
+        Ok(edn_to_json(edn))
+    }
+
+    fn edn_to_json(edn: Edn) -> serde_json::Value {
+        use serde_json::Value;
+        match edn {
+            Edn::Vector(v) => v.to_vec().into_iter().map(edn_to_json).collect(),
+            Edn::List(l) => l.to_vec().into_iter().map(edn_to_json).collect(),
+            Edn::Set(s) => s.to_set().into_iter().map(edn_to_json).collect(),
+            Edn::Map(m) => Value::Object(
+                m.to_map()
+                    .into_iter()
+                    .map(|(key, value)| (map_key(key), edn_to_json(value)))
+                    .collect(),
+            ),
+            Edn::Key(k) => Value::String(format!("{}{}", KEYWORD_PREFIX, k)),
+            Edn::Symbol(s) => Value::String(format!("{}{}", SYMBOL_PREFIX, s)),
+            Edn::Str(s) => Value::String(s),
+            Edn::Int(i) => i.into(),
+            Edn::UInt(u) => u.into(),
+            Edn::Double(_) => edn
+                .to_float()
+                .and_then(serde_json::Number::from_f64)
+                .map_or(Value::Null, Value::Number),
+            Edn::Rational(r) => Value::String(r),
+            Edn::Char(c) => Value::String(c.to_string()),
+            Edn::Bool(b) => Value::Bool(b),
+            Edn::Tagged(..) => Value::String(edn.to_string()),
+            // Nil, Empty, and any variants added upstream
+            _ => Value::Null,
         }
+    }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let output = zstd::encode_all(input, 0)
-                .map_err(|e| ConvertError::Failed(format!("Zstd compression failed: {}", e)))?;
-            let mut out_props = props.clone();
-            if let Some(inner) = props.get("format") {
-                out_props.insert("inner_format".into(), inner.clone());
-            }
-            out_props.insert("format".into(), "zstd".into());
-            Ok(ConvertOutput::Single(output, out_props))
+    /// edn-rs stores map keys as printed EDN; turn them into JSON keys.
+    fn map_key(key: String) -> String {
+        match Edn::from_str(&key) {
+            Ok(Edn::Str(s)) => s,
+            Ok(edn @ (Edn::Key(_) | Edn::Symbol(_))) => match edn_to_json(edn) {
+                serde_json::Value::String(s) => s,
+                _ => key,
+            },
+            _ => key,
         }
     }
 
-    /// Decompress zstd bytes.
-    pub struct ZstdDecompress;
+    /// Write a JSON value as EDN, one map entry or nested collection per line.
+    pub fn to_string(value: &serde_json::Value) -> String {
+        let mut out = String::new();
+        write_value(&mut out, value, 0);
+        out.push('\n');
+        out
+    }
 
-    impl Converter for ZstdDecompress {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "compression.zstd-to-raw",
-                    PropertyPattern::new().eq("format", "zstd"),
-                    PropertyPattern::new().eq("format", "raw"),
-                )
-                .description("Decompress zstd")
-            })
+    fn write_value(out: &mut String, value: &serde_json::Value, indent: usize) {
+        use serde_json::Value;
+        match value {
+            Value::Null => out.push_str("nil"),
+            Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Value::Number(n) => out.push_str(&n.to_string()),
+            Value::String(s) => write_string(out, s),
+            Value::Array(items) => {
+                // Keep flat vectors on one line
+                let nested = items.iter().any(|v| v.is_array() || v.is_object());
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        if nested {
+                            out.push('\n');
+                            out.push_str(&" ".repeat(indent + 1));
+                        } else {
+                            out.push(' ');
+                        }
+                    }
+                    write_value(out, item, indent + 1);
+                }
+                out.push(']');
+            }
+            Value::Object(map) => {
+                out.push('{');
+                for (i, (key, item)) in map.iter().enumerate() {
+                    if i > 0 {
+                        out.push('\n');
+                        out.push_str(&" ".repeat(indent + 1));
+                    }
+                    let start = out.len();
+                    write_string(out, key);
+                    out.push(' ');
+                    let key_width = out.len() - start;
+                    write_value(out, item, indent + 1 + key_width);
+                }
+                out.push('}');
+            }
         }
+    }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let output = zstd::decode_all(input).map_err(|e| {
-                ConvertError::InvalidInput(format!("Zstd decompression failed: {}", e))
-            })?;
-            let mut out_props = props.clone();
-            out_props.insert("format".into(), "raw".into());
-            Ok(ConvertOutput::Single(output, out_props))
+    /// Strings carrying a keyword or symbol marker are written bare.
+    fn write_string(out: &mut String, s: &str) {
+        if let Some(name) = s
+            .strip_prefix(KEYWORD_PREFIX)
+            .or_else(|| s.strip_prefix(SYMBOL_PREFIX))
+        {
+            out.push_str(name);
+            return;
+        }
+
+        out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c => out.push(c),
+            }
         }
+        out.push('"');
     }
 }
 
-#[cfg(feature = "zstd")]
-pub use zstd_impl::{ZstdCompress, ZstdDecompress};
+// ============================================
+// KDL (KDL Document Language)
+// ============================================
 
-#[cfg(feature = "brotli")]
-mod brotli_impl {
+#[cfg(feature = "kdl")]
+mod kdl_impl {
     use super::*;
-    use std::io::Read;
+    use kdl::{KdlDocument, KdlEntry, KdlNode, KdlValue};
+    use serde_json::{Map, Value};
 
-    /// Compress bytes with brotli.
-    pub struct BrotliCompress;
+    /// Key holding a node's name in its JSON form.
+    const NODE_KEY: &str = "_node";
+    /// Key holding a node's arguments in its JSON form.
+    const ARGS_KEY: &str = "_args";
+    /// Key holding a node's children in its JSON form.
+    const CHILDREN_KEY: &str = "_children";
 
-    impl Converter for BrotliCompress {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                // Accept any format - compression is format-agnostic
-                ConverterDecl::simple(
-                    "compression.brotli",
-                    PropertyPattern::new(),
-                    PropertyPattern::new().eq("format", "brotli"),
-                )
-                .description("Compress with brotli")
-            })
-        }
+    /// Parse a KDL document into a JSON array of nodes.
+    ///
+    /// Each node becomes `{"_node": name, "_args": [...], prop: value,
+    /// "_children": [...]}`, with `_args` and `_children` left out when
+    /// empty. Type annotations are dropped. KDL 2 is tried first, then
+    /// KDL 1.
+    pub fn from_str(text: &str) -> Result<Value, ConvertError> {
+        let doc =
+            KdlDocument::parse(text).map_err(|e| ConvertError::invalid_input("Invalid KDL", e))?;
+        Ok(document_to_json(&doc))
+    }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let mut output = Vec::new();
-            let mut compressor = brotli::CompressorReader::new(input, 4096, 6, 22);
-            compressor
-                .read_to_end(&mut output)
-                .map_err(|e| ConvertError::Failed(format!("Brotli compression failed: {}", e)))?;
-            let mut out_props = props.clone();
-            if let Some(inner) = props.get("format") {
-                out_props.insert("inner_format".into(), inner.clone());
+    /// Write a JSON value as KDL 2, indented by four spaces per level.
+    ///
+    /// An array of `{"_node": ...}` objects (the output of [`from_str`])
+    /// maps back to the same nodes. Anything else is mapped generically:
+    /// object keys become nodes, scalars and arrays of scalars become
+    /// arguments, and objects and other arrays become children (array
+    /// items as `-` nodes).
+    pub fn to_string(value: &Value) -> Result<String, ConvertError> {
+        let nodes = match value {
+            Value::Array(items) if items.iter().all(is_node_object) => items
+                .iter()
+                .map(node_from_json)
+                .collect::<Result<Vec<_>, _>>()?,
+            Value::Object(map) => map
+                .iter()
+                .map(|(key, value)| generic_node(key, value))
+                .collect::<Result<Vec<_>, _>>()?,
+            other => vec![generic_node("-", other)?],
+        };
+
+        let mut doc = document(nodes);
+        doc.autoformat();
+        Ok(doc.to_string())
+    }
+
+    fn document(nodes: Vec<KdlNode>) -> KdlDocument {
+        let mut doc = KdlDocument::new();
+        doc.nodes_mut().extend(nodes);
+        doc
+    }
+
+    fn document_to_json(doc: &KdlDocument) -> Value {
+        Value::Array(doc.nodes().iter().map(node_to_json).collect())
+    }
+
+    fn node_to_json(node: &KdlNode) -> Value {
+        let mut map = Map::new();
+        map.insert(NODE_KEY.into(), node.name().value().into());
+        let args: Vec<Value> = node
+            .entries()
+            .iter()
+            .filter(|entry| entry.name().is_none())
+            .map(|entry| value_to_json(entry.value()))
+            .collect();
+        if !args.is_empty() {
+            map.insert(ARGS_KEY.into(), Value::Array(args));
+        }
+        for entry in node.entries() {
+            if let Some(key) = entry.name() {
+                map.insert(key.value().to_string(), value_to_json(entry.value()));
             }
-            out_props.insert("format".into(), "brotli".into());
-            Ok(ConvertOutput::Single(output, out_props))
         }
+        if let Some(children) = node.children() {
+            map.insert(CHILDREN_KEY.into(), document_to_json(children));
+        }
+        Value::Object(map)
     }
 
-    /// Decompress brotli bytes.
-    pub struct BrotliDecompress;
+    fn value_to_json(value: &KdlValue) -> Value {
+        let float = |f: f64| serde_json::Number::from_f64(f).map_or(Value::Null, Value::Number);
+        match value {
+            KdlValue::String(s) => Value::String(s.clone()),
+            KdlValue::Integer(n) => i64::try_from(*n)
+                .map(Value::from)
+                .or_else(|_| u64::try_from(*n).map(Value::from))
+                .unwrap_or_else(|_| float(*n as f64)),
+            KdlValue::Float(f) => float(*f),
+            KdlValue::Bool(b) => Value::Bool(*b),
+            KdlValue::Null => Value::Null,
+        }
+    }
 
-    impl Converter for BrotliDecompress {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "compression.brotli-to-raw",
-                    PropertyPattern::new().eq("format", "brotli"),
-                    PropertyPattern::new().eq("format", "raw"),
-                )
-                .description("Decompress brotli")
-            })
+    /// Convert a JSON scalar to a KDL value.
+    fn value_from_json(value: &Value) -> KdlValue {
+        match value {
+            Value::String(s) => KdlValue::String(s.clone()),
+            Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+                (Some(i), _) => KdlValue::Integer(i.into()),
+                (None, Some(u)) => KdlValue::Integer(u.into()),
+                _ => KdlValue::Float(n.as_f64().unwrap_or_default()),
+            },
+            Value::Bool(b) => KdlValue::Bool(*b),
+            // Only scalars reach here
+            _ => KdlValue::Null,
         }
+    }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let mut output = Vec::new();
-            let mut decompressor = brotli::Decompressor::new(input, 4096);
-            decompressor.read_to_end(&mut output).map_err(|e| {
-                ConvertError::InvalidInput(format!("Brotli decompression failed: {}", e))
+    fn is_node_object(value: &Value) -> bool {
+        value
+            .as_object()
+            .is_some_and(|map| map.get(NODE_KEY).is_some_and(Value::is_string))
+    }
+
+    fn is_scalar(value: &Value) -> bool {
+        !matches!(value, Value::Array(_) | Value::Object(_))
+    }
+
+    fn node_from_json(value: &Value) -> Result<KdlNode, ConvertError> {
+        let map = value
+            .as_object()
+            .ok_or_else(|| ConvertError::invalid_input_msg("KDL node must be an object"))?;
+        let name = map
+            .get(NODE_KEY)
+            .and_then(Value::as_str)
+            .ok_or_else(|| ConvertError::invalid_input_msg("KDL node is missing '_node'"))?;
+
+        let mut node = KdlNode::new(name);
+        // Arguments first, so they read naturally before properties
+        if let Some(args) = map.get(ARGS_KEY) {
+            let args = args.as_array().filter(|a| a.iter().all(is_scalar));
+            let args = args.ok_or_else(|| {
+                ConvertError::invalid_input_msg(format!(
+                    "'_args' of KDL node '{}' must be an array of scalars",
+                    name
+                ))
             })?;
-            let mut out_props = props.clone();
-            out_props.insert("format".into(), "raw".into());
-            Ok(ConvertOutput::Single(output, out_props))
+            for arg in args {
+                node.entries_mut().push(KdlEntry::new(value_from_json(arg)));
+            }
         }
+        for (key, value) in map {
+            match key.as_str() {
+                NODE_KEY | ARGS_KEY => {}
+                CHILDREN_KEY => {
+                    let children = value.as_array().ok_or_else(|| {
+                        ConvertError::invalid_input_msg(format!(
+                            "'_children' of KDL node '{}' must be an array",
+                            name
+                        ))
+                    })?;
+                    let children = children
+                        .iter()
+                        .map(node_from_json)
+                        .collect::<Result<_, _>>()?;
+                    *node.children_mut() = Some(document(children));
+                }
+                _ if is_scalar(value) => node
+                    .entries_mut()
+                    .push(KdlEntry::new_prop(key.as_str(), value_from_json(value))),
+                _ => {
+                    return Err(ConvertError::invalid_input_msg(format!(
+                        "Property '{}' of KDL node '{}' must be a scalar",
+                        key, name
+                    )));
+                }
+            }
+        }
+        Ok(node)
     }
-}
 
-#[cfg(feature = "brotli")]
-pub use brotli_impl::{BrotliCompress, BrotliDecompress};
+    fn generic_node(name: &str, value: &Value) -> Result<KdlNode, ConvertError> {
+        let mut node = KdlNode::new(name);
+        match value {
+            Value::Object(map) => {
+                let children = map
+                    .iter()
+                    .map(|(key, value)| generic_node(key, value))
+                    .collect::<Result<_, _>>()?;
+                *node.children_mut() = Some(document(children));
+            }
+            Value::Array(items) if items.iter().all(is_scalar) => {
+                for item in items {
+                    node.entries_mut()
+                        .push(KdlEntry::new(value_from_json(item)));
+                }
+            }
+            Value::Array(items) => {
+                let children = items
+                    .iter()
+                    .map(|item| generic_node("-", item))
+                    .collect::<Result<_, _>>()?;
+                *node.children_mut() = Some(document(children));
+            }
+            scalar => node
+                .entries_mut()
+                .push(KdlEntry::new(value_from_json(scalar))),
+        }
+        Ok(node)
+    }
+}
 
 // ============================================
-// INI config format
+// XML options
 // ============================================
 
-#[cfg(feature = "ini")]
-mod ini_impl {
+#[cfg(feature = "xml")]
+mod xml_impl {
     use super::*;
-    use ini::Ini;
-
-    /// Convert INI to JSON.
-    pub struct IniToJson;
-
-    impl Converter for IniToJson {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "config.ini-to-json",
-                    PropertyPattern::new().eq("format", "ini"),
-                    PropertyPattern::new().eq("format", "json"),
-                )
-                .description("Convert INI to JSON")
-            })
-        }
+    use quick_xml::Reader;
+    use quick_xml::encoding::Decoder;
+    use quick_xml::events::{BytesStart, Event};
+    use quick_xml::name::QName;
+    use serde_json::{Map, Value};
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let text = std::str::from_utf8(input)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
-            let ini = Ini::load_from_str(text)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid INI: {}", e)))?;
+    /// Root element name used when none is given.
+    pub const DEFAULT_ROOT: &str = "root";
 
-            // Convert to JSON object
-            let mut root = serde_json::Map::new();
-            for (section, properties) in ini.iter() {
-                let section_name = section.unwrap_or("_global");
-                let mut section_obj = serde_json::Map::new();
-                for (key, value) in properties.iter() {
-                    section_obj.insert(
-                        key.to_string(),
-                        serde_json::Value::String(value.to_string()),
-                    );
-                }
-                root.insert(
-                    section_name.to_string(),
-                    serde_json::Value::Object(section_obj),
-                );
+    /// Parse XML into a JSON value, returning it with the root element name.
+    ///
+    /// Attributes become `@name` keys, repeated child elements become arrays
+    /// and text-only elements become strings; text next to attributes or
+    /// children is kept under `$text`.
+    ///
+    /// Namespace prefixes and `xmlns` declarations are dropped unless
+    /// `preserve_namespaces` is set, in which case names stay qualified
+    /// (`soap:Body`) and declarations are kept as `@xmlns` / `@xmlns:prefix`
+    /// keys so [`to_string`] can restore them.
+    pub fn from_str(
+        text: &str,
+        preserve_namespaces: bool,
+    ) -> Result<(Value, String), ConvertError> {
+        let mut reader = Reader::from_str(text);
+        let mut stack: Vec<Frame> = Vec::new();
+
+        loop {
+            let closed = match reader.read_event().map_err(invalid)? {
+                Event::Start(e) => {
+                    stack.push(Frame::open(&e, reader.decoder(), preserve_namespaces)?);
+                    None
+                }
+                Event::Empty(e) => {
+                    Some(Frame::open(&e, reader.decoder(), preserve_namespaces)?.close())
+                }
+                Event::End(_) => stack.pop().map(Frame::close),
+                Event::Text(e) => {
+                    if let Some(frame) = stack.last_mut() {
+                        frame.text.push_str(&e.decode().map_err(invalid)?);
+                    }
+                    None
+                }
+                Event::CData(e) => {
+                    if let Some(frame) = stack.last_mut() {
+                        frame.text.push_str(&e.decode().map_err(invalid)?);
+                    }
+                    None
+                }
+                Event::GeneralRef(e) => {
+                    if let Some(frame) = stack.last_mut() {
+                        match e.resolve_char_ref().map_err(invalid)? {
+                            Some(ch) => frame.text.push(ch),
+                            None => {
+                                let name = e.decode().map_err(invalid)?;
+                                let resolved = quick_xml::escape::resolve_predefined_entity(&name)
+                                    .ok_or_else(|| invalid(format!("unknown entity &{};", name)))?;
+                                frame.text.push_str(resolved);
+                            }
+                        }
+                    }
+                    None
+                }
+                Event::Eof => return Err(invalid("no root element")),
+                _ => None,
+            };
+
+            if let Some((name, value)) = closed {
+                match stack.last_mut() {
+                    Some(parent) => add_child(&mut parent.map, name, value),
+                    None => return Ok((value, name)),
+                }
             }
+        }
+    }
 
-            let output = serde_json::to_vec_pretty(&root)
-                .map_err(|e| ConvertError::Failed(format!("JSON serialization failed: {}", e)))?;
+    /// Serialize a JSON value as XML under the given root element.
+    ///
+    /// `@name` keys become attributes and `$text` becomes text content.
+    pub fn to_string(value: &Value, root: &str) -> Result<String, ConvertError> {
+        let mut out = String::new();
+        let serializer = quick_xml::se::Serializer::with_root(&mut out, Some(root))
+            .map_err(|e| ConvertError::invalid_input("Invalid XML root element", e))?;
+        serde::Serialize::serialize(value, serializer)
+            .map_err(|e| ConvertError::failed("XML serialization failed", e))?;
+        Ok(out)
+    }
 
-            let mut out_props = props.clone();
-            out_props.insert("format".into(), "json".into());
-            Ok(ConvertOutput::Single(output, out_props))
+    fn invalid(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> ConvertError {
+        ConvertError::invalid_input("Invalid XML", e)
+    }
+
+    fn name_str(name: QName, qualified: bool) -> String {
+        if qualified {
+            String::from_utf8_lossy(name.as_ref()).into_owned()
+        } else {
+            String::from_utf8_lossy(name.local_name().as_ref()).into_owned()
         }
     }
 
-    /// Convert JSON to INI.
-    pub struct JsonToIni;
+    /// An element being built: its name, attributes and children, and text.
+    struct Frame {
+        name: String,
+        map: Map<String, Value>,
+        text: String,
+    }
 
-    impl Converter for JsonToIni {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "config.json-to-ini",
-                    PropertyPattern::new().eq("format", "json"),
-                    PropertyPattern::new().eq("format", "ini"),
-                )
-                .description("Convert JSON to INI")
+    impl Frame {
+        fn open(
+            start: &BytesStart,
+            decoder: Decoder,
+            preserve_namespaces: bool,
+        ) -> Result<Self, ConvertError> {
+            let mut map = Map::new();
+            for attr in start.attributes() {
+                let attr = attr.map_err(invalid)?;
+                if !preserve_namespaces && attr.key.as_namespace_binding().is_some() {
+                    continue;
+                }
+                let key = name_str(attr.key, preserve_namespaces);
+                let value = attr.decode_and_unescape_value(decoder).map_err(invalid)?;
+                map.insert(format!("@{}", key), Value::String(value.into_owned()));
+            }
+            Ok(Self {
+                name: name_str(start.name(), preserve_namespaces),
+                map,
+                text: String::new(),
             })
         }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let value: serde_json::Value = serde_json::from_slice(input)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid JSON: {}", e)))?;
+        /// Text-only elements become strings; anything else an object.
+        fn close(mut self) -> (String, Value) {
+            let text = self.text.trim();
+            if self.map.is_empty() {
+                return (self.name, Value::String(text.to_string()));
+            }
+            if !text.is_empty() {
+                self.map
+                    .insert("$text".into(), Value::String(text.to_string()));
+            }
+            (self.name, Value::Object(self.map))
+        }
+    }
 
-            let obj = value
-                .as_object()
-                .ok_or_else(|| ConvertError::InvalidInput("JSON must be an object".into()))?;
+    /// Add a child, turning repeated names into arrays.
+    fn add_child(parent: &mut Map<String, Value>, name: String, value: Value) {
+        match parent.get_mut(&name) {
+            Some(Value::Array(items)) => items.push(value),
+            Some(existing) => {
+                let first = existing.take();
+                *existing = Value::Array(vec![first, value]);
+            }
+            None => {
+                parent.insert(name, value);
+            }
+        }
+    }
+}
 
-            let mut ini = Ini::new();
-            for (section, section_value) in obj {
-                let section_name = if section == "_global" {
-                    None
+// ============================================
+// S-expressions
+// ============================================
+
+#[cfg(feature = "lexpr")]
+mod lexpr_impl {
+    use super::*;
+    use serde_json::{Map, Number, Value};
+    use serde_lexpr::Value as Sexp;
+
+    /// Parse an S-expression into a JSON value.
+    ///
+    /// Vectors are arrays and `#nil` is null. A proper list is an object if
+    /// every element is a `(key . value)` pair with a string or symbol key,
+    /// and an array otherwise; `()` is an empty object. Symbols, keywords and
+    /// characters become strings.
+    pub fn from_str(text: &str) -> Result<Value, ConvertError> {
+        let sexp = serde_lexpr::parse::from_str(text)
+            .map_err(|e| ConvertError::invalid_input("Invalid S-expression", e))?;
+        to_json(&sexp)
+    }
+
+    /// Write a JSON value as an S-expression.
+    ///
+    /// Objects become association lists of `("key" . value)` pairs and
+    /// arrays vectors, so the two can't be confused when read back.
+    pub fn to_string(value: &Value) -> Result<String, ConvertError> {
+        serde_lexpr::print::to_string(&to_sexp(value))
+            .map_err(|e| ConvertError::failed("S-expression serialization failed", e))
+    }
+
+    fn to_json(sexp: &Sexp) -> Result<Value, ConvertError> {
+        Ok(match sexp {
+            Sexp::Nil => Value::Null,
+            Sexp::Null => Value::Object(Map::new()),
+            Sexp::Bool(b) => Value::Bool(*b),
+            Sexp::Number(n) => {
+                if let Some(u) = n.as_u64() {
+                    Value::from(u)
+                } else if let Some(i) = n.as_i64() {
+                    Value::from(i)
                 } else {
-                    Some(section.as_str())
+                    n.as_f64()
+                        .and_then(Number::from_f64)
+                        .map_or(Value::Null, Value::Number)
+                }
+            }
+            Sexp::Char(c) => Value::String(c.to_string()),
+            Sexp::String(s) | Sexp::Symbol(s) | Sexp::Keyword(s) => Value::String(s.to_string()),
+            Sexp::Bytes(bytes) => bytes.iter().map(|&b| Value::from(b)).collect(),
+            Sexp::Vector(items) => {
+                Value::Array(items.iter().map(to_json).collect::<Result<_, _>>()?)
+            }
+            Sexp::Cons(cons) => {
+                let Some(items) = sexp.to_vec() else {
+                    return Err(ConvertError::invalid_input_msg(format!(
+                        "Dotted list has no JSON equivalent: {}",
+                        cons.car()
+                    )));
                 };
-                if let Some(section_obj) = section_value.as_object() {
-                    for (key, val) in section_obj {
-                        let str_val = match val {
-                            serde_json::Value::String(s) => s.clone(),
-                            other => other.to_string(),
-                        };
-                        ini.with_section(section_name).set(key, str_val);
-                    }
+                let entries: Option<Vec<(&str, &Sexp)>> = items
+                    .iter()
+                    .map(|item| match item {
+                        Sexp::Cons(pair) => pair.car().as_name().map(|key| (key, pair.cdr())),
+                        _ => None,
+                    })
+                    .collect();
+                match entries {
+                    Some(entries) => Value::Object(
+                        entries
+                            .into_iter()
+                            .map(|(key, value)| Ok((key.to_string(), to_json(value)?)))
+                            .collect::<Result<_, ConvertError>>()?,
+                    ),
+                    None => Value::Array(items.iter().map(to_json).collect::<Result<_, _>>()?),
                 }
             }
+        })
+    }
 
-            let mut output = Vec::new();
-            ini.write_to(&mut output)
-                .map_err(|e| ConvertError::Failed(format!("INI serialization failed: {}", e)))?;
-
-            let mut out_props = props.clone();
-            out_props.insert("format".into(), "ini".into());
-            Ok(ConvertOutput::Single(output, out_props))
+    fn to_sexp(value: &Value) -> Sexp {
+        match value {
+            Value::Null => Sexp::Nil,
+            Value::Bool(b) => Sexp::Bool(*b),
+            Value::Number(n) => {
+                if let Some(u) = n.as_u64() {
+                    Sexp::from(u)
+                } else if let Some(i) = n.as_i64() {
+                    Sexp::from(i)
+                } else {
+                    Sexp::from(n.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            Value::String(s) => Sexp::string(s.as_str()),
+            Value::Array(items) => Sexp::vector(items.iter().map(to_sexp)),
+            Value::Object(map) => Sexp::list(
+                map.iter()
+                    .map(|(key, value)| Sexp::cons(Sexp::string(key.as_str()), to_sexp(value))),
+            ),
         }
     }
 }
 
-#[cfg(feature = "ini")]
-pub use ini_impl::{IniToJson, JsonToIni};
-
 // ============================================
-// Character encoding conversion
+// Bencode
 // ============================================
 
-#[cfg(feature = "charsets")]
-mod charsets_impl {
+#[cfg(feature = "bencode")]
+mod bencode_impl {
     use super::*;
+    use serde_bencode::value::Value as Bencode;
+    use serde_json::Value;
 
-    /// Convert from a character encoding to UTF-8.
-    pub struct CharsetToUtf8;
+    /// Parse Bencode into a JSON value.
+    ///
+    /// Bencode strings are raw bytes: valid UTF-8 becomes a string, anything
+    /// else (e.g. torrent piece hashes) an array of byte values.
+    pub fn from_slice(data: &[u8]) -> Result<Value, ConvertError> {
+        let value: Bencode = serde_bencode::from_bytes(data)
+            .map_err(|e| ConvertError::invalid_input("Invalid Bencode", e))?;
+        Ok(to_json(value))
+    }
 
-    impl Converter for CharsetToUtf8 {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "encoding.charset-to-utf8",
-                    PropertyPattern::new().exists("charset"),
-                    PropertyPattern::new().eq("charset", "utf-8"),
-                )
-                .description("Convert character encoding to UTF-8")
-            })
+    fn to_json(value: Bencode) -> Value {
+        match value {
+            Bencode::Int(n) => n.into(),
+            Bencode::Bytes(bytes) => match String::from_utf8(bytes) {
+                Ok(s) => Value::String(s),
+                Err(e) => e.into_bytes().into_iter().map(Value::from).collect(),
+            },
+            Bencode::List(items) => items.into_iter().map(to_json).collect(),
+            Bencode::Dict(entries) => Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (String::from_utf8_lossy(&k).into_owned(), to_json(v)))
+                    .collect(),
+            ),
         }
+    }
+}
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let charset = props
-                .get("charset")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| ConvertError::InvalidInput("Missing 'charset' property".into()))?;
+// ============================================
+// MessagePack options
+// ============================================
 
-            let encoding =
-                encoding_rs::Encoding::for_label(charset.as_bytes()).ok_or_else(|| {
-                    ConvertError::InvalidInput(format!("Unknown charset: {}", charset))
-                })?;
+#[cfg(feature = "msgpack")]
+mod msgpack_impl {
+    use super::*;
+    use base64::prelude::*;
+    use serde::de::{DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+    use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
 
-            let (decoded, _, had_errors) = encoding.decode(input);
-            if had_errors {
-                return Err(ConvertError::InvalidInput(format!(
-                    "Invalid {} sequence in input",
-                    charset
-                )));
+    /// Read MessagePack into a JSON value.
+    ///
+    /// Unlike going through `serde_json::Value` directly, this accepts
+    /// non-string map keys (stringified) and can base64-encode binary blobs.
+    pub fn from_slice(
+        data: &[u8],
+        binary_as_base64: bool,
+    ) -> Result<serde_json::Value, ConvertError> {
+        let mut de = rmp_serde::Deserializer::new(data);
+        JsonValueSeed { binary_as_base64 }
+            .deserialize(&mut de)
+            .map_err(|e| ConvertError::invalid_input("MessagePack parse error", e))
+    }
+
+    /// Write a JSON value as MessagePack, optionally turning keys like `"3"`
+    /// into integer keys.
+    pub fn to_vec(value: &serde_json::Value, integer_keys: bool) -> Result<Vec<u8>, ConvertError> {
+        rmp_serde::to_vec(&MsgpackValue {
+            value,
+            integer_keys,
+        })
+        .map_err(|e| ConvertError::failed("MessagePack serialization failed", e))
+    }
+
+    /// Key as a non-negative integer, if it is one in canonical decimal form.
+    pub(super) fn integer_key(key: &str) -> Option<u64> {
+        key.parse::<u64>().ok().filter(|n| n.to_string() == key)
+    }
+
+    struct MsgpackValue<'a> {
+        value: &'a serde_json::Value,
+        integer_keys: bool,
+    }
+
+    impl Serialize for MsgpackValue<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let wrap = |value| MsgpackValue {
+                value,
+                integer_keys: self.integer_keys,
+            };
+            match self.value {
+                serde_json::Value::Object(map) => {
+                    let mut out = serializer.serialize_map(Some(map.len()))?;
+                    for (key, value) in map {
+                        match integer_key(key).filter(|_| self.integer_keys) {
+                            Some(n) => out.serialize_entry(&n, &wrap(value))?,
+                            None => out.serialize_entry(key, &wrap(value))?,
+                        }
+                    }
+                    out.end()
+                }
+                serde_json::Value::Array(items) => {
+                    let mut out = serializer.serialize_seq(Some(items.len()))?;
+                    for item in items {
+                        out.serialize_element(&wrap(item))?;
+                    }
+                    out.end()
+                }
+                other => other.serialize(serializer),
             }
+        }
+    }
 
-            let mut out_props = props.clone();
-            out_props.insert("charset".into(), "utf-8".into());
-            Ok(ConvertOutput::Single(
-                decoded.into_owned().into_bytes(),
-                out_props,
-            ))
+    #[derive(Clone, Copy)]
+    struct JsonValueSeed {
+        binary_as_base64: bool,
+    }
+
+    impl<'de> DeserializeSeed<'de> for JsonValueSeed {
+        type Value = serde_json::Value;
+
+        fn deserialize<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_any(self)
         }
     }
 
-    /// Convert from UTF-8 to another character encoding.
-    pub struct Utf8ToCharset;
+    impl<'de> Visitor<'de> for JsonValueSeed {
+        type Value = serde_json::Value;
 
-    impl Converter for Utf8ToCharset {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "encoding.utf8-to-charset",
-                    PropertyPattern::new().eq("charset", "utf-8"),
-                    PropertyPattern::new().exists("target_charset"),
-                )
-                .description("Convert UTF-8 to another character encoding")
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a MessagePack value")
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+            Ok(v.into())
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+            Ok(v.into())
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(v.into())
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+            Ok(serde_json::Number::from_f64(v).map_or(serde_json::Value::Null, Into::into))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(v.into())
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(if self.binary_as_base64 {
+                BASE64_STANDARD.encode(v).into()
+            } else {
+                v.iter().map(|&b| serde_json::Value::from(b)).collect()
             })
         }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let target = props
-                .get("target_charset")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| {
-                    ConvertError::InvalidInput("Missing 'target_charset' property".into())
-                })?;
+        fn visit_none<E>(self) -> Result<Self::Value, E> {
+            Ok(serde_json::Value::Null)
+        }
 
-            let text = std::str::from_utf8(input)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
+        fn visit_unit<E>(self) -> Result<Self::Value, E> {
+            Ok(serde_json::Value::Null)
+        }
 
-            let encoding =
-                encoding_rs::Encoding::for_label(target.as_bytes()).ok_or_else(|| {
-                    ConvertError::InvalidInput(format!("Unknown charset: {}", target))
-                })?;
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            self.deserialize(deserializer)
+        }
 
-            let (encoded, _, had_errors) = encoding.encode(text);
-            if had_errors {
-                return Err(ConvertError::Failed(format!(
-                    "Cannot encode to {}: input contains unmappable characters",
-                    target
-                )));
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(item) = seq.next_element_seed(self)? {
+                items.push(item);
             }
+            Ok(serde_json::Value::Array(items))
+        }
 
-            let mut out_props = props.clone();
-            out_props.insert("charset".into(), target.into());
-            out_props.shift_remove("target_charset");
-            Ok(ConvertOutput::Single(encoded.into_owned(), out_props))
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut out = serde_json::Map::new();
+            while let Some(key) = map.next_key_seed(self)? {
+                let key = match key {
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                out.insert(key, map.next_value_seed(self)?);
+            }
+            Ok(serde_json::Value::Object(out))
         }
     }
 }
 
-#[cfg(feature = "charsets")]
-pub use charsets_impl::{CharsetToUtf8, Utf8ToCharset};
-
 // ============================================
-// Markdown → HTML
+// CBOR <-> MessagePack transcoding
 // ============================================
 
-#[cfg(feature = "markdown")]
-mod markdown_impl {
+#[cfg(all(feature = "cbor", feature = "msgpack"))]
+mod cbor_msgpack_impl {
     use super::*;
-    use pulldown_cmark::{Parser, html};
+    use ciborium::Value as CborValue;
 
-    /// Convert Markdown to HTML.
-    pub struct MarkdownToHtml;
+    /// Cheaper than a generic serde conversion, so plans between the two
+    /// formats never detour through JSON.
+    const DIRECT_COST: f64 = 0.5;
 
-    impl Converter for MarkdownToHtml {
+    /// Convert CBOR to MessagePack without a JSON intermediate.
+    ///
+    /// Byte strings stay binary and integers keep their full 64-bit range.
+    /// Map keys follow `msgpack_compact` like the serde route, and non-string
+    /// keys are kept as-is unless `msgpack_named_keys` is set. MessagePack has
+    /// no tags, so CBOR tags are unwrapped to their content.
+    pub struct CborToMsgpack;
+
+    impl Converter for CborToMsgpack {
         fn decl(&self) -> &ConverterDecl {
             static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
             DECL.get_or_init(|| {
                 ConverterDecl::simple(
-                    "text.markdown-to-html",
-                    PropertyPattern::new().eq("format", "markdown"),
-                    PropertyPattern::new().eq("format", "html"),
+                    "serde.cbor-to-msgpack",
+                    PropertyPattern::new().eq("format", "cbor"),
+                    PropertyPattern::new().eq("format", "msgpack"),
                 )
-                .description("Convert Markdown to HTML")
+                .description("Convert CBOR to MessagePack")
+                .with_cost(DIRECT_COST)
             })
         }
 
         fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let text = std::str::from_utf8(input)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
-
-            let parser = Parser::new(text);
-            let mut html_output = String::new();
-            html::push_html(&mut html_output, parser);
+            let value: CborValue = ciborium::from_reader(input)
+                .map_err(|e| ConvertError::invalid_input("CBOR parse error", e))?;
+            let compact = flag(props, "msgpack_compact", false);
+            let keys = if compact && !flag(props, "msgpack_named_keys", !compact) {
+                Keys::Integer
+            } else if flag(props, "msgpack_named_keys", false) {
+                Keys::Named
+            } else {
+                Keys::AsIs
+            };
+            let output = rmp_serde::to_vec(&prepare(value, keys))
+                .map_err(|e| ConvertError::failed("MessagePack serialization failed", e))?;
 
             let mut out_props = props.clone();
-            out_props.insert("format".into(), "html".into());
-            Ok(ConvertOutput::Single(html_output.into_bytes(), out_props))
+            out_props.insert("format".into(), "msgpack".into());
+            Ok(ConvertOutput::Single(output, out_props))
         }
     }
-}
-
-#[cfg(feature = "markdown")]
-pub use markdown_impl::MarkdownToHtml;
-
-// ============================================
-// HTML → Plain text
-// ============================================
-
-#[cfg(feature = "html2text")]
-mod html2text_impl {
-    use super::*;
 
-    /// Convert HTML to plain text.
-    pub struct HtmlToText;
+    /// Convert MessagePack to CBOR without a JSON intermediate.
+    ///
+    /// Binary values become CBOR byte strings. Honours `cbor_canonical`.
+    pub struct MsgpackToCbor;
 
-    impl Converter for HtmlToText {
+    impl Converter for MsgpackToCbor {
         fn decl(&self) -> &ConverterDecl {
             static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
             DECL.get_or_init(|| {
                 ConverterDecl::simple(
-                    "text.html-to-text",
-                    PropertyPattern::new().eq("format", "html"),
-                    PropertyPattern::new().eq("format", "text"),
+                    "serde.msgpack-to-cbor",
+                    PropertyPattern::new().eq("format", "msgpack"),
+                    PropertyPattern::new().eq("format", "cbor"),
                 )
-                .description("Convert HTML to plain text")
+                .description("Convert MessagePack to CBOR")
+                .with_cost(DIRECT_COST)
             })
         }
 
         fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let text = html2text::from_read(input, 80)
-                .map_err(|e| ConvertError::InvalidInput(format!("HTML parsing failed: {}", e)))?;
+            let value: CborValue = rmp_serde::from_slice(input)
+                .map_err(|e| ConvertError::invalid_input("MessagePack parse error", e))?;
+            let mut output = Vec::new();
+            ciborium::into_writer(&value, &mut output)
+                .map_err(|e| ConvertError::failed("CBOR serialization failed", e))?;
+            if flag(props, "cbor_canonical", false) {
+                output = canonicalize_cbor(&output)?;
+            }
 
             let mut out_props = props.clone();
-            out_props.insert("format".into(), "text".into());
-            Ok(ConvertOutput::Single(text.into_bytes(), out_props))
+            out_props.insert("format".into(), "cbor".into());
+            Ok(ConvertOutput::Single(output, out_props))
         }
     }
-}
 
-#[cfg(feature = "html2text")]
-pub use html2text_impl::HtmlToText;
+    /// How map keys are written to MessagePack.
+    #[derive(Clone, Copy)]
+    enum Keys {
+        AsIs,
+        /// Integer keys become strings.
+        Named,
+        /// String keys like `"3"` become integers.
+        Integer,
+    }
+
+    /// Unwrap tags and rewrite map keys.
+    fn prepare(value: CborValue, keys: Keys) -> CborValue {
+        match value {
+            CborValue::Tag(_, inner) => prepare(*inner, keys),
+            CborValue::Array(items) => {
+                CborValue::Array(items.into_iter().map(|v| prepare(v, keys)).collect())
+            }
+            CborValue::Map(entries) => CborValue::Map(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (prepare_key(prepare(k, keys), keys), prepare(v, keys)))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    fn prepare_key(key: CborValue, keys: Keys) -> CborValue {
+        match (keys, key) {
+            (Keys::Named, CborValue::Integer(n)) => CborValue::Text(i128::from(n).to_string()),
+            (Keys::Integer, CborValue::Text(s)) => match msgpack_impl::integer_key(&s) {
+                Some(n) => CborValue::Integer(n.into()),
+                None => CborValue::Text(s),
+            },
+            (_, key) => key,
+        }
+    }
+}
+
+#[cfg(all(feature = "cbor", feature = "msgpack"))]
+pub use cbor_msgpack_impl::{CborToMsgpack, MsgpackToCbor};
 
 // ============================================
-// Tar archives
+// BSON options
 // ============================================
 
-#[cfg(feature = "tar")]
-mod tar_impl {
+#[cfg(feature = "bson")]
+mod bson_impl {
     use super::*;
-    use std::io::{Cursor, Read};
+    use base64::prelude::*;
+    use bson::{Bson, Document};
+    use serde_json::{Map, Value, json};
 
-    /// Extract files from a tar archive.
-    pub struct TarExtract;
+    /// Read a BSON document into a JSON value.
+    ///
+    /// With `extended_json`, ObjectIds, dates, binary and decimals use
+    /// MongoDB Extended JSON (`{"$oid": "..."}`, `{"$date": "..."}`, ...);
+    /// otherwise they become plain strings. Rarer types (timestamps,
+    /// regexes, min/max keys, ...) always use relaxed Extended JSON.
+    pub fn from_slice(data: &[u8], extended_json: bool) -> Result<Value, ConvertError> {
+        let doc = Document::from_reader(data)
+            .map_err(|e| ConvertError::invalid_input("Invalid BSON", e))?;
+        Ok(bson_to_json(Bson::Document(doc), extended_json))
+    }
 
-    impl Converter for TarExtract {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(|| {
-                ConverterDecl::simple(
-                    "archive.tar-extract",
-                    PropertyPattern::new().eq("format", "tar"),
-                    PropertyPattern::new().eq("format", "raw"),
-                )
-                .description("Extract files from tar archive")
-            })
+    fn bson_to_json(value: Bson, extended_json: bool) -> Value {
+        match value {
+            Bson::Null => Value::Null,
+            Bson::Boolean(b) => Value::Bool(b),
+            Bson::Int32(n) => n.into(),
+            Bson::Int64(n) => n.into(),
+            // Non-finite doubles have no JSON number form
+            Bson::Double(f) if f.is_finite() => f.into(),
+            Bson::String(s) => Value::String(s),
+            Bson::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .map(|item| bson_to_json(item, extended_json))
+                    .collect(),
+            ),
+            Bson::Document(doc) => Value::Object(
+                doc.into_iter()
+                    .map(|(k, v)| (k, bson_to_json(v, extended_json)))
+                    .collect::<Map<_, _>>(),
+            ),
+            Bson::ObjectId(oid) if extended_json => json!({ "$oid": oid.to_hex() }),
+            Bson::ObjectId(oid) => Value::String(oid.to_hex()),
+            Bson::DateTime(dt) => match dt.try_to_rfc3339_string() {
+                Ok(iso) if extended_json => json!({ "$date": iso }),
+                Ok(iso) => Value::String(iso),
+                // Outside the RFC 3339 range: fall back to milliseconds
+                Err(_) if extended_json => {
+                    json!({ "$date": { "$numberLong": dt.timestamp_millis().to_string() } })
+                }
+                Err(_) => dt.timestamp_millis().into(),
+            },
+            Bson::Binary(bin) => {
+                let base64 = BASE64_STANDARD.encode(&bin.bytes);
+                if extended_json {
+                    let subtype: u8 = bin.subtype.into();
+                    json!({ "$binary": { "base64": base64, "subType": format!("{:02x}", subtype) } })
+                } else {
+                    Value::String(base64)
+                }
+            }
+            Bson::Decimal128(d) if extended_json => json!({ "$numberDecimal": d.to_string() }),
+            Bson::Decimal128(d) => Value::String(d.to_string()),
+            other => other.into_relaxed_extjson(),
         }
+    }
 
-        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let cursor = Cursor::new(input);
-            let mut archive = tar::Archive::new(cursor);
-
-            let mut outputs = Vec::new();
-            for entry in archive
-                .entries()
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid tar archive: {}", e)))?
-            {
-                let mut entry = entry
-                    .map_err(|e| ConvertError::InvalidInput(format!("Invalid tar entry: {}", e)))?;
+    /// Write a JSON value as a BSON document.
+    ///
+    /// With `extended_json`, `{"$oid": ...}`, `{"$date": ...}`,
+    /// `{"$binary": ...}` and `{"$numberDecimal": ...}` become the matching
+    /// BSON types. Objects that don't parse as such stay documents.
+    pub fn to_vec(value: &Value, extended_json: bool) -> Result<Vec<u8>, ConvertError> {
+        if !extended_json {
+            return bson::ser::serialize_to_vec(value)
+                .map_err(|e| ConvertError::failed("BSON serialization failed", e));
+        }
+        match json_to_bson(value)? {
+            Bson::Document(doc) => doc
+                .to_vec()
+                .map_err(|e| ConvertError::failed("BSON serialization failed", e)),
+            _ => Err(ConvertError::invalid_input_msg(
+                "BSON top-level value must be an object",
+            )),
+        }
+    }
 
-                // Skip directories
-                if entry.header().entry_type().is_dir() {
-                    continue;
+    fn json_to_bson(value: &Value) -> Result<Bson, ConvertError> {
+        Ok(match value {
+            Value::Null => Bson::Null,
+            Value::Bool(b) => Bson::Boolean(*b),
+            Value::Number(n) => match (n.as_i64(), n.as_f64()) {
+                (Some(i), _) => Bson::Int64(i),
+                (None, Some(f)) if n.is_f64() => Bson::Double(f),
+                _ => {
+                    return Err(ConvertError::failed_msg(format!(
+                        "BSON serialization failed: {n} does not fit in an i64"
+                    )));
                 }
+            },
+            Value::String(s) => Bson::String(s.clone()),
+            Value::Array(items) => {
+                Bson::Array(items.iter().map(json_to_bson).collect::<Result<_, _>>()?)
+            }
+            Value::Object(map) => match extended_value(map) {
+                Some(special) => special,
+                None => Bson::Document(
+                    map.iter()
+                        .map(|(k, v)| Ok((k.clone(), json_to_bson(v)?)))
+                        .collect::<Result<Document, ConvertError>>()?,
+                ),
+            },
+        })
+    }
 
-                let path = entry
-                    .path()
-                    .map_err(|e| ConvertError::InvalidInput(format!("Invalid path: {}", e)))?
-                    .to_string_lossy()
-                    .to_string();
-
-                let mut data = Vec::new();
-                entry.read_to_end(&mut data).map_err(|e| {
-                    ConvertError::InvalidInput(format!("Failed to read entry: {}", e))
-                })?;
-
-                let mut out_props = props.clone();
-                out_props.insert("format".into(), "raw".into());
-                out_props.insert("path".into(), path.into());
+    /// Parse a single-key Extended JSON wrapper, if `map` is one.
+    fn extended_value(map: &Map<String, Value>) -> Option<Bson> {
+        use bson::oid::ObjectId;
+        use bson::spec::BinarySubtype;
+        use bson::{Binary, DateTime, Decimal128};
 
-                outputs.push((data, out_props));
+        if map.len() != 1 {
+            return None;
+        }
+        let (key, value) = map.iter().next()?;
+        match (key.as_str(), value) {
+            ("$oid", Value::String(hex)) => ObjectId::parse_str(hex).ok().map(Bson::ObjectId),
+            ("$date", Value::String(iso)) => {
+                DateTime::parse_rfc3339_str(iso).ok().map(Bson::DateTime)
             }
-
-            Ok(ConvertOutput::Multiple(outputs))
+            ("$date", Value::Object(long)) => {
+                let millis = long.get("$numberLong")?.as_str()?.parse().ok()?;
+                Some(Bson::DateTime(DateTime::from_millis(millis)))
+            }
+            ("$date", Value::Number(millis)) => {
+                Some(Bson::DateTime(DateTime::from_millis(millis.as_i64()?)))
+            }
+            ("$binary", Value::Object(binary)) => {
+                let bytes = BASE64_STANDARD
+                    .decode(binary.get("base64")?.as_str()?)
+                    .ok()?;
+                let subtype = u8::from_str_radix(binary.get("subType")?.as_str()?, 16).ok()?;
+                Some(Bson::Binary(Binary {
+                    subtype: BinarySubtype::from(subtype),
+                    bytes,
+                }))
+            }
+            ("$numberDecimal", Value::String(d)) => {
+                d.parse::<Decimal128>().ok().map(Bson::Decimal128)
+            }
+            _ => None,
         }
     }
+}
 
-    /// Create a tar archive from multiple files.
-    pub struct TarCreate;
+// ============================================
+// Base64 encoding/decoding
+// ============================================
 
-    impl Converter for TarCreate {
+#[cfg(feature = "base64")]
+mod base64_impl {
+    use super::*;
+    use base64::prelude::*;
+
+    /// Encode raw bytes to base64 text.
+    pub struct Base64Encoder;
+
+    impl Converter for Base64Encoder {
         fn decl(&self) -> &ConverterDecl {
             static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
             DECL.get_or_init(|| {
-                ConverterDecl::new("archive.tar-create")
-                    .input(
-                        "in",
-                        PortDecl::list(PropertyPattern::new().eq("format", "raw")),
-                    )
-                    .output(
-                        "out",
-                        PortDecl::single(PropertyPattern::new().eq("format", "tar")),
-                    )
-                    .description("Create tar archive from files")
+                ConverterDecl::simple(
+                    "encoding.raw-to-base64",
+                    PropertyPattern::new().eq("format", "raw"),
+                    PropertyPattern::new().eq("format", "base64"),
+                )
+                .description("Encode raw bytes to base64")
             })
         }
 
-        fn convert(
-            &self,
-            _input: &[u8],
-            _props: &Properties,
-        ) -> Result<ConvertOutput, ConvertError> {
-            Err(ConvertError::BatchNotSupported)
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let encoded = BASE64_STANDARD.encode(input);
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "base64".into());
+            Ok(ConvertOutput::Single(encoded.into_bytes(), out_props))
         }
+    }
 
-        fn convert_batch(
-            &self,
-            inputs: &[(&[u8], &Properties)],
-        ) -> Result<ConvertOutput, ConvertError> {
-            let mut output = Vec::new();
-            {
-                let mut builder = tar::Builder::new(&mut output);
-
-                for (data, props) in inputs {
-                    let path = props.get("path").and_then(|v| v.as_str()).ok_or_else(|| {
-                        ConvertError::InvalidInput("Missing 'path' property for tar entry".into())
-                    })?;
-
-                    let mut header = tar::Header::new_gnu();
-                    header.set_size(data.len() as u64);
-                    header.set_mode(0o644);
-                    header.set_cksum();
-
-                    builder.append_data(&mut header, path, *data).map_err(|e| {
-                        ConvertError::Failed(format!("Failed to add entry '{}': {}", path, e))
-                    })?;
-                }
+    /// Decode base64 text to raw bytes.
+    pub struct Base64Decoder;
 
-                builder
-                    .finish()
-                    .map_err(|e| ConvertError::Failed(format!("Failed to finalize tar: {}", e)))?;
-            }
+    impl Converter for Base64Decoder {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.base64-to-raw",
+                    PropertyPattern::new().eq("format", "base64"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decode base64 to raw bytes")
+            })
+        }
 
-            let mut out_props = Properties::new();
-            out_props.insert("format".into(), "tar".into());
-            Ok(ConvertOutput::Single(output, out_props))
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            // Handle input as text (trim whitespace)
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?
+                .trim();
+            let decoded = BASE64_STANDARD
+                .decode(text)
+                .map_err(|e| ConvertError::invalid_input("Invalid base64", e))?;
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "raw".into());
+            Ok(ConvertOutput::Single(decoded, out_props))
         }
     }
 }
 
-#[cfg(feature = "tar")]
-pub use tar_impl::{TarCreate, TarExtract};
+#[cfg(feature = "base64")]
+pub use base64_impl::{Base64Decoder, Base64Encoder};
 
 // ============================================
-// Zip archives
+// Hex encoding/decoding
 // ============================================
 
-#[cfg(feature = "zip")]
-mod zip_impl {
+#[cfg(feature = "hex")]
+mod hex_impl {
     use super::*;
-    use std::io::{Cursor, Read, Write};
+    use paraphase_core::PropertySchema;
 
-    /// Extract files from a zip archive.
-    pub struct ZipExtract;
+    /// Encode raw bytes to hexadecimal text.
+    ///
+    /// Options:
+    /// - `hex_uppercase`: use `A-F` instead of `a-f` (default: false)
+    /// - `hex_separator`: string inserted between groups of bytes
+    /// - `hex_group_size`: bytes per group (default: 0, no separator)
+    ///
+    /// `hex_separator` is kept in the output properties so [`HexDecoder`]
+    /// can strip it again.
+    pub struct HexEncoder;
 
-    impl Converter for ZipExtract {
+    /// Insert `separator` between every `group_size` bytes of hex text.
+    fn group_hex(hex: &str, separator: &str, group_size: usize) -> String {
+        if separator.is_empty() || group_size == 0 {
+            return hex.to_string();
+        }
+        // Hex output is ASCII, so byte chunks are valid UTF-8
+        hex.as_bytes()
+            .chunks(group_size * 2)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap())
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    impl Converter for HexEncoder {
         fn decl(&self) -> &ConverterDecl {
             static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
             DECL.get_or_init(|| {
                 ConverterDecl::simple(
-                    "archive.zip-extract",
-                    PropertyPattern::new().eq("format", "zip"),
+                    "encoding.raw-to-hex",
                     PropertyPattern::new().eq("format", "raw"),
+                    PropertyPattern::new().eq("format", "hex"),
                 )
-                .description("Extract files from zip archive")
+                .description("Encode raw bytes to hexadecimal")
+                .with_property_schema("hex_uppercase", PropertySchema::boolean())
+                .with_property_schema("hex_separator", PropertySchema::string())
+                .with_property_schema("hex_group_size", PropertySchema::integer().min(0.0))
             })
         }
 
         fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let cursor = Cursor::new(input);
-            let mut archive = zip::ZipArchive::new(cursor)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid zip archive: {}", e)))?;
+            self.decl().check_props(props)?;
+            let encoded = if flag(props, "hex_uppercase", false) {
+                hex::encode_upper(input)
+            } else {
+                hex::encode(input)
+            };
+            let separator = props
+                .get("hex_separator")
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            let group_size = props
+                .get("hex_group_size")
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0) as usize;
+            let encoded = group_hex(&encoded, separator, group_size);
 
-            let mut outputs = Vec::new();
-            for i in 0..archive.len() {
-                let mut file = archive
-                    .by_index(i)
-                    .map_err(|e| ConvertError::InvalidInput(format!("Invalid zip entry: {}", e)))?;
+            let mut out_props = props.clone();
+            out_props.shift_remove("hex_uppercase");
+            out_props.shift_remove("hex_group_size");
+            out_props.insert("format".into(), "hex".into());
+            Ok(ConvertOutput::Single(encoded.into_bytes(), out_props))
+        }
+    }
 
-                // Skip directories
-                if file.is_dir() {
-                    continue;
-                }
+    /// Decode hexadecimal text to raw bytes.
+    ///
+    /// Spaces, `:` and `-` are ignored, as is the `hex_separator` property
+    /// if set.
+    pub struct HexDecoder;
 
-                let path = file.name().to_string();
+    impl Converter for HexDecoder {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.hex-to-raw",
+                    PropertyPattern::new().eq("format", "hex"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decode hexadecimal to raw bytes")
+            })
+        }
 
-                let mut data = Vec::new();
-                file.read_to_end(&mut data).map_err(|e| {
-                    ConvertError::InvalidInput(format!("Failed to read entry: {}", e))
-                })?;
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            // Handle input as text (trim whitespace, remove common separators)
+            let mut text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?
+                .trim()
+                .to_string();
+            if let Some(separator) = props.get("hex_separator").and_then(|v| v.as_str()) {
+                if !separator.is_empty() {
+                    text = text.replace(separator, "");
+                }
+            }
+            let text = text.replace([' ', ':', '-'], "");
+            let decoded =
+                hex::decode(&text).map_err(|e| ConvertError::invalid_input("Invalid hex", e))?;
+            let mut out_props = props.clone();
+            out_props.shift_remove("hex_separator");
+            out_props.insert("format".into(), "raw".into());
+            Ok(ConvertOutput::Single(decoded, out_props))
+        }
+    }
+}
 
-                let mut out_props = props.clone();
-                out_props.insert("format".into(), "raw".into());
-                out_props.insert("path".into(), path.into());
+#[cfg(feature = "hex")]
+pub use hex_impl::{HexDecoder, HexEncoder};
 
-                outputs.push((data, out_props));
-            }
+// ============================================
+// Base32 encoding/decoding
+// ============================================
 
-            Ok(ConvertOutput::Multiple(outputs))
+#[cfg(feature = "base32")]
+mod base32_impl {
+    use super::*;
+    use base32::Alphabet;
+    use paraphase_core::PropertySchema;
+
+    /// Alphabet named by the `base32_alphabet` property (default: "rfc4648").
+    fn alphabet(props: &Properties) -> Alphabet {
+        match props.get("base32_alphabet").and_then(|v| v.as_str()) {
+            Some("extended-hex") => Alphabet::Rfc4648Hex { padding: true },
+            _ => Alphabet::Rfc4648 { padding: true },
         }
     }
 
-    /// Create a zip archive from multiple files.
-    pub struct ZipCreate;
+    fn alphabet_schema() -> PropertySchema {
+        PropertySchema::string().one_of(["rfc4648", "extended-hex"])
+    }
 
-    impl Converter for ZipCreate {
+    /// Encode raw bytes to base32 text (RFC 4648, padded).
+    ///
+    /// Options:
+    /// - `base32_alphabet`: "rfc4648" (default) or "extended-hex"
+    ///
+    /// `base32_alphabet` is kept in the output properties so
+    /// [`Base32Decoder`] decodes with the same alphabet.
+    pub struct Base32Encoder;
+
+    impl Converter for Base32Encoder {
         fn decl(&self) -> &ConverterDecl {
             static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
             DECL.get_or_init(|| {
-                ConverterDecl::new("archive.zip-create")
-                    .input(
-                        "in",
-                        PortDecl::list(PropertyPattern::new().eq("format", "raw")),
-                    )
-                    .output(
-                        "out",
-                        PortDecl::single(PropertyPattern::new().eq("format", "zip")),
-                    )
-                    .description("Create zip archive from files")
+                ConverterDecl::simple(
+                    "encoding.raw-to-base32",
+                    PropertyPattern::new().eq("format", "raw"),
+                    PropertyPattern::new().eq("format", "base32"),
+                )
+                .description("Encode raw bytes to base32")
+                .with_property_schema("base32_alphabet", alphabet_schema())
             })
         }
 
-        fn convert(
-            &self,
-            _input: &[u8],
-            _props: &Properties,
-        ) -> Result<ConvertOutput, ConvertError> {
-            Err(ConvertError::BatchNotSupported)
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let encoded = base32::encode(alphabet(props), input);
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "base32".into());
+            Ok(ConvertOutput::Single(encoded.into_bytes(), out_props))
         }
+    }
 
-        fn convert_batch(
-            &self,
-            inputs: &[(&[u8], &Properties)],
-        ) -> Result<ConvertOutput, ConvertError> {
-            let mut output = Cursor::new(Vec::new());
-            {
-                let mut writer = zip::ZipWriter::new(&mut output);
-                let options = zip::write::SimpleFileOptions::default()
-                    .compression_method(zip::CompressionMethod::Deflated);
-
-                for (data, props) in inputs {
-                    let path = props.get("path").and_then(|v| v.as_str()).ok_or_else(|| {
-                        ConvertError::InvalidInput("Missing 'path' property for zip entry".into())
-                    })?;
-
-                    writer.start_file(path, options).map_err(|e| {
-                        ConvertError::Failed(format!("Failed to add entry '{}': {}", path, e))
-                    })?;
-
-                    writer.write_all(data).map_err(|e| {
-                        ConvertError::Failed(format!("Failed to write entry '{}': {}", path, e))
-                    })?;
-                }
+    /// Decode base32 text to raw bytes.
+    ///
+    /// Options:
+    /// - `base32_alphabet`: "rfc4648" (default) or "extended-hex"
+    pub struct Base32Decoder;
 
-                writer
-                    .finish()
-                    .map_err(|e| ConvertError::Failed(format!("Failed to finalize zip: {}", e)))?;
-            }
+    impl Converter for Base32Decoder {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.base32-to-raw",
+                    PropertyPattern::new().eq("format", "base32"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decode base32 to raw bytes")
+                .with_property_schema("base32_alphabet", alphabet_schema())
+            })
+        }
 
-            let mut out_props = Properties::new();
-            out_props.insert("format".into(), "zip".into());
-            Ok(ConvertOutput::Single(output.into_inner(), out_props))
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?
+                .trim();
+            let decoded = base32::decode(alphabet(props), text)
+                .ok_or_else(|| ConvertError::invalid_input_msg("Invalid base32"))?;
+            let mut out_props = props.clone();
+            out_props.shift_remove("base32_alphabet");
+            out_props.insert("format".into(), "raw".into());
+            Ok(ConvertOutput::Single(decoded, out_props))
         }
     }
 }
 
-#[cfg(feature = "zip")]
-pub use zip_impl::{ZipCreate, ZipExtract};
+#[cfg(feature = "base32")]
+pub use base32_impl::{Base32Decoder, Base32Encoder};
 
 // ============================================
-// SPREADSHEET FORMATS
+// Base58 encoding/decoding
 // ============================================
 
-#[cfg(feature = "spreadsheet")]
-mod spreadsheet_impl {
+#[cfg(feature = "base58")]
+mod base58_impl {
     use super::*;
-    use calamine::{Data, Reader, open_workbook_auto_from_rs};
-    use std::io::Cursor;
-
-    /// Read spreadsheet files (XLSX, ODS, XLS, XLSB) to JSON.
-    ///
-    /// Input properties:
-    /// - `format`: "xlsx", "ods", "xls", or "xlsb"
-    /// - `sheet`: optional sheet name or index (default: all sheets)
-    /// - `headers`: if "true", use first row as object keys
-    ///
-    /// Output: JSON with structure:
-    /// - If headers=false: `{"sheets": {"SheetName": [[cell, cell, ...], ...]}}`
-    /// - If headers=true: `{"sheets": {"SheetName": [{"col": value, ...}, ...]}}`
-    pub struct SpreadsheetToJson;
+    use paraphase_core::PropertySchema;
 
-    impl SpreadsheetToJson {
-        fn decl() -> ConverterDecl {
-            use paraphase_core::{Predicate, Value};
-            ConverterDecl::simple(
-                "spreadsheet-to-json",
-                PropertyPattern::new().with(
-                    "format",
-                    Predicate::OneOf(vec![
-                        Value::from("xlsx"),
-                        Value::from("ods"),
-                        Value::from("xls"),
-                        Value::from("xlsb"),
-                    ]),
-                ),
-                PropertyPattern::new().eq("format", "json"),
-            )
-            .description("Read spreadsheet to JSON")
+    /// Alphabet named by the `base58_alphabet` property (default: "bitcoin").
+    fn alphabet(props: &Properties) -> &'static bs58::Alphabet {
+        match props.get("base58_alphabet").and_then(|v| v.as_str()) {
+            Some("flickr") => bs58::Alphabet::FLICKR,
+            _ => bs58::Alphabet::BITCOIN,
         }
     }
 
-    impl Converter for SpreadsheetToJson {
+    fn alphabet_schema() -> PropertySchema {
+        PropertySchema::string().one_of(["bitcoin", "flickr"])
+    }
+
+    /// Encode raw bytes to base58 text.
+    ///
+    /// Options:
+    /// - `base58_alphabet`: "bitcoin" (default) or "flickr" (lowercase
+    ///   letters before uppercase)
+    ///
+    /// `base58_alphabet` is kept in the output properties so
+    /// [`Base58Decoder`] decodes with the same alphabet.
+    pub struct Base58Encoder;
+
+    impl Converter for Base58Encoder {
         fn decl(&self) -> &ConverterDecl {
             static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(Self::decl)
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.raw-to-base58",
+                    PropertyPattern::new().eq("format", "raw"),
+                    PropertyPattern::new().eq("format", "base58"),
+                )
+                .description("Encode raw bytes to base58")
+                .with_property_schema("base58_alphabet", alphabet_schema())
+            })
         }
 
         fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
-            let cursor = Cursor::new(input);
-            let mut workbook = open_workbook_auto_from_rs(cursor).map_err(|e| {
-                ConvertError::InvalidInput(format!("Failed to open spreadsheet: {}", e))
-            })?;
-
-            let use_headers = props
-                .get("headers")
-                .and_then(|v| v.as_str())
-                .map(|s| s == "true")
-                .unwrap_or(false);
-
-            let sheet_filter = props.get("sheet").and_then(|v| v.as_str());
-
-            let sheet_names: Vec<String> = workbook.sheet_names().to_vec();
-            let mut sheets = serde_json::Map::new();
-
-            for name in &sheet_names {
-                // Filter by sheet name if specified
-                if let Some(filter) = sheet_filter {
-                    if name != filter {
-                        continue;
-                    }
-                }
-
-                if let Ok(range) = workbook.worksheet_range(name) {
-                    let rows: Vec<Vec<serde_json::Value>> = range
-                        .rows()
-                        .map(|row| {
-                            row.iter()
-                                .map(|cell| match cell {
-                                    Data::Empty => serde_json::Value::Null,
-                                    Data::String(s) => serde_json::Value::String(s.clone()),
-                                    Data::Int(n) => serde_json::json!(*n),
-                                    Data::Float(f) => serde_json::json!(*f),
-                                    Data::Bool(b) => serde_json::Value::Bool(*b),
-                                    Data::Error(e) => {
-                                        serde_json::Value::String(format!("#ERROR:{:?}", e))
-                                    }
-                                    Data::DateTime(dt) => {
-                                        serde_json::Value::String(format!("{}", dt))
-                                    }
-                                    Data::DateTimeIso(s) => serde_json::Value::String(s.clone()),
-                                    Data::DurationIso(s) => serde_json::Value::String(s.clone()),
-                                })
-                                .collect()
-                        })
-                        .collect();
-
-                    let sheet_data = if use_headers && !rows.is_empty() {
-                        // Use first row as headers
-                        let headers: Vec<String> = rows[0]
-                            .iter()
-                            .enumerate()
-                            .map(|(i, v)| {
-                                v.as_str()
-                                    .map(|s| s.to_string())
-                                    .unwrap_or_else(|| format!("col_{}", i))
-                            })
-                            .collect();
-
-                        let objects: Vec<serde_json::Value> = rows[1..]
-                            .iter()
-                            .map(|row| {
-                                let mut obj = serde_json::Map::new();
-                                for (i, cell) in row.iter().enumerate() {
-                                    let key = headers
-                                        .get(i)
-                                        .cloned()
-                                        .unwrap_or_else(|| format!("col_{}", i));
-                                    obj.insert(key, cell.clone());
-                                }
-                                serde_json::Value::Object(obj)
-                            })
-                            .collect();
-
-                        serde_json::Value::Array(objects)
-                    } else {
-                        serde_json::Value::Array(
-                            rows.into_iter().map(serde_json::Value::Array).collect(),
-                        )
-                    };
-
-                    sheets.insert(name.clone(), sheet_data);
-                }
-            }
+            self.decl().check_props(props)?;
+            let encoded = bs58::encode(input)
+                .with_alphabet(alphabet(props))
+                .into_string();
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "base58".into());
+            Ok(ConvertOutput::Single(encoded.into_bytes(), out_props))
+        }
+    }
 
-            let result = serde_json::json!({ "sheets": sheets });
-            let output = serde_json::to_vec_pretty(&result)
-                .map_err(|e| ConvertError::Failed(format!("JSON serialization failed: {}", e)))?;
+    /// Decode base58 text to raw bytes.
+    ///
+    /// Options:
+    /// - `base58_alphabet`: "bitcoin" (default) or "flickr"
+    pub struct Base58Decoder;
 
-            let mut out_props = Properties::new();
-            out_props.insert("format".into(), "json".into());
+    impl Converter for Base58Decoder {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.base58-to-raw",
+                    PropertyPattern::new().eq("format", "base58"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decode base58 to raw bytes")
+                .with_property_schema("base58_alphabet", alphabet_schema())
+            })
+        }
 
-            Ok(ConvertOutput::Single(output, out_props))
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?
+                .trim();
+            let decoded = bs58::decode(text)
+                .with_alphabet(alphabet(props))
+                .into_vec()
+                .map_err(|e| ConvertError::invalid_input("Invalid base58", e))?;
+            let mut out_props = props.clone();
+            out_props.shift_remove("base58_alphabet");
+            out_props.insert("format".into(), "raw".into());
+            Ok(ConvertOutput::Single(decoded, out_props))
         }
     }
 }
 
-#[cfg(feature = "spreadsheet")]
-pub use spreadsheet_impl::SpreadsheetToJson;
+#[cfg(feature = "base58")]
+pub use base58_impl::{Base58Decoder, Base58Encoder};
 
 // ============================================
-// SCHEMA-BASED FORMATS (self-describing)
+// Legacy email encodings (quoted-printable, uuencode)
 // ============================================
 
-#[cfg(feature = "avro")]
-mod avro_impl {
+#[cfg(feature = "legacy-encoding")]
+mod legacy_encoding_impl {
     use super::*;
-    use apache_avro::Reader;
 
-    /// Read Avro container files to JSON.
+    /// Encode raw bytes as quoted-printable text (RFC 2045).
     ///
-    /// Avro container files are self-describing - the schema is embedded.
-    /// Outputs a JSON array of records.
-    pub struct AvroToJson;
+    /// Line breaks in the input are kept as hard line breaks; long lines
+    /// get soft breaks at 76 characters.
+    pub struct QuotedPrintableEncode;
 
-    impl AvroToJson {
-        fn decl() -> ConverterDecl {
-            ConverterDecl::simple(
-                "avro-to-json",
-                PropertyPattern::new().eq("format", "avro"),
-                PropertyPattern::new().eq("format", "json"),
-            )
-            .description("Read Avro container file to JSON")
+    impl Converter for QuotedPrintableEncode {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.raw-to-quoted-printable",
+                    PropertyPattern::new().eq("format", "raw"),
+                    PropertyPattern::new().eq("format", "quoted-printable"),
+                )
+                .description("Encode raw bytes as quoted-printable")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "quoted-printable".into());
+            Ok(ConvertOutput::Single(
+                quoted_printable::encode(input),
+                out_props,
+            ))
         }
     }
 
-    impl Converter for AvroToJson {
+    /// Decode quoted-printable text to raw bytes.
+    ///
+    /// Malformed escapes are passed through as-is rather than rejected, as
+    /// mail clients do.
+    pub struct QuotedPrintableDecode;
+
+    impl Converter for QuotedPrintableDecode {
         fn decl(&self) -> &ConverterDecl {
             static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(Self::decl)
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.quoted-printable-to-raw",
+                    PropertyPattern::new().eq("format", "quoted-printable"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decode quoted-printable to raw bytes")
+            })
         }
 
-        fn convert(
-            &self,
-            input: &[u8],
-            _props: &Properties,
-        ) -> Result<ConvertOutput, ConvertError> {
-            let reader = Reader::new(input)
-                .map_err(|e| ConvertError::InvalidInput(format!("Failed to read Avro: {}", e)))?;
-
-            let mut records = Vec::new();
-            for value in reader {
-                let value = value.map_err(|e| {
-                    ConvertError::Failed(format!("Failed to read Avro record: {}", e))
-                })?;
-                // Convert Avro value to JSON
-                let json_value = avro_value_to_json(&value);
-                records.push(json_value);
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let decoded = quoted_printable::decode(input, quoted_printable::ParseMode::Robust)
+                .map_err(|e| ConvertError::invalid_input("Invalid quoted-printable", e))?;
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "raw".into());
+            Ok(ConvertOutput::Single(decoded, out_props))
+        }
+    }
+
+    /// Bytes per encoded line, the traditional maximum.
+    const UU_LINE_BYTES: usize = 45;
+
+    /// Encode raw bytes as a uuencoded `begin ... end` block.
+    ///
+    /// Options:
+    /// - `uu_filename`: name on the `begin` line (default: the file name
+    ///   of `path`, or "data")
+    /// - `uu_permissions`: mode on the `begin` line, as an integer or an
+    ///   octal string like `"755"` (default: 644)
+    pub struct UuEncode;
+
+    impl Converter for UuEncode {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.raw-to-uuencoded",
+                    PropertyPattern::new().eq("format", "raw"),
+                    PropertyPattern::new().eq("format", "uuencoded"),
+                )
+                .description("Encode raw bytes with uuencode")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let filename = match props.get("uu_filename").and_then(|v| v.as_str()) {
+                Some(name) => name.to_string(),
+                None => props
+                    .get("path")
+                    .and_then(|v| v.as_str())
+                    .and_then(|path| std::path::Path::new(path).file_name())
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| "data".into()),
+            };
+            if filename.is_empty() || filename.contains(['\n', '\r']) {
+                return Err(ConvertError::invalid_input_msg(format!(
+                    "Invalid uu_filename '{}'",
+                    filename.escape_debug()
+                )));
+            }
+            let mode = match props.get("uu_permissions") {
+                Some(value) => uu_mode(value)?,
+                None => 0o644,
+            };
+
+            let mut out = format!("begin {:o} {}\n", mode, filename);
+            for line in input.chunks(UU_LINE_BYTES) {
+                out.push(uu_char(line.len() as u8));
+                for group in line.chunks(3) {
+                    let b = [
+                        group[0],
+                        group.get(1).copied().unwrap_or(0),
+                        group.get(2).copied().unwrap_or(0),
+                    ];
+                    out.push(uu_char(b[0] >> 2));
+                    out.push(uu_char((b[0] & 0x03) << 4 | b[1] >> 4));
+                    out.push(uu_char((b[1] & 0x0f) << 2 | b[2] >> 6));
+                    out.push(uu_char(b[2] & 0x3f));
+                }
+                out.push('\n');
             }
+            out.push_str("`\nend\n");
 
-            let output = serde_json::to_vec_pretty(&serde_json::Value::Array(records))
-                .map_err(|e| ConvertError::Failed(format!("JSON serialization failed: {}", e)))?;
+            let mut out_props = props.clone();
+            out_props.shift_remove("uu_filename");
+            out_props.shift_remove("uu_permissions");
+            out_props.insert("format".into(), "uuencoded".into());
+            Ok(ConvertOutput::Single(out.into_bytes(), out_props))
+        }
+    }
 
-            let mut out_props = Properties::new();
-            out_props.insert("format".into(), "json".into());
+    /// Decode a uuencoded `begin ... end` block to raw bytes.
+    ///
+    /// Text before the `begin` line (mail headers, ...) is skipped. The
+    /// file name from the `begin` line becomes the `path` property.
+    pub struct UuDecode;
+
+    impl Converter for UuDecode {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.uuencoded-to-raw",
+                    PropertyPattern::new().eq("format", "uuencoded"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decode uuencoded data to raw bytes")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            let mut lines = text.lines().map(|line| line.trim_end_matches('\r'));
+
+            let begin = lines
+                .by_ref()
+                .find(|line| line.starts_with("begin "))
+                .ok_or_else(|| ConvertError::invalid_input_msg("Missing uuencode 'begin' line"))?;
+            let mut fields = begin.splitn(3, ' ').skip(1);
+            let (Some(_mode), Some(filename)) = (fields.next(), fields.next()) else {
+                return Err(ConvertError::invalid_input_msg(format!(
+                    "Invalid uuencode 'begin' line: {}",
+                    begin
+                )));
+            };
+
+            let mut output = Vec::new();
+            let mut ended = false;
+            for line in lines {
+                if line == "end" {
+                    ended = true;
+                    break;
+                }
+                let Some((&len, body)) = line.as_bytes().split_first() else {
+                    continue;
+                };
+                let len = uu_value(len)? as usize;
+                let groups = len.div_ceil(3);
+                if body.len() < groups * 4 {
+                    return Err(ConvertError::invalid_input_msg(format!(
+                        "Truncated uuencoded line: {}",
+                        line
+                    )));
+                }
+
+                let start = output.len();
+                for chunk in body[..groups * 4].chunks(4) {
+                    let c = [
+                        uu_value(chunk[0])?,
+                        uu_value(chunk[1])?,
+                        uu_value(chunk[2])?,
+                        uu_value(chunk[3])?,
+                    ];
+                    output.push(c[0] << 2 | c[1] >> 4);
+                    output.push(c[1] << 4 | c[2] >> 2);
+                    output.push(c[2] << 6 | c[3]);
+                }
+                output.truncate(start + len);
+            }
+            if !ended {
+                return Err(ConvertError::invalid_input_msg(
+                    "Missing uuencode 'end' line",
+                ));
+            }
 
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "raw".into());
+            out_props.insert("path".into(), filename.into());
             Ok(ConvertOutput::Single(output, out_props))
         }
     }
 
-    /// Convert an Avro value to a JSON value.
-    fn avro_value_to_json(value: &apache_avro::types::Value) -> serde_json::Value {
-        use apache_avro::types::Value as AV;
-        match value {
-            AV::Null => serde_json::Value::Null,
-            AV::Boolean(b) => serde_json::Value::Bool(*b),
-            AV::Int(n) => serde_json::json!(*n),
-            AV::Long(n) => serde_json::json!(*n),
-            AV::Float(f) => serde_json::json!(*f),
-            AV::Double(d) => serde_json::json!(*d),
-            AV::Bytes(b) | AV::Fixed(_, b) => {
-                // Encode bytes as base64
-                use base64::Engine;
-                serde_json::Value::String(base64::engine::general_purpose::STANDARD.encode(b))
-            }
-            AV::String(s) => serde_json::Value::String(s.clone()),
-            AV::Enum(_, s) => serde_json::Value::String(s.clone()),
-            AV::Union(_, inner) => avro_value_to_json(inner),
-            AV::Array(arr) => {
-                serde_json::Value::Array(arr.iter().map(avro_value_to_json).collect())
+    /// Encode a 6-bit value, using '`' rather than ' ' for zero.
+    fn uu_char(value: u8) -> char {
+        match value & 0x3f {
+            0 => '`',
+            v => (v + 32) as char,
+        }
+    }
+
+    /// Decode a uuencoded character to its 6-bit value.
+    fn uu_value(c: u8) -> Result<u8, ConvertError> {
+        match c {
+            b' '..=b'`' => Ok((c - b' ') & 0x3f),
+            _ => Err(ConvertError::invalid_input_msg(format!(
+                "Invalid uuencoded character '{}'",
+                c.escape_ascii()
+            ))),
+        }
+    }
+
+    /// Parse `uu_permissions` from an integer or an octal string.
+    fn uu_mode(value: &paraphase_core::Value) -> Result<u32, ConvertError> {
+        let mode = match (value.as_i64(), value.as_str()) {
+            (Some(n), _) => u32::try_from(n).ok(),
+            (_, Some(s)) => u32::from_str_radix(s.trim_start_matches("0o"), 8).ok(),
+            _ => None,
+        };
+        mode.filter(|mode| *mode <= 0o7777).ok_or_else(|| {
+            ConvertError::invalid_input_msg(
+                "Invalid uu_permissions: expected permission bits like 0o644 or \"644\"",
+            )
+        })
+    }
+}
+
+#[cfg(feature = "legacy-encoding")]
+pub use legacy_encoding_impl::{QuotedPrintableDecode, QuotedPrintableEncode, UuDecode, UuEncode};
+
+// ============================================
+// Percent-encoding and HTML entities
+// ============================================
+
+#[cfg(feature = "urlencoded")]
+mod escape_impl {
+    use super::*;
+    use percent_encoding::{NON_ALPHANUMERIC, percent_decode, percent_encode};
+
+    /// Percent-encode text for use in URLs.
+    ///
+    /// Everything except ASCII alphanumerics is encoded. Extra characters to
+    /// leave as-is can be listed in the `url_safe_chars` property (e.g. `"-_.~/"`).
+    pub struct UrlPercentEncode;
+
+    impl Converter for UrlPercentEncode {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.percent-encode",
+                    PropertyPattern::new().eq("format", "text"),
+                    PropertyPattern::new().eq("format", "text"),
+                )
+                .description("Percent-encode text for URLs")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let safe: Vec<u8> = props
+                .get("url_safe_chars")
+                .and_then(|v| v.as_str())
+                .map(|s| s.bytes().filter(u8::is_ascii).collect())
+                .unwrap_or_default();
+
+            let mut encoded = String::with_capacity(input.len());
+            for &byte in input {
+                if safe.contains(&byte) {
+                    encoded.push(byte as char);
+                } else {
+                    encoded.extend(percent_encode(&[byte], NON_ALPHANUMERIC));
+                }
             }
-            AV::Map(map) => {
-                let obj: serde_json::Map<String, serde_json::Value> = map
-                    .iter()
-                    .map(|(k, v)| (k.clone(), avro_value_to_json(v)))
-                    .collect();
-                serde_json::Value::Object(obj)
+
+            let mut out_props = props.clone();
+            out_props.shift_remove("url_safe_chars");
+            out_props.insert("format".into(), "text".into());
+            Ok(ConvertOutput::Single(encoded.into_bytes(), out_props))
+        }
+    }
+
+    /// Decode percent-encoded text.
+    ///
+    /// Malformed escapes (e.g. `%zz`) are passed through unchanged.
+    pub struct UrlPercentDecode;
+
+    impl Converter for UrlPercentDecode {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.percent-decode",
+                    PropertyPattern::new().eq("format", "text"),
+                    PropertyPattern::new().eq("format", "text"),
+                )
+                .description("Decode percent-encoded text")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let decoded: Vec<u8> = percent_decode(input).collect();
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "text".into());
+            Ok(ConvertOutput::Single(decoded, out_props))
+        }
+    }
+
+    /// Escape `& < > " '` as HTML entities.
+    pub struct HtmlEntityEncode;
+
+    impl Converter for HtmlEntityEncode {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.html-escape",
+                    PropertyPattern::new().eq("format", "text"),
+                    PropertyPattern::new().eq("format", "text"),
+                )
+                .description("Escape HTML special characters as entities")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let mut escaped = Vec::with_capacity(input.len());
+            for &byte in input {
+                match byte {
+                    b'&' => escaped.extend_from_slice(b"&amp;"),
+                    b'<' => escaped.extend_from_slice(b"&lt;"),
+                    b'>' => escaped.extend_from_slice(b"&gt;"),
+                    b'"' => escaped.extend_from_slice(b"&quot;"),
+                    b'\'' => escaped.extend_from_slice(b"&#39;"),
+                    _ => escaped.push(byte),
+                }
             }
-            AV::Record(fields) => {
-                let obj: serde_json::Map<String, serde_json::Value> = fields
-                    .iter()
-                    .map(|(k, v)| (k.clone(), avro_value_to_json(v)))
-                    .collect();
-                serde_json::Value::Object(obj)
+
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "text".into());
+            Ok(ConvertOutput::Single(escaped, out_props))
+        }
+    }
+
+    /// Unescape HTML entities.
+    ///
+    /// Handles the entities produced by [`HtmlEntityEncode`] plus `&apos;`,
+    /// `&nbsp;` and numeric references (`&#NN;`, `&#xNN;`). Unknown entities
+    /// are left as-is.
+    pub struct HtmlEntityDecode;
+
+    impl Converter for HtmlEntityDecode {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.html-unescape",
+                    PropertyPattern::new().eq("format", "text"),
+                    PropertyPattern::new().eq("format", "text"),
+                )
+                .description("Unescape HTML entities")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "text".into());
+            Ok(ConvertOutput::Single(
+                unescape_html(text).into_bytes(),
+                out_props,
+            ))
+        }
+    }
+
+    fn unescape_html(text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(amp) = rest.find('&') {
+            out.push_str(&rest[..amp]);
+            rest = &rest[amp..];
+
+            // Entity names are short; don't scan arbitrarily far for ';'
+            let decoded = rest
+                .char_indices()
+                .take(12)
+                .find(|&(_, c)| c == ';')
+                .and_then(|(end, _)| decode_entity(&rest[1..end]).map(|c| (c, end)));
+
+            match decoded {
+                Some((c, end)) => {
+                    out.push(c);
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    out.push('&');
+                    rest = &rest[1..];
+                }
             }
-            AV::Date(d) => serde_json::json!(*d),
-            AV::Decimal(d) => serde_json::Value::String(format!("{:?}", d)),
-            AV::TimeMillis(t) => serde_json::json!(*t),
-            AV::TimeMicros(t) => serde_json::json!(*t),
-            AV::TimestampMillis(t) => serde_json::json!(*t),
-            AV::TimestampMicros(t) => serde_json::json!(*t),
-            AV::TimestampNanos(t) => serde_json::json!(*t),
-            AV::LocalTimestampMillis(t) => serde_json::json!(*t),
-            AV::LocalTimestampMicros(t) => serde_json::json!(*t),
-            AV::LocalTimestampNanos(t) => serde_json::json!(*t),
-            AV::Duration(d) => serde_json::Value::String(format!("{:?}", d)),
-            AV::Uuid(u) => serde_json::Value::String(u.to_string()),
-            AV::BigDecimal(bd) => serde_json::Value::String(bd.to_string()),
+        }
+
+        out.push_str(rest);
+        out
+    }
+
+    fn decode_entity(name: &str) -> Option<char> {
+        if let Some(num) = name.strip_prefix('#') {
+            let code = match num.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => num.parse().ok()?,
+            };
+            return char::from_u32(code);
+        }
+
+        match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => None,
         }
     }
 }
 
-#[cfg(feature = "avro")]
-pub use avro_impl::AvroToJson;
+#[cfg(feature = "urlencoded")]
+pub use escape_impl::{HtmlEntityDecode, HtmlEntityEncode, UrlPercentDecode, UrlPercentEncode};
 
-#[cfg(feature = "parquet")]
-mod parquet_impl {
+// ============================================
+// NDJSON (Newline-delimited JSON)
+// ============================================
+
+#[cfg(feature = "ndjson")]
+mod ndjson_impl {
     use super::*;
-    use arrow::array::*;
-    use bytes::Bytes;
-    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use serde::de::{Deserializer as _, MapAccess, SeqAccess, Visitor};
+    use std::io::{BufRead, BufReader, Lines, Read, Write};
 
-    /// Read Parquet files to JSON.
+    /// Convert JSON array to newline-delimited JSON.
     ///
-    /// Parquet files are self-describing - the schema is in the file footer.
-    /// Outputs a JSON array of records.
-    pub struct ParquetToJson;
+    /// Each array element becomes one line. Any other value becomes a
+    /// single line, as if wrapped in a one-element array.
+    ///
+    /// Input properties:
+    /// - `ndjson_stream`: write each element as it is parsed instead of
+    ///   loading the whole array first (default false)
+    /// - `ndjson_object_values`: for an object input, emit each value as a
+    ///   line instead of the whole object (default false)
+    /// - `ndjson_include_keys`: with `ndjson_object_values`, emit
+    ///   `{"key": ..., "value": ...}` lines instead of bare values
+    ///   (default false)
+    pub struct JsonToNdjson;
 
-    impl ParquetToJson {
-        fn decl() -> ConverterDecl {
-            ConverterDecl::simple(
-                "parquet-to-json",
-                PropertyPattern::new().eq("format", "parquet"),
-                PropertyPattern::new().eq("format", "json"),
-            )
-            .description("Read Parquet file to JSON")
+    impl JsonToNdjson {
+        /// Stream JSON from `input` to NDJSON on `output`, holding only one
+        /// element in memory at a time.
+        pub fn convert_streaming(
+            &self,
+            input: impl Read,
+            output: impl Write,
+            props: &Properties,
+        ) -> Result<(), ConvertError> {
+            let mut input = BufReader::new(input);
+            let mut output = std::io::BufWriter::new(output);
+            let mut writer = ElementWriter {
+                out: &mut output,
+                include_keys: flag(props, "ndjson_include_keys", false),
+            };
+
+            let first = first_non_whitespace(&mut input)
+                .map_err(|e| ConvertError::invalid_input("Failed to read JSON", e))?;
+            let mut de = serde_json::Deserializer::from_reader(input);
+            match first {
+                Some(b'[') => de.deserialize_seq(writer),
+                Some(b'{') if flag(props, "ndjson_object_values", false) => {
+                    de.deserialize_map(writer)
+                }
+                _ => serde::Deserialize::deserialize(&mut de)
+                    .and_then(|value: serde_json::Value| writer.write(&value)),
+            }
+            .map_err(|e| ConvertError::invalid_input("Invalid JSON", e))?;
+            de.end()
+                .map_err(|e| ConvertError::invalid_input("Invalid JSON", e))?;
+            output
+                .flush()
+                .map_err(|e| ConvertError::failed("Failed to write NDJSON", e))
+        }
+
+        /// Write the lines for an already-parsed value.
+        fn write_value(
+            &self,
+            value: serde_json::Value,
+            output: &mut Vec<u8>,
+            props: &Properties,
+        ) -> Result<(), serde_json::Error> {
+            let mut writer = ElementWriter {
+                out: output,
+                include_keys: flag(props, "ndjson_include_keys", false),
+            };
+            match value {
+                serde_json::Value::Array(items) => {
+                    items.iter().try_for_each(|item| writer.write(item))
+                }
+                serde_json::Value::Object(map) if flag(props, "ndjson_object_values", false) => map
+                    .into_iter()
+                    .try_for_each(|(key, value)| writer.write_entry(key, value)),
+                value => writer.write(&value),
+            }
+        }
+    }
+
+    /// Peek at the first non-whitespace byte without consuming it.
+    fn first_non_whitespace(input: &mut impl BufRead) -> std::io::Result<Option<u8>> {
+        loop {
+            let buf = input.fill_buf()?;
+            if buf.is_empty() {
+                return Ok(None);
+            }
+            match buf.iter().position(|b| !b.is_ascii_whitespace()) {
+                Some(i) => return Ok(Some(buf[i])),
+                None => {
+                    let len = buf.len();
+                    input.consume(len);
+                }
+            }
         }
     }
 
-    impl Converter for ParquetToJson {
-        fn decl(&self) -> &ConverterDecl {
-            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
-            DECL.get_or_init(Self::decl)
-        }
-
-        fn convert(
-            &self,
-            input: &[u8],
-            _props: &Properties,
-        ) -> Result<ConvertOutput, ConvertError> {
-            let bytes = Bytes::copy_from_slice(input);
-            let builder = ParquetRecordBatchReaderBuilder::try_new(bytes).map_err(|e| {
-                ConvertError::InvalidInput(format!("Failed to read Parquet: {}", e))
-            })?;
+    /// Visitor that writes each array element or object entry as one
+    /// NDJSON line.
+    struct ElementWriter<'a, W: Write> {
+        out: &'a mut W,
+        include_keys: bool,
+    }
+
+    impl<W: Write> ElementWriter<'_, W> {
+        fn write(&mut self, value: &serde_json::Value) -> Result<(), serde_json::Error> {
+            serde_json::to_writer(&mut *self.out, value)?;
+            self.out.write_all(b"\n").map_err(serde_json::Error::io)
+        }
+
+        fn write_entry(
+            &mut self,
+            key: String,
+            value: serde_json::Value,
+        ) -> Result<(), serde_json::Error> {
+            if self.include_keys {
+                self.write(&serde_json::json!({ "key": key, "value": value }))
+            } else {
+                self.write(&value)
+            }
+        }
+    }
+
+    impl<'de, W: Write> Visitor<'de> for ElementWriter<'_, W> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a JSON array or object")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<(), A::Error> {
+            use serde::de::Error;
+            while let Some(item) = seq.next_element::<serde_json::Value>()? {
+                self.write(&item).map_err(A::Error::custom)?;
+            }
+            Ok(())
+        }
+
+        fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<(), A::Error> {
+            use serde::de::Error;
+            while let Some((key, value)) = map.next_entry::<String, serde_json::Value>()? {
+                self.write_entry(key, value).map_err(A::Error::custom)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl Converter for JsonToNdjson {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "serde.json-to-ndjson",
+                    PropertyPattern::new().eq("format", "json"),
+                    PropertyPattern::new().eq("format", "ndjson"),
+                )
+                .description("Convert JSON array (or object values) to newline-delimited JSON")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let mut output = Vec::new();
+            if flag(props, "ndjson_stream", false) {
+                self.convert_streaming(input, &mut output, props)?;
+            } else {
+                let value: serde_json::Value = serde_json::from_slice(input)
+                    .map_err(|e| ConvertError::invalid_input("Invalid JSON", e))?;
+                self.write_value(value, &mut output, props)
+                    .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+            }
+
+            let mut out_props = props.clone();
+            for key in [
+                "ndjson_stream",
+                "ndjson_object_values",
+                "ndjson_include_keys",
+            ] {
+                out_props.shift_remove(key);
+            }
+            out_props.insert("format".into(), "ndjson".into());
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+
+    /// Convert newline-delimited JSON to JSON array.
+    ///
+    /// Input properties:
+    /// - `ndjson_stream`: write each line's value as it is read instead of
+    ///   collecting the whole array first (default false)
+    pub struct NdjsonToJson;
+
+    impl NdjsonToJson {
+        /// Stream NDJSON from `input` to a pretty-printed JSON array on
+        /// `output`, holding only one line in memory at a time.
+        pub fn convert_streaming(
+            &self,
+            input: impl Read,
+            output: impl Write,
+            _props: &Properties,
+        ) -> Result<(), ConvertError> {
+            let mut items = NdjsonItems {
+                lines: BufReader::new(input).lines(),
+                line_num: 0,
+                error: None,
+            };
+            let mut output = std::io::BufWriter::new(output);
+            let mut ser = serde_json::Serializer::pretty(&mut output);
+            let written = serde::Serializer::collect_seq(&mut ser, &mut items);
+
+            // A bad line ends the sequence early; report it over the write result
+            if let Some(error) = items.error {
+                return Err(error);
+            }
+            written.map_err(|e| ConvertError::failed("Failed to write JSON", e))?;
+            output
+                .flush()
+                .map_err(|e| ConvertError::failed("Failed to write JSON", e))
+        }
+    }
+
+    /// Parses NDJSON lines lazily, stopping at the first error.
+    struct NdjsonItems<R> {
+        lines: Lines<BufReader<R>>,
+        line_num: usize,
+        error: Option<ConvertError>,
+    }
+
+    impl<R: Read> Iterator for NdjsonItems<R> {
+        type Item = serde_json::Value;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.error.is_some() {
+                return None;
+            }
+            for line in self.lines.by_ref() {
+                self.line_num += 1;
+                let line = match line {
+                    Ok(line) => line,
+                    Err(e) => {
+                        self.error = Some(ConvertError::invalid_input(
+                            format!("Failed to read line {}", self.line_num),
+                            e,
+                        ));
+                        return None;
+                    }
+                };
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                match serde_json::from_str(line) {
+                    Ok(item) => return Some(item),
+                    Err(e) => {
+                        self.error = Some(ConvertError::invalid_input(
+                            format!("Invalid JSON at line {}", self.line_num),
+                            e,
+                        ));
+                        return None;
+                    }
+                }
+            }
+            None
+        }
+    }
+
+    impl Converter for NdjsonToJson {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "serde.ndjson-to-json",
+                    PropertyPattern::new().eq("format", "ndjson"),
+                    PropertyPattern::new().eq("format", "json"),
+                )
+                .description("Convert newline-delimited JSON to JSON array")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            if flag(props, "ndjson_stream", false) {
+                let mut output = Vec::new();
+                self.convert_streaming(input, &mut output, props)?;
+
+                let mut out_props = props.clone();
+                out_props.shift_remove("ndjson_stream");
+                out_props.insert("format".into(), "json".into());
+                return Ok(ConvertOutput::Single(output, out_props));
+            }
+
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+
+            let mut items = Vec::new();
+            for (line_num, line) in text.lines().enumerate() {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let item: serde_json::Value = serde_json::from_str(line).map_err(|e| {
+                    ConvertError::invalid_input(format!("Invalid JSON at line {}", line_num + 1), e)
+                })?;
+                items.push(item);
+            }
+
+            let array = serde_json::Value::Array(items);
+            let output = serde_json::to_vec_pretty(&array)
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "json".into());
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+}
+
+#[cfg(feature = "ndjson")]
+pub use ndjson_impl::{JsonToNdjson, NdjsonToJson};
+
+// ============================================
+// CSV
+// ============================================
+
+#[cfg(feature = "csv")]
+mod csv_impl {
+    use super::*;
+    use paraphase_core::PropertySchema;
+    use serde_json::{Map, Value};
+
+    /// Convert CSV with a header row to a JSON array of objects.
+    ///
+    /// Input properties:
+    /// - `csv_infer_types`: give each column a type from its cells instead
+    ///   of keeping every cell a string (default false)
+    /// - `csv_infer_rows`: rows scanned to infer column types, 0 for all
+    ///   (default 100)
+    ///
+    /// A column is integer, float or boolean (`true`/`false`/`yes`/`no`)
+    /// only if every non-empty scanned cell is. With inference, empty cells
+    /// become null, and cells after the scanned rows that don't fit their
+    /// column's type stay strings.
+    pub struct CsvToJson;
+
+    /// Inferred type of a CSV column.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum ColumnType {
+        Integer,
+        Float,
+        Boolean,
+        String,
+    }
+
+    impl ColumnType {
+        /// The narrowest type `cell` (non-empty) parses as.
+        fn of(cell: &str) -> Self {
+            if cell.parse::<i64>().is_ok() {
+                Self::Integer
+            } else if cell.parse::<f64>().is_ok_and(f64::is_finite) {
+                Self::Float
+            } else if parse_bool(cell).is_some() {
+                Self::Boolean
+            } else {
+                Self::String
+            }
+        }
+
+        /// The type covering both `self` and `other`.
+        fn widen(self, other: Self) -> Self {
+            match (self, other) {
+                (a, b) if a == b => a,
+                (Self::Integer, Self::Float) | (Self::Float, Self::Integer) => Self::Float,
+                _ => Self::String,
+            }
+        }
+
+        /// Convert a cell of this column, falling back to a string.
+        fn value(self, cell: &str) -> Value {
+            if cell.is_empty() {
+                return Value::Null;
+            }
+            let parsed = match self {
+                Self::Integer => cell.parse::<i64>().ok().map(Value::from),
+                Self::Float => cell
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number),
+                Self::Boolean => parse_bool(cell).map(Value::Bool),
+                Self::String => None,
+            };
+            parsed.unwrap_or_else(|| Value::String(cell.to_string()))
+        }
+    }
+
+    fn parse_bool(cell: &str) -> Option<bool> {
+        match cell.to_ascii_lowercase().as_str() {
+            "true" | "yes" => Some(true),
+            "false" | "no" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Infer each column's type from the first `rows` records (all if 0).
+    fn infer_types(records: &[csv::StringRecord], columns: usize, rows: usize) -> Vec<ColumnType> {
+        let rows = if rows == 0 { records.len() } else { rows };
+        (0..columns)
+            .map(|column| {
+                records
+                    .iter()
+                    .take(rows)
+                    .filter_map(|record| record.get(column))
+                    .filter(|cell| !cell.is_empty())
+                    .map(ColumnType::of)
+                    .reduce(ColumnType::widen)
+                    .unwrap_or(ColumnType::String)
+            })
+            .collect()
+    }
+
+    impl Converter for CsvToJson {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "serde.csv-to-json",
+                    PropertyPattern::new().eq("format", "csv"),
+                    PropertyPattern::new().eq("format", "json"),
+                )
+                .description("Convert CSV rows to a JSON array of objects")
+                .with_property_schema("csv_infer_types", PropertySchema::boolean())
+                .with_property_schema("csv_infer_rows", PropertySchema::integer().min(0.0))
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+
+            let mut reader = csv::Reader::from_reader(input);
+            let headers = reader
+                .headers()
+                .map_err(|e| ConvertError::invalid_input("Invalid CSV header", e))?
+                .clone();
+            let records = reader
+                .records()
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| ConvertError::invalid_input("Invalid CSV", e))?;
+
+            let types = if flag(props, "csv_infer_types", false) {
+                let rows = props
+                    .get("csv_infer_rows")
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or(100);
+                Some(infer_types(&records, headers.len(), rows as usize))
+            } else {
+                None
+            };
+
+            let rows: Vec<Value> = records
+                .iter()
+                .map(|record| {
+                    let object: Map<String, Value> = headers
+                        .iter()
+                        .zip(record.iter())
+                        .enumerate()
+                        .map(|(column, (header, cell))| {
+                            let value = match &types {
+                                Some(types) => types[column].value(cell),
+                                None => Value::String(cell.to_string()),
+                            };
+                            (header.to_string(), value)
+                        })
+                        .collect();
+                    Value::Object(object)
+                })
+                .collect();
+
+            let output = serde_json::to_vec_pretty(&rows)
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+            let mut out_props = props.clone();
+            out_props.shift_remove("csv_infer_types");
+            out_props.shift_remove("csv_infer_rows");
+            out_props.insert("format".into(), "json".into());
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+pub use csv_impl::CsvToJson;
+
+// ============================================
+// JSONPath queries
+// ============================================
+
+#[cfg(feature = "jsonpath")]
+mod jsonpath_impl {
+    use super::*;
+    use serde_json_path::JsonPath;
+
+    /// Filter a JSON document with a JSONPath expression (RFC 9535).
+    ///
+    /// Input properties:
+    /// - `jsonpath`: the query, e.g. `$.store.book[*].author`
+    /// - `unwrap_single`: if true and exactly one value matches, emit it
+    ///   directly instead of wrapping it in an array
+    ///
+    /// Output: JSON array of matched values (or the single match).
+    pub struct JsonPathFilter;
+
+    impl Converter for JsonPathFilter {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "serde.jsonpath-filter",
+                    PropertyPattern::new()
+                        .eq("format", "json")
+                        .exists("jsonpath"),
+                    PropertyPattern::new().eq("format", "json"),
+                )
+                .description("Filter JSON with a JSONPath expression")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let query = props
+                .get("jsonpath")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| ConvertError::MissingProperty("jsonpath".into()))?;
+
+            let path = JsonPath::parse(query).map_err(|e| {
+                ConvertError::invalid_input(format!("Invalid JSONPath '{}'", query), e)
+            })?;
+
+            let unwrap_single = flag(props, "unwrap_single", false);
+
+            let value: serde_json::Value = serde_json::from_slice(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid JSON", e))?;
+
+            let matches = path.query(&value).all();
+            let result = if unwrap_single && matches.len() == 1 {
+                matches[0].clone()
+            } else {
+                serde_json::Value::Array(matches.into_iter().cloned().collect())
+            };
+
+            let output = serde_json::to_vec_pretty(&result)
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "json".into());
+            out_props.shift_remove("jsonpath");
+            out_props.shift_remove("unwrap_single");
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+}
+
+#[cfg(feature = "jsonpath")]
+pub use jsonpath_impl::JsonPathFilter;
+
+// ============================================
+// JSON field projection
+// ============================================
+
+#[cfg(feature = "json")]
+mod project_impl {
+    use super::*;
+    use serde_json::Value as JsonValue;
+
+    /// Keep or drop fields of a JSON document.
+    ///
+    /// Input properties (one of):
+    /// - `json_include_fields`: comma-separated fields to keep, e.g. `name,version`
+    /// - `json_exclude_fields`: comma-separated fields to drop, e.g. `auth.password`
+    ///
+    /// Dotted paths address nested keys; arrays are traversed element-wise,
+    /// so `dependencies.name` applies to every object in `dependencies`.
+    pub struct JsonProjectConverter;
+
+    impl Converter for JsonProjectConverter {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "serde.json-project",
+                    PropertyPattern::new().eq("format", "json"),
+                    PropertyPattern::new().eq("format", "json"),
+                )
+                .description("Keep or drop JSON fields by dotted path")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let include = props.get("json_include_fields").and_then(|v| v.as_str());
+            let exclude = props.get("json_exclude_fields").and_then(|v| v.as_str());
+
+            let mut value: JsonValue = serde_json::from_slice(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid JSON", e))?;
+
+            match (include, exclude) {
+                (Some(include), _) => {
+                    let paths = parse_paths(include);
+                    value = project(&value, &paths).unwrap_or(JsonValue::Null);
+                }
+                (None, Some(exclude)) => {
+                    for path in parse_paths(exclude) {
+                        remove_path(&mut value, &path);
+                    }
+                }
+                (None, None) => {
+                    return Err(ConvertError::MissingProperty(
+                        "json_include_fields or json_exclude_fields".into(),
+                    ));
+                }
+            }
+
+            let output = serde_json::to_vec_pretty(&value)
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "json".into());
+            out_props.shift_remove("json_include_fields");
+            out_props.shift_remove("json_exclude_fields");
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+
+    fn parse_paths(list: &str) -> Vec<Vec<&str>> {
+        list.split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| s.split('.').collect())
+            .collect()
+    }
+
+    /// Keep only the given paths. Returns `None` if nothing under `value` matched.
+    fn project(value: &JsonValue, paths: &[Vec<&str>]) -> Option<JsonValue> {
+        match value {
+            JsonValue::Object(map) => {
+                let mut out = serde_json::Map::new();
+                for (key, child) in map {
+                    let tails: Vec<Vec<&str>> = paths
+                        .iter()
+                        .filter(|p| p[0] == key)
+                        .map(|p| p[1..].to_vec())
+                        .collect();
+                    if tails.is_empty() {
+                        continue;
+                    }
+                    if tails.iter().any(|t| t.is_empty()) {
+                        out.insert(key.clone(), child.clone());
+                    } else if let Some(projected) = project(child, &tails) {
+                        out.insert(key.clone(), projected);
+                    }
+                }
+                Some(JsonValue::Object(out))
+            }
+            JsonValue::Array(items) => Some(JsonValue::Array(
+                items.iter().filter_map(|v| project(v, paths)).collect(),
+            )),
+            _ => None,
+        }
+    }
+
+    fn remove_path(value: &mut JsonValue, path: &[&str]) {
+        match value {
+            JsonValue::Object(map) => match path {
+                [key] => map.retain(|k, _| k != key),
+                [key, rest @ ..] => {
+                    if let Some(child) = map.get_mut(*key) {
+                        remove_path(child, rest);
+                    }
+                }
+                [] => {}
+            },
+            JsonValue::Array(items) => {
+                for item in items {
+                    remove_path(item, path);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+pub use project_impl::JsonProjectConverter;
+
+// ============================================
+// JSON Merge Patch (RFC 7396)
+// ============================================
+
+#[cfg(feature = "json")]
+mod merge_patch_impl {
+    use super::*;
+    use indexmap::IndexMap;
+    use paraphase_core::NamedInput;
+    use serde_json::Value as JsonValue;
+
+    pub(super) fn json_port() -> PortDecl {
+        PortDecl::single(PropertyPattern::new().eq("format", "json"))
+    }
+
+    pub(super) fn parse_input(
+        inputs: &IndexMap<String, NamedInput<'_>>,
+        port: &str,
+    ) -> Result<JsonValue, ConvertError> {
+        let input = inputs
+            .get(port)
+            .ok_or_else(|| ConvertError::MissingInput(port.into()))?;
+        serde_json::from_slice(input.data)
+            .map_err(|e| ConvertError::invalid_input(format!("Invalid JSON in {}", port), e))
+    }
+
+    pub(super) fn single_output(
+        value: &JsonValue,
+        props: &Properties,
+    ) -> Result<ConvertOutput, ConvertError> {
+        let output = serde_json::to_vec_pretty(value)
+            .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+        let mut out_props = props.clone();
+        out_props.insert("format".into(), "json".into());
+        Ok(ConvertOutput::Single(output, out_props))
+    }
+
+    /// Apply an RFC 7396 merge patch to `base`.
+    ///
+    /// `null` in the patch deletes the key from `base`; objects merge
+    /// recursively; anything else replaces the target. Output properties
+    /// come from `base`.
+    pub struct JsonMergePatch;
+
+    impl Converter for JsonMergePatch {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::new("serde.json-merge-patch")
+                    .description("Apply a JSON Merge Patch (RFC 7396)")
+                    .input("base", json_port())
+                    .input("patch", json_port())
+                    .output("out", json_port())
+            })
+        }
+
+        fn convert(
+            &self,
+            _input: &[u8],
+            _props: &Properties,
+        ) -> Result<ConvertOutput, ConvertError> {
+            // Needs both base and patch - use convert_multi
+            Err(ConvertError::MultiInputNotSupported)
+        }
+
+        fn convert_multi(
+            &self,
+            inputs: &IndexMap<String, NamedInput<'_>>,
+        ) -> Result<ConvertOutput, ConvertError> {
+            let mut value = parse_input(inputs, "base")?;
+            let patch = parse_input(inputs, "patch")?;
+            merge_patch(&mut value, &patch);
+            single_output(&value, inputs["base"].props)
+        }
+    }
+
+    /// Create the RFC 7396 merge patch turning `base` into `new`.
+    ///
+    /// Keys missing from `new` become `null`, changed objects are diffed
+    /// recursively and other changed values are copied whole. Merge patches
+    /// can't set a value to `null`; such keys are deleted instead. Output
+    /// properties come from `new`.
+    pub struct JsonMergeDiff;
+
+    impl Converter for JsonMergeDiff {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::new("serde.json-merge-diff")
+                    .description("Create a JSON Merge Patch (RFC 7396) between two inputs")
+                    .input("base", json_port())
+                    .input("new", json_port())
+                    .output("out", json_port())
+            })
+        }
+
+        fn convert(
+            &self,
+            _input: &[u8],
+            _props: &Properties,
+        ) -> Result<ConvertOutput, ConvertError> {
+            // Needs both base and new - use convert_multi
+            Err(ConvertError::MultiInputNotSupported)
+        }
+
+        fn convert_multi(
+            &self,
+            inputs: &IndexMap<String, NamedInput<'_>>,
+        ) -> Result<ConvertOutput, ConvertError> {
+            let base = parse_input(inputs, "base")?;
+            let new = parse_input(inputs, "new")?;
+            single_output(&merge_diff(&base, &new), inputs["new"].props)
+        }
+    }
+
+    /// RFC 7396 section 2 `MergePatch(Target, Patch)`.
+    fn merge_patch(target: &mut JsonValue, patch: &JsonValue) {
+        let JsonValue::Object(patch) = patch else {
+            *target = patch.clone();
+            return;
+        };
+        if !target.is_object() {
+            *target = JsonValue::Object(serde_json::Map::new());
+        }
+        let JsonValue::Object(map) = target else {
+            unreachable!()
+        };
+        for (key, value) in patch {
+            if value.is_null() {
+                map.remove(key);
+            } else {
+                merge_patch(map.entry(key.clone()).or_insert(JsonValue::Null), value);
+            }
+        }
+    }
+
+    fn merge_diff(base: &JsonValue, new: &JsonValue) -> JsonValue {
+        let (JsonValue::Object(base), JsonValue::Object(new)) = (base, new) else {
+            return new.clone();
+        };
+        let mut patch = serde_json::Map::new();
+        for key in base.keys() {
+            if new.get(key).is_none_or(JsonValue::is_null) {
+                patch.insert(key.clone(), JsonValue::Null);
+            }
+        }
+        for (key, value) in new {
+            if value.is_null() {
+                continue;
+            }
+            match base.get(key) {
+                Some(old) if old == value => {}
+                Some(old) => {
+                    patch.insert(key.clone(), merge_diff(old, value));
+                }
+                None => {
+                    patch.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        JsonValue::Object(patch)
+    }
+}
+
+#[cfg(feature = "json")]
+pub use merge_patch_impl::{JsonMergeDiff, JsonMergePatch};
+
+// ============================================
+// JSON Patch (RFC 6902)
+// ============================================
+
+#[cfg(feature = "json")]
+mod json_patch_impl {
+    use super::merge_patch_impl::{json_port, parse_input, single_output};
+    use super::*;
+    use indexmap::IndexMap;
+    use json_patch::{Patch, PatchErrorKind, PatchOperation};
+    use paraphase_core::{NamedInput, PropertySchema};
+
+    /// Apply an RFC 6902 JSON Patch to `base`.
+    ///
+    /// The `patch` input is an array of operations (`add`, `remove`,
+    /// `replace`, `move`, `copy`, `test`), applied in order. Any failing
+    /// operation fails the conversion with `InvalidInput`; see
+    /// [`with_test_failure_as_error`](Self::with_test_failure_as_error) for
+    /// `test`. Output properties come from `base`.
+    ///
+    /// Options (via `base` properties):
+    /// - `json_patch_test_failure_as_error`: overrides
+    ///   [`with_test_failure_as_error`](Self::with_test_failure_as_error)
+    pub struct JsonPatchApply {
+        test_failure_as_error: bool,
+    }
+
+    impl JsonPatchApply {
+        pub fn new() -> Self {
+            Self {
+                test_failure_as_error: true,
+            }
+        }
+
+        /// Whether a failed `test` operation is an error (default: true).
+        ///
+        /// When false, the failed `test` is skipped and the remaining
+        /// operations still apply.
+        pub fn with_test_failure_as_error(mut self, enabled: bool) -> Self {
+            self.test_failure_as_error = enabled;
+            self
+        }
+    }
+
+    impl Default for JsonPatchApply {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Converter for JsonPatchApply {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::new("serde.json-patch-apply")
+                    .description("Apply a JSON Patch (RFC 6902)")
+                    .input("base", json_port())
+                    .input("patch", json_port())
+                    .output("out", json_port())
+                    .with_property_schema(
+                        "json_patch_test_failure_as_error",
+                        PropertySchema::boolean(),
+                    )
+            })
+        }
+
+        fn convert(
+            &self,
+            _input: &[u8],
+            _props: &Properties,
+        ) -> Result<ConvertOutput, ConvertError> {
+            // Needs both base and patch - use convert_multi
+            Err(ConvertError::MultiInputNotSupported)
+        }
+
+        fn convert_multi(
+            &self,
+            inputs: &IndexMap<String, NamedInput<'_>>,
+        ) -> Result<ConvertOutput, ConvertError> {
+            let mut value = parse_input(inputs, "base")?;
+            let base_props = inputs["base"].props;
+            self.decl().check_props(base_props)?;
+            let test_failure_as_error = flag(
+                base_props,
+                "json_patch_test_failure_as_error",
+                self.test_failure_as_error,
+            );
+
+            let Patch(operations) = serde_json::from_value(parse_input(inputs, "patch")?)
+                .map_err(|e| ConvertError::invalid_input("Invalid JSON Patch", e))?;
+            // One operation at a time, so a failed `test` can be skipped
+            for (index, operation) in operations.iter().enumerate() {
+                if let Err(e) = json_patch::patch(&mut value, std::slice::from_ref(operation)) {
+                    let skip = matches!(operation, PatchOperation::Test(_))
+                        && matches!(e.kind, PatchErrorKind::TestFailed)
+                        && !test_failure_as_error;
+                    if !skip {
+                        return Err(ConvertError::invalid_input_msg(format!(
+                            "JSON Patch operation {} failed at '{}': {}",
+                            index, e.path, e.kind
+                        )));
+                    }
+                }
+            }
+
+            let mut out_props = base_props.clone();
+            out_props.shift_remove("json_patch_test_failure_as_error");
+            single_output(&value, &out_props)
+        }
+    }
+
+    /// Create an RFC 6902 JSON Patch turning `base` into `new`.
+    ///
+    /// Objects are compared key by key and arrays index by index; values
+    /// that differ are replaced whole. Output properties come from `new`.
+    pub struct JsonPatchGenerate;
+
+    impl Converter for JsonPatchGenerate {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::new("serde.json-patch-generate")
+                    .description("Create a JSON Patch (RFC 6902) between two inputs")
+                    .input("base", json_port())
+                    .input("new", json_port())
+                    .output("out", json_port())
+            })
+        }
+
+        fn convert(
+            &self,
+            _input: &[u8],
+            _props: &Properties,
+        ) -> Result<ConvertOutput, ConvertError> {
+            // Needs both base and new - use convert_multi
+            Err(ConvertError::MultiInputNotSupported)
+        }
+
+        fn convert_multi(
+            &self,
+            inputs: &IndexMap<String, NamedInput<'_>>,
+        ) -> Result<ConvertOutput, ConvertError> {
+            let base = parse_input(inputs, "base")?;
+            let new = parse_input(inputs, "new")?;
+            let patch = serde_json::to_value(json_patch::diff(&base, &new))
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+            single_output(&patch, inputs["new"].props)
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+pub use json_patch_impl::{JsonPatchApply, JsonPatchGenerate};
+
+// ============================================
+// Compression (gzip, zstd, brotli, bzip2, snappy)
+// ============================================
+
+#[cfg(feature = "gzip")]
+mod gzip_impl {
+    use super::*;
+    use flate2::Compression;
+    use flate2::read::{
+        DeflateDecoder, DeflateEncoder, GzDecoder, GzEncoder, ZlibDecoder, ZlibEncoder,
+    };
+    use paraphase_core::PropertySchema;
+    use std::io::Read;
+
+    /// Compression level from the `level` property (0-9, default 6).
+    fn level(props: &Properties) -> Compression {
+        props
+            .get("level")
+            .and_then(|v| v.as_i64())
+            .map(|l| Compression::new(l.clamp(0, 9) as u32))
+            .unwrap_or_default()
+    }
+
+    /// Output properties for compressed data, tracking the inner format.
+    fn compressed_props(props: &Properties, format: &str) -> Properties {
+        let mut out_props = props.clone();
+        // Track inner format for decompression
+        if let Some(inner) = props.get("format") {
+            out_props.insert("inner_format".into(), inner.clone());
+        }
+        out_props.insert("format".into(), format.into());
+        out_props.shift_remove("level");
+        out_props
+    }
+
+    /// Compress bytes with gzip.
+    ///
+    /// Options: `level` (0-9, default 6).
+    pub struct GzipCompress;
+
+    impl Converter for GzipCompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                // Accept any format - compression is format-agnostic
+                ConverterDecl::simple(
+                    "compression.gzip",
+                    PropertyPattern::new(),
+                    PropertyPattern::new().eq("format", "gzip"),
+                )
+                .description("Compress with gzip")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let mut encoder = GzEncoder::new(input, level(props));
+            let mut output = Vec::new();
+            encoder
+                .read_to_end(&mut output)
+                .map_err(|e| ConvertError::failed("Gzip compression failed", e))?;
+            Ok(ConvertOutput::Single(
+                output,
+                compressed_props(props, "gzip"),
+            ))
+        }
+
+        fn estimate_output_size(&self, input_len: usize, _props: &Properties) -> Option<usize> {
+            Some(compressed_size_estimate(input_len))
+        }
+    }
+
+    /// Decompress gzip bytes.
+    pub struct GzipDecompress;
+
+    impl Converter for GzipDecompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "compression.gzip-to-raw",
+                    PropertyPattern::new().eq("format", "gzip"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decompress gzip")
+                .restore("format", "inner_format")
+                .restore_flag("restore_inner_format")
+                .with_property_schema("restore_inner_format", PropertySchema::boolean())
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let mut decoder = GzDecoder::new(input);
+            let mut output = Vec::new();
+            decoder
+                .read_to_end(&mut output)
+                .map_err(|e| ConvertError::invalid_input("Gzip decompression failed", e))?;
+            Ok(ConvertOutput::Single(output, decompressed_props(props)))
+        }
+
+        /// Unknown without decompressing: gzip can expand data 10-100x and
+        /// more, so let the executor's heuristics decide.
+        fn estimate_output_size(&self, _input_len: usize, _props: &Properties) -> Option<usize> {
+            None
+        }
+    }
+
+    /// Compress bytes with zlib (deflate with a zlib header).
+    ///
+    /// Options: `level` (0-9, default 6).
+    pub struct ZlibCompress;
+
+    impl Converter for ZlibCompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "compression.zlib",
+                    PropertyPattern::new(),
+                    PropertyPattern::new().eq("format", "zlib"),
+                )
+                .description("Compress with zlib")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let mut encoder = ZlibEncoder::new(input, level(props));
+            let mut output = Vec::new();
+            encoder
+                .read_to_end(&mut output)
+                .map_err(|e| ConvertError::failed("Zlib compression failed", e))?;
+            Ok(ConvertOutput::Single(
+                output,
+                compressed_props(props, "zlib"),
+            ))
+        }
+
+        fn estimate_output_size(&self, input_len: usize, _props: &Properties) -> Option<usize> {
+            Some(compressed_size_estimate(input_len))
+        }
+    }
+
+    /// Decompress zlib bytes.
+    pub struct ZlibDecompress;
+
+    impl Converter for ZlibDecompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "compression.zlib-to-raw",
+                    PropertyPattern::new().eq("format", "zlib"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decompress zlib")
+                .restore("format", "inner_format")
+                .restore_flag("restore_inner_format")
+                .with_property_schema("restore_inner_format", PropertySchema::boolean())
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let mut decoder = ZlibDecoder::new(input);
+            let mut output = Vec::new();
+            decoder
+                .read_to_end(&mut output)
+                .map_err(|e| ConvertError::invalid_input("Zlib decompression failed", e))?;
+            Ok(ConvertOutput::Single(output, decompressed_props(props)))
+        }
+    }
+
+    /// Compress bytes with raw deflate (no header or checksum).
+    ///
+    /// Options: `level` (0-9, default 6).
+    pub struct DeflateCompress;
+
+    impl Converter for DeflateCompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "compression.deflate",
+                    PropertyPattern::new(),
+                    PropertyPattern::new().eq("format", "deflate"),
+                )
+                .description("Compress with raw deflate")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let mut encoder = DeflateEncoder::new(input, level(props));
+            let mut output = Vec::new();
+            encoder
+                .read_to_end(&mut output)
+                .map_err(|e| ConvertError::failed("Deflate compression failed", e))?;
+            Ok(ConvertOutput::Single(
+                output,
+                compressed_props(props, "deflate"),
+            ))
+        }
+
+        fn estimate_output_size(&self, input_len: usize, _props: &Properties) -> Option<usize> {
+            Some(compressed_size_estimate(input_len))
+        }
+    }
+
+    /// Decompress raw deflate bytes.
+    pub struct DeflateDecompress;
+
+    impl Converter for DeflateDecompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "compression.deflate-to-raw",
+                    PropertyPattern::new().eq("format", "deflate"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decompress raw deflate")
+                .restore("format", "inner_format")
+                .restore_flag("restore_inner_format")
+                .with_property_schema("restore_inner_format", PropertySchema::boolean())
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let mut decoder = DeflateDecoder::new(input);
+            let mut output = Vec::new();
+            decoder
+                .read_to_end(&mut output)
+                .map_err(|e| ConvertError::invalid_input("Deflate decompression failed", e))?;
+            Ok(ConvertOutput::Single(output, decompressed_props(props)))
+        }
+    }
+}
+
+#[cfg(feature = "gzip")]
+pub use gzip_impl::{
+    DeflateCompress, DeflateDecompress, GzipCompress, GzipDecompress, ZlibCompress, ZlibDecompress,
+};
+
+#[cfg(feature = "zstd")]
+mod zstd_impl {
+    use super::*;
+    use paraphase_core::PropertySchema;
+
+    /// Compress bytes with zstd.
+    pub struct ZstdCompress;
+
+    impl Converter for ZstdCompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                // Accept any format - compression is format-agnostic
+                ConverterDecl::simple(
+                    "compression.zstd",
+                    PropertyPattern::new(),
+                    PropertyPattern::new().eq("format", "zstd"),
+                )
+                .description("Compress with zstd")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let output = zstd::encode_all(input, 0)
+                .map_err(|e| ConvertError::failed("Zstd compression failed", e))?;
+            let mut out_props = props.clone();
+            if let Some(inner) = props.get("format") {
+                out_props.insert("inner_format".into(), inner.clone());
+            }
+            out_props.insert("format".into(), "zstd".into());
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+
+        fn estimate_output_size(&self, input_len: usize, _props: &Properties) -> Option<usize> {
+            Some(compressed_size_estimate(input_len))
+        }
+    }
+
+    /// Decompress zstd bytes.
+    pub struct ZstdDecompress;
+
+    impl Converter for ZstdDecompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "compression.zstd-to-raw",
+                    PropertyPattern::new().eq("format", "zstd"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decompress zstd")
+                .restore("format", "inner_format")
+                .restore_flag("restore_inner_format")
+                .with_property_schema("restore_inner_format", PropertySchema::boolean())
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let output = zstd::decode_all(input)
+                .map_err(|e| ConvertError::invalid_input("Zstd decompression failed", e))?;
+            Ok(ConvertOutput::Single(output, decompressed_props(props)))
+        }
+    }
+}
+
+#[cfg(feature = "zstd")]
+pub use zstd_impl::{ZstdCompress, ZstdDecompress};
+
+#[cfg(feature = "brotli")]
+mod brotli_impl {
+    use super::*;
+    use paraphase_core::PropertySchema;
+    use std::io::Read;
+
+    /// Compress bytes with brotli.
+    pub struct BrotliCompress;
+
+    impl Converter for BrotliCompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                // Accept any format - compression is format-agnostic
+                ConverterDecl::simple(
+                    "compression.brotli",
+                    PropertyPattern::new(),
+                    PropertyPattern::new().eq("format", "brotli"),
+                )
+                .description("Compress with brotli")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let mut output = Vec::new();
+            let mut compressor = brotli::CompressorReader::new(input, 4096, 6, 22);
+            compressor
+                .read_to_end(&mut output)
+                .map_err(|e| ConvertError::failed("Brotli compression failed", e))?;
+            let mut out_props = props.clone();
+            if let Some(inner) = props.get("format") {
+                out_props.insert("inner_format".into(), inner.clone());
+            }
+            out_props.insert("format".into(), "brotli".into());
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+
+        fn estimate_output_size(&self, input_len: usize, _props: &Properties) -> Option<usize> {
+            Some(compressed_size_estimate(input_len))
+        }
+    }
+
+    /// Decompress brotli bytes.
+    pub struct BrotliDecompress;
+
+    impl Converter for BrotliDecompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "compression.brotli-to-raw",
+                    PropertyPattern::new().eq("format", "brotli"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decompress brotli")
+                .restore("format", "inner_format")
+                .restore_flag("restore_inner_format")
+                .with_property_schema("restore_inner_format", PropertySchema::boolean())
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let mut output = Vec::new();
+            let mut decompressor = brotli::Decompressor::new(input, 4096);
+            decompressor
+                .read_to_end(&mut output)
+                .map_err(|e| ConvertError::invalid_input("Brotli decompression failed", e))?;
+            Ok(ConvertOutput::Single(output, decompressed_props(props)))
+        }
+    }
+}
+
+#[cfg(feature = "brotli")]
+pub use brotli_impl::{BrotliCompress, BrotliDecompress};
+
+#[cfg(feature = "bzip2")]
+mod bzip2_impl {
+    use super::*;
+    use bzip2::Compression;
+    use bzip2::read::{BzDecoder, BzEncoder};
+    use paraphase_core::PropertySchema;
+    use std::io::Read;
+
+    /// Compress bytes with bzip2.
+    ///
+    /// Options: `bzip2_level` (1-9, default 6).
+    pub struct Bzip2Compress;
+
+    impl Converter for Bzip2Compress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                // Accept any format - compression is format-agnostic
+                ConverterDecl::simple(
+                    "compression.bzip2",
+                    PropertyPattern::new(),
+                    PropertyPattern::new().eq("format", "bz2"),
+                )
+                .description("Compress with bzip2")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let level = props
+                .get("bzip2_level")
+                .and_then(|v| v.as_i64())
+                .map_or(6, |l| l.clamp(1, 9) as u32);
+            let mut encoder = BzEncoder::new(input, Compression::new(level));
+            let mut output = Vec::new();
+            encoder
+                .read_to_end(&mut output)
+                .map_err(|e| ConvertError::failed("Bzip2 compression failed", e))?;
+
+            let mut out_props = props.clone();
+            if let Some(inner) = props.get("format") {
+                out_props.insert("inner_format".into(), inner.clone());
+            }
+            out_props.insert("format".into(), "bz2".into());
+            out_props.shift_remove("bzip2_level");
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+
+        fn estimate_output_size(&self, input_len: usize, _props: &Properties) -> Option<usize> {
+            Some(compressed_size_estimate(input_len))
+        }
+    }
+
+    /// Decompress bzip2 bytes.
+    pub struct Bzip2Decompress;
+
+    impl Converter for Bzip2Decompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "compression.bzip2-to-raw",
+                    PropertyPattern::new().eq("format", "bz2"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decompress bzip2")
+                .restore("format", "inner_format")
+                .restore_flag("restore_inner_format")
+                .with_property_schema("restore_inner_format", PropertySchema::boolean())
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let mut decoder = BzDecoder::new(input);
+            let mut output = Vec::new();
+            decoder
+                .read_to_end(&mut output)
+                .map_err(|e| ConvertError::invalid_input("Bzip2 decompression failed", e))?;
+            Ok(ConvertOutput::Single(output, decompressed_props(props)))
+        }
+    }
+}
+
+#[cfg(feature = "bzip2")]
+pub use bzip2_impl::{Bzip2Compress, Bzip2Decompress};
+
+#[cfg(feature = "snappy")]
+mod snappy_impl {
+    use super::*;
+    use paraphase_core::{Predicate, PropertySchema, Value};
+    use std::io::Read;
+
+    fn snappy_formats() -> PropertyPattern {
+        PropertyPattern::new().with(
+            "format",
+            Predicate::OneOf(vec![Value::from("snappy"), Value::from("snappy-raw")]),
+        )
+    }
+
+    /// Compress bytes with Snappy.
+    ///
+    /// Snappy has no compression levels; it always favours speed.
+    ///
+    /// Options:
+    /// - `snappy_framing`: use the framing format, as read by Kafka and
+    ///   Parquet tooling (default: true, format "snappy"); false writes a
+    ///   single raw block (format "snappy-raw")
+    pub struct SnappyCompress;
+
+    impl Converter for SnappyCompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                // Accept any format - compression is format-agnostic
+                ConverterDecl::simple(
+                    "compression.snappy",
+                    PropertyPattern::new(),
+                    snappy_formats(),
+                )
+                .description("Compress with Snappy")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let (output, format) = if flag(props, "snappy_framing", true) {
+                let mut encoder = snap::read::FrameEncoder::new(input);
+                let mut output = Vec::new();
+                encoder
+                    .read_to_end(&mut output)
+                    .map_err(|e| ConvertError::failed("Snappy compression failed", e))?;
+                (output, "snappy")
+            } else {
+                let output = snap::raw::Encoder::new()
+                    .compress_vec(input)
+                    .map_err(|e| ConvertError::failed("Snappy compression failed", e))?;
+                (output, "snappy-raw")
+            };
+
+            let mut out_props = props.clone();
+            if let Some(inner) = props.get("format") {
+                out_props.insert("inner_format".into(), inner.clone());
+            }
+            out_props.insert("format".into(), format.into());
+            out_props.shift_remove("snappy_framing");
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+
+        fn estimate_output_size(&self, input_len: usize, _props: &Properties) -> Option<usize> {
+            Some(compressed_size_estimate(input_len))
+        }
+    }
+
+    /// Decompress Snappy bytes.
+    ///
+    /// The input `format` selects the framed ("snappy") or raw ("snappy-raw")
+    /// decoder.
+    pub struct SnappyDecompress;
+
+    impl Converter for SnappyDecompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "compression.snappy-to-raw",
+                    snappy_formats(),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decompress Snappy")
+                .restore("format", "inner_format")
+                .restore_flag("restore_inner_format")
+                .with_property_schema("restore_inner_format", PropertySchema::boolean())
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let raw = props.get("format").and_then(|v| v.as_str()) == Some("snappy-raw");
+            let output = if raw {
+                snap::raw::Decoder::new()
+                    .decompress_vec(input)
+                    .map_err(|e| ConvertError::invalid_input("Snappy decompression failed", e))?
+            } else {
+                let mut decoder = snap::read::FrameDecoder::new(input);
+                let mut output = Vec::new();
+                decoder
+                    .read_to_end(&mut output)
+                    .map_err(|e| ConvertError::invalid_input("Snappy decompression failed", e))?;
+                output
+            };
+            Ok(ConvertOutput::Single(output, decompressed_props(props)))
+        }
+    }
+}
+
+#[cfg(feature = "snappy")]
+pub use snappy_impl::{SnappyCompress, SnappyDecompress};
+
+#[cfg(any(
+    feature = "gzip",
+    feature = "zstd",
+    feature = "bzip2",
+    feature = "brotli"
+))]
+mod auto_decompress_impl {
+    use super::*;
+    use paraphase_core::PropertySchema;
+
+    /// Decompress data of unknown compression.
+    ///
+    /// The compression is sniffed from magic bytes: gzip (`1F 8B`), zstd
+    /// (`28 B5 2F FD`) and bzip2 (`BZh`). Brotli has no magic bytes, so it
+    /// is tried last. The `format` property is ignored, so mislabeled data
+    /// works too; the detected compression is recorded as `compression`.
+    /// Like the other decompressors, the output `format` is restored from
+    /// `inner_format` when known.
+    ///
+    /// Only compressions whose feature is enabled are detected.
+    pub struct AutoDecompress;
+
+    impl Converter for AutoDecompress {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "compression.auto-decompress",
+                    PropertyPattern::new().eq("format", "compressed"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Decompress gzip, zstd, bzip2 or brotli, detected from the data")
+                .restore("format", "inner_format")
+                .restore_flag("restore_inner_format")
+                .with_property_schema("restore_inner_format", PropertySchema::boolean())
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let (compression, output) = match input {
+                #[cfg(feature = "gzip")]
+                [0x1f, 0x8b, ..] => ("gzip", GzipDecompress.convert(input, props)?),
+                #[cfg(feature = "zstd")]
+                [0x28, 0xb5, 0x2f, 0xfd, ..] => ("zstd", ZstdDecompress.convert(input, props)?),
+                #[cfg(feature = "bzip2")]
+                [b'B', b'Z', b'h', ..] => ("bzip2", Bzip2Decompress.convert(input, props)?),
+                #[cfg(feature = "brotli")]
+                _ => match BrotliDecompress.convert(input, props) {
+                    Ok(output) => ("brotli", output),
+                    Err(_) => return Err(unrecognized()),
+                },
+                #[cfg(not(feature = "brotli"))]
+                _ => return Err(unrecognized()),
+            };
+
+            match output {
+                ConvertOutput::Single(data, mut out_props) => {
+                    out_props.insert("compression".into(), compression.into());
+                    Ok(ConvertOutput::Single(data, out_props))
+                }
+                output => Ok(output),
+            }
+        }
+    }
+
+    fn unrecognized() -> ConvertError {
+        ConvertError::invalid_input_msg("Data does not match any known compression format")
+    }
+}
+
+#[cfg(any(
+    feature = "gzip",
+    feature = "zstd",
+    feature = "bzip2",
+    feature = "brotli"
+))]
+pub use auto_decompress_impl::AutoDecompress;
+
+// ============================================
+// Binary diff (bsdiff)
+// ============================================
+
+#[cfg(feature = "bsdiff")]
+mod bsdiff_impl {
+    use super::*;
+    use indexmap::IndexMap;
+    use paraphase_core::NamedInput;
+
+    /// Create a bsdiff patch turning `base` into `new`.
+    ///
+    /// Output properties come from `new`, with `format: "bsdiff"` and the
+    /// original format kept as `inner_format`.
+    pub struct BinaryDiff;
+
+    impl Converter for BinaryDiff {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::new("diff.bsdiff")
+                    .description("Create a binary patch between two inputs")
+                    .input("base", PortDecl::single(PropertyPattern::new()))
+                    .input("new", PortDecl::single(PropertyPattern::new()))
+                    .output(
+                        "out",
+                        PortDecl::single(PropertyPattern::new().eq("format", "bsdiff")),
+                    )
+            })
+        }
+
+        fn convert(
+            &self,
+            _input: &[u8],
+            _props: &Properties,
+        ) -> Result<ConvertOutput, ConvertError> {
+            // Needs both base and new - use convert_multi
+            Err(ConvertError::MultiInputNotSupported)
+        }
+
+        fn convert_multi(
+            &self,
+            inputs: &IndexMap<String, NamedInput<'_>>,
+        ) -> Result<ConvertOutput, ConvertError> {
+            let base = inputs
+                .get("base")
+                .ok_or_else(|| ConvertError::MissingInput("base".into()))?;
+            let new = inputs
+                .get("new")
+                .ok_or_else(|| ConvertError::MissingInput("new".into()))?;
+
+            let mut patch = Vec::new();
+            bsdiff::diff(base.data, new.data, &mut patch)
+                .map_err(|e| ConvertError::failed("bsdiff failed", e))?;
+
+            let mut out_props = new.props.clone();
+            if let Some(inner) = new.props.get("format") {
+                out_props.insert("inner_format".into(), inner.clone());
+            }
+            out_props.insert("format".into(), "bsdiff".into());
+            Ok(ConvertOutput::Single(patch, out_props))
+        }
+    }
+
+    /// Apply a bsdiff patch to `base`.
+    ///
+    /// The output format is the patch's `inner_format`, or `raw` if unknown.
+    pub struct BinaryPatch;
+
+    impl Converter for BinaryPatch {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::new("diff.bspatch")
+                    .description("Apply a binary patch to a base input")
+                    .input(
+                        "patch",
+                        PortDecl::single(PropertyPattern::new().eq("format", "bsdiff")),
+                    )
+                    .input("base", PortDecl::single(PropertyPattern::new()))
+                    .output("out", PortDecl::single(PropertyPattern::new()))
+            })
+        }
+
+        fn convert(
+            &self,
+            _input: &[u8],
+            _props: &Properties,
+        ) -> Result<ConvertOutput, ConvertError> {
+            // Needs both patch and base - use convert_multi
+            Err(ConvertError::MultiInputNotSupported)
+        }
+
+        fn convert_multi(
+            &self,
+            inputs: &IndexMap<String, NamedInput<'_>>,
+        ) -> Result<ConvertOutput, ConvertError> {
+            let patch = inputs
+                .get("patch")
+                .ok_or_else(|| ConvertError::MissingInput("patch".into()))?;
+            let base = inputs
+                .get("base")
+                .ok_or_else(|| ConvertError::MissingInput("base".into()))?;
+
+            let mut output = Vec::new();
+            bsdiff::patch(base.data, &mut &*patch.data, &mut output)
+                .map_err(|e| ConvertError::invalid_input("Invalid bsdiff patch", e))?;
+
+            let mut out_props = patch.props.clone();
+            let format = out_props
+                .shift_remove("inner_format")
+                .unwrap_or_else(|| "raw".into());
+            out_props.insert("format".into(), format);
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+}
+
+#[cfg(feature = "bsdiff")]
+pub use bsdiff_impl::{BinaryDiff, BinaryPatch};
+
+// ============================================
+// INI config format
+// ============================================
+
+#[cfg(feature = "ini")]
+mod ini_impl {
+    use super::*;
+    use indexmap::IndexMap;
+    use ini::Ini;
+
+    const OPTIONS: [&str; 6] = [
+        "ini_multi_value",
+        "ini_lowercase_keys",
+        "ini_comment_char",
+        "ini_global_section",
+        "ini_comments",
+        "ini_file_comment",
+    ];
+
+    /// JSON key holding key comments embedded in a `JsonToIni` input.
+    const COMMENTS_KEY: &str = "_comments";
+
+    /// Name of the JSON key holding keys outside any section (default: "").
+    fn global_section(props: &Properties) -> String {
+        props
+            .get("ini_global_section")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string()
+    }
+
+    /// Escape lines starting with whichever of `;`/`#` is not a comment
+    /// character, so the parser reads them as keys.
+    fn escape_non_comments(text: &str, comment_char: &str) -> Result<String, ConvertError> {
+        let literal = match comment_char {
+            "both" => return Ok(text.to_string()),
+            ";" => '#',
+            "#" => ';',
+            other => {
+                return Err(ConvertError::invalid_input_msg(format!(
+                    "Unknown ini_comment_char: {} (expected \";\", \"#\" or \"both\")",
+                    other
+                )));
+            }
+        };
+
+        let mut out = String::with_capacity(text.len());
+        for line in text.split_inclusive('\n') {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with(literal) {
+                out.push_str(&line[..line.len() - trimmed.len()]);
+                out.push('\\');
+                out.push_str(trimmed);
+            } else {
+                out.push_str(line);
+            }
+        }
+        Ok(out)
+    }
+
+    /// Comments by `"section.key"` path (plain `"key"` for the global
+    /// section). Entries in `ini_comments` override embedded `_comments`.
+    fn key_comments(
+        obj: &serde_json::Map<String, serde_json::Value>,
+        props: &Properties,
+    ) -> IndexMap<String, String> {
+        let mut comments: IndexMap<String, String> = obj
+            .get(COMMENTS_KEY)
+            .and_then(|v| v.as_object())
+            .into_iter()
+            .flatten()
+            .filter_map(|(path, c)| Some((path.clone(), c.as_str()?.to_string())))
+            .collect();
+
+        let overrides = props.get("ini_comments").and_then(|v| v.as_object());
+        for (path, c) in overrides.into_iter().flatten() {
+            if let Some(c) = c.as_str() {
+                comments.insert(path.clone(), c.to_string());
+            }
+        }
+        comments
+    }
+
+    /// Write `comment` as one comment line per line of text.
+    fn push_comment(out: &mut String, marker: char, comment: &str) {
+        for line in comment.lines() {
+            out.push(marker);
+            if !line.is_empty() {
+                out.push(' ');
+                out.push_str(line);
+            }
+            out.push_str(ini::LineSeparator::SystemDefault.as_str());
+        }
+    }
+
+    /// Add comments to the output of `Ini::write_to`.
+    ///
+    /// The writer emits one line per key (newlines in values are escaped)
+    /// in `ini.iter()` order, so its output is walked alongside the
+    /// sections to find where each key's line starts.
+    fn insert_comments(
+        ini: &Ini,
+        written: &str,
+        comments: &IndexMap<String, String>,
+        file_comment: Option<&str>,
+        marker: char,
+    ) -> String {
+        let mut lines = written.split_inclusive('\n');
+        let mut out = String::with_capacity(written.len());
+
+        if let Some(comment) = file_comment {
+            push_comment(&mut out, marker, comment);
+            out.push_str(ini::LineSeparator::SystemDefault.as_str());
+        }
+
+        let mut first = true;
+        for (section, properties) in ini.iter() {
+            // Blank line between non-empty sections, then the header
+            if !properties.is_empty() && !std::mem::take(&mut first) {
+                out.push_str(lines.next().unwrap_or_default());
+            }
+            if section.is_some() {
+                out.push_str(lines.next().unwrap_or_default());
+            }
+            for (key, _) in properties.iter() {
+                let path = match section {
+                    Some(section) => format!("{}.{}", section, key),
+                    None => key.to_string(),
+                };
+                if let Some(comment) = comments.get(&path) {
+                    push_comment(&mut out, marker, comment);
+                }
+                out.push_str(lines.next().unwrap_or_default());
+            }
+        }
+
+        out.extend(lines);
+        out
+    }
+
+    /// Convert INI to JSON.
+    ///
+    /// Options (via properties):
+    /// - `ini_multi_value`: collect duplicate keys into an array (default: false, last wins)
+    /// - `ini_lowercase_keys`: lowercase key names (default: false)
+    /// - `ini_comment_char`: `";"`, `"#"` or `"both"` (default: `"both"`)
+    /// - `ini_global_section`: JSON key for keys outside any section (default: `""`)
+    pub struct IniToJson;
+
+    impl Converter for IniToJson {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "config.ini-to-json",
+                    PropertyPattern::new().eq("format", "ini"),
+                    PropertyPattern::new().eq("format", "json"),
+                )
+                .description("Convert INI to JSON")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            let comment_char = props
+                .get("ini_comment_char")
+                .and_then(|v| v.as_str())
+                .unwrap_or("both");
+            let text = escape_non_comments(text, comment_char)?;
+            let ini = Ini::load_from_str(&text)
+                .map_err(|e| ConvertError::invalid_input("Invalid INI", e))?;
+
+            let multi_value = flag(props, "ini_multi_value", false);
+            let lowercase_keys = flag(props, "ini_lowercase_keys", false);
+            let global = global_section(props);
+
+            // Convert to JSON object; repeated sections are merged
+            let mut root = serde_json::Map::new();
+            for (section, properties) in ini.iter() {
+                let section_name = section.map_or_else(|| global.clone(), str::to_string);
+                let section_obj = root
+                    .entry(section_name)
+                    .or_insert_with(|| serde_json::Value::Object(Default::default()))
+                    .as_object_mut()
+                    .expect("sections are objects");
+
+                for (key, value) in properties.iter() {
+                    let key = if lowercase_keys {
+                        key.to_lowercase()
+                    } else {
+                        key.to_string()
+                    };
+                    let value = serde_json::Value::String(value.to_string());
+
+                    match section_obj.get_mut(&key) {
+                        Some(serde_json::Value::Array(values)) if multi_value => values.push(value),
+                        Some(existing) if multi_value => {
+                            let first = existing.take();
+                            *existing = serde_json::Value::Array(vec![first, value]);
+                        }
+                        _ => {
+                            section_obj.insert(key, value);
+                        }
+                    }
+                }
+            }
+
+            let output = serde_json::to_vec_pretty(&root)
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+            let mut out_props = props.clone();
+            for key in OPTIONS {
+                out_props.shift_remove(key);
+            }
+            out_props.insert("format".into(), "json".into());
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+
+    /// Convert JSON to INI.
+    ///
+    /// Arrays are written as repeated keys. The section named by
+    /// `ini_global_section` (default: `""`) is written without a header.
+    ///
+    /// Comments (via properties or the input):
+    /// - `ini_comments`: object mapping `"section.key"` (or `"key"` for the
+    ///   global section) to a comment written above that key
+    /// - `_comments`: the same object embedded as a top-level key of the
+    ///   input; `ini_comments` wins where both set a path
+    /// - `ini_file_comment`: header comment at the top of the file
+    ///
+    /// Comments start with `#`, or `;` if `ini_comment_char` is `";"`.
+    pub struct JsonToIni;
+
+    impl Converter for JsonToIni {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "config.json-to-ini",
+                    PropertyPattern::new().eq("format", "json"),
+                    PropertyPattern::new().eq("format", "ini"),
+                )
+                .description("Convert JSON to INI")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let value: serde_json::Value = serde_json::from_slice(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid JSON", e))?;
+
+            let obj = value
+                .as_object()
+                .ok_or_else(|| ConvertError::invalid_input_msg("JSON must be an object"))?;
+
+            let to_ini_value = |val: &serde_json::Value| match val {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+
+            let global = global_section(props);
+            let mut ini = Ini::new();
+            for (section, section_value) in obj {
+                if section == COMMENTS_KEY {
+                    continue;
+                }
+                let section_name = if *section == global {
+                    None
+                } else {
+                    Some(section.as_str())
+                };
+                if let Some(section_obj) = section_value.as_object() {
+                    for (key, val) in section_obj {
+                        match val {
+                            serde_json::Value::Array(items) => {
+                                let mut setter = ini.with_section(section_name);
+                                for item in items {
+                                    setter.add(key, to_ini_value(item));
+                                }
+                            }
+                            _ => {
+                                ini.with_section(section_name).set(key, to_ini_value(val));
+                            }
+                        }
+                    }
+                }
+            }
+
+            let mut output = Vec::new();
+            ini.write_to(&mut output)
+                .map_err(|e| ConvertError::failed("INI serialization failed", e))?;
+
+            let comments = key_comments(obj, props);
+            let file_comment = props.get("ini_file_comment").and_then(|v| v.as_str());
+            if !comments.is_empty() || file_comment.is_some() {
+                let marker = match props.get("ini_comment_char").and_then(|v| v.as_str()) {
+                    Some(";") => ';',
+                    _ => '#',
+                };
+                let written = String::from_utf8(output)
+                    .map_err(|e| ConvertError::failed("INI serialization failed", e))?;
+                output =
+                    insert_comments(&ini, &written, &comments, file_comment, marker).into_bytes();
+            }
+
+            let mut out_props = props.clone();
+            for key in OPTIONS {
+                out_props.shift_remove(key);
+            }
+            out_props.insert("format".into(), "ini".into());
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+}
+
+#[cfg(feature = "ini")]
+pub use ini_impl::{IniToJson, JsonToIni};
+
+// ============================================
+// Character encoding conversion
+// ============================================
+
+#[cfg(feature = "charsets")]
+mod charsets_impl {
+    use super::*;
+
+    /// Convert from a character encoding to UTF-8.
+    pub struct CharsetToUtf8;
+
+    impl Converter for CharsetToUtf8 {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.charset-to-utf8",
+                    PropertyPattern::new().exists("charset"),
+                    PropertyPattern::new().eq("charset", "utf-8"),
+                )
+                .description("Convert character encoding to UTF-8")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let charset = props
+                .get("charset")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| ConvertError::invalid_input_msg("Missing 'charset' property"))?;
+
+            let encoding =
+                encoding_rs::Encoding::for_label(charset.as_bytes()).ok_or_else(|| {
+                    ConvertError::invalid_input_msg(format!("Unknown charset: {}", charset))
+                })?;
+
+            let (decoded, _, had_errors) = encoding.decode(input);
+            if had_errors {
+                return Err(ConvertError::invalid_input_msg(format!(
+                    "Invalid {} sequence in input",
+                    charset
+                )));
+            }
+
+            let mut out_props = props.clone();
+            out_props.insert("charset".into(), "utf-8".into());
+            Ok(ConvertOutput::Single(
+                decoded.into_owned().into_bytes(),
+                out_props,
+            ))
+        }
+    }
+
+    /// Convert from UTF-8 to another character encoding.
+    pub struct Utf8ToCharset;
+
+    impl Converter for Utf8ToCharset {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "encoding.utf8-to-charset",
+                    PropertyPattern::new().eq("charset", "utf-8"),
+                    PropertyPattern::new().exists("target_charset"),
+                )
+                .description("Convert UTF-8 to another character encoding")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let target = props
+                .get("target_charset")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| {
+                    ConvertError::invalid_input_msg("Missing 'target_charset' property")
+                })?;
+
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+
+            let encoding =
+                encoding_rs::Encoding::for_label(target.as_bytes()).ok_or_else(|| {
+                    ConvertError::invalid_input_msg(format!("Unknown charset: {}", target))
+                })?;
+
+            let (encoded, _, had_errors) = encoding.encode(text);
+            if had_errors {
+                return Err(ConvertError::failed_msg(format!(
+                    "Cannot encode to {}: input contains unmappable characters",
+                    target
+                )));
+            }
+
+            let mut out_props = props.clone();
+            out_props.insert("charset".into(), target.into());
+            out_props.shift_remove("target_charset");
+            Ok(ConvertOutput::Single(encoded.into_owned(), out_props))
+        }
+    }
+}
+
+#[cfg(feature = "charsets")]
+pub use charsets_impl::{CharsetToUtf8, Utf8ToCharset};
+
+// ============================================
+// Markdown → HTML
+// ============================================
+
+#[cfg(feature = "markdown")]
+mod markdown_impl {
+    use super::*;
+    use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd, html};
+
+    /// Extensions enabled when `markdown_extensions` is not set (matches GitHub).
+    const DEFAULT_EXTENSIONS: &str = "tables,strikethrough,tasklists";
+
+    /// Convert Markdown to HTML.
+    ///
+    /// Input properties:
+    /// - `markdown_extensions`: comma-separated extensions to enable, from
+    ///   `tables`, `footnotes`, `strikethrough`, `tasklists` (default
+    ///   "tables,strikethrough,tasklists"; empty for plain CommonMark)
+    /// - `markdown_smart_punctuation`: curly quotes and en/em dashes (default false)
+    pub struct MarkdownToHtml;
+
+    impl Converter for MarkdownToHtml {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "text.markdown-to-html",
+                    PropertyPattern::new().eq("format", "markdown"),
+                    PropertyPattern::new().eq("format", "html"),
+                )
+                .description("Convert Markdown to HTML")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+
+            let extensions = props
+                .get("markdown_extensions")
+                .and_then(|v| v.as_str())
+                .unwrap_or(DEFAULT_EXTENSIONS);
+            let mut options = parse_extensions(extensions)?;
+            if flag(props, "markdown_smart_punctuation", false) {
+                options.insert(Options::ENABLE_SMART_PUNCTUATION);
+            }
+
+            let parser = Parser::new_ext(text, options);
+            let mut html_output = String::new();
+            html::push_html(&mut html_output, parser);
+
+            let mut out_props = props.clone();
+            out_props.shift_remove("markdown_extensions");
+            out_props.shift_remove("markdown_smart_punctuation");
+            out_props.insert("format".into(), "html".into());
+            Ok(ConvertOutput::Single(html_output.into_bytes(), out_props))
+        }
+    }
+
+    /// Map a comma-separated extension list to parser options.
+    fn parse_extensions(list: &str) -> Result<Options, ConvertError> {
+        let mut options = Options::empty();
+        for name in list.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            options.insert(match name {
+                "tables" => Options::ENABLE_TABLES,
+                "footnotes" => Options::ENABLE_FOOTNOTES,
+                "strikethrough" => Options::ENABLE_STRIKETHROUGH,
+                "tasklists" => Options::ENABLE_TASKLISTS,
+                other => {
+                    return Err(ConvertError::invalid_input_msg(format!(
+                        "Unknown Markdown extension '{}' (expected tables, footnotes, strikethrough or tasklists)",
+                        other
+                    )));
+                }
+            });
+        }
+        Ok(options)
+    }
+
+    /// Convert Markdown to plain text.
+    ///
+    /// Markup is dropped; headings and paragraphs become blank-line separated
+    /// blocks and list items keep an ASCII marker (`-` or `1.`).
+    ///
+    /// Input properties:
+    /// - `text_wrap_width`: wrap lines at this many characters (default 0 = no wrapping)
+    /// - `text_strip_links`: drop link URLs instead of appending them in parens (default false)
+    pub struct MarkdownToText;
+
+    impl Converter for MarkdownToText {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "text.markdown-to-text",
+                    PropertyPattern::new().eq("format", "markdown"),
+                    PropertyPattern::new().eq("format", "text"),
+                )
+                .description("Convert Markdown to plain text")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let text = std::str::from_utf8(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+
+            let wrap_width = props
+                .get("text_wrap_width")
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0)
+                .max(0) as usize;
+            let strip_links = flag(props, "text_strip_links", false);
+
+            let mut writer = TextWriter::new(wrap_width, strip_links);
+            for event in Parser::new(text) {
+                writer.event(event);
+            }
+
+            let mut out_props = props.clone();
+            out_props.shift_remove("text_wrap_width");
+            out_props.shift_remove("text_strip_links");
+            out_props.insert("format".into(), "text".into());
+            Ok(ConvertOutput::Single(
+                writer.finish().into_bytes(),
+                out_props,
+            ))
+        }
+    }
+
+    /// Event sink that renders Markdown as plain text, one block at a time.
+    struct TextWriter {
+        out: String,
+        /// Text of the block being built.
+        block: String,
+        /// Marker for the current list item, emitted with its first block.
+        marker: Option<String>,
+        /// Open lists; `Some(n)` is the next number of an ordered list.
+        lists: Vec<Option<u64>>,
+        /// URLs of open links and images.
+        links: Vec<String>,
+        quote_depth: usize,
+        in_code: bool,
+        /// Whether the last emitted block belongs to the open list.
+        in_list_run: bool,
+        wrap_width: usize,
+        strip_links: bool,
+    }
+
+    impl TextWriter {
+        fn new(wrap_width: usize, strip_links: bool) -> Self {
+            Self {
+                out: String::new(),
+                block: String::new(),
+                marker: None,
+                lists: Vec::new(),
+                links: Vec::new(),
+                quote_depth: 0,
+                in_code: false,
+                in_list_run: false,
+                wrap_width,
+                strip_links,
+            }
+        }
+
+        fn event(&mut self, event: Event) {
+            match event {
+                Event::Start(tag) => self.start(tag),
+                Event::End(tag) => self.end(tag),
+                Event::Text(text)
+                | Event::Code(text)
+                | Event::InlineMath(text)
+                | Event::DisplayMath(text) => self.block.push_str(&text),
+                Event::FootnoteReference(name) => {
+                    self.block.push_str(&format!("[{}]", name));
+                }
+                Event::SoftBreak => self.block.push(' '),
+                Event::HardBreak => self.block.push('\n'),
+                Event::Rule => {
+                    self.flush();
+                    self.block.push_str("---");
+                    self.flush();
+                }
+                Event::TaskListMarker(checked) => {
+                    self.block.push_str(if checked { "[x] " } else { "[ ] " });
+                }
+                Event::Html(_) | Event::InlineHtml(_) => {}
+            }
+        }
+
+        fn start(&mut self, tag: Tag) {
+            match tag {
+                Tag::List(start) => {
+                    self.flush();
+                    self.lists.push(start);
+                }
+                Tag::Item => {
+                    self.flush();
+                    let marker = match self.lists.last_mut() {
+                        Some(Some(n)) => {
+                            *n += 1;
+                            format!("{}. ", *n - 1)
+                        }
+                        _ => "- ".to_string(),
+                    };
+                    self.marker = Some(marker);
+                }
+                Tag::BlockQuote(_) => {
+                    self.flush();
+                    self.quote_depth += 1;
+                }
+                Tag::CodeBlock(_) => {
+                    self.flush();
+                    self.in_code = true;
+                }
+                Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                    self.links.push(dest_url.to_string());
+                }
+                _ => {}
+            }
+        }
+
+        fn end(&mut self, tag: TagEnd) {
+            match tag {
+                TagEnd::Paragraph | TagEnd::Heading(_) | TagEnd::Item => self.flush(),
+                TagEnd::List(_) => {
+                    self.flush();
+                    self.lists.pop();
+                    self.in_list_run = !self.lists.is_empty();
+                }
+                TagEnd::BlockQuote(_) => {
+                    self.flush();
+                    self.quote_depth -= 1;
+                }
+                TagEnd::CodeBlock => {
+                    self.flush();
+                    self.in_code = false;
+                }
+                TagEnd::Link | TagEnd::Image => {
+                    let url = self.links.pop().unwrap_or_default();
+                    if !self.strip_links && !url.is_empty() && !self.block.ends_with(&url) {
+                        self.block.push_str(&format!(" ({})", url));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        /// Emit the current block with list and quote prefixes.
+        fn flush(&mut self) {
+            let block = std::mem::take(&mut self.block);
+            let block = if self.in_code {
+                block.trim_end_matches('\n')
+            } else {
+                block.trim()
+            };
+            if block.is_empty() {
+                return;
+            }
+
+            // Blocks are separated by a blank line, except within a list
+            if !self.out.is_empty() && !self.in_list_run {
+                self.out.push('\n');
+            }
+            self.in_list_run = !self.lists.is_empty();
+
+            let quote = "> ".repeat(self.quote_depth);
+            let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+            let marker = self.marker.take().unwrap_or_default();
+            let first = format!("{}{}{}", quote, indent, marker);
+            let rest = format!(
+                "{}{}{}",
+                quote,
+                indent,
+                " ".repeat(if self.lists.is_empty() { 0 } else { 2 })
+            );
+
+            let mut lines = Vec::new();
+            for line in block.lines() {
+                if self.in_code || self.wrap_width == 0 {
+                    lines.push(line.to_string());
+                } else {
+                    lines.extend(wrap(
+                        line,
+                        self.wrap_width.saturating_sub(rest.len()).max(1),
+                    ));
+                }
+            }
+
+            for (i, line) in lines.iter().enumerate() {
+                self.out.push_str(if i == 0 { &first } else { &rest });
+                self.out.push_str(line);
+                self.out.push('\n');
+            }
+        }
+
+        fn finish(mut self) -> String {
+            self.flush();
+            self.out
+        }
+    }
+
+    /// Greedy word wrap. Words longer than `width` get a line of their own.
+    fn wrap(line: &str, width: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut current = String::new();
+        for word in line.split_whitespace() {
+            if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > width {
+                lines.push(std::mem::take(&mut current));
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+        if !current.is_empty() {
+            lines.push(current);
+        }
+        lines
+    }
+}
+
+#[cfg(feature = "markdown")]
+pub use markdown_impl::{MarkdownToHtml, MarkdownToText};
+
+// ============================================
+// HTML → Plain text
+// ============================================
+
+#[cfg(feature = "html2text")]
+mod html2text_impl {
+    use super::*;
+
+    /// Convert HTML to plain text.
+    pub struct HtmlToText;
+
+    impl Converter for HtmlToText {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "text.html-to-text",
+                    PropertyPattern::new().eq("format", "html"),
+                    PropertyPattern::new().eq("format", "text"),
+                )
+                .description("Convert HTML to plain text")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let text = html2text::from_read(input, 80)
+                .map_err(|e| ConvertError::invalid_input("HTML parsing failed", e))?;
+
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "text".into());
+            Ok(ConvertOutput::Single(text.into_bytes(), out_props))
+        }
+    }
+}
+
+#[cfg(feature = "html2text")]
+pub use html2text_impl::HtmlToText;
+
+// ============================================
+// Templates
+// ============================================
+
+#[cfg(feature = "tera")]
+mod tera_impl {
+    use super::*;
+    use paraphase_core::describe_error;
+    use tera::{Context, Tera};
+
+    /// Name the main template is registered under.
+    const MAIN: &str = "__main";
+
+    /// Render a Tera template with JSON data.
+    ///
+    /// Input properties (one required):
+    /// - `tera_template`: inline template
+    /// - `tera_template_file`: path to a template file
+    ///
+    /// Options:
+    /// - `tera_template_dir`: directory of templates the main template can
+    ///   `include`, `import` or `extends`, by path relative to the directory
+    ///
+    /// The JSON root must be an object; its keys become template variables.
+    /// Output is not HTML-escaped.
+    pub struct TeraRender;
+
+    impl Converter for TeraRender {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "template.tera-render",
+                    PropertyPattern::new().eq("format", "json"),
+                    PropertyPattern::new().eq("format", "text"),
+                )
+                .description("Render JSON data with a Tera template")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let data: serde_json::Value = serde_json::from_slice(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid JSON", e))?;
+            let context = Context::from_value(data)
+                .map_err(|e| ConvertError::invalid_input("Template data must be an object", e))?;
+
+            let mut tera = match props.get("tera_template_dir").and_then(|v| v.as_str()) {
+                Some(dir) => {
+                    Tera::new(&format!("{}/**/*", dir.trim_end_matches('/'))).map_err(|e| {
+                        ConvertError::invalid_input_msg(format!(
+                            "Failed to load templates from '{}': {}",
+                            dir,
+                            describe_error(&e)
+                        ))
+                    })?
+                }
+                None => Tera::default(),
+            };
+            let added = if let Some(inline) = props.get("tera_template").and_then(|v| v.as_str()) {
+                tera.add_raw_template(MAIN, inline)
+            } else if let Some(path) = props.get("tera_template_file").and_then(|v| v.as_str()) {
+                tera.add_template_file(path, Some(MAIN))
+            } else {
+                return Err(ConvertError::MissingProperty(
+                    "tera_template or tera_template_file".into(),
+                ));
+            };
+            added.map_err(|e| {
+                ConvertError::invalid_input_msg(format!(
+                    "Invalid Tera template: {}",
+                    describe_error(&e)
+                ))
+            })?;
+
+            let text = tera.render(MAIN, &context).map_err(|e| {
+                ConvertError::failed_msg(format!("Tera rendering failed: {}", describe_error(&e)))
+            })?;
+
+            let mut out_props = props.clone();
+            out_props.shift_remove("tera_template");
+            out_props.shift_remove("tera_template_file");
+            out_props.shift_remove("tera_template_dir");
+            out_props.insert("format".into(), "text".into());
+            Ok(ConvertOutput::Single(text.into_bytes(), out_props))
+        }
+    }
+}
+
+#[cfg(feature = "tera")]
+pub use tera_impl::TeraRender;
+
+#[cfg(feature = "handlebars")]
+mod handlebars_impl {
+    use super::*;
+    use handlebars::Handlebars;
+
+    /// Name the main template is registered under.
+    const MAIN: &str = "__main";
+
+    /// Render a Handlebars template with JSON data.
+    ///
+    /// Input properties (one required):
+    /// - `hbs_template`: inline template
+    /// - `hbs_template_file`: path to a template file
+    ///
+    /// Options:
+    /// - `hbs_strict`: fail on missing variables instead of rendering them
+    ///   empty (default: false)
+    ///
+    /// Output is not HTML-escaped.
+    pub struct HandlebarsRender;
+
+    impl Converter for HandlebarsRender {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "template.handlebars-render",
+                    PropertyPattern::new().eq("format", "json"),
+                    PropertyPattern::new().eq("format", "text"),
+                )
+                .description("Render JSON data with a Handlebars template")
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let data: serde_json::Value = serde_json::from_slice(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid JSON", e))?;
+
+            let mut handlebars = Handlebars::new();
+            handlebars.register_escape_fn(handlebars::no_escape);
+            handlebars.set_strict_mode(flag(props, "hbs_strict", false));
+            let registered =
+                if let Some(inline) = props.get("hbs_template").and_then(|v| v.as_str()) {
+                    handlebars.register_template_string(MAIN, inline)
+                } else if let Some(path) = props.get("hbs_template_file").and_then(|v| v.as_str()) {
+                    handlebars.register_template_file(MAIN, path)
+                } else {
+                    return Err(ConvertError::MissingProperty(
+                        "hbs_template or hbs_template_file".into(),
+                    ));
+                };
+            registered
+                .map_err(|e| ConvertError::invalid_input("Invalid Handlebars template", e))?;
+
+            let text = handlebars
+                .render(MAIN, &data)
+                .map_err(|e| ConvertError::failed("Handlebars rendering failed", e))?;
+
+            let mut out_props = props.clone();
+            out_props.shift_remove("hbs_template");
+            out_props.shift_remove("hbs_template_file");
+            out_props.shift_remove("hbs_strict");
+            out_props.insert("format".into(), "text".into());
+            Ok(ConvertOutput::Single(text.into_bytes(), out_props))
+        }
+    }
+}
+
+#[cfg(feature = "handlebars")]
+pub use handlebars_impl::HandlebarsRender;
+
+// ============================================
+// Tar archives
+// ============================================
+
+#[cfg(feature = "tar")]
+mod tar_impl {
+    use super::*;
+    use paraphase_core::{PropertiesExt, PropertySchema};
+    use std::io::{Cursor, Read, Write};
+    use std::path::PathBuf;
+
+    /// Extract files from a tar archive.
+    ///
+    /// Options:
+    /// - `tar_include`: glob pattern; only matching entry paths are extracted
+    ///   (`*` also matches `/`, so `*.json` finds JSON files at any depth)
+    /// - `tar_exclude`: glob pattern; matching entry paths are skipped
+    /// - `tar_max_entries`: fail if more entries than this would be extracted
+    /// - `tar_max_entry_size`: fail if an extracted entry is larger than this
+    ///   many bytes
+    pub struct TarExtract;
+
+    /// Parse an optional glob pattern property.
+    fn glob_pattern(props: &Properties, key: &str) -> Result<Option<glob::Pattern>, ConvertError> {
+        props
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(|pattern| {
+                glob::Pattern::new(pattern).map_err(|e| {
+                    ConvertError::invalid_input(format!("Invalid {} pattern '{}'", key, pattern), e)
+                })
+            })
+            .transpose()
+    }
+
+    impl Converter for TarExtract {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "archive.tar-extract",
+                    PropertyPattern::new().eq("format", "tar"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Extract files from tar archive")
+                .with_property_schema("tar_include", PropertySchema::string())
+                .with_property_schema("tar_exclude", PropertySchema::string())
+                .with_property_schema("tar_max_entries", PropertySchema::integer().min(1.0))
+                .with_property_schema("tar_max_entry_size", PropertySchema::integer().min(0.0))
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            self.decl().check_props(props)?;
+            let include = glob_pattern(props, "tar_include")?;
+            let exclude = glob_pattern(props, "tar_exclude")?;
+            let max_entries = props.get("tar_max_entries").and_then(|v| v.as_i64());
+            let max_entry_size = props
+                .get("tar_max_entry_size")
+                .and_then(|v| v.as_i64())
+                .map(|n| n as u64);
+
+            let mut base_props = props.clone();
+            for key in [
+                "tar_include",
+                "tar_exclude",
+                "tar_max_entries",
+                "tar_max_entry_size",
+            ] {
+                base_props.shift_remove(key);
+            }
+
+            let cursor = Cursor::new(input);
+            let mut archive = tar::Archive::new(cursor);
+
+            let mut outputs = Vec::new();
+            for entry in archive
+                .entries()
+                .map_err(|e| ConvertError::invalid_input("Invalid tar archive", e))?
+            {
+                let mut entry =
+                    entry.map_err(|e| ConvertError::invalid_input("Invalid tar entry", e))?;
+
+                // Skip directories
+                if entry.header().entry_type().is_dir() {
+                    continue;
+                }
+
+                let path = entry
+                    .path()
+                    .map_err(|e| ConvertError::invalid_input("Invalid path", e))?
+                    .to_string_lossy()
+                    .to_string();
+
+                if include.as_ref().is_some_and(|p| !p.matches(&path))
+                    || exclude.as_ref().is_some_and(|p| p.matches(&path))
+                {
+                    continue;
+                }
+
+                if let Some(max) = max_entries.filter(|&max| outputs.len() as i64 >= max) {
+                    return Err(ConvertError::invalid_input_msg(format!(
+                        "Tar archive has more than {} matching entries",
+                        max
+                    )));
+                }
+                let size = entry.size();
+                if let Some(max) = max_entry_size.filter(|&max| size > max) {
+                    return Err(ConvertError::invalid_input_msg(format!(
+                        "Tar entry '{}' is {} bytes, over the limit of {}",
+                        path, size, max
+                    )));
+                }
+
+                let mut data = Vec::new();
+                entry
+                    .read_to_end(&mut data)
+                    .map_err(|e| ConvertError::invalid_input("Failed to read entry", e))?;
+
+                let mut out_props = base_props.clone();
+                out_props.insert("format".into(), "raw".into());
+                out_props.insert("path".into(), path.into());
+
+                outputs.push((data, out_props));
+            }
+
+            Ok(ConvertOutput::Multiple(outputs))
+        }
+    }
+
+    /// Create a tar archive from multiple files.
+    ///
+    /// Input properties (per file):
+    /// - `path`: entry path in the archive
+    /// - `tar_strip_prefix`: prefix removed from `path` (if present)
+    /// - `tar_base_dir`: directory prepended to every entry path
+    /// - `tar_preserve_permissions`: if true, copy the mode of the file at
+    ///   `path` on disk instead of using `0o644`
+    /// - `tar_mode`: permission bits, as an integer or an octal string like
+    ///   `"755"` (overrides `tar_preserve_permissions`)
+    /// - `tar_mtime`: modification time as a Unix timestamp (default: 0)
+    /// - `tar_uname` / `tar_gname`: owner user and group names
+    ///
+    /// [`TarCreate::create_streaming`] writes entries one at a time for
+    /// archives too large to hold in memory.
+    pub struct TarCreate;
+
+    impl TarCreate {
+        /// Write a tar archive to `output` entry by entry, without buffering
+        /// the inputs.
+        ///
+        /// Each input takes the per-file properties above, plus `size`: the
+        /// entry length in bytes, which the tar header needs before the
+        /// data. Exactly `size` bytes are read; inputs without it are read
+        /// into memory first to measure them.
+        pub fn create_streaming<R: Read, W: Write>(
+            inputs: impl IntoIterator<Item = (R, Properties)>,
+            output: W,
+        ) -> Result<(), ConvertError> {
+            let mut builder = tar::Builder::new(output);
+            for (reader, props) in inputs {
+                append_entry(&mut builder, reader, &props)?;
+            }
+            builder
+                .into_inner()
+                .and_then(|mut output| output.flush())
+                .map_err(|e| ConvertError::failed("Failed to finalize tar", e))
+        }
+
+        /// Create a tar archive from files on disk, keeping each path as
+        /// given as its entry path. Files are opened one at a time.
+        pub fn from_paths(paths: &[PathBuf]) -> Result<Vec<u8>, ConvertError> {
+            let mut output = Vec::new();
+            let mut builder = tar::Builder::new(&mut output);
+            for path in paths {
+                let open_error =
+                    |e| ConvertError::invalid_input(format!("Cannot read '{}'", path.display()), e);
+                let file = std::fs::File::open(path).map_err(open_error)?;
+                let size = file.metadata().map_err(open_error)?.len();
+                let props = Properties::new()
+                    .with("path", path.to_string_lossy().as_ref())
+                    .with("size", size as i64);
+                append_entry(&mut builder, file, &props)?;
+            }
+            builder
+                .finish()
+                .map_err(|e| ConvertError::failed("Failed to finalize tar", e))?;
+            drop(builder);
+            Ok(output)
+        }
+    }
+
+    /// Add one file to a tar archive (see [`TarCreate`] for the properties).
+    fn append_entry<W: Write>(
+        builder: &mut tar::Builder<W>,
+        mut reader: impl Read,
+        props: &Properties,
+    ) -> Result<(), ConvertError> {
+        let path = props.get("path").and_then(|v| v.as_str()).ok_or_else(|| {
+            ConvertError::invalid_input_msg("Missing 'path' property for tar entry")
+        })?;
+
+        let preserve_permissions = props
+            .get("tar_preserve_permissions")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let mode = match props.get("tar_mode") {
+            Some(mode) => parse_mode(mode)?,
+            None if preserve_permissions => file_mode(path)?,
+            None => 0o644,
+        };
+        let entry_path = tar_entry_path(path, props);
+        let entry_error =
+            |e| ConvertError::failed(format!("Failed to add entry '{}'", entry_path), e);
+
+        let mut header = tar::Header::new_gnu();
+        header.set_mode(mode);
+        if let Some(mtime) = props.get("tar_mtime").and_then(|v| v.as_i64()) {
+            let mtime = u64::try_from(mtime).map_err(|_| {
+                ConvertError::invalid_input_msg(format!("Invalid tar_mtime {}", mtime))
+            })?;
+            header.set_mtime(mtime);
+        }
+        if let Some(uname) = props.get("tar_uname").and_then(|v| v.as_str()) {
+            header
+                .set_username(uname)
+                .map_err(|e| ConvertError::invalid_input("Invalid tar_uname", e))?;
+        }
+        if let Some(gname) = props.get("tar_gname").and_then(|v| v.as_str()) {
+            header
+                .set_groupname(gname)
+                .map_err(|e| ConvertError::invalid_input("Invalid tar_gname", e))?;
+        }
+
+        match props.get("size").and_then(|v| v.as_i64()) {
+            Some(size) => {
+                let size = size as u64;
+                header.set_size(size);
+                header.set_cksum();
+                let data = ExactReader {
+                    inner: reader,
+                    remaining: size,
+                };
+                builder
+                    .append_data(&mut header, &entry_path, data)
+                    .map_err(entry_error)
+            }
+            None => {
+                let mut data = Vec::new();
+                reader.read_to_end(&mut data).map_err(entry_error)?;
+                header.set_size(data.len() as u64);
+                header.set_cksum();
+                builder
+                    .append_data(&mut header, &entry_path, data.as_slice())
+                    .map_err(entry_error)
+            }
+        }
+    }
+
+    /// Reads exactly `remaining` bytes and fails if the source ends early,
+    /// so a wrong `size` cannot produce a corrupt archive.
+    struct ExactReader<R> {
+        inner: R,
+        remaining: u64,
+    }
+
+    impl<R: Read> Read for ExactReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.remaining == 0 {
+                return Ok(0);
+            }
+            let max = buf
+                .len()
+                .min(usize::try_from(self.remaining).unwrap_or(usize::MAX));
+            let n = self.inner.read(&mut buf[..max])?;
+            if n == 0 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    format!("input ended {} bytes short of its size", self.remaining),
+                ));
+            }
+            self.remaining -= n as u64;
+            Ok(n)
+        }
+    }
+
+    /// Compute the archive entry path from the input properties.
+    ///
+    /// The prefix is matched by whole components, so `/work/project` does
+    /// not strip `/work/project2/file`.
+    fn tar_entry_path(path: &str, props: &Properties) -> String {
+        let mut entry = std::path::Path::new(path);
+        if let Some(prefix) = props.get("tar_strip_prefix").and_then(|v| v.as_str()) {
+            if let Ok(rest) = entry.strip_prefix(prefix) {
+                entry = rest;
+            }
+        }
+        let entry = entry.to_str().unwrap_or(path).trim_start_matches('/');
+
+        match props.get("tar_base_dir").and_then(|v| v.as_str()) {
+            Some(base) if !base.is_empty() => format!("{}/{}", base.trim_end_matches('/'), entry),
+            _ => entry.to_string(),
+        }
+    }
+
+    /// Parse `tar_mode` from an integer or an octal string.
+    fn parse_mode(value: &paraphase_core::Value) -> Result<u32, ConvertError> {
+        let mode = match (value.as_i64(), value.as_str()) {
+            (Some(n), _) => u32::try_from(n).ok(),
+            (_, Some(s)) => u32::from_str_radix(s.trim_start_matches("0o"), 8).ok(),
+            _ => None,
+        };
+        mode.filter(|mode| *mode <= 0o7777).ok_or_else(|| {
+            ConvertError::invalid_input_msg(
+                "Invalid tar_mode: expected permission bits like 0o755 or \"755\"",
+            )
+        })
+    }
+
+    /// Read the permission bits of a file on disk.
+    fn file_mode(path: &str) -> Result<u32, ConvertError> {
+        let metadata = std::fs::metadata(path).map_err(|e| {
+            ConvertError::invalid_input(format!("Cannot read permissions of '{}'", path), e)
+        })?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            Ok(metadata.permissions().mode() & 0o7777)
+        }
+        #[cfg(not(unix))]
+        {
+            Ok(if metadata.permissions().readonly() {
+                0o444
+            } else {
+                0o644
+            })
+        }
+    }
+
+    impl Converter for TarCreate {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::new("archive.tar-create")
+                    .input(
+                        "in",
+                        PortDecl::list(PropertyPattern::new().eq("format", "raw")),
+                    )
+                    .output(
+                        "out",
+                        PortDecl::single(PropertyPattern::new().eq("format", "tar")),
+                    )
+                    .description("Create tar archive from files")
+            })
+        }
+
+        fn convert(
+            &self,
+            _input: &[u8],
+            _props: &Properties,
+        ) -> Result<ConvertOutput, ConvertError> {
+            Err(ConvertError::BatchNotSupported)
+        }
+
+        fn convert_batch(
+            &self,
+            inputs: &[(&[u8], &Properties)],
+        ) -> Result<ConvertOutput, ConvertError> {
+            let entries = inputs.iter().map(|(data, props)| {
+                let mut props = (*props).clone();
+                props.insert("size".into(), (data.len() as i64).into());
+                (*data, props)
+            });
+            let mut output = Vec::new();
+            Self::create_streaming(entries, &mut output)?;
+
+            let mut out_props = Properties::new();
+            out_props.insert("format".into(), "tar".into());
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+}
+
+#[cfg(feature = "tar")]
+pub use tar_impl::{TarCreate, TarExtract};
+
+// ============================================
+// Zip archives
+// ============================================
+
+#[cfg(feature = "zip")]
+mod zip_impl {
+    use super::*;
+    use paraphase_core::PropertySchema;
+    use std::io::{Cursor, Read, Seek, Write};
+
+    /// Extract files from a zip archive.
+    ///
+    /// Options:
+    /// - `zip_entry`: extract only the entry with this path, as a single
+    ///   output; other entries are not read
+    pub struct ZipExtract;
+
+    impl ZipExtract {
+        /// Extract from a seekable reader, such as a `File`, instead of a
+        /// byte slice.
+        ///
+        /// Zip keeps its central directory at the end, so the archive must
+        /// be seekable; only the entries being extracted are read into
+        /// memory.
+        pub fn with_streaming_seek(
+            input: impl Read + Seek,
+            props: &Properties,
+        ) -> Result<ConvertOutput, ConvertError> {
+            let mut archive = zip::ZipArchive::new(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid zip archive", e))?;
+
+            if let Some(entry) = props.get("zip_entry").and_then(|v| v.as_str()) {
+                let file = match archive.by_name(entry) {
+                    Ok(file) if !file.is_dir() => file,
+                    Ok(_) | Err(zip::result::ZipError::FileNotFound) => {
+                        return Err(ConvertError::invalid_input_msg(format!(
+                            "No file '{}' in zip archive",
+                            entry
+                        )));
+                    }
+                    Err(e) => return Err(ConvertError::invalid_input("Invalid zip entry", e)),
+                };
+                let path = file.name().to_string();
+                let (data, mut out_props) = read_entry(file, path, props)?;
+                out_props.shift_remove("zip_entry");
+                return Ok(ConvertOutput::Single(data, out_props));
+            }
+
+            let mut outputs = Vec::new();
+            for i in 0..archive.len() {
+                let file = archive
+                    .by_index(i)
+                    .map_err(|e| ConvertError::invalid_input("Invalid zip entry", e))?;
+
+                // Skip directories
+                if file.is_dir() {
+                    continue;
+                }
+
+                let path = file.name().to_string();
+                outputs.push(read_entry(file, path, props)?);
+            }
+
+            Ok(ConvertOutput::Multiple(outputs))
+        }
+    }
+
+    /// Read one entry, with `format: raw` and its `path`.
+    fn read_entry(
+        mut file: impl Read,
+        path: String,
+        props: &Properties,
+    ) -> Result<(Vec<u8>, Properties), ConvertError> {
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)
+            .map_err(|e| ConvertError::invalid_input("Failed to read entry", e))?;
+
+        let mut out_props = props.clone();
+        out_props.insert("format".into(), "raw".into());
+        out_props.insert("path".into(), path.into());
+        Ok((data, out_props))
+    }
+
+    impl Converter for ZipExtract {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "archive.zip-extract",
+                    PropertyPattern::new().eq("format", "zip"),
+                    PropertyPattern::new().eq("format", "raw"),
+                )
+                .description("Extract files from zip archive")
+                .with_property_schema("zip_entry", PropertySchema::string())
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            Self::with_streaming_seek(Cursor::new(input), props)
+        }
+    }
+
+    /// Create a zip archive from multiple files.
+    ///
+    /// Options (via entry properties, so each file can differ):
+    /// - `zip_method`: "store", "deflate" (default), "bzip2" or "zstd"
+    /// - `zip_level`: 0-9 for deflate and bzip2, 1-22 for zstd (default:
+    ///   the method's own default); ignored for store
+    pub struct ZipCreate;
+
+    impl Converter for ZipCreate {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::new("archive.zip-create")
+                    .input(
+                        "in",
+                        PortDecl::list(PropertyPattern::new().eq("format", "raw")),
+                    )
+                    .output(
+                        "out",
+                        PortDecl::single(PropertyPattern::new().eq("format", "zip")),
+                    )
+                    .description("Create zip archive from files")
+            })
+        }
+
+        fn convert(
+            &self,
+            _input: &[u8],
+            _props: &Properties,
+        ) -> Result<ConvertOutput, ConvertError> {
+            Err(ConvertError::BatchNotSupported)
+        }
+
+        fn convert_batch(
+            &self,
+            inputs: &[(&[u8], &Properties)],
+        ) -> Result<ConvertOutput, ConvertError> {
+            let mut output = Cursor::new(Vec::new());
+            {
+                let mut writer = zip::ZipWriter::new(&mut output);
+
+                for (data, props) in inputs {
+                    let path = props.get("path").and_then(|v| v.as_str()).ok_or_else(|| {
+                        ConvertError::invalid_input_msg("Missing 'path' property for zip entry")
+                    })?;
+                    let options = file_options(props)?;
+
+                    writer.start_file(path, options).map_err(|e| {
+                        ConvertError::failed(format!("Failed to add entry '{}'", path), e)
+                    })?;
+
+                    writer.write_all(data).map_err(|e| {
+                        ConvertError::failed(format!("Failed to write entry '{}'", path), e)
+                    })?;
+                }
+
+                writer
+                    .finish()
+                    .map_err(|e| ConvertError::failed("Failed to finalize zip", e))?;
+            }
+
+            let mut out_props = Properties::new();
+            out_props.insert("format".into(), "zip".into());
+            Ok(ConvertOutput::Single(output.into_inner(), out_props))
+        }
+    }
+
+    /// Build the compression options for one entry from `zip_method` and
+    /// `zip_level`.
+    fn file_options(props: &Properties) -> Result<zip::write::SimpleFileOptions, ConvertError> {
+        use zip::CompressionMethod;
+
+        let method = props
+            .get("zip_method")
+            .and_then(|v| v.as_str())
+            .unwrap_or("deflate");
+        let (method, levels) = match method {
+            "store" => (CompressionMethod::Stored, None),
+            "deflate" => (CompressionMethod::Deflated, Some(0..=9)),
+            "bzip2" => (CompressionMethod::Bzip2, Some(0..=9)),
+            "zstd" => (CompressionMethod::Zstd, Some(1..=22)),
+            other => {
+                return Err(ConvertError::invalid_input_msg(format!(
+                    "Unknown zip_method '{}' (expected store, deflate, bzip2 or zstd)",
+                    other
+                )));
+            }
+        };
+
+        let level = match (props.get("zip_level").and_then(|v| v.as_i64()), levels) {
+            (Some(level), Some(range)) if !range.contains(&level) => {
+                return Err(ConvertError::invalid_input_msg(format!(
+                    "zip_level {} out of range {}-{} for this method",
+                    level,
+                    range.start(),
+                    range.end()
+                )));
+            }
+            (level, Some(_)) => level,
+            // Stored entries reject any level
+            (_, None) => None,
+        };
+
+        Ok(zip::write::SimpleFileOptions::default()
+            .compression_method(method)
+            .compression_level(level))
+    }
+}
+
+#[cfg(feature = "zip")]
+pub use zip_impl::{ZipCreate, ZipExtract};
+
+// ============================================
+// SPREADSHEET FORMATS
+// ============================================
+
+#[cfg(feature = "spreadsheet")]
+mod spreadsheet_impl {
+    use super::*;
+    use calamine::{Data, Reader, open_workbook_auto_from_rs};
+    use std::io::Cursor;
+
+    /// Read spreadsheet files (XLSX, ODS, XLS, XLSB) to JSON.
+    ///
+    /// Input properties:
+    /// - `format`: "xlsx", "ods", "xls", or "xlsb"
+    /// - `sheet`: optional sheet name or index (default: all sheets)
+    /// - `headers`: if true, use first row as object keys
+    /// - `spreadsheet_split_sheets`: if true, emit one output per sheet
+    ///
+    /// Output: JSON with structure:
+    /// - If headers=false: `{"sheets": {"SheetName": [[cell, cell, ...], ...]}}`
+    /// - If headers=true: `{"sheets": {"SheetName": [{"col": value, ...}, ...]}}`
+    ///
+    /// When splitting, each output holds just that sheet's rows, with
+    /// `sheet_name` and `sheet_index` properties.
+    pub struct SpreadsheetToJson;
+
+    impl SpreadsheetToJson {
+        fn decl() -> ConverterDecl {
+            use paraphase_core::{Predicate, Value};
+            ConverterDecl::simple(
+                "spreadsheet-to-json",
+                PropertyPattern::new().with(
+                    "format",
+                    Predicate::OneOf(vec![
+                        Value::from("xlsx"),
+                        Value::from("ods"),
+                        Value::from("xls"),
+                        Value::from("xlsb"),
+                    ]),
+                ),
+                PropertyPattern::new().eq("format", "json"),
+            )
+            .description("Read spreadsheet to JSON")
+        }
+    }
+
+    impl Converter for SpreadsheetToJson {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(Self::decl)
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let cursor = Cursor::new(input);
+            let mut workbook = open_workbook_auto_from_rs(cursor)
+                .map_err(|e| ConvertError::invalid_input("Failed to open spreadsheet", e))?;
+
+            let use_headers = flag(props, "headers", false);
+
+            let sheet_filter = props.get("sheet").and_then(|v| v.as_str());
+
+            let split_sheets = flag(props, "spreadsheet_split_sheets", false);
+
+            let sheet_names: Vec<String> = workbook.sheet_names().to_vec();
+            let mut sheets = Vec::new();
+
+            for (index, name) in sheet_names.iter().enumerate() {
+                // Filter by sheet name if specified
+                if let Some(filter) = sheet_filter {
+                    if name != filter {
+                        continue;
+                    }
+                }
+
+                if let Ok(range) = workbook.worksheet_range(name) {
+                    let rows: Vec<Vec<serde_json::Value>> = range
+                        .rows()
+                        .map(|row| {
+                            row.iter()
+                                .map(|cell| match cell {
+                                    Data::Empty => serde_json::Value::Null,
+                                    Data::String(s) => serde_json::Value::String(s.clone()),
+                                    Data::Int(n) => serde_json::json!(*n),
+                                    Data::Float(f) => serde_json::json!(*f),
+                                    Data::Bool(b) => serde_json::Value::Bool(*b),
+                                    Data::Error(e) => {
+                                        serde_json::Value::String(format!("#ERROR:{:?}", e))
+                                    }
+                                    Data::DateTime(dt) => {
+                                        serde_json::Value::String(format!("{}", dt))
+                                    }
+                                    Data::DateTimeIso(s) => serde_json::Value::String(s.clone()),
+                                    Data::DurationIso(s) => serde_json::Value::String(s.clone()),
+                                })
+                                .collect()
+                        })
+                        .collect();
+
+                    let sheet_data = if use_headers && !rows.is_empty() {
+                        // Use first row as headers
+                        let headers: Vec<String> = rows[0]
+                            .iter()
+                            .enumerate()
+                            .map(|(i, v)| {
+                                v.as_str()
+                                    .map(|s| s.to_string())
+                                    .unwrap_or_else(|| format!("col_{}", i))
+                            })
+                            .collect();
+
+                        let objects: Vec<serde_json::Value> = rows[1..]
+                            .iter()
+                            .map(|row| {
+                                let mut obj = serde_json::Map::new();
+                                for (i, cell) in row.iter().enumerate() {
+                                    let key = headers
+                                        .get(i)
+                                        .cloned()
+                                        .unwrap_or_else(|| format!("col_{}", i));
+                                    obj.insert(key, cell.clone());
+                                }
+                                serde_json::Value::Object(obj)
+                            })
+                            .collect();
+
+                        serde_json::Value::Array(objects)
+                    } else {
+                        serde_json::Value::Array(
+                            rows.into_iter().map(serde_json::Value::Array).collect(),
+                        )
+                    };
+
+                    sheets.push((index, name.clone(), sheet_data));
+                }
+            }
+
+            if split_sheets {
+                let outputs = sheets
+                    .into_iter()
+                    .map(|(index, name, sheet_data)| {
+                        let output = serde_json::to_vec_pretty(&sheet_data)
+                            .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+                        let mut out_props = Properties::new();
+                        out_props.insert("format".into(), "json".into());
+                        out_props.insert("sheet_name".into(), name.into());
+                        out_props.insert("sheet_index".into(), (index as i64).into());
+                        Ok((output, out_props))
+                    })
+                    .collect::<Result<Vec<_>, ConvertError>>()?;
+                return Ok(ConvertOutput::Multiple(outputs));
+            }
+
+            let sheets: serde_json::Map<_, _> = sheets
+                .into_iter()
+                .map(|(_, name, sheet_data)| (name, sheet_data))
+                .collect();
+            let result = serde_json::json!({ "sheets": sheets });
+            let output = serde_json::to_vec_pretty(&result)
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+            let mut out_props = Properties::new();
+            out_props.insert("format".into(), "json".into());
+
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+
+    /// Write JSON to an XLSX workbook (reverse of [`SpreadsheetToJson`]).
+    ///
+    /// Input: JSON with structure `{"sheets": {"SheetName": rows}}`, where
+    /// rows are either objects (`headers: true` output) or arrays of cells.
+    /// Object rows get a header row built from their keys, in order of first
+    /// appearance.
+    ///
+    /// Options:
+    /// - `xlsx_bold_headers`: render the header row in bold (default: false)
+    /// - `xlsx_freeze_header_row`: keep the header row visible when
+    ///   scrolling (default: false)
+    /// - `xlsx_number_format`: Excel number format for numeric cells,
+    ///   e.g. `"#,##0.00"`
+    pub struct JsonToXlsx;
+
+    impl JsonToXlsx {
+        fn decl() -> ConverterDecl {
+            use paraphase_core::PropertySchema;
+            ConverterDecl::simple(
+                "json-to-xlsx",
+                PropertyPattern::new().eq("format", "json"),
+                PropertyPattern::new().eq("format", "xlsx"),
+            )
+            .description("Write JSON to an XLSX spreadsheet")
+            .with_property_schema("xlsx_bold_headers", PropertySchema::boolean())
+            .with_property_schema("xlsx_freeze_header_row", PropertySchema::boolean())
+            .with_property_schema("xlsx_number_format", PropertySchema::string())
+        }
+    }
+
+    impl Converter for JsonToXlsx {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(Self::decl)
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            use rust_xlsxwriter::{Format, Workbook};
+
+            self.decl().check_props(props)?;
+
+            let value: serde_json::Value = serde_json::from_slice(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid JSON", e))?;
+            let sheets = value
+                .get("sheets")
+                .and_then(|s| s.as_object())
+                .ok_or_else(|| {
+                    ConvertError::invalid_input_msg("Expected an object with a \"sheets\" map")
+                })?;
+
+            let header_format = if flag(props, "xlsx_bold_headers", false) {
+                Format::new().set_bold()
+            } else {
+                Format::new()
+            };
+            let number_format = props
+                .get("xlsx_number_format")
+                .and_then(|v| v.as_str())
+                .map(|f| Format::new().set_num_format(f));
+            let freeze_header = flag(props, "xlsx_freeze_header_row", false);
+
+            let xlsx_error = |e| ConvertError::failed("Failed to write XLSX", e);
+            let mut workbook = Workbook::new();
+
+            for (name, rows) in sheets {
+                let rows = rows.as_array().ok_or_else(|| {
+                    ConvertError::invalid_input_msg(format!("Sheet '{}' is not an array", name))
+                })?;
+
+                let sheet = workbook.add_worksheet();
+                sheet.set_name(name).map_err(xlsx_error)?;
+
+                // Object rows share one header row; array rows are written as-is
+                let mut headers: Vec<&str> = Vec::new();
+                for row in rows {
+                    if let Some(obj) = row.as_object() {
+                        for key in obj.keys() {
+                            if !headers.contains(&key.as_str()) {
+                                headers.push(key);
+                            }
+                        }
+                    }
+                }
+
+                let mut row_idx: u32 = 0;
+                if !headers.is_empty() {
+                    for (col, header) in headers.iter().enumerate() {
+                        sheet
+                            .write_string_with_format(0, col as u16, *header, &header_format)
+                            .map_err(xlsx_error)?;
+                    }
+                    if freeze_header {
+                        sheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+                    }
+                    row_idx = 1;
+                }
+
+                for row in rows {
+                    let cells: Vec<&serde_json::Value> = match row {
+                        serde_json::Value::Object(obj) => headers
+                            .iter()
+                            .map(|h| obj.get(*h).unwrap_or(&serde_json::Value::Null))
+                            .collect(),
+                        serde_json::Value::Array(cells) => cells.iter().collect(),
+                        cell => vec![cell],
+                    };
+
+                    for (col, cell) in cells.into_iter().enumerate() {
+                        let col = col as u16;
+                        match cell {
+                            serde_json::Value::Null => {}
+                            serde_json::Value::Bool(b) => {
+                                sheet.write_boolean(row_idx, col, *b).map_err(xlsx_error)?;
+                            }
+                            serde_json::Value::Number(n) => {
+                                let n = n.as_f64().unwrap_or_default();
+                                match &number_format {
+                                    Some(format) => {
+                                        sheet.write_number_with_format(row_idx, col, n, format)
+                                    }
+                                    None => sheet.write_number(row_idx, col, n),
+                                }
+                                .map_err(xlsx_error)?;
+                            }
+                            serde_json::Value::String(s) => {
+                                sheet.write_string(row_idx, col, s).map_err(xlsx_error)?;
+                            }
+                            nested => {
+                                sheet
+                                    .write_string(row_idx, col, nested.to_string())
+                                    .map_err(xlsx_error)?;
+                            }
+                        }
+                    }
+                    row_idx += 1;
+                }
+            }
+
+            let output = workbook.save_to_buffer().map_err(xlsx_error)?;
+
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "xlsx".into());
+            out_props.shift_remove("xlsx_bold_headers");
+            out_props.shift_remove("xlsx_freeze_header_row");
+            out_props.shift_remove("xlsx_number_format");
+
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+}
+
+#[cfg(feature = "spreadsheet")]
+pub use spreadsheet_impl::{JsonToXlsx, SpreadsheetToJson};
+
+// ============================================
+// SCHEMA-BASED FORMATS (self-describing)
+// ============================================
+
+#[cfg(feature = "avro")]
+mod avro_impl {
+    use super::*;
+    use apache_avro::schema_compatibility::SchemaCompatibility;
+    use apache_avro::{Codec, Reader, Schema, Writer};
+    use paraphase_core::{PropertyError, PropertySchema};
+
+    /// Read Avro container files to JSON.
+    ///
+    /// Avro container files are self-describing - the schema is embedded.
+    /// Outputs a JSON array of records.
+    ///
+    /// Input properties:
+    /// - `avro_reader_schema`: inline reader schema (JSON string). Records
+    ///   are resolved from the embedded writer schema into it, filling in
+    ///   field defaults and promoting types (e.g. int to long).
+    pub struct AvroToJson;
+
+    impl AvroToJson {
+        fn decl() -> ConverterDecl {
+            ConverterDecl::simple(
+                "avro-to-json",
+                PropertyPattern::new().eq("format", "avro"),
+                PropertyPattern::new().eq("format", "json"),
+            )
+            .description("Read Avro container file to JSON")
+        }
+    }
+
+    impl Converter for AvroToJson {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(Self::decl)
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let reader_schema = props
+                .get("avro_reader_schema")
+                .and_then(|v| v.as_str())
+                .map(|raw| {
+                    Schema::parse_str(raw)
+                        .map_err(|e| ConvertError::invalid_input("Invalid Avro reader schema", e))
+                })
+                .transpose()?;
+
+            let read_error = |e| ConvertError::invalid_input("Failed to read Avro", e);
+            let reader = match &reader_schema {
+                Some(schema) => {
+                    let reader = Reader::with_schema(schema, input).map_err(read_error)?;
+                    SchemaCompatibility::can_read(reader.writer_schema(), schema).map_err(|e| {
+                        ConvertError::invalid_input(
+                            "Avro reader schema is incompatible with writer schema",
+                            e,
+                        )
+                    })?;
+                    reader
+                }
+                None => Reader::new(input).map_err(read_error)?,
+            };
+
+            let mut records = Vec::new();
+            for value in reader {
+                let value =
+                    value.map_err(|e| ConvertError::failed("Failed to read Avro record", e))?;
+                // Convert Avro value to JSON
+                let json_value = avro_value_to_json(&value);
+                records.push(json_value);
+            }
+
+            let output = serde_json::to_vec_pretty(&serde_json::Value::Array(records))
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+            let mut out_props = Properties::new();
+            out_props.insert("format".into(), "json".into());
+
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+
+    /// Write a JSON array of records to an Avro container file.
+    ///
+    /// Input properties:
+    /// - `avro_schema` (required): inline Avro schema (JSON string)
+    ///
+    /// Options:
+    /// - `avro_codec`: block compression codec, e.g. `"null"` or `"deflate"`
+    ///   (default: `"null"`). Other codecs depend on the apache-avro features
+    ///   enabled in the build.
+    ///
+    /// Each array element becomes one record. JSON numbers map to Long or
+    /// Double and objects to Records, then are resolved against the schema
+    /// (e.g. Long to Int, null to a nullable union branch).
+    pub struct JsonToAvro;
+
+    impl JsonToAvro {
+        fn decl() -> ConverterDecl {
+            ConverterDecl::simple(
+                "json-to-avro",
+                PropertyPattern::new()
+                    .eq("format", "json")
+                    .exists("avro_schema"),
+                PropertyPattern::new().eq("format", "avro"),
+            )
+            .description("Write JSON array to Avro container file")
+            .with_property_schema("avro_codec", PropertySchema::string())
+        }
+
+        fn codec(props: &Properties) -> Result<Codec, ConvertError> {
+            let name = props
+                .get("avro_codec")
+                .and_then(|v| v.as_str())
+                .unwrap_or("null");
+            name.parse().map_err(|_| {
+                ConvertError::from(PropertyError::Invalid {
+                    property: "avro_codec".into(),
+                    message: format!("avro_codec must be a supported codec, got: {}", name),
+                })
+            })
+        }
+
+        fn schema(props: &Properties) -> Result<Schema, ConvertError> {
+            let raw = if let Some(inline) = props.get("avro_schema").and_then(|v| v.as_str()) {
+                inline.to_string()
+            } else if let Some(path) = props.get("avro_schema_file").and_then(|v| v.as_str()) {
+                std::fs::read_to_string(path).map_err(|e| {
+                    ConvertError::invalid_input(
+                        format!("Failed to read Avro schema file '{}'", path),
+                        e,
+                    )
+                })?
+            } else {
+                return Err(ConvertError::MissingProperty(
+                    "avro_schema or avro_schema_file".into(),
+                ));
+            };
+
+            Schema::parse_str(&raw)
+                .map_err(|e| ConvertError::invalid_input("Invalid Avro schema", e))
+        }
+    }
+
+    impl Converter for JsonToAvro {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(Self::decl)
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let schema = Self::schema(props)?;
+            let codec = Self::codec(props)?;
+
+            let value: serde_json::Value = serde_json::from_slice(input)
+                .map_err(|e| ConvertError::invalid_input("Invalid JSON", e))?;
+            let serde_json::Value::Array(items) = value else {
+                return Err(ConvertError::invalid_input_msg(
+                    "JSON must be an array of records",
+                ));
+            };
+
+            let mut writer = Writer::with_codec(&schema, Vec::new(), codec);
+            for (idx, item) in items.into_iter().enumerate() {
+                let record = apache_avro::types::Value::from(item)
+                    .resolve(&schema)
+                    .map_err(|e| {
+                        ConvertError::invalid_input(
+                            format!("Record {} does not match Avro schema", idx),
+                            e,
+                        )
+                    })?;
+                writer
+                    .append(record)
+                    .map_err(|e| ConvertError::failed("Failed to write Avro record", e))?;
+            }
+
+            let output = writer
+                .into_inner()
+                .map_err(|e| ConvertError::failed("Failed to finish Avro file", e))?;
+
+            let mut out_props = props.clone();
+            out_props.insert("format".into(), "avro".into());
+            out_props.shift_remove("avro_schema");
+            out_props.shift_remove("avro_schema_file");
+            out_props.shift_remove("avro_codec");
+
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+
+    /// Write a JSON array to an Avro container file, reading the schema from
+    /// the path in `avro_schema_file`.
+    ///
+    /// Otherwise the same as [`JsonToAvro`], including `avro_codec`.
+    pub struct JsonToAvroFile;
+
+    impl Converter for JsonToAvroFile {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(|| {
+                ConverterDecl::simple(
+                    "json-to-avro-file",
+                    PropertyPattern::new()
+                        .eq("format", "json")
+                        .exists("avro_schema_file"),
+                    PropertyPattern::new().eq("format", "avro"),
+                )
+                .description("Write JSON array to Avro container file using a schema file")
+                .with_property_schema("avro_codec", PropertySchema::string())
+            })
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            JsonToAvro.convert(input, props)
+        }
+    }
+
+    /// Convert an Avro value to a JSON value.
+    fn avro_value_to_json(value: &apache_avro::types::Value) -> serde_json::Value {
+        use apache_avro::types::Value as AV;
+        match value {
+            AV::Null => serde_json::Value::Null,
+            AV::Boolean(b) => serde_json::Value::Bool(*b),
+            AV::Int(n) => serde_json::json!(*n),
+            AV::Long(n) => serde_json::json!(*n),
+            AV::Float(f) => serde_json::json!(*f),
+            AV::Double(d) => serde_json::json!(*d),
+            AV::Bytes(b) | AV::Fixed(_, b) => {
+                // Encode bytes as base64
+                use base64::Engine;
+                serde_json::Value::String(base64::engine::general_purpose::STANDARD.encode(b))
+            }
+            AV::String(s) => serde_json::Value::String(s.clone()),
+            AV::Enum(_, s) => serde_json::Value::String(s.clone()),
+            AV::Union(_, inner) => avro_value_to_json(inner),
+            AV::Array(arr) => {
+                serde_json::Value::Array(arr.iter().map(avro_value_to_json).collect())
+            }
+            AV::Map(map) => {
+                let obj: serde_json::Map<String, serde_json::Value> = map
+                    .iter()
+                    .map(|(k, v)| (k.clone(), avro_value_to_json(v)))
+                    .collect();
+                serde_json::Value::Object(obj)
+            }
+            AV::Record(fields) => {
+                let obj: serde_json::Map<String, serde_json::Value> = fields
+                    .iter()
+                    .map(|(k, v)| (k.clone(), avro_value_to_json(v)))
+                    .collect();
+                serde_json::Value::Object(obj)
+            }
+            AV::Date(d) => serde_json::json!(*d),
+            AV::Decimal(d) => serde_json::Value::String(format!("{:?}", d)),
+            AV::TimeMillis(t) => serde_json::json!(*t),
+            AV::TimeMicros(t) => serde_json::json!(*t),
+            AV::TimestampMillis(t) => serde_json::json!(*t),
+            AV::TimestampMicros(t) => serde_json::json!(*t),
+            AV::TimestampNanos(t) => serde_json::json!(*t),
+            AV::LocalTimestampMillis(t) => serde_json::json!(*t),
+            AV::LocalTimestampMicros(t) => serde_json::json!(*t),
+            AV::LocalTimestampNanos(t) => serde_json::json!(*t),
+            AV::Duration(d) => serde_json::Value::String(format!("{:?}", d)),
+            AV::Uuid(u) => serde_json::Value::String(u.to_string()),
+            AV::BigDecimal(bd) => serde_json::Value::String(bd.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+pub use avro_impl::{AvroToJson, JsonToAvro, JsonToAvroFile};
+
+#[cfg(feature = "parquet")]
+mod parquet_filter;
+
+#[cfg(feature = "parquet")]
+mod parquet_impl {
+    use super::*;
+    use crate::parquet_filter::Expr;
+    use arrow::array::*;
+    use bytes::Bytes;
+    use parquet::arrow::ProjectionMask;
+    use parquet::arrow::arrow_reader::{
+        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowFilter,
+    };
+
+    /// Read Parquet files to JSON.
+    ///
+    /// Parquet files are self-describing - the schema is in the file footer.
+    /// Outputs a JSON array of records.
+    ///
+    /// Input properties:
+    /// - `parquet_filter`: optional row predicate, e.g.
+    ///   `age > 18 AND status = 'active'`. Row groups whose statistics rule
+    ///   out a match are skipped entirely; remaining rows are filtered while
+    ///   decoding.
+    pub struct ParquetToJson;
+
+    impl ParquetToJson {
+        fn decl() -> ConverterDecl {
+            ConverterDecl::simple(
+                "parquet-to-json",
+                PropertyPattern::new().eq("format", "parquet"),
+                PropertyPattern::new().eq("format", "json"),
+            )
+            .description("Read Parquet file to JSON")
+        }
+    }
+
+    impl Converter for ParquetToJson {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(Self::decl)
+        }
+
+        fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+            let filter = props
+                .get("parquet_filter")
+                .and_then(|v| v.as_str())
+                .map(|s| {
+                    Expr::parse(s).map_err(|e| {
+                        ConvertError::invalid_input(format!("Invalid parquet_filter '{}'", s), e)
+                    })
+                })
+                .transpose()?;
+
+            let bytes = Bytes::copy_from_slice(input);
+            let mut builder = ParquetRecordBatchReaderBuilder::try_new(bytes)
+                .map_err(|e| ConvertError::invalid_input("Failed to read Parquet", e))?;
+
+            if let Some(filter) = filter {
+                builder = apply_filter(builder, filter)?;
+            }
+
+            let reader = builder
+                .build()
+                .map_err(|e| ConvertError::failed("Failed to build Parquet reader", e))?;
+
+            let schema = reader.schema();
+            let mut all_records = Vec::new();
+
+            for batch_result in reader {
+                let batch = batch_result
+                    .map_err(|e| ConvertError::failed("Failed to read Parquet batch", e))?;
+
+                // Convert each row to JSON
+                for row_idx in 0..batch.num_rows() {
+                    let mut record = serde_json::Map::new();
+                    for (col_idx, field) in schema.fields().iter().enumerate() {
+                        let column = batch.column(col_idx);
+                        let value = array_value_to_json(column.as_ref(), row_idx);
+                        record.insert(field.name().clone(), value);
+                    }
+                    all_records.push(serde_json::Value::Object(record));
+                }
+            }
+
+            let output = serde_json::to_vec_pretty(&serde_json::Value::Array(all_records))
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+            let mut out_props = Properties::new();
+            out_props.insert("format".into(), "json".into());
+
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+
+    /// Describe a Parquet file's schema as JSON Schema (draft-07).
+    ///
+    /// Only the footer is read. The schema describes the output of
+    /// [`ParquetToJson`]: an array of records with one property per column.
+    /// Nullable columns become `{"anyOf": [<type>, {"type": "null"}]}`.
+    ///
+    /// Costs more than `parquet-to-json` so plans for data never pick it.
+    pub struct ParquetSchemaToJson;
+
+    impl ParquetSchemaToJson {
+        fn decl() -> ConverterDecl {
+            ConverterDecl::simple(
+                "parquet-schema-to-json",
+                PropertyPattern::new().eq("format", "parquet"),
+                PropertyPattern::new().eq("format", "json"),
+            )
+            .description("Extract Parquet schema as JSON Schema")
+            .with_cost(10.0)
+        }
+    }
+
+    impl Converter for ParquetSchemaToJson {
+        fn decl(&self) -> &ConverterDecl {
+            static DECL: std::sync::OnceLock<ConverterDecl> = std::sync::OnceLock::new();
+            DECL.get_or_init(Self::decl)
+        }
+
+        fn convert(
+            &self,
+            input: &[u8],
+            _props: &Properties,
+        ) -> Result<ConvertOutput, ConvertError> {
+            let bytes = Bytes::copy_from_slice(input);
+            let builder = ParquetRecordBatchReaderBuilder::try_new(bytes)
+                .map_err(|e| ConvertError::invalid_input("Failed to read Parquet", e))?;
+
+            let schema = serde_json::json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "array",
+                "items": object_schema(builder.schema().fields()),
+            });
+
+            let output = serde_json::to_vec_pretty(&schema)
+                .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+            let mut out_props = Properties::new();
+            out_props.insert("format".into(), "json".into());
+
+            Ok(ConvertOutput::Single(output, out_props))
+        }
+    }
+
+    /// JSON Schema for a record with the given fields.
+    ///
+    /// Every field is required: records always carry every column, with
+    /// `null` for missing values.
+    fn object_schema(fields: &arrow::datatypes::Fields) -> serde_json::Value {
+        let properties: serde_json::Map<String, serde_json::Value> = fields
+            .iter()
+            .map(|f| (f.name().clone(), field_schema(f)))
+            .collect();
+        let required: Vec<&String> = fields.iter().map(|f| f.name()).collect();
+        serde_json::json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+        })
+    }
+
+    /// JSON Schema for one field, allowing `null` if it is nullable.
+    fn field_schema(field: &arrow::datatypes::Field) -> serde_json::Value {
+        let schema = data_type_schema(field.data_type());
+        if field.is_nullable() {
+            serde_json::json!({ "anyOf": [schema, { "type": "null" }] })
+        } else {
+            schema
+        }
+    }
+
+    /// JSON Schema for an Arrow data type.
+    fn data_type_schema(data_type: &arrow::datatypes::DataType) -> serde_json::Value {
+        use arrow::datatypes::DataType;
+        use serde_json::json;
+
+        match data_type {
+            DataType::Null => json!({ "type": "null" }),
+            DataType::Boolean => json!({ "type": "boolean" }),
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64 => json!({ "type": "integer" }),
+            DataType::Float16
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal128(..)
+            | DataType::Decimal256(..) => json!({ "type": "number" }),
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => {
+                json!({ "type": "string" })
+            }
+            // Binary values are emitted base64-encoded
+            DataType::Binary
+            | DataType::LargeBinary
+            | DataType::BinaryView
+            | DataType::FixedSizeBinary(_) => {
+                json!({ "type": "string", "contentEncoding": "base64" })
+            }
+            DataType::Date32 | DataType::Date64 => json!({ "type": "string", "format": "date" }),
+            DataType::Timestamp(..) => json!({ "type": "string", "format": "date-time" }),
+            DataType::List(item) | DataType::LargeList(item) | DataType::FixedSizeList(item, _) => {
+                json!({ "type": "array", "items": field_schema(item) })
+            }
+            DataType::Struct(fields) => object_schema(fields),
+            // No JSON Schema equivalent; accept anything
+            _ => json!({}),
+        }
+    }
+
+    /// Push a filter expression down into the Parquet reader.
+    fn apply_filter(
+        builder: ParquetRecordBatchReaderBuilder<Bytes>,
+        filter: Expr,
+    ) -> Result<ParquetRecordBatchReaderBuilder<Bytes>, ConvertError> {
+        let schema = builder.parquet_schema();
+        let mut leaves = Vec::new();
+        for name in filter.columns() {
+            let idx = schema
+                .columns()
+                .iter()
+                .position(|c| c.name() == name)
+                .ok_or_else(|| {
+                    ConvertError::invalid_input_msg(format!(
+                        "Unknown column in parquet_filter: {}",
+                        name
+                    ))
+                })?;
+            leaves.push(idx);
+        }
+        let projection = ProjectionMask::leaves(schema, leaves);
+        filter.check_types(builder.schema()).map_err(|e| {
+            ConvertError::invalid_input_msg(format!("Invalid parquet_filter: {}", e))
+        })?;
+
+        // Skip row groups whose min/max statistics can't satisfy the filter
+        let row_groups: Vec<usize> = builder
+            .metadata()
+            .row_groups()
+            .iter()
+            .enumerate()
+            .filter(|(_, rg)| filter.may_match(rg))
+            .map(|(i, _)| i)
+            .collect();
+
+        let predicate = ArrowPredicateFn::new(projection, move |batch| filter.evaluate(&batch));
+
+        Ok(builder
+            .with_row_groups(row_groups)
+            .with_row_filter(RowFilter::new(vec![Box::new(predicate)])))
+    }
+
+    /// Convert an Arrow array value at a given index to JSON.
+    fn array_value_to_json(array: &dyn Array, idx: usize) -> serde_json::Value {
+        if array.is_null(idx) {
+            return serde_json::Value::Null;
+        }
+
+        // Handle different Arrow data types
+        if let Some(arr) = array.as_any().downcast_ref::<StringArray>() {
+            return serde_json::Value::String(arr.value(idx).to_string());
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<LargeStringArray>() {
+            return serde_json::Value::String(arr.value(idx).to_string());
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<Int32Array>() {
+            return serde_json::json!(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<Int64Array>() {
+            return serde_json::json!(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<Float32Array>() {
+            return serde_json::json!(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<Float64Array>() {
+            return serde_json::json!(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<BooleanArray>() {
+            return serde_json::Value::Bool(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<Int8Array>() {
+            return serde_json::json!(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<Int16Array>() {
+            return serde_json::json!(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<UInt8Array>() {
+            return serde_json::json!(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<UInt16Array>() {
+            return serde_json::json!(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<UInt32Array>() {
+            return serde_json::json!(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<UInt64Array>() {
+            return serde_json::json!(arr.value(idx));
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<BinaryArray>() {
+            use base64::Engine;
+            return serde_json::Value::String(
+                base64::engine::general_purpose::STANDARD.encode(arr.value(idx)),
+            );
+        }
+        if let Some(arr) = array.as_any().downcast_ref::<LargeBinaryArray>() {
+            use base64::Engine;
+            return serde_json::Value::String(
+                base64::engine::general_purpose::STANDARD.encode(arr.value(idx)),
+            );
+        }
+
+        // Fallback for unsupported types
+        serde_json::Value::String(format!("<unsupported: {:?}>", array.data_type()))
+    }
+}
+
+#[cfg(feature = "parquet")]
+pub use parquet_impl::{ParquetSchemaToJson, ParquetToJson};
+
+/// Deserialize bytes to a serde Value.
+fn deserialize(format: &str, data: &[u8]) -> Result<serde_json::Value, ConvertError> {
+    match format {
+        // === Text formats ===
+        #[cfg(feature = "json")]
+        "json" => {
+            serde_json::from_slice(data).map_err(|e| ConvertError::invalid_input("Invalid JSON", e))
+        }
+
+        #[cfg(feature = "yaml")]
+        "yaml" => {
+            serde_yaml::from_slice(data).map_err(|e| ConvertError::invalid_input("Invalid YAML", e))
+        }
+
+        #[cfg(feature = "toml")]
+        "toml" => {
+            let s = std::str::from_utf8(data)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            toml_impl::from_str(s, true)
+        }
+
+        #[cfg(feature = "ron")]
+        "ron" => {
+            let s = std::str::from_utf8(data)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            ron::from_str(s).map_err(|e| ConvertError::invalid_input("Invalid RON", e))
+        }
+
+        #[cfg(feature = "json5")]
+        "json5" => {
+            let s = std::str::from_utf8(data)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            json5::from_str(s).map_err(|e| ConvertError::invalid_input("Invalid JSON5", e))
+        }
+
+        // Blocks become nested objects keyed by type and labels;
+        // heredocs are plain strings
+        #[cfg(feature = "hcl")]
+        "hcl" => hcl::from_slice(data).map_err(|e| ConvertError::invalid_input("Invalid HCL", e)),
+
+        #[cfg(feature = "edn")]
+        "edn" => {
+            let s = std::str::from_utf8(data)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            edn_impl::from_str(s)
+        }
+
+        #[cfg(feature = "kdl")]
+        "kdl" => {
+            let s = std::str::from_utf8(data)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            kdl_impl::from_str(s)
+        }
+
+        #[cfg(feature = "xml")]
+        "xml" => {
+            let s = std::str::from_utf8(data)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            xml_impl::from_str(s, false).map(|(value, _)| value)
+        }
+
+        #[cfg(feature = "lexpr")]
+        "lexpr" => {
+            let s = std::str::from_utf8(data)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            lexpr_impl::from_str(s)
+        }
+
+        #[cfg(feature = "urlencoded")]
+        "urlencoded" => {
+            let s = std::str::from_utf8(data)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            serde_urlencoded::from_str(s)
+                .map_err(|e| ConvertError::invalid_input("Invalid URL-encoded", e))
+        }
+
+        #[cfg(feature = "qs")]
+        "qs" => {
+            let s = std::str::from_utf8(data)
+                .map_err(|e| ConvertError::invalid_input("Invalid UTF-8", e))?;
+            // serde_qs can't deserialize into an untyped value at the top level
+            serde_qs::from_str::<serde_json::Map<String, serde_json::Value>>(s)
+                .map(serde_json::Value::Object)
+                .map_err(|e| ConvertError::invalid_input("Invalid query string", e))
+        }
+
+        // === Binary formats ===
+        #[cfg(feature = "msgpack")]
+        "msgpack" => rmp_serde::from_slice(data)
+            .map_err(|e| ConvertError::invalid_input("Invalid MessagePack", e)),
+
+        #[cfg(feature = "cbor")]
+        "cbor" => {
+            ciborium::from_reader(data).map_err(|e| ConvertError::invalid_input("Invalid CBOR", e))
+        }
+
+        #[cfg(feature = "bincode")]
+        "bincode" => {
+            let (value, _): (serde_json::Value, _) =
+                bincode::serde::decode_from_slice(data, bincode::config::standard())
+                    .map_err(|e| ConvertError::invalid_input("Invalid Bincode", e))?;
+            Ok(value)
+        }
+
+        #[cfg(feature = "postcard")]
+        "postcard" => postcard::from_bytes(data)
+            .map_err(|e| ConvertError::invalid_input("Invalid Postcard", e)),
+
+        #[cfg(feature = "bson")]
+        "bson" => bson_impl::from_slice(data, true),
+
+        #[cfg(feature = "flexbuffers")]
+        "flexbuffers" => flexbuffers::from_slice(data)
+            .map_err(|e| ConvertError::invalid_input("Invalid FlexBuffers", e)),
+
+        #[cfg(feature = "bencode")]
+        "bencode" => bencode_impl::from_slice(data),
+
+        #[cfg(feature = "pickle")]
+        "pickle" => serde_pickle::from_slice(data, serde_pickle::DeOptions::default())
+            .map_err(|e| ConvertError::invalid_input("Invalid Pickle", e)),
+
+        // Binary and XML property lists are told apart by their header
+        #[cfg(feature = "plist")]
+        "plist" | "plist-xml" => plist::from_bytes(data)
+            .map_err(|e| ConvertError::invalid_input("Invalid Property List", e)),
+
+        _ => Err(ConvertError::failed_msg(format!(
+            "Unsupported source format: {}",
+            format
+        ))),
+    }
+}
+
+/// Pessimistic compressed size used for memory estimates (30% of the input).
+#[cfg(any(
+    feature = "gzip",
+    feature = "zstd",
+    feature = "brotli",
+    feature = "bzip2",
+    feature = "snappy"
+))]
+fn compressed_size_estimate(input_len: usize) -> usize {
+    input_len.saturating_mul(3) / 10
+}
+
+/// Output properties for decompressed data.
+///
+/// The `format` recorded as `inner_format` on compression is restored, so
+/// `{format: gzip, inner_format: json}` decompresses to `{format: json}`.
+/// Without an `inner_format`, or with `restore_inner_format` set to false,
+/// the output is `raw`.
+#[cfg(any(
+    feature = "gzip",
+    feature = "zstd",
+    feature = "brotli",
+    feature = "bzip2",
+    feature = "snappy"
+))]
+fn decompressed_props(props: &Properties) -> Properties {
+    let mut out_props = props.clone();
+    let restore = flag(props, "restore_inner_format", true);
+    out_props.shift_remove("restore_inner_format");
+    let format = match out_props.get("inner_format") {
+        Some(_) if restore => out_props.shift_remove("inner_format").unwrap(),
+        _ => "raw".into(),
+    };
+    out_props.insert("format".into(), format);
+    out_props
+}
+
+/// Read a boolean option, accepting `true`/`false` or their string forms.
+fn flag(props: &Properties, key: &str, default: bool) -> bool {
+    match props.get(key) {
+        Some(v) => v
+            .as_bool()
+            .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+            .unwrap_or(default),
+        None => default,
+    }
+}
+
+/// Recursively sort object keys.
+fn sort_keys(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = std::mem::take(map).into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (key, mut v) in entries {
+                sort_keys(&mut v);
+                map.insert(key, v);
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(sort_keys),
+        _ => {}
+    }
+}
+
+/// Re-encode CBOR in canonical form (RFC 8949 Section 4.2).
+///
+/// Map keys are ordered by the length of their encoding, then bytewise.
+/// ciborium already emits the shortest integer and float encodings and
+/// definite lengths, so sorting keys is all that's left to do.
+#[cfg(feature = "cbor")]
+pub fn canonicalize_cbor(data: &[u8]) -> Result<Vec<u8>, ConvertError> {
+    fn encode(value: &ciborium::Value) -> Result<Vec<u8>, ConvertError> {
+        let mut buf = Vec::new();
+        ciborium::into_writer(value, &mut buf)
+            .map_err(|e| ConvertError::failed("CBOR serialization failed", e))?;
+        Ok(buf)
+    }
+
+    fn canonicalize(value: &mut ciborium::Value) -> Result<(), ConvertError> {
+        match value {
+            ciborium::Value::Map(entries) => {
+                let mut keyed = Vec::with_capacity(entries.len());
+                for (mut k, mut v) in std::mem::take(entries) {
+                    canonicalize(&mut k)?;
+                    canonicalize(&mut v)?;
+                    keyed.push((encode(&k)?, k, v));
+                }
+                keyed.sort_by(|(a, ..), (b, ..)| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+                entries.extend(keyed.into_iter().map(|(_, k, v)| (k, v)));
+            }
+            ciborium::Value::Array(items) => {
+                for item in items {
+                    canonicalize(item)?;
+                }
+            }
+            ciborium::Value::Tag(_, inner) => canonicalize(inner)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    let mut value: ciborium::Value = ciborium::from_reader(data)
+        .map_err(|e| ConvertError::invalid_input("CBOR parse error", e))?;
+    canonicalize(&mut value)?;
+    encode(&value)
+}
+
+/// Serialize a serde Value to bytes.
+fn serialize(format: &str, value: &serde_json::Value) -> Result<Vec<u8>, ConvertError> {
+    match format {
+        // === Text formats ===
+        #[cfg(feature = "json")]
+        "json" => serde_json::to_vec_pretty(value)
+            .map_err(|e| ConvertError::failed("JSON serialization failed", e)),
+
+        #[cfg(feature = "yaml")]
+        "yaml" => serde_yaml::to_string(value)
+            .map(|s| s.into_bytes())
+            .map_err(|e| ConvertError::failed("YAML serialization failed", e)),
+
+        #[cfg(feature = "toml")]
+        "toml" => {
+            toml_impl::to_string(value, toml_impl::NullStrategy::Omit).map(|s| s.into_bytes())
+        }
+
+        #[cfg(feature = "ron")]
+        "ron" => ron::to_string(value)
+            .map(|s| s.into_bytes())
+            .map_err(|e| ConvertError::failed("RON serialization failed", e)),
+
+        #[cfg(feature = "json5")]
+        "json5" => {
+            // json5 crate doesn't have serialization, output as JSON (compatible)
+            serde_json::to_vec_pretty(value)
+                .map_err(|e| ConvertError::failed("JSON5 serialization failed", e))
+        }
+
+        #[cfg(feature = "hcl")]
+        "hcl" => hcl::to_string(value)
+            .map(|s| s.into_bytes())
+            .map_err(|e| ConvertError::failed("HCL serialization failed", e)),
+
+        #[cfg(feature = "edn")]
+        "edn" => Ok(edn_impl::to_string(value).into_bytes()),
+
+        #[cfg(feature = "kdl")]
+        "kdl" => kdl_impl::to_string(value).map(String::into_bytes),
+
+        #[cfg(feature = "xml")]
+        "xml" => xml_impl::to_string(value, xml_impl::DEFAULT_ROOT).map(|s| s.into_bytes()),
+
+        #[cfg(feature = "lexpr")]
+        "lexpr" => lexpr_impl::to_string(value).map(String::into_bytes),
+
+        #[cfg(feature = "urlencoded")]
+        "urlencoded" => serde_urlencoded::to_string(value)
+            .map(|s| s.into_bytes())
+            .map_err(|e| ConvertError::failed("URL-encoded serialization failed", e)),
+
+        #[cfg(feature = "qs")]
+        "qs" => serde_qs::to_string(value)
+            .map(|s| s.into_bytes())
+            .map_err(|e| ConvertError::failed("Query string serialization failed", e)),
+
+        // === Binary formats ===
+        #[cfg(feature = "msgpack")]
+        "msgpack" => rmp_serde::to_vec(value)
+            .map_err(|e| ConvertError::failed("MessagePack serialization failed", e)),
+
+        #[cfg(feature = "cbor")]
+        "cbor" => {
+            let mut buf = Vec::new();
+            ciborium::into_writer(value, &mut buf)
+                .map_err(|e| ConvertError::failed("CBOR serialization failed", e))?;
+            Ok(buf)
+        }
+
+        #[cfg(feature = "bincode")]
+        "bincode" => bincode::serde::encode_to_vec(value, bincode::config::standard())
+            .map_err(|e| ConvertError::failed("Bincode serialization failed", e)),
+
+        #[cfg(feature = "postcard")]
+        "postcard" => postcard::to_allocvec(value)
+            .map_err(|e| ConvertError::failed("Postcard serialization failed", e)),
+
+        #[cfg(feature = "bson")]
+        "bson" => bson::ser::serialize_to_vec(value)
+            .map_err(|e| ConvertError::failed("BSON serialization failed", e)),
+
+        #[cfg(feature = "flexbuffers")]
+        "flexbuffers" => flexbuffers::to_vec(value)
+            .map_err(|e| ConvertError::failed("FlexBuffers serialization failed", e)),
+
+        #[cfg(feature = "bencode")]
+        "bencode" => serde_bencode::to_bytes(value)
+            .map_err(|e| ConvertError::failed("Bencode serialization failed", e)),
+
+        #[cfg(feature = "pickle")]
+        "pickle" => serde_pickle::to_vec(value, serde_pickle::SerOptions::default())
+            .map_err(|e| ConvertError::failed("Pickle serialization failed", e)),
+
+        #[cfg(feature = "plist")]
+        "plist" => {
+            let mut buf = Vec::new();
+            plist::to_writer_binary(&mut buf, value)
+                .map_err(|e| ConvertError::failed("Property List serialization failed", e))?;
+            Ok(buf)
+        }
+
+        #[cfg(feature = "plist")]
+        "plist-xml" => {
+            let mut buf = Vec::new();
+            plist::to_writer_xml(&mut buf, value)
+                .map_err(|e| ConvertError::failed("Property List serialization failed", e))?;
+            Ok(buf)
+        }
+
+        _ => Err(ConvertError::failed_msg(format!(
+            "Unsupported target format: {}",
+            format
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paraphase_core::PropertiesExt;
+
+    #[test]
+    #[cfg(all(feature = "json", feature = "yaml"))]
+    fn test_json_to_yaml() {
+        let converter = SerdeConverter::new("json", "yaml");
+        let input = br#"{"name": "test", "value": 42}"#;
+        let props = Properties::new().with("format", "json");
+
+        let result = converter.convert(input, &props).unwrap();
+
+        match result {
+            ConvertOutput::Single(output, out_props) => {
+                let output_str = String::from_utf8(output).unwrap();
+                assert!(output_str.contains("name:"));
+                assert!(output_str.contains("test"));
+                assert_eq!(out_props.get("format").unwrap().as_str(), Some("yaml"));
+            }
+            _ => panic!("Expected single output"),
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "yaml", feature = "json"))]
+    fn test_yaml_to_json() {
+        let converter = SerdeConverter::new("yaml", "json");
+        let input = b"name: test\nvalue: 42\n";
+        let props = Properties::new().with("format", "yaml");
+
+        let result = converter.convert(input, &props).unwrap();
+
+        match result {
+            ConvertOutput::Single(output, out_props) => {
+                let output_str = String::from_utf8(output).unwrap();
+                assert!(output_str.contains("\"name\""));
+                assert!(output_str.contains("\"test\""));
+                assert_eq!(out_props.get("format").unwrap().as_str(), Some("json"));
+            }
+            _ => panic!("Expected single output"),
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "yaml", feature = "json"))]
+    fn test_json_output_options() {
+        let converter = SerdeConverter::new("yaml", "json");
+        let input = b"zeta: 1\nalpha:\n  young: true\n  old: [{b: 1, a: 2}]\n";
+        let convert = |props: Properties| match converter.convert(input, &props).unwrap() {
+            ConvertOutput::Single(output, _) => String::from_utf8(output).unwrap(),
+            _ => panic!("Expected single output"),
+        };
+
+        // Compact output is a single line, in document key order
+        let compact = convert(
+            Properties::new()
+                .with("format", "yaml")
+                .with("json_pretty", false),
+        );
+        assert!(!compact.contains('\n'));
+        assert_eq!(
+            compact,
+            r#"{"zeta":1,"alpha":{"young":true,"old":[{"b":1,"a":2}]}}"#
+        );
+
+        // Pretty is the default
+        let pretty = convert(Properties::new().with("format", "yaml"));
+        assert!(pretty.lines().count() > 1);
+
+        // Sorted keys at every nesting level, combined with compact
+        let sorted = convert(
+            Properties::new()
+                .with("format", "yaml")
+                .with("json_pretty", false)
+                .with("json_sorted_keys", true),
+        );
+        assert_eq!(
+            sorted,
+            r#"{"alpha":{"old":[{"a":2,"b":1}],"young":true},"zeta":1}"#
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "bson", feature = "json"))]
+    fn test_bson_special_types_to_json() {
+        use bson::spec::BinarySubtype;
+        use bson::{Binary, DateTime, Decimal128, doc, oid::ObjectId};
+
+        let oid = ObjectId::parse_str("507f1f77bcf86cd799439011").unwrap();
+        let document = doc! {
+            "_id": oid,
+            "created": DateTime::from_millis(1_673_778_600_000),
+            "blob": Binary { subtype: BinarySubtype::Generic, bytes: b"hi".to_vec() },
+            "price": "12.50".parse::<Decimal128>().unwrap(),
+            "tags": ["a", 1_i64],
+        };
+        let input = bson::serialize_to_vec(&document).unwrap();
+
+        let converter = SerdeConverter::new("bson", "json");
+        let props = Properties::new().with("format", "bson");
+        let json = match converter.convert(&input, &props).unwrap() {
+            ConvertOutput::Single(output, _) => {
+                serde_json::from_slice::<serde_json::Value>(&output).unwrap()
+            }
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "_id": { "$oid": "507f1f77bcf86cd799439011" },
+                "created": { "$date": "2023-01-15T10:30:00Z" },
+                "blob": { "$binary": { "base64": "aGk=", "subType": "00" } },
+                "price": { "$numberDecimal": "12.50" },
+                "tags": ["a", 1],
+            })
+        );
+
+        let props = props.with("bson_extended_json", false);
+        let json = match converter.convert(&input, &props).unwrap() {
+            ConvertOutput::Single(output, _) => {
+                serde_json::from_slice::<serde_json::Value>(&output).unwrap()
+            }
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(json["_id"], "507f1f77bcf86cd799439011");
+        assert_eq!(json["created"], "2023-01-15T10:30:00Z");
+        assert_eq!(json["blob"], "aGk=");
+        assert_eq!(json["price"], "12.50");
+    }
+
+    #[test]
+    #[cfg(all(feature = "bson", feature = "json"))]
+    fn test_json_extended_to_bson() {
+        use bson::{Bson, Document};
+
+        let input = br#"{
+            "_id": {"$oid": "507f1f77bcf86cd799439011"},
+            "blob": {"$binary": {"base64": "aGk=", "subType": "00"}},
+            "created": {"$date": "2023-01-15T10:30:00Z"},
+            "name": "widget",
+            "nested": {"$oid": "not hex"}
+        }"#;
+        let to_bson = SerdeConverter::new("json", "bson");
+        let props = Properties::new().with("format", "json");
+        let bson_bytes = match to_bson.convert(input, &props).unwrap() {
+            ConvertOutput::Single(output, _) => output,
+            _ => panic!("Expected single output"),
+        };
+        let doc = Document::from_reader(bson_bytes.as_slice()).unwrap();
+        assert!(matches!(doc.get("_id"), Some(Bson::ObjectId(_))));
+        assert!(matches!(doc.get("blob"), Some(Bson::Binary(_))));
+        assert!(matches!(doc.get("created"), Some(Bson::DateTime(_))));
+        assert!(matches!(doc.get("nested"), Some(Bson::Document(_))));
+
+        // And back to the same Extended JSON
+        let to_json = SerdeConverter::new("bson", "json");
+        let json = match to_json
+            .convert(&bson_bytes, &Properties::new().with("format", "bson"))
+            .unwrap()
+        {
+            ConvertOutput::Single(output, _) => {
+                serde_json::from_slice::<serde_json::Value>(&output).unwrap()
+            }
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(
+            json,
+            serde_json::from_slice::<serde_json::Value>(input).unwrap()
+        );
+
+        // Disabled: the wrappers stay plain documents
+        let props = props.with("bson_extended_json", false);
+        let bson_bytes = match to_bson.convert(input, &props).unwrap() {
+            ConvertOutput::Single(output, _) => output,
+            _ => panic!("Expected single output"),
+        };
+        let doc = Document::from_reader(bson_bytes.as_slice()).unwrap();
+        assert!(matches!(doc.get("_id"), Some(Bson::Document(_))));
+    }
+
+    #[test]
+    #[cfg(all(feature = "msgpack", feature = "json"))]
+    fn test_msgpack_options() {
+        let to_msgpack = SerdeConverter::new("json", "msgpack");
+        let input = br#"{"0":"a","1":"b","2":{"10":"c","name":"d"}}"#;
+        let encode = |props: Properties| match to_msgpack.convert(input, &props).unwrap() {
+            ConvertOutput::Single(output, _) => output,
+            _ => panic!("Expected single output"),
+        };
+
+        let named = encode(Properties::new().with("format", "json"));
+        let compact = encode(
+            Properties::new()
+                .with("format", "json")
+                .with("msgpack_compact", true),
+        );
+        // As fixints, "0", "1" and "2" save a byte each and "10" saves two
+        assert_eq!(named.len(), compact.len() + 5);
+
+        // Named keys win when explicitly requested
+        let forced = encode(
+            Properties::new()
+                .with("format", "json")
+                .with("msgpack_compact", true)
+                .with("msgpack_named_keys", true),
+        );
+        assert_eq!(forced, named);
+
+        // Integer keys read back as strings
+        let from_msgpack = SerdeConverter::new("msgpack", "json");
+        let decode = |data: &[u8], props: Properties| -> serde_json::Value {
+            match from_msgpack.convert(data, &props).unwrap() {
+                ConvertOutput::Single(output, _) => serde_json::from_slice(&output).unwrap(),
+                _ => panic!("Expected single output"),
+            }
+        };
+        let original: serde_json::Value = serde_json::from_slice(input).unwrap();
+        assert_eq!(decode(&compact, Properties::new()), original);
+
+        // {"data": bin [1, 2, 3]}
+        let binary = b"\x81\xa4data\xc4\x03\x01\x02\x03";
+        assert_eq!(decode(binary, Properties::new())["data"], "AQID");
+        assert_eq!(
+            decode(
+                binary,
+                Properties::new().with("msgpack_binary_as_base64", false)
+            )["data"],
+            serde_json::json!([1, 2, 3])
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "cbor", feature = "json"))]
+    fn test_cbor_canonical() {
+        let converter = SerdeConverter::new("json", "cbor");
+        let props = Properties::new()
+            .with("format", "json")
+            .with("cbor_canonical", true);
+        let convert = |input: &[u8]| match converter.convert(input, &props).unwrap() {
+            ConvertOutput::Single(output, _) => output,
+            _ => panic!("Expected single output"),
+        };
+
+        let a = convert(br#"{"b":1,"a":2}"#);
+        let b = convert(br#"{"a":2,"b":1}"#);
+        assert_eq!(a, b);
+
+        // Shorter keys sort first, regardless of lexicographic order
+        let output = convert(br#"{"aa":1,"b":{"yy":1,"z":2}}"#);
+        let value: ciborium::Value = ciborium::from_reader(output.as_slice()).unwrap();
+        let keys = |v: &ciborium::Value| -> Vec<String> {
+            v.as_map()
+                .unwrap()
+                .iter()
+                .map(|(k, _)| k.as_text().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(keys(&value), ["b", "aa"]);
+        assert_eq!(keys(&value.as_map().unwrap()[0].1), ["z", "yy"]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "plist", feature = "json"))]
+    fn test_plist_binary_and_xml() {
+        let input = br#"{"CFBundleName":"Paraphase","LSMinimumSystemVersion":"10.15","Count":3,"Enabled":true,"Tags":["a","b"]}"#;
+        let expected: serde_json::Value = serde_json::from_slice(input).unwrap();
+        let json = Properties::new().with("format", "json");
+        let convert =
+            |from, to, input: &[u8], props: &Properties| match SerdeConverter::new(from, to)
+                .convert(input, props)
+                .unwrap()
+            {
+                ConvertOutput::Single(output, props) => (output, props),
+                _ => panic!("Expected single output"),
+            };
+
+        // Binary by default
+        let (binary, props) = convert("json", "plist", input, &json);
+        assert!(binary.starts_with(b"bplist00"));
+        let (back, _) = convert("plist", "json", &binary, &props);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&back).unwrap(),
+            expected
+        );
+
+        // XML when asked for, or always via plist-xml
+        let (xml, _) = convert(
+            "json",
+            "plist",
+            input,
+            &json.clone().with("plist_format", "xml"),
+        );
+        let (plist_xml, props) = convert("json", "plist-xml", input, &json);
+        assert_eq!(xml, plist_xml);
+        let text = std::str::from_utf8(&xml).unwrap();
+        assert!(text.starts_with("<?xml"));
+        assert!(text.contains("<key>CFBundleName</key>"));
+        assert!(text.contains("<string>Paraphase</string>"));
+        assert_eq!(props.get("format").unwrap().as_str(), Some("plist-xml"));
+        let (back, _) = convert("plist-xml", "json", &xml, &props);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&back).unwrap(),
+            expected
+        );
+
+        // Either reader accepts either encoding
+        let (back, _) = convert("plist", "json", &xml, &props);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&back).unwrap(),
+            expected
+        );
+        let (xml, _) = convert("plist", "plist-xml", &binary, &props);
+        assert_eq!(xml, plist_xml);
+
+        let invalid = json.with("plist_format", "ascii");
+        assert!(
+            SerdeConverter::new("json", "plist")
+                .convert(input, &invalid)
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "cbor", feature = "msgpack"))]
+    fn test_cbor_msgpack_direct() {
+        use ciborium::Value as CborValue;
+        use paraphase_core::{Cardinality, Planner};
+
+        let original = CborValue::Map(vec![
+            (
+                CborValue::Text("blob".into()),
+                CborValue::Bytes(vec![0, 1, 0xff]),
+            ),
+            (
+                CborValue::Text("big".into()),
+                CborValue::Integer(u64::MAX.into()),
+            ),
+            (
+                CborValue::Integer(7.into()),
+                CborValue::Text("int key".into()),
+            ),
+            (
+                CborValue::Text("date".into()),
+                CborValue::Tag(1, Box::new(CborValue::Integer(0.into()))),
+            ),
+        ]);
+        let mut input = Vec::new();
+        ciborium::into_writer(&original, &mut input).unwrap();
+
+        let props = Properties::new().with("format", "cbor");
+        let (msgpack, props) = match CborToMsgpack.convert(&input, &props).unwrap() {
+            ConvertOutput::Single(output, props) => (output, props),
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(props.get("format").unwrap().as_str(), Some("msgpack"));
+        // bin 8 [0, 1, 255] rather than a base64 string
+        assert!(msgpack.windows(5).any(|w| w == b"\xc4\x03\x00\x01\xff"));
+
+        let cbor = match MsgpackToCbor.convert(&msgpack, &props).unwrap() {
+            ConvertOutput::Single(output, _) => output,
+            _ => panic!("Expected single output"),
+        };
+        let value: CborValue = ciborium::from_reader(cbor.as_slice()).unwrap();
+        let entries = value.as_map().unwrap();
+        assert_eq!(entries[0].1, CborValue::Bytes(vec![0, 1, 0xff]));
+        assert_eq!(entries[1].1, CborValue::Integer(u64::MAX.into()));
+        assert_eq!(entries[2].0, CborValue::Integer(7.into()));
+        // MessagePack has no tags, so only the content survives
+        assert_eq!(entries[3].1, CborValue::Integer(0.into()));
+
+        // Key options match the serde route
+        let keys = |input: &[u8], props: &Properties| -> Vec<CborValue> {
+            let msgpack = match CborToMsgpack.convert(input, props).unwrap() {
+                ConvertOutput::Single(output, _) => output,
+                _ => panic!("Expected single output"),
+            };
+            let value: CborValue = rmp_serde::from_slice(&msgpack).unwrap();
+            value
+                .as_map()
+                .unwrap()
+                .iter()
+                .map(|(k, _)| k.clone())
+                .collect()
+        };
+        let props = Properties::new().with("format", "cbor");
+        let named = props.clone().with("msgpack_named_keys", true);
+        assert_eq!(keys(&input, &props)[2], CborValue::Integer(7.into()));
+        assert_eq!(keys(&input, &named)[2], CborValue::Text("7".into()));
+
+        let mut numbered = Vec::new();
+        let map = CborValue::Map(vec![(CborValue::Text("3".into()), CborValue::Null)]);
+        ciborium::into_writer(&map, &mut numbered).unwrap();
+        let compact = props.clone().with("msgpack_compact", true);
+        assert_eq!(keys(&numbered, &props), [CborValue::Text("3".into())]);
+        assert_eq!(keys(&numbered, &compact), [CborValue::Integer(3.into())]);
+        assert_eq!(
+            keys(&numbered, &compact.with("msgpack_named_keys", true)),
+            [CborValue::Text("3".into())]
+        );
+
+        // The planner takes the direct route
+        let mut registry = Registry::new();
+        register_all(&mut registry);
+        let plan = Planner::new(&registry)
+            .plan(
+                &Properties::new().with("format", "cbor"),
+                &PropertyPattern::new().eq("format", "msgpack"),
+                Cardinality::One,
+                Cardinality::One,
+            )
+            .unwrap();
+        assert_eq!(plan.steps.len(), 1);
+        assert_eq!(plan.steps[0].converter_id, "serde.cbor-to-msgpack");
+    }
+
+    #[test]
+    #[cfg(all(feature = "edn", feature = "json"))]
+    fn test_edn_json_roundtrip() {
+        let input = br#"{:name "paraphase"
+ :tags #{:data :convert}
+ :deps [{:id clojure.core :version "1.12"} nil]
+ "plain" 1.5}"#;
+        let convert = |from, to, input: &[u8]| {
+            let props = Properties::new().with("format", from);
+            match SerdeConverter::new(from, to)
+                .convert(input, &props)
+                .unwrap()
+            {
+                ConvertOutput::Single(output, _) => output,
+                _ => panic!("Expected single output"),
+            }
+        };
+
+        let json = convert("edn", "json", input);
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(value["#keyword :name"], "paraphase");
+        assert_eq!(
+            value["#keyword :tags"],
+            serde_json::json!(["#keyword :convert", "#keyword :data"])
+        );
+        assert_eq!(
+            value["#keyword :deps"][0]["#keyword :id"],
+            "#symbol clojure.core"
+        );
+        assert_eq!(value["plain"], 1.5);
+
+        let edn = convert("json", "edn", &json);
+        let text = String::from_utf8(edn.clone()).unwrap();
+        assert!(text.contains(":name \"paraphase\""));
+        assert!(text.contains(":tags [:convert :data]"));
+
+        // Keywords and symbols survive the trip back
+        let again: serde_json::Value =
+            serde_json::from_slice(&convert("edn", "json", &edn)).unwrap();
+        assert_eq!(again, value);
+    }
+
+    #[test]
+    #[cfg(all(feature = "kdl", feature = "json"))]
+    fn test_kdl_json_roundtrip() {
+        let input = br##"// Server config
+server "web" port=8080 {
+    listen "0.0.0.0" 443 ssl=#true
+    /- debug #true
+    route "/api" timeout=2.5 {
+        backend #"http://localhost:9000"# weight=0x10
+    }
+}
+"##;
+        let convert = |from, to, input: &[u8]| {
+            let props = Properties::new().with("format", from);
+            match SerdeConverter::new(from, to)
+                .convert(input, &props)
+                .unwrap()
+            {
+                ConvertOutput::Single(output, _) => output,
+                _ => panic!("Expected single output"),
+            }
+        };
+        let parse = |kdl: &[u8]| -> serde_json::Value {
+            serde_json::from_slice(&convert("kdl", "json", kdl)).unwrap()
+        };
+
+        let json = convert("kdl", "json", input);
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        let server = &value[0];
+        assert_eq!(server["_node"], "server");
+        assert_eq!(server["_args"], serde_json::json!(["web"]));
+        assert_eq!(server["port"], 8080);
+        let listen = &server["_children"][0];
+        assert_eq!(listen["_args"], serde_json::json!(["0.0.0.0", 443]));
+        assert_eq!(listen["ssl"], true);
+        assert_eq!(server["_children"].as_array().unwrap().len(), 2);
+        let backend = &server["_children"][1]["_children"][0];
+        assert_eq!(backend["_args"][0], "http://localhost:9000");
+        assert_eq!(backend["weight"], 16);
+        assert_eq!(server["_children"][1]["timeout"], 2.5);
+
+        let kdl = convert("json", "kdl", &json);
+        let text = String::from_utf8(kdl.clone()).unwrap();
+        assert!(
+            text.contains("\n    listen \"0.0.0.0\" 443 ssl=#true\n"),
+            "{text}"
+        );
+        assert!(text.contains("\n        backend "), "{text}");
+        assert_eq!(parse(&kdl), value);
+
+        // KDL 1 documents are still read
+        let v1 = parse(b"node true null r\"raw\\n\" key=false\n");
+        assert_eq!(
+            v1,
+            serde_json::json!([{"_node": "node", "_args": [true, null, "raw\\n"], "key": false}])
+        );
+
+        // Plain JSON maps keys to nodes
+        let kdl = convert(
+            "json",
+            "kdl",
+            br#"{"db": {"host": "localhost", "port": 5432}, "name": "demo", "tags": ["a", "b"]}"#,
+        );
+        let text = String::from_utf8(kdl.clone()).unwrap();
+        assert!(text.starts_with("db {\n    host "), "{text}");
+        assert_eq!(
+            parse(&kdl),
+            serde_json::json!([
+                {"_node": "db", "_children": [
+                    {"_node": "host", "_args": ["localhost"]},
+                    {"_node": "port", "_args": [5432]}
+                ]},
+                {"_node": "name", "_args": ["demo"]},
+                {"_node": "tags", "_args": ["a", "b"]}
+            ])
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "hcl", feature = "json"))]
+    fn test_hcl_json_roundtrip() {
+        let input = br#"
+resource "aws_instance" "web" {
+  ami           = "ami-0c55b159cbfafe1f0"
+  instance_type = "t2.micro"
+  count         = 2
+
+  tags = {
+    Name = "HelloWorld"
+  }
+
+  user_data = <<-EOT
+    #!/bin/bash
+    echo hello
+  EOT
+}
+"#;
+        let props = Properties::new().with("format", "hcl");
+        let json = match SerdeConverter::new("hcl", "json")
+            .convert(input, &props)
+            .unwrap()
+        {
+            ConvertOutput::Single(output, out_props) => {
+                assert_eq!(out_props.get("format").unwrap().as_str(), Some("json"));
+                output
+            }
+            _ => panic!("Expected single output"),
+        };
+
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        let web = &value["resource"]["aws_instance"]["web"];
+        assert_eq!(web["instance_type"], "t2.micro");
+        assert_eq!(web["count"], 2);
+        assert_eq!(web["tags"]["Name"], "HelloWorld");
+        assert_eq!(web["user_data"], "#!/bin/bash\necho hello\n");
+
+        // And back: JSON -> HCL -> JSON preserves the structure
+        let props = Properties::new().with("format", "json");
+        let hcl = match SerdeConverter::new("json", "hcl")
+            .convert(&json, &props)
+            .unwrap()
+        {
+            ConvertOutput::Single(output, _) => output,
+            _ => panic!("Expected single output"),
+        };
+        let hcl_text = String::from_utf8(hcl.clone()).unwrap();
+        assert!(hcl_text.contains(r#""instance_type" = "t2.micro""#));
+
+        let reparsed: serde_json::Value = hcl::from_slice(&hcl).unwrap();
+        assert_eq!(reparsed, value);
+    }
+
+    #[test]
+    #[cfg(all(feature = "json", feature = "toml"))]
+    fn test_json_to_toml() {
+        let converter = SerdeConverter::new("json", "toml");
+        let input = br#"{"name": "test", "value": 42}"#;
+        let props = Properties::new().with("format", "json");
+
+        let result = converter.convert(input, &props).unwrap();
+
+        match result {
+            ConvertOutput::Single(output, out_props) => {
+                let output_str = String::from_utf8(output).unwrap();
+                assert!(output_str.contains("name"));
+                assert!(output_str.contains("test"));
+                assert_eq!(out_props.get("format").unwrap().as_str(), Some("toml"));
+            }
+            _ => panic!("Expected single output"),
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "json", feature = "toml"))]
+    fn test_toml_array_of_tables_roundtrip() {
+        let run = |from: &'static str, to: &'static str, input: &[u8]| {
+            let props = Properties::new().with("format", from);
+            match SerdeConverter::new(from, to).convert(input, &props) {
+                Ok(ConvertOutput::Single(output, _)) => Ok(output),
+                Ok(_) => panic!("Expected single output"),
+                Err(e) => Err(e),
+            }
+        };
+
+        let toml_in = r#"
+[package]
+name = "demo"
+
+[[package.dependencies]]
+name = "serde"
+version = "1"
+
+[[package.dependencies]]
+name = "toml"
+version = "0.9"
+optional = true
+"#;
+        let json = run("toml", "json", toml_in.as_bytes()).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(value["package"]["dependencies"][1]["name"], "toml");
+
+        let toml_out = String::from_utf8(run("json", "toml", &json).unwrap()).unwrap();
+        assert_eq!(toml_out.matches("[[package.dependencies]]").count(), 2);
+        let original: toml::Table = toml::from_str(toml_in).unwrap();
+        let round_tripped: toml::Table = toml::from_str(&toml_out).unwrap();
+        assert_eq!(round_tripped, original);
+
+        // Nulls inside tables of an array are omitted rather than failing
+        let json = br#"{"servers": [{"host": "a", "port": null}, {"host": "b", "port": 80}]}"#;
+        let toml_out = String::from_utf8(run("json", "toml", json).unwrap()).unwrap();
+        let value: toml::Table = toml::from_str(&toml_out).unwrap();
+        let servers = value["servers"].as_array().unwrap();
+        assert!(servers[0].get("port").is_none());
+        assert_eq!(servers[1]["port"].as_integer(), Some(80));
+        assert_eq!(toml_out.matches("[[servers]]").count(), 2);
+
+        // ...unless toml_null says otherwise
+        let props = Properties::new()
+            .with("format", "json")
+            .with("toml_null", "empty_string");
+        let toml_out = match SerdeConverter::new("json", "toml").convert(json, &props) {
+            Ok(ConvertOutput::Single(output, _)) => String::from_utf8(output).unwrap(),
+            _ => panic!("Expected single output"),
+        };
+        let value: toml::Table = toml::from_str(&toml_out).unwrap();
+        assert_eq!(value["servers"][0]["port"].as_str(), Some(""));
+        let props = props.with("toml_null", "error");
+        assert!(
+            SerdeConverter::new("json", "toml")
+                .convert(json, &props)
+                .is_err()
+        );
+
+        // TOML has no root arrays
+        let err = run("json", "toml", br#"[{"a": 1}]"#).unwrap_err();
+        assert!(err.to_string().contains("TOML documents must be a table"));
+    }
+
+    #[test]
+    fn test_register_all() {
+        let mut registry = Registry::new();
+        register_all(&mut registry);
+
+        // Should have n*(n-1) serde converters for n formats
+        let n = enabled_formats().len();
+        let mut expected = n * (n - 1);
+
+        // Plus encoding converters
+        #[cfg(feature = "base64")]
+        {
+            expected += 2;
+        }
+        #[cfg(feature = "hex")]
+        {
+            expected += 2;
+        }
+        #[cfg(feature = "base32")]
+        {
+            expected += 2;
+        }
+        #[cfg(feature = "base58")]
+        {
+            expected += 2;
+        }
+        #[cfg(feature = "legacy-encoding")]
+        {
+            expected += 4;
+        }
+        #[cfg(feature = "urlencoded")]
+        {
+            expected += 4;
+        }
+        #[cfg(feature = "ndjson")]
+        {
+            expected += 2;
+        }
+        #[cfg(feature = "csv")]
+        {
+            expected += 1;
+        }
+        #[cfg(feature = "jsonpath")]
+        {
+            expected += 1;
+        }
+        #[cfg(feature = "json")]
+        {
+            expected += 5;
+        }
+
+        // Plus compression converters
+        #[cfg(feature = "gzip")]
+        {
+            expected += 6;
+        }
+        #[cfg(feature = "zstd")]
+        {
+            expected += 2;
+        }
+        #[cfg(feature = "brotli")]
+        {
+            expected += 2;
+        }
+        #[cfg(feature = "bzip2")]
+        {
+            expected += 2;
+        }
+        #[cfg(feature = "snappy")]
+        {
+            expected += 2;
+        }
+        #[cfg(any(
+            feature = "gzip",
+            feature = "zstd",
+            feature = "bzip2",
+            feature = "brotli"
+        ))]
+        {
+            expected += 1;
+        }
+
+        // Plus binary diff converters
+        #[cfg(feature = "bsdiff")]
+        {
+            expected += 2;
+        }
+
+        // Plus config format converters
+        #[cfg(feature = "ini")]
+        {
+            expected += 2;
+        }
+
+        // Plus charset converters
+        #[cfg(feature = "charsets")]
+        {
+            expected += 2;
+        }
+
+        // Plus text transform converters
+        #[cfg(feature = "markdown")]
+        {
+            expected += 2;
+        }
+        #[cfg(feature = "html2text")]
+        {
+            expected += 1;
+        }
+
+        // Plus archive converters
+        #[cfg(feature = "tar")]
+        {
+            expected += 2;
+        }
+        #[cfg(feature = "zip")]
+        {
+            expected += 2;
+        }
+
+        // Plus template converters
+        #[cfg(feature = "tera")]
+        {
+            expected += 1;
+        }
+        #[cfg(feature = "handlebars")]
+        {
+            expected += 1;
+        }
+
+        // Plus spreadsheet converters
+        #[cfg(feature = "spreadsheet")]
+        {
+            expected += 2;
+        }
+
+        // Plus schema-based format converters
+        #[cfg(feature = "avro")]
+        {
+            expected += 3;
+        }
+        #[cfg(feature = "parquet")]
+        {
+            expected += 2;
+        }
+
+        assert_eq!(registry.len(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "base64")]
+    fn test_base64_roundtrip() {
+        use crate::{Base64Decoder, Base64Encoder};
+
+        let original = b"Hello, World! \x00\x01\x02\xff";
+        let props = Properties::new().with("format", "raw");
+
+        // Encode
+        let encoded_result = Base64Encoder.convert(original, &props).unwrap();
+        let (encoded, encoded_props) = match encoded_result {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(
+            encoded_props.get("format").unwrap().as_str(),
+            Some("base64")
+        );
+        assert_eq!(
+            String::from_utf8(encoded.clone()).unwrap(),
+            "SGVsbG8sIFdvcmxkISAAAQL/"
+        );
+
+        // Decode
+        let decoded_result = Base64Decoder.convert(&encoded, &encoded_props).unwrap();
+        let (decoded, _) = match decoded_result {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    #[cfg(feature = "urlencoded")]
+    fn test_percent_and_html_escape() {
+        let props = Properties::new().with("format", "text");
+        let run = |conv: &dyn Converter, input: &str, props: &Properties| match conv
+            .convert(input.as_bytes(), props)
+            .unwrap()
+        {
+            ConvertOutput::Single(data, out) => (String::from_utf8(data).unwrap(), out),
+            _ => panic!("Expected single output"),
+        };
+
+        let (encoded, _) = run(&UrlPercentEncode, "a b/ü", &props);
+        assert_eq!(encoded, "a%20b%2F%C3%BC");
+        let (decoded, _) = run(&UrlPercentDecode, &encoded, &props);
+        assert_eq!(decoded, "a b/ü");
+
+        let safe = props.clone().with("url_safe_chars", "/");
+        let (encoded, out) = run(&UrlPercentEncode, "a b/c", &safe);
+        assert_eq!(encoded, "a%20b/c");
+        assert!(!out.contains_key("url_safe_chars"));
+
+        let (escaped, _) = run(&HtmlEntityEncode, "<a href=\"x\">Tom & Jerry's</a>", &props);
+        assert_eq!(
+            escaped,
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+        let (unescaped, _) = run(&HtmlEntityDecode, &escaped, &props);
+        assert_eq!(unescaped, "<a href=\"x\">Tom & Jerry's</a>");
+
+        let (unescaped, _) = run(&HtmlEntityDecode, "&#x41;&#66; &unknown; & &apos;", &props);
+        assert_eq!(unescaped, "AB &unknown; & '");
+    }
+
+    #[test]
+    #[cfg(feature = "hex")]
+    fn test_hex_roundtrip() {
+        use crate::{HexDecoder, HexEncoder};
+
+        let original = b"\xde\xad\xbe\xef";
+        let props = Properties::new().with("format", "raw");
+
+        // Encode
+        let encoded_result = HexEncoder.convert(original, &props).unwrap();
+        let (encoded, encoded_props) = match encoded_result {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(encoded_props.get("format").unwrap().as_str(), Some("hex"));
+        assert_eq!(String::from_utf8(encoded.clone()).unwrap(), "deadbeef");
+
+        // Decode
+        let decoded_result = HexDecoder.convert(&encoded, &encoded_props).unwrap();
+        let (decoded, _) = match decoded_result {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    #[cfg(feature = "hex")]
+    fn test_hex_with_separators() {
+        use crate::HexDecoder;
 
-            let reader = builder.build().map_err(|e| {
-                ConvertError::Failed(format!("Failed to build Parquet reader: {}", e))
-            })?;
+        // Hex with various separators
+        let input = b"de:ad:be:ef";
+        let props = Properties::new().with("format", "hex");
 
-            let schema = reader.schema();
-            let mut all_records = Vec::new();
+        let result = HexDecoder.convert(input, &props).unwrap();
+        let (decoded, _) = match result {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(decoded, b"\xde\xad\xbe\xef");
+    }
 
-            for batch_result in reader {
-                let batch = batch_result.map_err(|e| {
-                    ConvertError::Failed(format!("Failed to read Parquet batch: {}", e))
-                })?;
+    #[test]
+    #[cfg(feature = "hex")]
+    fn test_hex_uppercase_grouped() {
+        use crate::{HexDecoder, HexEncoder};
 
-                // Convert each row to JSON
-                for row_idx in 0..batch.num_rows() {
-                    let mut record = serde_json::Map::new();
-                    for (col_idx, field) in schema.fields().iter().enumerate() {
-                        let column = batch.column(col_idx);
-                        let value = array_value_to_json(column.as_ref(), row_idx);
-                        record.insert(field.name().clone(), value);
-                    }
-                    all_records.push(serde_json::Value::Object(record));
-                }
-            }
+        let encode = |props: Properties| match HexEncoder.convert(b"\xde\xad\xbe\xef", &props) {
+            Ok(ConvertOutput::Single(b, p)) => (String::from_utf8(b).unwrap(), p),
+            _ => panic!("Expected single"),
+        };
+        let raw = Properties::new().with("format", "raw");
 
-            let output = serde_json::to_vec_pretty(&serde_json::Value::Array(all_records))
-                .map_err(|e| ConvertError::Failed(format!("JSON serialization failed: {}", e)))?;
+        let (mac, props) = encode(
+            raw.clone()
+                .with("hex_uppercase", true)
+                .with("hex_separator", ":")
+                .with("hex_group_size", 1),
+        );
+        assert_eq!(mac, "DE:AD:BE:EF");
+        assert!(props.get("hex_uppercase").is_none());
 
-            let mut out_props = Properties::new();
-            out_props.insert("format".into(), "json".into());
+        let (grouped, props) = encode(
+            raw.clone()
+                .with("hex_separator", "|")
+                .with("hex_group_size", 3),
+        );
+        assert_eq!(grouped, "deadbe|ef");
 
-            Ok(ConvertOutput::Single(output, out_props))
+        // The decoder strips the separator the encoder recorded
+        match HexDecoder.convert(grouped.as_bytes(), &props).unwrap() {
+            ConvertOutput::Single(b, p) => {
+                assert_eq!(b, b"\xde\xad\xbe\xef");
+                assert!(p.get("hex_separator").is_none());
+            }
+            _ => panic!("Expected single"),
         }
+
+        // A separator without a group size is not used
+        let (plain, _) = encode(raw.clone().with("hex_separator", ":"));
+        assert_eq!(plain, "deadbeef");
+
+        assert!(matches!(
+            HexEncoder.convert(b"", &raw.with("hex_group_size", -1)),
+            Err(ConvertError::InvalidProperties(_))
+        ));
     }
 
-    /// Convert an Arrow array value at a given index to JSON.
-    fn array_value_to_json(array: &dyn Array, idx: usize) -> serde_json::Value {
-        if array.is_null(idx) {
-            return serde_json::Value::Null;
+    /// Encode `input` with `encoder`, check the text, and decode it back.
+    #[cfg(any(feature = "base32", feature = "base58"))]
+    fn assert_text_roundtrip(
+        encoder: &dyn Converter,
+        decoder: &dyn Converter,
+        props: &Properties,
+        input: &[u8],
+        expected: &str,
+    ) {
+        let (text, out_props) = match encoder.convert(input, props).unwrap() {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(std::str::from_utf8(&text).unwrap(), expected);
+        match decoder.convert(&text, &out_props).unwrap() {
+            ConvertOutput::Single(b, p) => {
+                assert_eq!(b, input);
+                assert_eq!(p.get("format").unwrap().as_str(), Some("raw"));
+            }
+            _ => panic!("Expected single"),
         }
+    }
 
-        // Handle different Arrow data types
-        if let Some(arr) = array.as_any().downcast_ref::<StringArray>() {
-            return serde_json::Value::String(arr.value(idx).to_string());
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<LargeStringArray>() {
-            return serde_json::Value::String(arr.value(idx).to_string());
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<Int32Array>() {
-            return serde_json::json!(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<Int64Array>() {
-            return serde_json::json!(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<Float32Array>() {
-            return serde_json::json!(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<Float64Array>() {
-            return serde_json::json!(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<BooleanArray>() {
-            return serde_json::Value::Bool(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<Int8Array>() {
-            return serde_json::json!(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<Int16Array>() {
-            return serde_json::json!(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<UInt8Array>() {
-            return serde_json::json!(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<UInt16Array>() {
-            return serde_json::json!(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<UInt32Array>() {
-            return serde_json::json!(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<UInt64Array>() {
-            return serde_json::json!(arr.value(idx));
-        }
-        if let Some(arr) = array.as_any().downcast_ref::<BinaryArray>() {
-            use base64::Engine;
-            return serde_json::Value::String(
-                base64::engine::general_purpose::STANDARD.encode(arr.value(idx)),
+    #[test]
+    #[cfg(feature = "base32")]
+    fn test_base32_roundtrip() {
+        use crate::{Base32Decoder, Base32Encoder};
+
+        // RFC 4648 section 10 test vectors
+        let raw = Properties::new().with("format", "raw");
+        for (input, expected) in [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foobar", "MZXW6YTBOI======"),
+        ] {
+            assert_text_roundtrip(
+                &Base32Encoder,
+                &Base32Decoder,
+                &raw,
+                input.as_bytes(),
+                expected,
             );
         }
-        if let Some(arr) = array.as_any().downcast_ref::<LargeBinaryArray>() {
-            use base64::Engine;
-            return serde_json::Value::String(
-                base64::engine::general_purpose::STANDARD.encode(arr.value(idx)),
+
+        let hex = raw.clone().with("base32_alphabet", "extended-hex");
+        for (input, expected) in [("f", "CO======"), ("foobar", "CPNMUOJ1E8======")] {
+            assert_text_roundtrip(
+                &Base32Encoder,
+                &Base32Decoder,
+                &hex,
+                input.as_bytes(),
+                expected,
             );
         }
 
-        // Fallback for unsupported types
-        serde_json::Value::String(format!("<unsupported: {:?}>", array.data_type()))
+        assert!(matches!(
+            Base32Encoder.convert(b"", &raw.with("base32_alphabet", "z-base")),
+            Err(ConvertError::InvalidProperties(_))
+        ));
     }
-}
-
-#[cfg(feature = "parquet")]
-pub use parquet_impl::ParquetToJson;
-
-/// Deserialize bytes to a serde Value.
-fn deserialize(format: &str, data: &[u8]) -> Result<serde_json::Value, ConvertError> {
-    match format {
-        // === Text formats ===
-        #[cfg(feature = "json")]
-        "json" => serde_json::from_slice(data)
-            .map_err(|e| ConvertError::InvalidInput(format!("Invalid JSON: {}", e))),
-
-        #[cfg(feature = "yaml")]
-        "yaml" => serde_yaml::from_slice(data)
-            .map_err(|e| ConvertError::InvalidInput(format!("Invalid YAML: {}", e))),
-
-        #[cfg(feature = "toml")]
-        "toml" => {
-            let s = std::str::from_utf8(data)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
-            toml::from_str(s)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid TOML: {}", e)))
-        }
-
-        #[cfg(feature = "ron")]
-        "ron" => {
-            let s = std::str::from_utf8(data)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
-            ron::from_str(s).map_err(|e| ConvertError::InvalidInput(format!("Invalid RON: {}", e)))
-        }
-
-        #[cfg(feature = "json5")]
-        "json5" => {
-            let s = std::str::from_utf8(data)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
-            json5::from_str(s)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid JSON5: {}", e)))
-        }
 
-        #[cfg(feature = "xml")]
-        "xml" => {
-            let s = std::str::from_utf8(data)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
-            quick_xml::de::from_str(s)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid XML: {}", e)))
-        }
+    #[test]
+    #[cfg(feature = "legacy-encoding")]
+    fn test_quoted_printable() {
+        let props = Properties::new().with("format", "raw");
+        let encoded = match QuotedPrintableEncode
+            .convert("Café = €\r\n".as_bytes(), &props)
+            .unwrap()
+        {
+            ConvertOutput::Single(data, _) => data,
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(encoded, b"Caf=C3=A9 =3D =E2=82=AC\r\n");
 
-        #[cfg(feature = "lexpr")]
-        "lexpr" => {
-            let s = std::str::from_utf8(data)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
-            serde_lexpr::from_str(s)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid S-expression: {}", e)))
+        let props = Properties::new().with("format", "quoted-printable");
+        match QuotedPrintableDecode.convert(&encoded, &props).unwrap() {
+            ConvertOutput::Single(data, _) => assert_eq!(data, "Café = €\r\n".as_bytes()),
+            _ => panic!("Expected single output"),
         }
+    }
 
-        #[cfg(feature = "urlencoded")]
-        "urlencoded" => {
-            let s = std::str::from_utf8(data)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
-            serde_urlencoded::from_str(s)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid URL-encoded: {}", e)))
+    #[test]
+    #[cfg(feature = "legacy-encoding")]
+    fn test_uuencode_roundtrip() {
+        let input: Vec<u8> = (0..=255u8).cycle().take(100).collect();
+        let props = Properties::new()
+            .with("format", "raw")
+            .with("path", "files/blob.bin")
+            .with("uu_permissions", "600");
+        let (encoded, out_props) = match UuEncode.convert(&input, &props).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+        let text = std::str::from_utf8(&encoded).unwrap();
+        assert!(text.starts_with("begin 600 blob.bin\nM"));
+        assert!(text.ends_with("\n`\nend\n"));
+        assert!(!out_props.contains_key("uu_permissions"));
+
+        // "Cat" is the classic example
+        match UuEncode.convert(b"Cat", &Properties::new()).unwrap() {
+            ConvertOutput::Single(data, _) => {
+                assert_eq!(data, b"begin 644 data\n#0V%T\n`\nend\n")
+            }
+            _ => panic!("Expected single output"),
         }
 
-        #[cfg(feature = "qs")]
-        "qs" => {
-            let s = std::str::from_utf8(data)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid UTF-8: {}", e)))?;
-            serde_qs::from_str(s)
-                .map_err(|e| ConvertError::InvalidInput(format!("Invalid query string: {}", e)))
+        let mail = format!("Subject: blob\r\n\r\n{}", text.replace('\n', "\r\n"));
+        let props = Properties::new().with("format", "uuencoded");
+        match UuDecode.convert(mail.as_bytes(), &props).unwrap() {
+            ConvertOutput::Single(data, props) => {
+                assert_eq!(data, input);
+                assert_eq!(props.get("path").unwrap().as_str(), Some("blob.bin"));
+            }
+            _ => panic!("Expected single output"),
         }
 
-        // === Binary formats ===
-        #[cfg(feature = "msgpack")]
-        "msgpack" => rmp_serde::from_slice(data)
-            .map_err(|e| ConvertError::InvalidInput(format!("Invalid MessagePack: {}", e))),
-
-        #[cfg(feature = "cbor")]
-        "cbor" => ciborium::from_reader(data)
-            .map_err(|e| ConvertError::InvalidInput(format!("Invalid CBOR: {}", e))),
-
-        #[cfg(feature = "bincode")]
-        "bincode" => {
-            let (value, _): (serde_json::Value, _) =
-                bincode::serde::decode_from_slice(data, bincode::config::standard())
-                    .map_err(|e| ConvertError::InvalidInput(format!("Invalid Bincode: {}", e)))?;
-            Ok(value)
-        }
+        let truncated = "begin 644 x\n#0V\nend\n";
+        assert!(UuDecode.convert(truncated.as_bytes(), &props).is_err());
+        assert!(UuDecode.convert(b"begin 644 x\n#0V%T\n", &props).is_err());
+    }
 
-        #[cfg(feature = "postcard")]
-        "postcard" => postcard::from_bytes(data)
-            .map_err(|e| ConvertError::InvalidInput(format!("Invalid Postcard: {}", e))),
+    #[test]
+    #[cfg(feature = "base58")]
+    fn test_base58_roundtrip() {
+        use crate::{Base58Decoder, Base58Encoder};
+
+        // Vectors from the Base58 IETF draft
+        let raw = Properties::new().with("format", "raw");
+        assert_text_roundtrip(
+            &Base58Encoder,
+            &Base58Decoder,
+            &raw,
+            b"Hello World!",
+            "2NEpo7TZRRrLZSi2U",
+        );
+        assert_text_roundtrip(
+            &Base58Encoder,
+            &Base58Decoder,
+            &raw,
+            b"\x00\x00\x28\x7f\xb4\xcd",
+            "11233QC4",
+        );
 
-        #[cfg(feature = "bson")]
-        "bson" => bson::de::deserialize_from_slice(data)
-            .map_err(|e| ConvertError::InvalidInput(format!("Invalid BSON: {}", e))),
+        let flickr = raw.clone().with("base58_alphabet", "flickr");
+        assert_text_roundtrip(
+            &Base58Encoder,
+            &Base58Decoder,
+            &flickr,
+            b"Hello World!",
+            "2nePN7syqqRkyrH2t",
+        );
 
-        #[cfg(feature = "flexbuffers")]
-        "flexbuffers" => flexbuffers::from_slice(data)
-            .map_err(|e| ConvertError::InvalidInput(format!("Invalid FlexBuffers: {}", e))),
+        assert!(matches!(
+            Base58Decoder.convert(b"0OIl", &raw),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+    }
 
-        #[cfg(feature = "bencode")]
-        "bencode" => serde_bencode::from_bytes(data)
-            .map_err(|e| ConvertError::InvalidInput(format!("Invalid Bencode: {}", e))),
+    #[test]
+    #[cfg(feature = "ndjson")]
+    fn test_json_to_ndjson() {
+        use crate::JsonToNdjson;
 
-        #[cfg(feature = "pickle")]
-        "pickle" => serde_pickle::from_slice(data, serde_pickle::DeOptions::default())
-            .map_err(|e| ConvertError::InvalidInput(format!("Invalid Pickle: {}", e))),
+        let input = br#"[{"a": 1}, {"b": 2}, {"c": 3}]"#;
+        let props = Properties::new().with("format", "json");
 
-        #[cfg(feature = "plist")]
-        "plist" => plist::from_bytes(data)
-            .map_err(|e| ConvertError::InvalidInput(format!("Invalid Property List: {}", e))),
+        let result = JsonToNdjson.convert(input, &props).unwrap();
+        let (output, out_props) = match result {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
 
-        _ => Err(ConvertError::Failed(format!(
-            "Unsupported source format: {}",
-            format
-        ))),
+        let output_str = String::from_utf8(output).unwrap();
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("ndjson"));
+        assert!(output_str.contains(r#"{"a":1}"#));
+        assert!(output_str.contains(r#"{"b":2}"#));
+        assert!(output_str.contains(r#"{"c":3}"#));
+        assert_eq!(output_str.lines().count(), 3);
     }
-}
 
-/// Serialize a serde Value to bytes.
-fn serialize(format: &str, value: &serde_json::Value) -> Result<Vec<u8>, ConvertError> {
-    match format {
-        // === Text formats ===
-        #[cfg(feature = "json")]
-        "json" => serde_json::to_vec_pretty(value)
-            .map_err(|e| ConvertError::Failed(format!("JSON serialization failed: {}", e))),
+    #[test]
+    #[cfg(feature = "csv")]
+    fn test_csv_infer_types() {
+        use crate::CsvToJson;
+
+        let input = b"id,price,active,name,code\n\
+            1,9.5,yes,Widget,007\n\
+            2,10,no,,x1\n\
+            3,,true,Gadget,42\n";
+        let run = |props: &Properties| match CsvToJson.convert(input, props).unwrap() {
+            ConvertOutput::Single(output, _) => {
+                serde_json::from_slice::<serde_json::Value>(&output).unwrap()
+            }
+            _ => panic!("Expected single output"),
+        };
 
-        #[cfg(feature = "yaml")]
-        "yaml" => serde_yaml::to_string(value)
-            .map(|s| s.into_bytes())
-            .map_err(|e| ConvertError::Failed(format!("YAML serialization failed: {}", e))),
+        // Strings by default
+        let props = Properties::new().with("format", "csv");
+        let json = run(&props);
+        assert_eq!(json[0]["id"], "1");
+        assert_eq!(json[1]["name"], "");
 
-        #[cfg(feature = "toml")]
-        "toml" => toml::to_string_pretty(value)
-            .map(|s| s.into_bytes())
-            .map_err(|e| ConvertError::Failed(format!("TOML serialization failed: {}", e))),
+        let props = props.with("csv_infer_types", true);
+        let json = run(&props);
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"id": 1, "price": 9.5, "active": true, "name": "Widget", "code": "007"},
+                {"id": 2, "price": 10.0, "active": false, "name": null, "code": "x1"},
+                {"id": 3, "price": null, "active": true, "name": "Gadget", "code": "42"},
+            ])
+        );
+        assert!(json[0]["id"].is_i64());
+        assert!(json[1]["price"].is_f64());
+
+        // Only the first row scanned: `code` looks like an integer column,
+        // and the later cell that doesn't fit stays a string
+        let json = run(&props.clone().with("csv_infer_rows", 1));
+        assert_eq!(json[0]["code"], 7);
+        assert_eq!(json[1]["code"], "x1");
+        assert_eq!(json[2]["code"], 42);
+
+        let bad = props.with("csv_infer_rows", -1);
+        assert!(matches!(
+            CsvToJson.convert(input, &bad),
+            Err(ConvertError::InvalidProperties(_))
+        ));
+    }
 
-        #[cfg(feature = "ron")]
-        "ron" => ron::to_string(value)
-            .map(|s| s.into_bytes())
-            .map_err(|e| ConvertError::Failed(format!("RON serialization failed: {}", e))),
+    #[test]
+    #[cfg(feature = "ndjson")]
+    fn test_ndjson_to_json() {
+        use crate::NdjsonToJson;
 
-        #[cfg(feature = "json5")]
-        "json5" => {
-            // json5 crate doesn't have serialization, output as JSON (compatible)
-            serde_json::to_vec_pretty(value)
-                .map_err(|e| ConvertError::Failed(format!("JSON5 serialization failed: {}", e)))
-        }
+        let input = b"{\"a\": 1}\n{\"b\": 2}\n{\"c\": 3}\n";
+        let props = Properties::new().with("format", "ndjson");
 
-        #[cfg(feature = "xml")]
-        "xml" => quick_xml::se::to_string(value)
-            .map(|s| s.into_bytes())
-            .map_err(|e| ConvertError::Failed(format!("XML serialization failed: {}", e))),
+        let result = NdjsonToJson.convert(input, &props).unwrap();
+        let (output, out_props) = match result {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
 
-        #[cfg(feature = "lexpr")]
-        "lexpr" => serde_lexpr::to_string(value)
-            .map(|s| s.into_bytes())
-            .map_err(|e| ConvertError::Failed(format!("S-expression serialization failed: {}", e))),
+        let value: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("json"));
+        assert!(value.is_array());
+        assert_eq!(value.as_array().unwrap().len(), 3);
+    }
 
-        #[cfg(feature = "urlencoded")]
-        "urlencoded" => serde_urlencoded::to_string(value)
-            .map(|s| s.into_bytes())
-            .map_err(|e| ConvertError::Failed(format!("URL-encoded serialization failed: {}", e))),
+    #[test]
+    #[cfg(feature = "ndjson")]
+    fn test_ndjson_streaming() {
+        use crate::{JsonToNdjson, NdjsonToJson};
 
-        #[cfg(feature = "qs")]
-        "qs" => serde_qs::to_string(value)
-            .map(|s| s.into_bytes())
-            .map_err(|e| ConvertError::Failed(format!("Query string serialization failed: {}", e))),
+        let ndjson = b"{\"a\": 1}\n\n{\"b\": [2, 3]}\n";
+        let props = Properties::new().with("format", "ndjson");
+        let buffered = match NdjsonToJson.convert(ndjson, &props).unwrap() {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
 
-        // === Binary formats ===
-        #[cfg(feature = "msgpack")]
-        "msgpack" => rmp_serde::to_vec(value)
-            .map_err(|e| ConvertError::Failed(format!("MessagePack serialization failed: {}", e))),
+        // Streaming output is byte-identical to the buffered path
+        let mut json = Vec::new();
+        NdjsonToJson
+            .convert_streaming(&ndjson[..], &mut json, &props)
+            .unwrap();
+        assert_eq!(json, buffered);
 
-        #[cfg(feature = "cbor")]
-        "cbor" => {
-            let mut buf = Vec::new();
-            ciborium::into_writer(value, &mut buf)
-                .map_err(|e| ConvertError::Failed(format!("CBOR serialization failed: {}", e)))?;
-            Ok(buf)
+        let mut lines = Vec::new();
+        JsonToNdjson
+            .convert_streaming(json.as_slice(), &mut lines, &props)
+            .unwrap();
+        assert_eq!(lines, b"{\"a\":1}\n{\"b\":[2,3]}\n");
+
+        // The property selects streaming from convert()
+        let props = props.with("ndjson_stream", true);
+        match JsonToNdjson.convert(&json, &props).unwrap() {
+            ConvertOutput::Single(b, p) => {
+                assert_eq!(b, lines);
+                assert!(p.get("ndjson_stream").is_none());
+            }
+            _ => panic!("Expected single"),
         }
 
-        #[cfg(feature = "bincode")]
-        "bincode" => bincode::serde::encode_to_vec(value, bincode::config::standard())
-            .map_err(|e| ConvertError::Failed(format!("Bincode serialization failed: {}", e))),
+        let mut sink = Vec::new();
+        let err = NdjsonToJson
+            .convert_streaming(&b"{\"a\": 1}\nnot json\n"[..], &mut sink, &props)
+            .unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+        assert!(
+            JsonToNdjson
+                .convert_streaming(&b"[{\"a\": 1}"[..], &mut sink, &props)
+                .is_err()
+        );
+    }
 
-        #[cfg(feature = "postcard")]
-        "postcard" => postcard::to_allocvec(value)
-            .map_err(|e| ConvertError::Failed(format!("Postcard serialization failed: {}", e))),
+    #[test]
+    #[cfg(feature = "ndjson")]
+    fn test_json_object_to_ndjson() {
+        use crate::JsonToNdjson;
 
-        #[cfg(feature = "bson")]
-        "bson" => bson::ser::serialize_to_vec(value)
-            .map_err(|e| ConvertError::Failed(format!("BSON serialization failed: {}", e))),
+        let json = br#"{"alice": {"age": 30}, "bob": {"age": 25}, "carol": null}"#;
+        let lines = |props: &Properties| -> Vec<String> {
+            let buffered = match JsonToNdjson.convert(json, props).unwrap() {
+                ConvertOutput::Single(b, _) => b,
+                _ => panic!("Expected single"),
+            };
+            let mut streamed = Vec::new();
+            JsonToNdjson
+                .convert_streaming(&json[..], &mut streamed, props)
+                .unwrap();
+            assert_eq!(streamed, buffered);
+            String::from_utf8(buffered)
+                .unwrap()
+                .lines()
+                .map(String::from)
+                .collect()
+        };
 
-        #[cfg(feature = "flexbuffers")]
-        "flexbuffers" => flexbuffers::to_vec(value)
-            .map_err(|e| ConvertError::Failed(format!("FlexBuffers serialization failed: {}", e))),
+        // Without the option an object is a single line
+        let props = Properties::new().with("format", "json");
+        assert_eq!(lines(&props).len(), 1);
 
-        #[cfg(feature = "bencode")]
-        "bencode" => serde_bencode::to_bytes(value)
-            .map_err(|e| ConvertError::Failed(format!("Bencode serialization failed: {}", e))),
+        let props = props.with("ndjson_object_values", true);
+        assert_eq!(lines(&props), ["{\"age\":30}", "{\"age\":25}", "null"]);
 
-        #[cfg(feature = "pickle")]
-        "pickle" => serde_pickle::to_vec(value, serde_pickle::SerOptions::default())
-            .map_err(|e| ConvertError::Failed(format!("Pickle serialization failed: {}", e))),
+        let props = props.with("ndjson_include_keys", true);
+        let keyed = lines(&props);
+        assert_eq!(keyed.len(), 3);
+        assert_eq!(keyed[0], r#"{"key":"alice","value":{"age":30}}"#);
 
-        #[cfg(feature = "plist")]
-        "plist" => {
-            let mut buf = Vec::new();
-            plist::to_writer_binary(&mut buf, value).map_err(|e| {
-                ConvertError::Failed(format!("Property List serialization failed: {}", e))
-            })?;
-            Ok(buf)
+        // Scalars are wrapped as a single line
+        match JsonToNdjson.convert(b" 42 ", &props).unwrap() {
+            ConvertOutput::Single(b, p) => {
+                assert_eq!(b, b"42\n");
+                assert!(p.get("ndjson_object_values").is_none());
+            }
+            _ => panic!("Expected single"),
         }
-
-        _ => Err(ConvertError::Failed(format!(
-            "Unsupported target format: {}",
-            format
-        ))),
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use paraphase_core::PropertiesExt;
 
     #[test]
     #[cfg(all(feature = "json", feature = "yaml"))]
-    fn test_json_to_yaml() {
-        let converter = SerdeConverter::new("json", "yaml");
-        let input = br#"{"name": "test", "value": 42}"#;
+    fn test_roundtrip() {
+        let original = br#"{"name": "roundtrip", "nested": {"a": 1, "b": 2}}"#;
+
+        let json_to_yaml = SerdeConverter::new("json", "yaml");
+        let yaml_to_json = SerdeConverter::new("yaml", "json");
+
         let props = Properties::new().with("format", "json");
 
-        let result = converter.convert(input, &props).unwrap();
+        // JSON -> YAML
+        let yaml_result = json_to_yaml.convert(original, &props).unwrap();
+        let (yaml_bytes, yaml_props) = match yaml_result {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
 
-        match result {
-            ConvertOutput::Single(output, out_props) => {
-                let output_str = String::from_utf8(output).unwrap();
-                assert!(output_str.contains("name:"));
-                assert!(output_str.contains("test"));
-                assert_eq!(out_props.get("format").unwrap().as_str(), Some("yaml"));
-            }
-            _ => panic!("Expected single output"),
-        }
+        // YAML -> JSON
+        let json_result = yaml_to_json.convert(&yaml_bytes, &yaml_props).unwrap();
+        let (json_bytes, _) = match json_result {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+
+        // Parse both and compare
+        let original_value: serde_json::Value = serde_json::from_slice(original).unwrap();
+        let roundtrip_value: serde_json::Value = serde_json::from_slice(&json_bytes).unwrap();
+        assert_eq!(original_value, roundtrip_value);
     }
 
     #[test]
-    #[cfg(all(feature = "yaml", feature = "json"))]
-    fn test_yaml_to_json() {
-        let converter = SerdeConverter::new("yaml", "json");
-        let input = b"name: test\nvalue: 42\n";
-        let props = Properties::new().with("format", "yaml");
+    #[cfg(feature = "gzip")]
+    fn test_gzip_roundtrip() {
+        use crate::{GzipCompress, GzipDecompress};
 
-        let result = converter.convert(input, &props).unwrap();
+        let original = b"Hello, World! This is test data. ".repeat(100);
+        let props = Properties::new().with("format", "raw");
 
-        match result {
-            ConvertOutput::Single(output, out_props) => {
-                let output_str = String::from_utf8(output).unwrap();
-                assert!(output_str.contains("\"name\""));
-                assert!(output_str.contains("\"test\""));
-                assert_eq!(out_props.get("format").unwrap().as_str(), Some("json"));
-            }
-            _ => panic!("Expected single output"),
-        }
+        // Compress
+        let compressed = GzipCompress.convert(&original, &props).unwrap();
+        let (compressed_bytes, compressed_props) = match compressed {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(
+            compressed_props.get("format").unwrap().as_str(),
+            Some("gzip")
+        );
+        assert!(compressed_bytes.len() < original.len()); // Should be smaller for repeated data
+
+        // Decompress
+        let decompressed = GzipDecompress
+            .convert(&compressed_bytes, &compressed_props)
+            .unwrap();
+        let (decompressed_bytes, _) = match decompressed {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(decompressed_bytes, original);
     }
 
     #[test]
-    #[cfg(all(feature = "json", feature = "toml"))]
-    fn test_json_to_toml() {
-        let converter = SerdeConverter::new("json", "toml");
-        let input = br#"{"name": "test", "value": 42}"#;
+    #[cfg(all(feature = "gzip", feature = "json", feature = "yaml"))]
+    fn test_decompress_restores_inner_format() {
+        use crate::{GzipCompress, GzipDecompress};
+        use paraphase_core::{
+            Cardinality, ExecutionContext, Executor, Planner, PropertyPattern, SimpleExecutor,
+        };
+        use std::sync::Arc;
+
+        let original = br#"{"name": "paraphase", "tags": ["a", "b"]}"#;
         let props = Properties::new().with("format", "json");
+        let (compressed, compressed_props) = match GzipCompress.convert(original, &props).unwrap() {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
 
-        let result = converter.convert(input, &props).unwrap();
+        match GzipDecompress
+            .convert(&compressed, &compressed_props)
+            .unwrap()
+        {
+            ConvertOutput::Single(b, p) => {
+                assert_eq!(b, original);
+                assert_eq!(p.get("format").unwrap().as_str(), Some("json"));
+                assert!(!p.contains_key("inner_format"));
+            }
+            _ => panic!("Expected single"),
+        }
 
-        match result {
-            ConvertOutput::Single(output, out_props) => {
-                let output_str = String::from_utf8(output).unwrap();
-                assert!(output_str.contains("name"));
-                assert!(output_str.contains("test"));
-                assert_eq!(out_props.get("format").unwrap().as_str(), Some("toml"));
+        // Opting out leaves raw bytes
+        let keep = compressed_props.clone().with("restore_inner_format", false);
+        match GzipDecompress.convert(&compressed, &keep).unwrap() {
+            ConvertOutput::Single(_, p) => {
+                assert_eq!(p.get("format").unwrap().as_str(), Some("raw"));
+                assert!(!p.contains_key("restore_inner_format"));
             }
-            _ => panic!("Expected single output"),
+            _ => panic!("Expected single"),
         }
-    }
+        let bad = compressed_props.clone().with("restore_inner_format", "no");
+        assert!(matches!(
+            GzipDecompress.convert(&compressed, &bad),
+            Err(ConvertError::InvalidProperties(_))
+        ));
 
-    #[test]
-    fn test_register_all() {
+        // The planner sees the restored format and chains straight to YAML
         let mut registry = Registry::new();
         register_all(&mut registry);
+        let raw = Planner::new(&registry)
+            .plan(
+                &keep,
+                &PropertyPattern::new().eq("format", "raw"),
+                Cardinality::One,
+                Cardinality::One,
+            )
+            .unwrap();
+        assert_eq!(raw.steps.len(), 1);
+        assert_eq!(
+            raw.steps[0]
+                .output_properties
+                .get("format")
+                .unwrap()
+                .as_str(),
+            Some("raw")
+        );
+        let plan = Planner::new(&registry)
+            .plan(
+                &compressed_props,
+                &PropertyPattern::new().eq("format", "yaml"),
+                Cardinality::One,
+                Cardinality::One,
+            )
+            .unwrap();
+        let ids: Vec<_> = plan.steps.iter().map(|s| s.converter_id.as_str()).collect();
+        assert_eq!(ids, ["compression.gzip-to-raw", "serde.json-to-yaml"]);
+        assert_eq!(
+            plan.steps[0]
+                .output_properties
+                .get("format")
+                .unwrap()
+                .as_str(),
+            Some("json")
+        );
 
-        // Should have n*(n-1) serde converters for n formats
-        let n = enabled_formats().len();
-        let mut expected = n * (n - 1);
+        let ctx = ExecutionContext::new(Arc::new(registry));
+        let result = SimpleExecutor::new()
+            .execute(&ctx, &plan, compressed, compressed_props)
+            .unwrap();
+        assert_eq!(result.props.get("format").unwrap().as_str(), Some("yaml"));
+        let yaml = String::from_utf8(result.data).unwrap();
+        assert!(yaml.contains("name: paraphase"));
+    }
 
-        // Plus encoding converters
-        #[cfg(feature = "base64")]
-        {
-            expected += 2;
-        }
-        #[cfg(feature = "hex")]
-        {
-            expected += 2;
-        }
-        #[cfg(feature = "ndjson")]
-        {
-            expected += 2;
-        }
+    #[test]
+    #[cfg(all(feature = "gzip", feature = "zstd"))]
+    fn test_auto_decompress() {
+        use crate::{AutoDecompress, GzipCompress, ZstdCompress};
 
-        // Plus compression converters
-        #[cfg(feature = "gzip")]
-        {
-            expected += 2;
-        }
-        #[cfg(feature = "zstd")]
-        {
-            expected += 2;
-        }
-        #[cfg(feature = "brotli")]
-        {
-            expected += 2;
-        }
+        let original = b"Hello, World! This is test data. ".repeat(100);
+        let raw = Properties::new().with("format", "raw");
+        let compress = |converter: &dyn Converter| match converter.convert(&original, &raw) {
+            Ok(ConvertOutput::Single(b, _)) => b,
+            _ => panic!("Expected single"),
+        };
 
-        // Plus config format converters
-        #[cfg(feature = "ini")]
-        {
-            expected += 2;
+        // Mislabeled gzip data still decompresses
+        let mislabeled = Properties::new().with("format", "zip");
+        for (compressed, compression) in [
+            (compress(&GzipCompress), "gzip"),
+            (compress(&ZstdCompress), "zstd"),
+        ] {
+            match AutoDecompress.convert(&compressed, &mislabeled).unwrap() {
+                ConvertOutput::Single(b, p) => {
+                    assert_eq!(b, original);
+                    assert_eq!(p.get("format").unwrap().as_str(), Some("raw"));
+                    assert_eq!(p.get("compression").unwrap().as_str(), Some(compression));
+                }
+                _ => panic!("Expected single"),
+            }
         }
 
-        // Plus charset converters
-        #[cfg(feature = "charsets")]
-        {
-            expected += 2;
-        }
+        assert!(matches!(
+            AutoDecompress.convert(b"plain text", &raw),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+    }
 
-        // Plus text transform converters
-        #[cfg(feature = "markdown")]
-        {
-            expected += 1;
-        }
-        #[cfg(feature = "html2text")]
-        {
-            expected += 1;
-        }
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn test_zlib_deflate_roundtrip() {
+        use crate::{DeflateCompress, DeflateDecompress, ZlibCompress, ZlibDecompress};
 
-        // Plus archive converters
-        #[cfg(feature = "tar")]
-        {
-            expected += 2;
-        }
-        #[cfg(feature = "zip")]
-        {
-            expected += 2;
-        }
+        // Known 1 KB payload
+        let original: Vec<u8> = (0..1024u32).map(|i| (i % 251) as u8).collect();
+        let props = Properties::new().with("format", "raw").with("level", 9i64);
 
-        // Plus spreadsheet converters
-        #[cfg(feature = "spreadsheet")]
-        {
-            expected += 1;
-        }
+        let roundtrip = |compress: &dyn Converter, decompress: &dyn Converter, format: &str| {
+            let (compressed, compressed_props) = match compress.convert(&original, &props).unwrap()
+            {
+                ConvertOutput::Single(b, p) => (b, p),
+                _ => panic!("Expected single"),
+            };
+            assert_eq!(
+                compressed_props.get("format").unwrap().as_str(),
+                Some(format)
+            );
+            assert!(compressed_props.get("level").is_none());
+
+            let decompressed = match decompress.convert(&compressed, &compressed_props).unwrap() {
+                ConvertOutput::Single(b, _) => b,
+                _ => panic!("Expected single"),
+            };
+            assert_eq!(decompressed, original);
+            compressed
+        };
 
-        // Plus schema-based format converters
-        #[cfg(feature = "avro")]
-        {
-            expected += 1;
-        }
-        #[cfg(feature = "parquet")]
-        {
-            expected += 1;
-        }
+        let zlib = roundtrip(&ZlibCompress, &ZlibDecompress, "zlib");
+        // zlib header: CM=8 (deflate), FCHECK makes the first two bytes divisible by 31
+        assert_eq!(zlib[0] & 0x0f, 8);
+        assert_eq!(u16::from_be_bytes([zlib[0], zlib[1]]) % 31, 0);
 
-        assert_eq!(registry.len(), expected);
+        let deflate = roundtrip(&DeflateCompress, &DeflateDecompress, "deflate");
+        // Raw deflate is the zlib stream minus the 2-byte header and 4-byte checksum
+        assert_eq!(deflate, zlib[2..zlib.len() - 4]);
     }
 
     #[test]
-    #[cfg(feature = "base64")]
-    fn test_base64_roundtrip() {
-        use crate::{Base64Decoder, Base64Encoder};
+    #[cfg(feature = "bzip2")]
+    fn test_bzip2_roundtrip() {
+        use crate::{Bzip2Compress, Bzip2Decompress};
 
-        let original = b"Hello, World! \x00\x01\x02\xff";
-        let props = Properties::new().with("format", "raw");
+        let original = b"Hello, World! This is some test data that should compress well.";
+        let props = Properties::new()
+            .with("format", "json")
+            .with("bzip2_level", 9i64);
 
-        // Encode
-        let encoded_result = Base64Encoder.convert(original, &props).unwrap();
-        let (encoded, encoded_props) = match encoded_result {
+        let (compressed, compressed_props) = match Bzip2Compress.convert(original, &props).unwrap()
+        {
             ConvertOutput::Single(b, p) => (b, p),
             _ => panic!("Expected single"),
         };
+        // "BZh" magic followed by the block size digit
+        assert!(compressed.starts_with(b"BZh9"));
         assert_eq!(
-            encoded_props.get("format").unwrap().as_str(),
-            Some("base64")
+            compressed_props.get("format").unwrap().as_str(),
+            Some("bz2")
         );
         assert_eq!(
-            String::from_utf8(encoded.clone()).unwrap(),
-            "SGVsbG8sIFdvcmxkISAAAQL/"
+            compressed_props.get("inner_format").unwrap().as_str(),
+            Some("json")
         );
+        assert!(compressed_props.get("bzip2_level").is_none());
 
-        // Decode
-        let decoded_result = Base64Decoder.convert(&encoded, &encoded_props).unwrap();
-        let (decoded, _) = match decoded_result {
+        let decompressed = match Bzip2Decompress
+            .convert(&compressed, &compressed_props)
+            .unwrap()
+        {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(decompressed, original);
+    }
+
+    #[test]
+    #[cfg(all(feature = "snappy", feature = "gzip"))]
+    fn test_snappy_vs_gzip() {
+        use crate::{GzipCompress, SnappyCompress, SnappyDecompress};
+
+        let original = "The quick brown fox jumps over the lazy dog. ".repeat(20_000);
+        let compress = |converter: &dyn Converter, props: &Properties| match converter
+            .convert(original.as_bytes(), props)
+            .unwrap()
+        {
             ConvertOutput::Single(b, p) => (b, p),
             _ => panic!("Expected single"),
         };
-        assert_eq!(decoded, original);
+
+        let props = Properties::new().with("format", "text");
+        let (framed, framed_props) = compress(&SnappyCompress, &props);
+        let (gzipped, _) = compress(&GzipCompress, &props.clone().with("level", 9i64));
+        // Snappy trades ratio for speed: gzip -9 should beat it on size
+        assert!(gzipped.len() < framed.len());
+        assert!(framed.len() < original.len() / 4);
+
+        // Framed stream identifier chunk
+        assert!(framed.starts_with(b"\xff\x06\x00\x00sNaPpY"));
+        assert_eq!(framed_props.get("format").unwrap().as_str(), Some("snappy"));
+
+        let (raw, raw_props) = compress(
+            &SnappyCompress,
+            &props.clone().with("snappy_framing", false),
+        );
+        assert_eq!(
+            raw_props.get("format").unwrap().as_str(),
+            Some("snappy-raw")
+        );
+        assert!(raw_props.get("snappy_framing").is_none());
+
+        for (data, props) in [(framed, framed_props), (raw, raw_props)] {
+            match SnappyDecompress.convert(&data, &props).unwrap() {
+                ConvertOutput::Single(b, p) => {
+                    assert_eq!(b, original.as_bytes());
+                    assert_eq!(p.get("format").unwrap().as_str(), Some("text"));
+                }
+                _ => panic!("Expected single"),
+            }
+        }
     }
 
     #[test]
-    #[cfg(feature = "hex")]
-    fn test_hex_roundtrip() {
-        use crate::{HexDecoder, HexEncoder};
+    #[cfg(feature = "zstd")]
+    fn test_zstd_roundtrip() {
+        use crate::{ZstdCompress, ZstdDecompress};
 
-        let original = b"\xde\xad\xbe\xef";
+        let original = b"Hello, World! This is some test data that should compress well.";
         let props = Properties::new().with("format", "raw");
 
-        // Encode
-        let encoded_result = HexEncoder.convert(original, &props).unwrap();
-        let (encoded, encoded_props) = match encoded_result {
+        // Compress
+        let compressed = ZstdCompress.convert(original, &props).unwrap();
+        let (compressed_bytes, _) = match compressed {
             ConvertOutput::Single(b, p) => (b, p),
             _ => panic!("Expected single"),
         };
-        assert_eq!(encoded_props.get("format").unwrap().as_str(), Some("hex"));
-        assert_eq!(String::from_utf8(encoded.clone()).unwrap(), "deadbeef");
 
-        // Decode
-        let decoded_result = HexDecoder.convert(&encoded, &encoded_props).unwrap();
-        let (decoded, _) = match decoded_result {
+        // Decompress
+        let decompressed = ZstdDecompress
+            .convert(&compressed_bytes, &Properties::new())
+            .unwrap();
+        let (decompressed_bytes, _) = match decompressed {
             ConvertOutput::Single(b, p) => (b, p),
             _ => panic!("Expected single"),
         };
-        assert_eq!(decoded, original);
+        assert_eq!(decompressed_bytes, original);
     }
 
     #[test]
-    #[cfg(feature = "hex")]
-    fn test_hex_with_separators() {
-        use crate::HexDecoder;
+    #[cfg(all(feature = "bsdiff", feature = "cbor", feature = "json"))]
+    fn test_bsdiff_roundtrip() {
+        use crate::{BinaryDiff, BinaryPatch};
+        use indexmap::IndexMap;
+        use paraphase_core::NamedInput;
+
+        let to_cbor = |json: &[u8]| {
+            let props = Properties::new().with("format", "json");
+            match SerdeConverter::new("json", "cbor")
+                .convert(json, &props)
+                .unwrap()
+            {
+                ConvertOutput::Single(data, props) => (data, props),
+                _ => panic!("Expected single output"),
+            }
+        };
+        let (base, base_props) =
+            to_cbor(br#"{"name": "paraphase", "version": 1, "tags": ["a", "b"]}"#);
+        let (new, new_props) =
+            to_cbor(br#"{"name": "paraphase", "version": 2, "tags": ["a", "b", "c"]}"#);
+
+        let mut inputs = IndexMap::new();
+        inputs.insert(
+            "base".to_string(),
+            NamedInput {
+                data: &base,
+                props: &base_props,
+            },
+        );
+        inputs.insert(
+            "new".to_string(),
+            NamedInput {
+                data: &new,
+                props: &new_props,
+            },
+        );
+        let (patch, patch_props) = match BinaryDiff.convert_multi(&inputs).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(patch_props.get("format").unwrap().as_str(), Some("bsdiff"));
+
+        let mut inputs = IndexMap::new();
+        inputs.insert(
+            "patch".to_string(),
+            NamedInput {
+                data: &patch,
+                props: &patch_props,
+            },
+        );
+        inputs.insert(
+            "base".to_string(),
+            NamedInput {
+                data: &base,
+                props: &base_props,
+            },
+        );
+        let (patched, patched_props) = match BinaryPatch.convert_multi(&inputs).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(patched, new);
+        assert_eq!(patched_props.get("format").unwrap().as_str(), Some("cbor"));
+        assert!(!patched_props.contains_key("inner_format"));
+    }
 
-        // Hex with various separators
-        let input = b"de:ad:be:ef";
-        let props = Properties::new().with("format", "hex");
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_json_merge_patch() {
+        use crate::{JsonMergeDiff, JsonMergePatch};
+        use indexmap::IndexMap;
+        use paraphase_core::NamedInput;
 
-        let result = HexDecoder.convert(input, &props).unwrap();
-        let (decoded, _) = match result {
-            ConvertOutput::Single(b, p) => (b, p),
-            _ => panic!("Expected single"),
+        let props = Properties::new().with("format", "json");
+        let run = |converter: &dyn Converter, ports: [(&str, &[u8]); 2]| {
+            let inputs: IndexMap<String, NamedInput<'_>> = ports
+                .into_iter()
+                .map(|(port, data)| {
+                    let input = NamedInput {
+                        data,
+                        props: &props,
+                    };
+                    (port.to_string(), input)
+                })
+                .collect();
+            match converter.convert_multi(&inputs).unwrap() {
+                ConvertOutput::Single(data, _) => {
+                    serde_json::from_slice::<serde_json::Value>(&data).unwrap()
+                }
+                _ => panic!("Expected single output"),
+            }
         };
-        assert_eq!(decoded, b"\xde\xad\xbe\xef");
+
+        let base = br#"{"a": 1, "b": 2}"#;
+        let patch = br#"{"b": null, "c": 3}"#;
+        let merged = run(&JsonMergePatch, [("base", base), ("patch", patch)]);
+        assert_eq!(merged, serde_json::json!({"a": 1, "c": 3}));
+
+        let merged = serde_json::to_vec(&merged).unwrap();
+        let diff = run(&JsonMergeDiff, [("base", base), ("new", &merged)]);
+        assert_eq!(
+            diff,
+            serde_json::from_slice::<serde_json::Value>(patch).unwrap()
+        );
+
+        // Nested objects merge recursively; arrays are replaced whole
+        let base = br#"{"title": "Goodbye!", "author": {"given": "John", "family": "Doe"}, "tags": ["example", "sample"]}"#;
+        let patch = br#"{"title": "Hello!", "author": {"family": null}, "tags": ["example"]}"#;
+        let merged = run(&JsonMergePatch, [("base", base), ("patch", patch)]);
+        assert_eq!(
+            merged,
+            serde_json::json!({"title": "Hello!", "author": {"given": "John"}, "tags": ["example"]})
+        );
+        let merged = serde_json::to_vec(&merged).unwrap();
+        let diff = run(&JsonMergeDiff, [("base", base), ("new", &merged)]);
+        assert_eq!(
+            diff,
+            serde_json::from_slice::<serde_json::Value>(patch).unwrap()
+        );
     }
 
     #[test]
-    #[cfg(feature = "ndjson")]
-    fn test_json_to_ndjson() {
-        use crate::JsonToNdjson;
+    #[cfg(feature = "json")]
+    fn test_json_patch() {
+        use crate::{JsonPatchApply, JsonPatchGenerate};
+        use indexmap::IndexMap;
+        use paraphase_core::NamedInput;
+        use serde_json::json;
 
-        let input = br#"[{"a": 1}, {"b": 2}, {"c": 3}]"#;
         let props = Properties::new().with("format", "json");
+        let run = |converter: &dyn Converter, ports: [(&str, serde_json::Value); 2]| {
+            let data: Vec<(&str, Vec<u8>)> = ports
+                .iter()
+                .map(|(port, value)| (*port, serde_json::to_vec(value).unwrap()))
+                .collect();
+            let inputs: IndexMap<String, NamedInput<'_>> = data
+                .iter()
+                .map(|(port, data)| {
+                    let input = NamedInput {
+                        data,
+                        props: &props,
+                    };
+                    (port.to_string(), input)
+                })
+                .collect();
+            converter.convert_multi(&inputs).map(|output| match output {
+                ConvertOutput::Single(data, _) => {
+                    serde_json::from_slice::<serde_json::Value>(&data).unwrap()
+                }
+                _ => panic!("Expected single output"),
+            })
+        };
+        let apply = |base, patch| run(&JsonPatchApply::new(), [("base", base), ("patch", patch)]);
 
-        let result = JsonToNdjson.convert(input, &props).unwrap();
-        let (output, out_props) = match result {
-            ConvertOutput::Single(b, p) => (b, p),
-            _ => panic!("Expected single"),
+        // RFC 6902 appendix A examples
+        let cases = [
+            (
+                json!({"foo": "bar"}),
+                json!([{"op": "add", "path": "/baz", "value": "qux"}]),
+                json!({"baz": "qux", "foo": "bar"}),
+            ),
+            (
+                json!({"foo": ["bar", "baz"]}),
+                json!([{"op": "add", "path": "/foo/1", "value": "qux"}]),
+                json!({"foo": ["bar", "qux", "baz"]}),
+            ),
+            (
+                json!({"baz": "qux", "foo": "bar"}),
+                json!([{"op": "remove", "path": "/baz"}]),
+                json!({"foo": "bar"}),
+            ),
+            (
+                json!({"foo": ["bar", "qux", "baz"]}),
+                json!([{"op": "remove", "path": "/foo/1"}]),
+                json!({"foo": ["bar", "baz"]}),
+            ),
+            (
+                json!({"baz": "qux", "foo": "bar"}),
+                json!([{"op": "replace", "path": "/baz", "value": "boo"}]),
+                json!({"baz": "boo", "foo": "bar"}),
+            ),
+            (
+                json!({"foo": {"bar": "baz", "waldo": "fred"}, "qux": {"corge": "grault"}}),
+                json!([{"op": "move", "from": "/foo/waldo", "path": "/qux/thud"}]),
+                json!({"foo": {"bar": "baz"}, "qux": {"corge": "grault", "thud": "fred"}}),
+            ),
+            (
+                json!({"foo": ["all", "grass", "cows", "eat"]}),
+                json!([{"op": "move", "from": "/foo/1", "path": "/foo/3"}]),
+                json!({"foo": ["all", "cows", "eat", "grass"]}),
+            ),
+            (
+                json!({"baz": "qux", "foo": ["a", 2, "c"]}),
+                json!([
+                    {"op": "test", "path": "/baz", "value": "qux"},
+                    {"op": "test", "path": "/foo/1", "value": 2}
+                ]),
+                json!({"baz": "qux", "foo": ["a", 2, "c"]}),
+            ),
+            (
+                json!({"foo": "bar"}),
+                json!([{"op": "add", "path": "/child", "value": {"grandchild": {}}}]),
+                json!({"foo": "bar", "child": {"grandchild": {}}}),
+            ),
+            (
+                json!({"foo": ["bar"]}),
+                json!([{"op": "add", "path": "/foo/-", "value": ["abc", "def"]}]),
+                json!({"foo": ["bar", ["abc", "def"]]}),
+            ),
+            (
+                json!({"/": 9, "~1": 10}),
+                json!([{"op": "test", "path": "/~01", "value": 10}]),
+                json!({"/": 9, "~1": 10}),
+            ),
+        ];
+        for (base, patch, expected) in cases {
+            assert_eq!(apply(base, patch).unwrap(), expected);
+        }
+
+        // Failed tests and missing targets are rejected
+        let failing = [
+            (
+                json!({"baz": "qux"}),
+                json!([{"op": "test", "path": "/baz", "value": "bar"}]),
+            ),
+            (
+                json!({"/": 9, "~1": 10}),
+                json!([{"op": "test", "path": "/~01", "value": "10"}]),
+            ),
+            (
+                json!({"foo": "bar"}),
+                json!([{"op": "add", "path": "/baz/bat", "value": "qux"}]),
+            ),
+            (
+                json!({"foo": "bar"}),
+                json!([{"op": "remove", "path": "/baz"}]),
+            ),
+            (
+                json!({"foo": [1]}),
+                json!([{"op": "add", "path": "/foo/2", "value": 3}]),
+            ),
+        ];
+        for (base, patch) in failing {
+            assert!(matches!(
+                apply(base, patch),
+                Err(ConvertError::InvalidInput { .. })
+            ));
+        }
+
+        // A failed test can be skipped instead
+        let lenient = JsonPatchApply::new().with_test_failure_as_error(false);
+        let patched = run(
+            &lenient,
+            [
+                ("base", json!({"baz": "qux"})),
+                (
+                    "patch",
+                    json!([
+                        {"op": "test", "path": "/baz", "value": "bar"},
+                        {"op": "add", "path": "/foo", "value": 1}
+                    ]),
+                ),
+            ],
+        )
+        .unwrap();
+        assert_eq!(patched, json!({"baz": "qux", "foo": 1}));
+
+        // ... also through the base's properties
+        let lenient_props = props
+            .clone()
+            .with("json_patch_test_failure_as_error", false);
+        let base = serde_json::to_vec(&json!({"baz": "qux"})).unwrap();
+        let patch = serde_json::to_vec(&json!([
+            {"op": "test", "path": "/baz", "value": "bar"},
+            {"op": "add", "path": "/foo", "value": 1}
+        ]))
+        .unwrap();
+        let inputs = |base_props| -> IndexMap<String, NamedInput<'_>> {
+            IndexMap::from([
+                (
+                    "base".to_string(),
+                    NamedInput {
+                        data: &base,
+                        props: base_props,
+                    },
+                ),
+                (
+                    "patch".to_string(),
+                    NamedInput {
+                        data: &patch,
+                        props: &props,
+                    },
+                ),
+            ])
         };
+        match JsonPatchApply::new()
+            .convert_multi(&inputs(&lenient_props))
+            .unwrap()
+        {
+            ConvertOutput::Single(data, out_props) => {
+                let patched: serde_json::Value = serde_json::from_slice(&data).unwrap();
+                assert_eq!(patched, json!({"baz": "qux", "foo": 1}));
+                assert!(out_props.get("json_patch_test_failure_as_error").is_none());
+            }
+            _ => panic!("Expected single output"),
+        }
+        let invalid_props = props
+            .clone()
+            .with("json_patch_test_failure_as_error", "sometimes");
+        assert!(matches!(
+            JsonPatchApply::new().convert_multi(&inputs(&invalid_props)),
+            Err(ConvertError::InvalidProperties(_))
+        ));
 
-        let output_str = String::from_utf8(output).unwrap();
-        assert_eq!(out_props.get("format").unwrap().as_str(), Some("ndjson"));
-        assert!(output_str.contains(r#"{"a":1}"#));
-        assert!(output_str.contains(r#"{"b":2}"#));
-        assert!(output_str.contains(r#"{"c":3}"#));
-        assert_eq!(output_str.lines().count(), 3);
+        // Generated patches round-trip
+        let base = json!({
+            "title": "Goodbye!",
+            "author": {"given": "John", "family": "Doe"},
+            "tags": ["example", "sample", "test"],
+            "a/b": 1
+        });
+        let new = json!({
+            "title": "Hello!",
+            "author": {"given": "John"},
+            "tags": ["example", "new", "test", "more"],
+            "a/b": 2
+        });
+        let patch = run(
+            &JsonPatchGenerate,
+            [("base", base.clone()), ("new", new.clone())],
+        )
+        .unwrap();
+        // Object key order depends on serde_json's preserve_order feature
+        let mut ops = patch.as_array().unwrap().clone();
+        ops.sort_by_key(|op| op["path"].as_str().unwrap().to_string());
+        assert_eq!(
+            ops,
+            vec![
+                json!({"op": "remove", "path": "/author/family"}),
+                json!({"op": "replace", "path": "/a~1b", "value": 2}),
+                json!({"op": "replace", "path": "/tags/1", "value": "new"}),
+                json!({"op": "add", "path": "/tags/3", "value": "more"}),
+                json!({"op": "replace", "path": "/title", "value": "Hello!"}),
+            ]
+        );
+        assert_eq!(apply(base, patch).unwrap(), new);
+
+        let base = json!([1, 2, 3, 4, 5, 6]);
+        let new = json!([0, 1, 3, 4, 7, 6, 8]);
+        let patch = run(
+            &JsonPatchGenerate,
+            [("base", base.clone()), ("new", new.clone())],
+        )
+        .unwrap();
+        assert_eq!(patch.as_array().unwrap().len(), 4);
+        assert_eq!(apply(base, patch).unwrap(), new);
+
+        // Large, dissimilar arrays diff in linear space
+        let base: serde_json::Value = (0..50_000).collect();
+        let new: serde_json::Value = (0..50_000).map(|i| -i - 1).collect();
+        let patch = run(
+            &JsonPatchGenerate,
+            [("base", base.clone()), ("new", new.clone())],
+        )
+        .unwrap();
+        assert_eq!(patch.as_array().unwrap().len(), 50_000);
+        assert_eq!(apply(base, patch).unwrap(), new);
     }
 
     #[test]
-    #[cfg(feature = "ndjson")]
-    fn test_ndjson_to_json() {
-        use crate::NdjsonToJson;
+    #[cfg(feature = "ini")]
+    fn test_ini_to_json() {
+        use crate::IniToJson;
 
-        let input = b"{\"a\": 1}\n{\"b\": 2}\n{\"c\": 3}\n";
-        let props = Properties::new().with("format", "ndjson");
+        let input = b"[section]\nkey=value\nnum=42\n";
+        let props = Properties::new().with("format", "ini");
 
-        let result = NdjsonToJson.convert(input, &props).unwrap();
+        let result = IniToJson.convert(input, &props).unwrap();
         let (output, out_props) = match result {
             ConvertOutput::Single(b, p) => (b, p),
             _ => panic!("Expected single"),
@@ -2132,115 +9664,415 @@ mod tests {
 
         let value: serde_json::Value = serde_json::from_slice(&output).unwrap();
         assert_eq!(out_props.get("format").unwrap().as_str(), Some("json"));
-        assert!(value.is_array());
-        assert_eq!(value.as_array().unwrap().len(), 3);
+        assert_eq!(value["section"]["key"], "value");
+        assert_eq!(value["section"]["num"], "42");
     }
 
     #[test]
-    #[cfg(all(feature = "json", feature = "yaml"))]
-    fn test_roundtrip() {
-        let original = br#"{"name": "roundtrip", "nested": {"a": 1, "b": 2}}"#;
+    #[cfg(feature = "ini")]
+    fn test_ini_options() {
+        use crate::{IniToJson, JsonToIni};
+
+        let input = b"top=1\n[Paths]\nValue=a\n#color=red\nvalue=b\n";
+        let convert = |props: Properties| -> serde_json::Value {
+            match IniToJson.convert(input, &props).unwrap() {
+                ConvertOutput::Single(output, out_props) => {
+                    assert!(!out_props.contains_key("ini_multi_value"));
+                    serde_json::from_slice(&output).unwrap()
+                }
+                _ => panic!("Expected single"),
+            }
+        };
 
-        let json_to_yaml = SerdeConverter::new("json", "yaml");
-        let yaml_to_json = SerdeConverter::new("yaml", "json");
+        // Defaults: global keys under "", `#` is a comment, last value wins
+        let value = convert(Properties::new().with("format", "ini"));
+        assert_eq!(value[""]["top"], "1");
+        assert_eq!(value["Paths"]["Value"], "a");
+        assert_eq!(value["Paths"]["value"], "b");
+        assert!(value["Paths"].get("#color").is_none());
+
+        // Duplicate keys (after lowercasing) collected into an array
+        let value = convert(
+            Properties::new()
+                .with("format", "ini")
+                .with("ini_multi_value", true)
+                .with("ini_lowercase_keys", true)
+                .with("ini_global_section", "_global"),
+        );
+        assert_eq!(value["_global"]["top"], "1");
+        assert_eq!(value["Paths"]["value"], serde_json::json!(["a", "b"]));
+
+        // Only `;` starts a comment, so the `#` line becomes a key
+        let value = convert(
+            Properties::new()
+                .with("format", "ini")
+                .with("ini_comment_char", ";"),
+        );
+        assert_eq!(value["Paths"]["#color"], "red");
 
-        let props = Properties::new().with("format", "json");
+        let result = IniToJson.convert(input, &Properties::new().with("ini_comment_char", "//"));
+        assert!(matches!(result, Err(ConvertError::InvalidInput { .. })));
 
-        // JSON -> YAML
-        let yaml_result = json_to_yaml.convert(original, &props).unwrap();
-        let (yaml_bytes, yaml_props) = match yaml_result {
-            ConvertOutput::Single(b, p) => (b, p),
+        // Arrays round-trip as repeated keys
+        let json = br#"{"": {"top": "1"}, "Paths": {"value": ["a", "b"]}}"#;
+        let ini = match JsonToIni.convert(json, &Properties::new()).unwrap() {
+            ConvertOutput::Single(output, _) => String::from_utf8(output).unwrap(),
             _ => panic!("Expected single"),
         };
+        assert!(ini.starts_with("top=1\n"));
+        assert!(ini.contains("value=a\nvalue=b\n"));
+    }
 
-        // YAML -> JSON
-        let json_result = yaml_to_json.convert(&yaml_bytes, &yaml_props).unwrap();
-        let (json_bytes, _) = match json_result {
+    #[test]
+    #[cfg(feature = "ini")]
+    fn test_json_to_ini_comments() {
+        use crate::{IniToJson, JsonToIni};
+
+        let input = br#"{
+            "_comments": {"server.port": "Listen port", "name": "overridden"},
+            "": {"name": "demo"},
+            "server": {"host": "localhost", "port": 8080}
+        }"#;
+        let props = Properties::new()
+            .with("format", "json")
+            .with("ini_file_comment", "Generated config\nDo not edit")
+            .with(
+                "ini_comments",
+                paraphase_core::Value::from(serde_json::json!({"name": "Application name"})),
+            );
+
+        let (output, out_props) = match JsonToIni.convert(input, &props).unwrap() {
             ConvertOutput::Single(b, p) => (b, p),
             _ => panic!("Expected single"),
         };
+        assert!(!out_props.contains_key("ini_comments"));
+        assert!(!out_props.contains_key("ini_file_comment"));
+
+        let text = String::from_utf8(output.clone())
+            .unwrap()
+            .replace("\r\n", "\n");
+        assert_eq!(
+            text,
+            "# Generated config\n# Do not edit\n\n\
+             # Application name\nname=demo\n\n\
+             [server]\nhost=localhost\n# Listen port\nport=8080\n"
+        );
+
+        // Comments are dropped again on the way back
+        let value: serde_json::Value = match IniToJson.convert(&output, &out_props).unwrap() {
+            ConvertOutput::Single(b, _) => serde_json::from_slice(&b).unwrap(),
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(
+            value,
+            serde_json::json!({"": {"name": "demo"}, "server": {"host": "localhost", "port": "8080"}})
+        );
+    }
+    #[test]
+    #[cfg(all(feature = "toml", feature = "json"))]
+    fn test_toml_datetime_to_json() {
+        let input = b"date = 2023-01-15T10:30:00Z\n\n[build]\nday = 1970-01-02\nat = 10:30:00\nshifted = 2023-01-15T12:30:00.5+02:00\n";
+        let run = |props: &Properties| {
+            let output = match SerdeConverter::new("toml", "json")
+                .convert(input, props)
+                .unwrap()
+            {
+                ConvertOutput::Single(output, _) => output,
+                _ => panic!("Expected single output"),
+            };
+            serde_json::from_slice::<serde_json::Value>(&output).unwrap()
+        };
+
+        let props = Properties::new().with("format", "toml");
+        let json = run(&props);
+        assert_eq!(json["date"], "2023-01-15T10:30:00Z");
+        assert_eq!(json["build"]["day"], "1970-01-02");
+        assert_eq!(json["build"]["at"], "10:30:00");
+
+        let json = run(&props.clone().with("toml_datetime_as_string", false));
+        assert_eq!(json["date"], 1673778600);
+        assert_eq!(json["build"]["day"], 86400);
+        // Local times have no timestamp
+        assert_eq!(json["build"]["at"], "10:30:00");
+        assert_eq!(json["build"]["shifted"], 1673778600.5);
+    }
+
+    #[test]
+    #[cfg(all(feature = "xml", feature = "json"))]
+    fn test_xml_namespaces() {
+        let soap = r#"<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope" soap:encodingStyle="http://www.w3.org/2003/05/soap-encoding">
+  <soap:Body xmlns:m="https://www.example.org/stock">
+    <m:GetStockPrice>
+      <m:StockName>Fish &amp; Chips</m:StockName>
+      <m:StockName>T&#233;a</m:StockName>
+    </m:GetStockPrice>
+  </soap:Body>
+</soap:Envelope>"#;
+
+        let run = |from: &'static str, to: &'static str, data: &[u8], props: &Properties| {
+            match SerdeConverter::new(from, to).convert(data, props).unwrap() {
+                ConvertOutput::Single(output, props) => (output, props),
+                _ => panic!("Expected single output"),
+            }
+        };
+
+        // Namespace declarations and prefixes are kept as JSON keys
+        let props = Properties::new()
+            .with("format", "xml")
+            .with("xml_preserve_namespaces", true);
+        let (json, json_props) = run("xml", "json", soap.as_bytes(), &props);
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(
+            value["@xmlns:soap"],
+            "http://www.w3.org/2003/05/soap-envelope"
+        );
+        let body = &value["soap:Body"];
+        assert_eq!(body["@xmlns:m"], "https://www.example.org/stock");
+        assert_eq!(
+            body["m:GetStockPrice"]["m:StockName"],
+            serde_json::json!(["Fish & Chips", "Téa"])
+        );
+        assert_eq!(
+            json_props.get("xml_root_element").unwrap().as_str(),
+            Some("soap:Envelope")
+        );
+
+        // ...and restored on the way back
+        let (xml, _) = run("json", "xml", &json, &json_props);
+        let xml = String::from_utf8(xml).unwrap();
+        assert!(xml.starts_with("<soap:Envelope "), "{}", xml);
+        assert!(xml.contains(r#" xmlns:soap="http://www.w3.org/2003/05/soap-envelope""#));
+        assert!(xml.contains(r#"<soap:Body xmlns:m="https://www.example.org/stock">"#));
+        assert!(xml.contains("<m:StockName>Fish &amp; Chips</m:StockName>"));
+        let (reparsed, _) = run("xml", "json", xml.as_bytes(), &props);
+        assert_eq!(reparsed, json);
+
+        // Without the option, prefixes and declarations are dropped
+        let plain = Properties::new().with("format", "xml");
+        let (json, json_props) = run("xml", "json", soap.as_bytes(), &plain);
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert!(value.get("Body").is_some());
+        assert!(value.get("@xmlns:soap").is_none());
+        assert_eq!(
+            json_props.get("xml_root_element").unwrap().as_str(),
+            Some("Envelope")
+        );
 
-        // Parse both and compare
-        let original_value: serde_json::Value = serde_json::from_slice(original).unwrap();
-        let roundtrip_value: serde_json::Value = serde_json::from_slice(&json_bytes).unwrap();
-        assert_eq!(original_value, roundtrip_value);
+        // Objects get a root element, defaulting to <root>
+        let input = br#"{"name":"app","port":8080}"#;
+        let (xml, _) = run(
+            "json",
+            "xml",
+            input,
+            &Properties::new().with("format", "json"),
+        );
+        assert_eq!(xml, b"<root><name>app</name><port>8080</port></root>");
+        let named = Properties::new()
+            .with("format", "json")
+            .with("xml_root_element", "config");
+        let (xml, xml_props) = run("json", "xml", input, &named);
+        assert_eq!(xml, b"<config><name>app</name><port>8080</port></config>");
+        assert!(xml_props.get("xml_root_element").is_none());
     }
 
     #[test]
-    #[cfg(feature = "gzip")]
-    fn test_gzip_roundtrip() {
-        use crate::{GzipCompress, GzipDecompress};
-
-        let original = b"Hello, World! This is test data. ".repeat(100);
-        let props = Properties::new().with("format", "raw");
+    #[cfg(all(feature = "yaml", feature = "toml"))]
+    fn test_yaml_to_toml() {
+        use crate::YamlToToml;
 
-        // Compress
-        let compressed = GzipCompress.convert(&original, &props).unwrap();
-        let (compressed_bytes, compressed_props) = match compressed {
-            ConvertOutput::Single(b, p) => (b, p),
-            _ => panic!("Expected single"),
+        let props = Properties::new().with("format", "yaml");
+        let run = |yaml: &str, props: &Properties| {
+            YamlToToml
+                .convert(yaml.as_bytes(), props)
+                .map(|output| match output {
+                    ConvertOutput::Single(data, props) => (String::from_utf8(data).unwrap(), props),
+                    _ => panic!("Expected single output"),
+                })
         };
+
+        // The planner picks the dedicated converter
+        let mut registry = Registry::new();
+        register_all(&mut registry);
+        assert!(registry.get("serde.yaml-to-toml").is_some());
+
+        // Timestamps become TOML datetimes, plain strings stay strings
+        let (toml_text, _) = run(
+            "released: 2024-01-15\nupdated: 2024-01-15T10:30:00Z\nat: '12:30:00'\nname: app\n",
+            &props,
+        )
+        .unwrap();
+        let value: toml::Table = toml::from_str(&toml_text).unwrap();
+        assert!(value["released"].is_datetime());
+        assert!(value["updated"].is_datetime());
+        assert_eq!(value["at"].as_str(), Some("12:30:00"));
+        assert!(toml_text.contains("released = 2024-01-15\n"));
+
+        // Only plain scalars are promoted; quoted and tagged dates stay strings
+        let (toml_text, _) = run(
+            "a: \"2024-01-01\"\nb: '2024-01-01'\nc: !!str 2024-01-01\nd: 2024-01-01\n",
+            &props,
+        )
+        .unwrap();
+        let value: toml::Table = toml::from_str(&toml_text).unwrap();
+        assert_eq!(value["a"].as_str(), Some("2024-01-01"));
+        assert_eq!(value["b"].as_str(), Some("2024-01-01"));
+        assert_eq!(value["c"].as_str(), Some("2024-01-01"));
+        assert!(value["d"].is_datetime());
+
+        // Aliases are expanded; scalars resolve by the core schema
+        let (toml_text, _) = run(
+            "base: &base {port: 0x1F90, ratio: .5, on: true}\ncopy: *base\nquoted: '42'\n",
+            &props,
+        )
+        .unwrap();
+        let value: toml::Table = toml::from_str(&toml_text).unwrap();
+        assert_eq!(value["copy"]["port"].as_integer(), Some(8080));
+        assert_eq!(value["copy"]["ratio"].as_float(), Some(0.5));
+        assert_eq!(value["copy"]["on"].as_bool(), Some(true));
+        assert_eq!(value["quoted"].as_str(), Some("42"));
+
+        // Multi-line strings survive
+        let (toml_text, _) = run("text: |\n  line one\n  line two\n", &props).unwrap();
+        let value: toml::Table = toml::from_str(&toml_text).unwrap();
+        assert_eq!(value["text"].as_str(), Some("line one\nline two\n"));
+
+        // Tags are dropped and non-string keys stringified
+        let (toml_text, _) = run("port: !int 8080\ncodes:\n  404: missing\n", &props).unwrap();
+        let value: toml::Table = toml::from_str(&toml_text).unwrap();
+        assert_eq!(value["port"].as_integer(), Some(8080));
+        assert_eq!(value["codes"]["404"].as_str(), Some("missing"));
+
+        // Nulls are omitted and reported, or rejected on request
+        let yaml = "a: 1\nb: null\nnested:\n  c: ~\nlist: [1, null, 2]\n";
+        let (toml_text, out_props) = run(yaml, &props).unwrap();
+        let value: toml::Table = toml::from_str(&toml_text).unwrap();
+        assert!(!value.contains_key("b"));
+        assert_eq!(value["list"].as_array().unwrap().len(), 2);
         assert_eq!(
-            compressed_props.get("format").unwrap().as_str(),
-            Some("gzip")
+            out_props.get("toml_omitted").unwrap().as_str(),
+            Some("b,nested.c,list[1]")
         );
-        assert!(compressed_bytes.len() < original.len()); // Should be smaller for repeated data
 
-        // Decompress
-        let decompressed = GzipDecompress
-            .convert(&compressed_bytes, &compressed_props)
-            .unwrap();
-        let (decompressed_bytes, _) = match decompressed {
-            ConvertOutput::Single(b, p) => (b, p),
-            _ => panic!("Expected single"),
-        };
-        assert_eq!(decompressed_bytes, original);
+        let strict = props.clone().with("toml_null", "error");
+        assert!(matches!(
+            run(yaml, &strict),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+
+        // ...or written as empty strings
+        let empty = props.clone().with("toml_null", "empty_string");
+        let (toml_text, out_props) = run(yaml, &empty).unwrap();
+        let value: toml::Table = toml::from_str(&toml_text).unwrap();
+        assert_eq!(value["b"].as_str(), Some(""));
+        assert_eq!(value["list"].as_array().unwrap().len(), 3);
+        assert!(out_props.get("toml_omitted").is_none());
+
+        // YAML 1.1 booleans are strings unless aliases are enabled
+        let yaml = "key: null\nenabled: yes\nverbose: Off\n";
+        let (toml_text, _) = run(yaml, &props).unwrap();
+        let value: toml::Table = toml::from_str(&toml_text).unwrap();
+        assert_eq!(value["enabled"].as_str(), Some("yes"));
+        let aliases = props.clone().with("yaml_bool_aliases", true);
+        let (toml_text, out_props) = run(yaml, &aliases).unwrap();
+        let value: toml::Table = toml::from_str(&toml_text).unwrap();
+        assert_eq!(value["enabled"].as_bool(), Some(true));
+        assert_eq!(value["verbose"].as_bool(), Some(false));
+        assert!(!value.contains_key("key"));
+        assert!(out_props.get("yaml_bool_aliases").is_none());
+
+        // Integers beyond i64 can't be represented
+        assert!(run("big: 18446744073709551615\n", &props).is_err());
+
+        // The root must be a table
+        match run("[1, 2, 3]", &props) {
+            Err(ConvertError::InvalidInput { message: msg, .. }) => {
+                assert_eq!(msg, "YAML value is not a TOML-compatible table")
+            }
+            _ => panic!("Expected InvalidInput"),
+        }
     }
 
     #[test]
-    #[cfg(feature = "zstd")]
-    fn test_zstd_roundtrip() {
-        use crate::{ZstdCompress, ZstdDecompress};
-
-        let original = b"Hello, World! This is some test data that should compress well.";
-        let props = Properties::new().with("format", "raw");
+    #[cfg(feature = "tera")]
+    fn test_tera_render() {
+        use crate::TeraRender;
 
-        // Compress
-        let compressed = ZstdCompress.convert(original, &props).unwrap();
-        let (compressed_bytes, _) = match compressed {
-            ConvertOutput::Single(b, p) => (b, p),
-            _ => panic!("Expected single"),
+        let render = |props: &Properties| match TeraRender.convert(br#"{"name": "World"}"#, props) {
+            Ok(ConvertOutput::Single(output, out_props)) => {
+                Ok((String::from_utf8(output).unwrap(), out_props))
+            }
+            Ok(_) => panic!("Expected single output"),
+            Err(e) => Err(e),
         };
 
-        // Decompress
-        let decompressed = ZstdDecompress
-            .convert(&compressed_bytes, &Properties::new())
-            .unwrap();
-        let (decompressed_bytes, _) = match decompressed {
-            ConvertOutput::Single(b, p) => (b, p),
-            _ => panic!("Expected single"),
-        };
-        assert_eq!(decompressed_bytes, original);
+        let props = Properties::new()
+            .with("format", "json")
+            .with("tera_template", "Hello, {{ name }}!");
+        let (text, out_props) = render(&props).unwrap();
+        assert_eq!(text, "Hello, World!");
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("text"));
+        assert!(out_props.get("tera_template").is_none());
+
+        // The main template can extend templates from a directory
+        let dir = std::env::temp_dir().join(format!("paraphase-tera-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("base.txt"), "[{% block body %}{% endblock %}]").unwrap();
+        let props = Properties::new()
+            .with("format", "json")
+            .with("tera_template_dir", dir.to_string_lossy().as_ref())
+            .with(
+                "tera_template",
+                r#"{% extends "base.txt" %}{% block body %}Hi {{ name }}{% endblock %}"#,
+            );
+        let result = render(&props);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result.unwrap().0, "[Hi World]");
+
+        // Unknown variables name the culprit
+        let props = Properties::new()
+            .with("format", "json")
+            .with("tera_template", "{{ missing }}");
+        assert!(render(&props).unwrap_err().to_string().contains("missing"));
+
+        let props = Properties::new().with("format", "json");
+        assert!(matches!(
+            render(&props),
+            Err(ConvertError::MissingProperty(_))
+        ));
     }
 
     #[test]
-    #[cfg(feature = "ini")]
-    fn test_ini_to_json() {
-        use crate::IniToJson;
+    #[cfg(feature = "handlebars")]
+    fn test_handlebars_render() {
+        use crate::HandlebarsRender;
+
+        let input = br#"{"name": "World", "tag": "<b>"}"#;
+        let render = |props: &Properties| match HandlebarsRender.convert(input, props) {
+            Ok(ConvertOutput::Single(output, out_props)) => {
+                Ok((String::from_utf8(output).unwrap(), out_props))
+            }
+            Ok(_) => panic!("Expected single output"),
+            Err(e) => Err(e),
+        };
 
-        let input = b"[section]\nkey=value\nnum=42\n";
-        let props = Properties::new().with("format", "ini");
+        let props = Properties::new()
+            .with("format", "json")
+            .with("hbs_template", "Hello, {{ name }}!");
+        let (text, out_props) = render(&props).unwrap();
+        assert_eq!(text, "Hello, World!");
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("text"));
+        assert!(out_props.get("hbs_template").is_none());
 
-        let result = IniToJson.convert(input, &props).unwrap();
-        let (output, out_props) = match result {
-            ConvertOutput::Single(b, p) => (b, p),
-            _ => panic!("Expected single"),
-        };
+        // Text output: no HTML escaping
+        let props = props.with("hbs_template", "{{ tag }}");
+        assert_eq!(render(&props).unwrap().0, "<b>");
 
-        let value: serde_json::Value = serde_json::from_slice(&output).unwrap();
-        assert_eq!(out_props.get("format").unwrap().as_str(), Some("json"));
-        assert_eq!(value["section"]["key"], "value");
-        assert_eq!(value["section"]["num"], "42");
+        // Missing variables render empty unless strict
+        let props = props.with("hbs_template", "[{{ missing }}]");
+        assert_eq!(render(&props).unwrap().0, "[]");
+        let props = props.with("hbs_strict", true);
+        assert!(render(&props).is_err());
     }
 
     #[test]
@@ -2263,6 +10095,75 @@ mod tests {
         assert!(output_str.contains("<strong>bold</strong>"));
     }
 
+    #[test]
+    #[cfg(feature = "markdown")]
+    fn test_markdown_extensions() {
+        use crate::MarkdownToHtml;
+
+        let input = b"| a | b |\n|---|---|\n| 1 | 2 |\n\n~~strike~~ and \"quotes\"";
+        let render = |props: Properties| match MarkdownToHtml.convert(input, &props).unwrap() {
+            ConvertOutput::Single(b, _) => String::from_utf8(b).unwrap(),
+            _ => panic!("Expected single"),
+        };
+
+        // GitHub-style extensions are on by default
+        let html = render(Properties::new().with("format", "markdown"));
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<del>strike</del>"));
+        assert!(html.contains("\"quotes\""));
+
+        let html = render(
+            Properties::new()
+                .with("markdown_extensions", "")
+                .with("markdown_smart_punctuation", true),
+        );
+        assert!(!html.contains("<table>"));
+        assert!(!html.contains("<del>"));
+        assert!(html.contains("\u{201c}quotes\u{201d}"));
+
+        let props = Properties::new().with("markdown_extensions", "tables,emoji");
+        assert!(matches!(
+            MarkdownToHtml.convert(input, &props),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "markdown")]
+    fn test_markdown_to_text() {
+        use crate::MarkdownToText;
+
+        let run = |input: &str, props: &Properties| match MarkdownToText
+            .convert(input.as_bytes(), props)
+            .unwrap()
+        {
+            ConvertOutput::Single(b, p) => (String::from_utf8(b).unwrap(), p),
+            _ => panic!("Expected single"),
+        };
+        let props = Properties::new().with("format", "markdown");
+
+        let (output, out_props) = run("# Title\n\n**Bold** text", &props);
+        assert_eq!(output, "Title\n\nBold text\n");
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("text"));
+
+        // Lists keep markers, links keep their URL unless stripped
+        let input = "Intro\n\n- one\n- [two](https://example.com)\n\n1. first\n2. second";
+        let (output, _) = run(input, &props);
+        assert_eq!(
+            output,
+            "Intro\n\n- one\n- two (https://example.com)\n\n1. first\n2. second\n"
+        );
+
+        let stripped = props.clone().with("text_strip_links", true);
+        let (output, _) = run("See [docs](https://example.com).", &stripped);
+        assert_eq!(output, "See docs.\n");
+
+        // Wrapping
+        let wrapped = props.clone().with("text_wrap_width", 10i64);
+        let (output, _) = run("aaa bbb ccc ddd eee", &wrapped);
+        assert_eq!(output, "aaa bbb\nccc ddd\neee\n");
+    }
+
     #[test]
     #[cfg(feature = "html2text")]
     fn test_html_to_text() {
@@ -2284,6 +10185,124 @@ mod tests {
         assert!(output_str.contains("World"));
     }
 
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_json_project() {
+        use crate::JsonProjectConverter;
+
+        let doc = br#"{
+            "name": "paraphase", "version": "0.1.0", "edition": "2024",
+            "license": "MIT", "authors": ["rhizome"], "readme": "README.md",
+            "repository": "https://example.com", "keywords": ["convert"],
+            "auth": {"user": "admin", "password": "hunter2"},
+            "dependencies": [
+                {"name": "serde", "version": "1"},
+                {"name": "indexmap", "version": "2"}
+            ]
+        }"#;
+
+        let run = |props: &Properties| match JsonProjectConverter.convert(doc, props).unwrap() {
+            ConvertOutput::Single(data, props) => (
+                serde_json::from_slice::<serde_json::Value>(&data).unwrap(),
+                props,
+            ),
+            _ => panic!("Expected single output"),
+        };
+
+        // Projection keeps the listed keys, including nested paths through arrays
+        let props = Properties::new()
+            .with("format", "json")
+            .with("json_include_fields", "name, version, dependencies.name");
+        let (value, out_props) = run(&props);
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "name": "paraphase",
+                "version": "0.1.0",
+                "dependencies": [{"name": "serde"}, {"name": "indexmap"}]
+            })
+        );
+        assert!(!out_props.contains_key("json_include_fields"));
+
+        // Exclusion drops nested keys and leaves everything else
+        let props = Properties::new()
+            .with("format", "json")
+            .with("json_exclude_fields", "auth.password,keywords");
+        let (value, _) = run(&props);
+        let obj = value.as_object().unwrap();
+        assert_eq!(obj.len(), 9);
+        assert_eq!(value["auth"], serde_json::json!({"user": "admin"}));
+
+        // One of the field lists is required
+        let props = Properties::new().with("format", "json");
+        assert!(matches!(
+            JsonProjectConverter.convert(doc, &props),
+            Err(ConvertError::MissingProperty(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "jsonpath")]
+    fn test_jsonpath_filter() {
+        use crate::JsonPathFilter;
+
+        // Example document from the JSONPath spec
+        let doc = br#"{
+            "store": {
+                "book": [
+                    {"category": "reference", "author": "Nigel Rees",
+                     "title": "Sayings of the Century", "price": 8.95},
+                    {"category": "fiction", "author": "Evelyn Waugh",
+                     "title": "Sword of Honour", "price": 12.99},
+                    {"category": "fiction", "author": "Herman Melville",
+                     "title": "Moby Dick", "isbn": "0-553-21311-3", "price": 8.99},
+                    {"category": "fiction", "author": "J. R. R. Tolkien",
+                     "title": "The Lord of the Rings", "isbn": "0-395-19395-8",
+                     "price": 22.99}
+                ],
+                "bicycle": {"color": "red", "price": 399}
+            }
+        }"#;
+
+        let props = Properties::new()
+            .with("format", "json")
+            .with("jsonpath", "$.store.book[*].author");
+        let (output, out_props) = match JsonPathFilter.convert(doc, &props).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+        let authors: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(
+            authors,
+            serde_json::json!([
+                "Nigel Rees",
+                "Evelyn Waugh",
+                "Herman Melville",
+                "J. R. R. Tolkien"
+            ])
+        );
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("json"));
+        assert!(out_props.get("jsonpath").is_none());
+
+        // Single match is unwrapped when requested
+        let props = Properties::new()
+            .with("format", "json")
+            .with("jsonpath", "$.store.bicycle.color")
+            .with("unwrap_single", true);
+        let output = match JsonPathFilter.convert(doc, &props).unwrap() {
+            ConvertOutput::Single(data, _) => data,
+            _ => panic!("Expected single output"),
+        };
+        let color: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(color, serde_json::json!("red"));
+
+        // Invalid expressions are rejected
+        let props = Properties::new()
+            .with("format", "json")
+            .with("jsonpath", "$.store[");
+        assert!(JsonPathFilter.convert(doc, &props).is_err());
+    }
+
     #[test]
     #[cfg(feature = "tar")]
     fn test_tar_roundtrip() {
@@ -2322,20 +10341,343 @@ mod tests {
             _ => panic!("Expected multiple"),
         };
 
-        assert_eq!(extracted.len(), 2);
+        assert_eq!(extracted.len(), 2);
+
+        // Find and verify files
+        let file1 = extracted
+            .iter()
+            .find(|(_, p)| p.get("path").unwrap().as_str() == Some("dir/file1.txt"))
+            .unwrap();
+        assert_eq!(file1.0, b"Hello from file 1");
+
+        let file2 = extracted
+            .iter()
+            .find(|(_, p)| p.get("path").unwrap().as_str() == Some("file2.txt"))
+            .unwrap();
+        assert_eq!(file2.0, b"Content of file 2");
+    }
+
+    #[test]
+    #[cfg(feature = "tar")]
+    fn test_tar_extract_filters() {
+        use crate::{TarCreate, TarExtract};
+
+        let files: Vec<(Vec<u8>, Properties)> = [
+            ("notes.txt", "notes"),
+            ("docs/readme.txt", "readme"),
+            ("data.json", "{}"),
+        ]
+        .into_iter()
+        .map(|(path, data)| {
+            (
+                data.as_bytes().to_vec(),
+                Properties::new().with("path", path).with("format", "raw"),
+            )
+        })
+        .collect();
+        let inputs: Vec<(&[u8], &Properties)> =
+            files.iter().map(|(d, p)| (d.as_slice(), p)).collect();
+        let archive = match TarCreate.convert_batch(&inputs).unwrap() {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+
+        let extract = |props: Properties| match TarExtract.convert(&archive, &props) {
+            Ok(ConvertOutput::Multiple(files)) => Ok(files),
+            Ok(_) => panic!("Expected multiple"),
+            Err(e) => Err(e),
+        };
+        let paths = |files: &[(Vec<u8>, Properties)]| -> Vec<String> {
+            files
+                .iter()
+                .map(|(_, p)| p.get("path").unwrap().as_str().unwrap().to_string())
+                .collect()
+        };
+
+        let tar = Properties::new().with("format", "tar");
+        let txt = extract(tar.clone().with("tar_include", "*.txt")).unwrap();
+        assert_eq!(paths(&txt), ["notes.txt", "docs/readme.txt"]);
+        assert_eq!(txt[1].0, b"readme");
+        assert!(txt[0].1.get("tar_include").is_none());
+
+        let rest = extract(
+            tar.clone()
+                .with("tar_include", "*.txt")
+                .with("tar_exclude", "docs/**"),
+        )
+        .unwrap();
+        assert_eq!(paths(&rest), ["notes.txt"]);
+
+        // Limits only count entries that pass the filters
+        assert!(
+            extract(
+                tar.clone()
+                    .with("tar_include", "*.txt")
+                    .with("tar_max_entries", 2)
+            )
+            .is_ok()
+        );
+        assert!(matches!(
+            extract(tar.clone().with("tar_max_entries", 2)),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            extract(tar.clone().with("tar_max_entry_size", 5)),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            extract(tar.clone().with("tar_include", "[")),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            extract(tar.with("tar_max_entries", 0)),
+            Err(ConvertError::InvalidProperties(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "tar")]
+    fn test_tar_create_streaming() {
+        use crate::{TarCreate, TarExtract};
+
+        let big_a = vec![0xA5u8; 1024 * 1024];
+        let big_b: Vec<u8> = (0..1024 * 1024).map(|i| (i % 251) as u8).collect();
+        let inputs = [
+            (
+                big_a.as_slice(),
+                Properties::new()
+                    .with("path", "a.bin")
+                    .with("size", 1024 * 1024),
+            ),
+            // No size: measured in memory
+            (
+                big_b.as_slice(),
+                Properties::new().with("path", "data/b.bin"),
+            ),
+        ];
+
+        let mut archive = Vec::new();
+        TarCreate::create_streaming(inputs, &mut archive).unwrap();
+
+        let props = Properties::new().with("format", "tar");
+        let extracted = match TarExtract.convert(&archive, &props).unwrap() {
+            ConvertOutput::Multiple(files) => files,
+            _ => panic!("Expected multiple"),
+        };
+        assert_eq!(extracted.len(), 2);
+        assert_eq!(extracted[0].1.get("path").unwrap().as_str(), Some("a.bin"));
+        assert!(extracted[0].0 == big_a);
+        assert_eq!(
+            extracted[1].1.get("path").unwrap().as_str(),
+            Some("data/b.bin")
+        );
+        assert!(extracted[1].0 == big_b);
+
+        // A size larger than the input is an error, not a corrupt archive
+        let short = [(
+            &b"abc"[..],
+            Properties::new().with("path", "x").with("size", 10),
+        )];
+        let err = TarCreate::create_streaming(short, Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("Failed to add entry 'x'"));
+    }
+
+    #[test]
+    #[cfg(feature = "tar")]
+    fn test_tar_create_path_options() {
+        use crate::TarCreate;
+
+        let files = vec![
+            (
+                b"fn main() {}".to_vec(),
+                Properties::new()
+                    .with("path", "/work/project/src/main.rs")
+                    .with("format", "raw")
+                    .with("tar_strip_prefix", "/work/project")
+                    .with("tar_base_dir", "project-1.0/"),
+            ),
+            (
+                b"#[test] fn t() {}".to_vec(),
+                Properties::new()
+                    .with("path", "/work/project/tests/test1.rs")
+                    .with("format", "raw")
+                    .with("tar_strip_prefix", "/work/project")
+                    .with("tar_base_dir", "project-1.0/"),
+            ),
+            (
+                b"not under the prefix".to_vec(),
+                Properties::new()
+                    .with("path", "/work/project2/notes.txt")
+                    .with("format", "raw")
+                    .with("tar_strip_prefix", "/work/project")
+                    .with("tar_base_dir", "project-1.0/"),
+            ),
+        ];
+        let inputs: Vec<(&[u8], &Properties)> =
+            files.iter().map(|(d, p)| (d.as_slice(), p)).collect();
+        let archive = match TarCreate.convert_batch(&inputs).unwrap() {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+
+        let mut tar = tar::Archive::new(archive.as_slice());
+        let paths: Vec<String> = tar
+            .entries()
+            .unwrap()
+            .map(|e| e.unwrap().path().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "project-1.0/src/main.rs",
+                "project-1.0/tests/test1.rs",
+                "project-1.0/work/project2/notes.txt"
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "tar", unix))]
+    fn test_tar_create_preserve_permissions() {
+        use crate::TarCreate;
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(format!("paraphase-tar-mode-{}", std::process::id()));
+        std::fs::write(&path, b"#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let props = Properties::new()
+            .with("path", path.to_string_lossy().as_ref())
+            .with("format", "raw")
+            .with(
+                "tar_strip_prefix",
+                std::env::temp_dir().to_string_lossy().as_ref(),
+            )
+            .with("tar_preserve_permissions", true);
+        let data = b"#!/bin/sh\n".to_vec();
+        let result = TarCreate.convert_batch(&[(data.as_slice(), &props)]);
+        std::fs::remove_file(&path).unwrap();
+
+        let archive = match result.unwrap() {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+        let mut tar = tar::Archive::new(archive.as_slice());
+        let entry = tar.entries().unwrap().next().unwrap().unwrap();
+        assert_eq!(entry.header().mode().unwrap(), 0o755);
+        assert!(!entry.path().unwrap().is_absolute());
+    }
+
+    #[test]
+    #[cfg(feature = "tar")]
+    fn test_tar_create_entry_metadata() {
+        use crate::TarCreate;
+
+        let script = Properties::new()
+            .with("path", "bin/run.sh")
+            .with("tar_mode", "755")
+            .with("tar_mtime", 1_700_000_000i64)
+            .with("tar_uname", "builder")
+            .with("tar_gname", "staff");
+        let readme = Properties::new()
+            .with("path", "README")
+            .with("tar_mode", 0o600i64);
+        let archive = match TarCreate
+            .convert_batch(&[(b"#!/bin/sh\n", &script), (b"hello\n", &readme)])
+            .unwrap()
+        {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+
+        let mut tar = tar::Archive::new(archive.as_slice());
+        let mut entries = tar.entries().unwrap().map(|e| e.unwrap());
+        let first = entries.next().unwrap();
+        let header = first.header();
+        assert_eq!(header.mode().unwrap(), 0o755);
+        assert_eq!(header.mtime().unwrap(), 1_700_000_000);
+        assert_eq!(header.username().unwrap(), Some("builder"));
+        assert_eq!(header.groupname().unwrap(), Some("staff"));
+        let second = entries.next().unwrap();
+        assert_eq!(second.header().mode().unwrap(), 0o600);
+        assert_eq!(second.header().mtime().unwrap(), 0);
+
+        let bad = readme.clone().with("tar_mode", "rwx");
+        assert!(matches!(
+            TarCreate.convert_batch(&[(b"", &bad)]),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "tar", feature = "yaml", feature = "json"))]
+    fn test_tar_extract_pipeline_fans_out() {
+        use crate::TarCreate;
+        use paraphase_core::{ExecutionContext, Executor, Plan, PlanStep, SimpleExecutor};
+        use std::sync::Arc;
+
+        let files = vec![
+            (
+                b"name: first\ncount: 1\n".to_vec(),
+                Properties::new()
+                    .with("path", "a.yaml")
+                    .with("format", "yaml"),
+            ),
+            (
+                b"name: second\ncount: 2\n".to_vec(),
+                Properties::new()
+                    .with("path", "nested/b.yaml")
+                    .with("format", "yaml"),
+            ),
+        ];
+        let inputs: Vec<(&[u8], &Properties)> =
+            files.iter().map(|(d, p)| (d.as_slice(), p)).collect();
+        let (archive_data, archive_props) = match TarCreate.convert_batch(&inputs).unwrap() {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+
+        let mut registry = Registry::new();
+        register_all(&mut registry);
+        let ctx = ExecutionContext::new(Arc::new(registry));
 
-        // Find and verify files
-        let file1 = extracted
-            .iter()
-            .find(|(_, p)| p.get("path").unwrap().as_str() == Some("dir/file1.txt"))
+        // tar -> extract -> json, each extracted file converted independently
+        let plan = Plan {
+            steps: vec![
+                PlanStep::new(
+                    "archive.tar-extract",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "raw"),
+                ),
+                PlanStep::new(
+                    "serde.yaml-to-json",
+                    "in",
+                    "out",
+                    Properties::new().with("format", "json"),
+                ),
+            ],
+            cost: 2.0,
+        };
+
+        let results = SimpleExecutor::new()
+            .execute_expanding(&ctx, &plan, archive_data, archive_props)
             .unwrap();
-        assert_eq!(file1.0, b"Hello from file 1");
+        assert_eq!(results.len(), 2);
 
-        let file2 = extracted
+        for (result, (expected_path, expected_name, expected_count)) in results
             .iter()
-            .find(|(_, p)| p.get("path").unwrap().as_str() == Some("file2.txt"))
-            .unwrap();
-        assert_eq!(file2.0, b"Content of file 2");
+            .zip([("a.yaml", "first", 1), ("nested/b.yaml", "second", 2)])
+        {
+            assert_eq!(result.props.get("format").unwrap().as_str(), Some("json"));
+            assert_eq!(
+                result.props.get("path").unwrap().as_str(),
+                Some(expected_path)
+            );
+            let value: serde_json::Value = serde_json::from_slice(&result.data).unwrap();
+            assert_eq!(value["name"], expected_name);
+            assert_eq!(value["count"], expected_count);
+        }
     }
 
     #[test]
@@ -2392,6 +10734,131 @@ mod tests {
         assert_eq!(file2.0, b"Content of file 2");
     }
 
+    #[test]
+    #[cfg(feature = "zip")]
+    fn test_zip_single_entry() {
+        use crate::{ZipCreate, ZipExtract};
+        use std::io::Cursor;
+
+        let files = [
+            (b"first".as_slice(), Properties::new().with("path", "a.txt")),
+            (
+                b"second".as_slice(),
+                Properties::new().with("path", "dir/b.txt"),
+            ),
+        ];
+        let inputs: Vec<(&[u8], &Properties)> = files.iter().map(|(d, p)| (*d, p)).collect();
+        let archive = match ZipCreate.convert_batch(&inputs).unwrap() {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+
+        let props = Properties::new()
+            .with("format", "zip")
+            .with("zip_entry", "dir/b.txt");
+        match ZipExtract.convert(&archive, &props).unwrap() {
+            ConvertOutput::Single(data, p) => {
+                assert_eq!(data, b"second");
+                assert_eq!(p.get("path").unwrap().as_str(), Some("dir/b.txt"));
+                assert_eq!(p.get("format").unwrap().as_str(), Some("raw"));
+                assert!(!p.contains_key("zip_entry"));
+            }
+            _ => panic!("Expected single"),
+        }
+
+        // Same from a seekable reader
+        let props = props.with("zip_entry", "a.txt");
+        match ZipExtract::with_streaming_seek(Cursor::new(&archive), &props).unwrap() {
+            ConvertOutput::Single(data, _) => assert_eq!(data, b"first"),
+            _ => panic!("Expected single"),
+        }
+
+        let missing = props.with("zip_entry", "nope.txt");
+        assert!(matches!(
+            ZipExtract.convert(&archive, &missing),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "zip")]
+    fn test_zip_compression_method() {
+        use crate::{ZipCreate, ZipExtract};
+
+        let text = "the quick brown fox jumps over the lazy dog\n".repeat(200);
+        let create = |props: Properties| {
+            let props = props.with("path", "fox.txt").with("format", "raw");
+            match ZipCreate.convert_batch(&[(text.as_bytes(), &props)]) {
+                Ok(ConvertOutput::Single(b, _)) => Ok(b),
+                Ok(_) => panic!("Expected single"),
+                Err(e) => Err(e),
+            }
+        };
+
+        let stored = create(Properties::new().with("zip_method", "store")).unwrap();
+        let deflated = create(Properties::new().with("zip_level", 9)).unwrap();
+        let zstd = create(
+            Properties::new()
+                .with("zip_method", "zstd")
+                .with("zip_level", 19),
+        )
+        .unwrap();
+        assert!(stored.len() > text.len());
+        assert!(deflated.len() < stored.len());
+        assert!(zstd.len() < stored.len());
+
+        for archive in [&stored, &deflated, &zstd] {
+            let props = Properties::new().with("format", "zip");
+            match ZipExtract.convert(archive, &props).unwrap() {
+                ConvertOutput::Multiple(files) => assert_eq!(files[0].0, text.as_bytes()),
+                _ => panic!("Expected multiple"),
+            }
+        }
+
+        assert!(matches!(
+            create(Properties::new().with("zip_method", "lzma")),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            create(Properties::new().with("zip_level", 12)),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "zip")]
+    fn test_zip_per_entry_method() {
+        use crate::ZipCreate;
+
+        let photo = Properties::new()
+            .with("path", "photo.jpg")
+            .with("zip_method", "store");
+        let notes = Properties::new()
+            .with("path", "notes.txt")
+            .with("zip_method", "deflate")
+            .with("zip_level", 9);
+        let archive = match ZipCreate
+            .convert_batch(&[
+                (b"\xff\xd8\xff\xe0", &photo),
+                (b"notes notes notes", &notes),
+            ])
+            .unwrap()
+        {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+
+        let mut zip = zip::ZipArchive::new(std::io::Cursor::new(archive)).unwrap();
+        assert_eq!(
+            zip.by_name("photo.jpg").unwrap().compression(),
+            zip::CompressionMethod::Stored
+        );
+        assert_eq!(
+            zip.by_name("notes.txt").unwrap().compression(),
+            zip::CompressionMethod::Deflated
+        );
+    }
+
     #[test]
     #[cfg(feature = "spreadsheet")]
     fn test_spreadsheet_invalid_input() {
@@ -2408,6 +10875,182 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(all(feature = "spreadsheet", feature = "zip"))]
+    fn test_spreadsheet_split_sheets() {
+        use crate::SpreadsheetToJson;
+        use std::io::Write;
+
+        // Minimal three-sheet XLSX with one numeric cell per sheet
+        let names = ["Alpha", "Beta", "Gamma"];
+        let mut buf = std::io::Cursor::new(Vec::new());
+        {
+            let mut zip = zip::ZipWriter::new(&mut buf);
+            let options = zip::write::SimpleFileOptions::default();
+            let mut add = |path: &str, content: String| {
+                zip.start_file(path, options).unwrap();
+                zip.write_all(content.as_bytes()).unwrap();
+            };
+
+            let overrides: String = (1..=names.len())
+                .map(|i| {
+                    format!(
+                        r#"<Override PartName="/xl/worksheets/sheet{i}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#
+                    )
+                })
+                .collect();
+            add(
+                "[Content_Types].xml",
+                format!(
+                    r#"<?xml version="1.0" encoding="UTF-8"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>{overrides}</Types>"#
+                ),
+            );
+            add(
+                "_rels/.rels",
+                r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#.to_string(),
+            );
+
+            let sheets: String = names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    format!(
+                        r#"<sheet name="{name}" sheetId="{id}" r:id="rId{id}"/>"#,
+                        id = i + 1
+                    )
+                })
+                .collect();
+            add(
+                "xl/workbook.xml",
+                format!(
+                    r#"<?xml version="1.0" encoding="UTF-8"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets>{sheets}</sheets></workbook>"#
+                ),
+            );
+
+            let rels: String = (1..=names.len())
+                .map(|i| {
+                    format!(
+                        r#"<Relationship Id="rId{i}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{i}.xml"/>"#
+                    )
+                })
+                .collect();
+            add(
+                "xl/_rels/workbook.xml.rels",
+                format!(
+                    r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{rels}</Relationships>"#
+                ),
+            );
+
+            for i in 1..=names.len() {
+                add(
+                    &format!("xl/worksheets/sheet{i}.xml"),
+                    format!(
+                        r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData><row r="1"><c r="A1"><v>{i}</v></c></row></sheetData></worksheet>"#
+                    ),
+                );
+            }
+            zip.finish().unwrap();
+        }
+        let xlsx = buf.into_inner();
+
+        let props = Properties::new()
+            .with("format", "xlsx")
+            .with("spreadsheet_split_sheets", true);
+        let outputs = match SpreadsheetToJson.convert(&xlsx, &props).unwrap() {
+            ConvertOutput::Multiple(outputs) => outputs,
+            _ => panic!("Expected multiple outputs"),
+        };
+
+        assert_eq!(outputs.len(), 3);
+        for (i, (data, out_props)) in outputs.iter().enumerate() {
+            assert_eq!(out_props.get("format").unwrap().as_str(), Some("json"));
+            assert_eq!(
+                out_props.get("sheet_name").unwrap().as_str(),
+                Some(names[i])
+            );
+            assert_eq!(
+                out_props.get("sheet_index").unwrap().as_i64(),
+                Some(i as i64)
+            );
+
+            let rows: serde_json::Value = serde_json::from_slice(data).unwrap();
+            assert_eq!(rows[0][0].as_f64(), Some((i + 1) as f64));
+        }
+
+        // Default keeps everything in one document
+        let props = Properties::new().with("format", "xlsx");
+        match SpreadsheetToJson.convert(&xlsx, &props).unwrap() {
+            ConvertOutput::Single(data, _) => {
+                let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+                assert_eq!(value["sheets"].as_object().unwrap().len(), 3);
+            }
+            _ => panic!("Expected single output"),
+        }
+
+        // Typed props, as from `--from-props sheet=Beta headers=true`
+        let props = Properties::new()
+            .with("format", "xlsx")
+            .with("sheet", "Beta")
+            .with("headers", true);
+        match SpreadsheetToJson.convert(&xlsx, &props).unwrap() {
+            ConvertOutput::Single(data, _) => {
+                let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+                // The only row became the header row
+                assert_eq!(value, serde_json::json!({ "sheets": { "Beta": [] } }));
+            }
+            _ => panic!("Expected single output"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "spreadsheet")]
+    fn test_json_to_xlsx_roundtrip() {
+        let json = serde_json::json!({
+            "sheets": {
+                "People": [
+                    {"name": "Ada", "age": 36},
+                    {"name": "Grace", "age": 85},
+                ]
+            }
+        });
+        let props = Properties::new()
+            .with("format", "json")
+            .with("xlsx_bold_headers", true)
+            .with("xlsx_freeze_header_row", true)
+            .with("xlsx_number_format", "0.00");
+
+        let (xlsx, out_props) =
+            match JsonToXlsx.convert(&serde_json::to_vec(&json).unwrap(), &props) {
+                Ok(ConvertOutput::Single(data, props)) => (data, props),
+                _ => panic!("Expected single output"),
+            };
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("xlsx"));
+        assert!(out_props.get("xlsx_number_format").is_none());
+
+        let read_props = Properties::new()
+            .with("format", "xlsx")
+            .with("headers", true);
+        let data = match SpreadsheetToJson.convert(&xlsx, &read_props).unwrap() {
+            ConvertOutput::Single(data, _) => data,
+            _ => panic!("Expected single output"),
+        };
+        let result: serde_json::Value = serde_json::from_slice(&data).unwrap();
+        let rows = result["sheets"]["People"].as_array().unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0]["name"], "Ada");
+        assert_eq!(rows[0]["age"].as_f64(), Some(36.0));
+        assert_eq!(rows[1]["name"], "Grace");
+        assert_eq!(rows[1]["age"].as_f64(), Some(85.0));
+
+        let bad = Properties::new()
+            .with("format", "json")
+            .with("xlsx_bold_headers", "yes");
+        assert!(matches!(
+            JsonToXlsx.convert(b"{}", &bad),
+            Err(ConvertError::InvalidProperties(_))
+        ));
+    }
+
     #[test]
     #[cfg(feature = "parquet")]
     fn test_parquet_roundtrip() {
@@ -2459,6 +11102,173 @@ mod tests {
         assert_eq!(arr[1]["age"], 25);
     }
 
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_parquet_schema_to_json() {
+        use crate::ParquetSchemaToJson;
+        use arrow::array::{Int32Array, ListArray, StringArray};
+        use arrow::datatypes::{DataType, Field, Int32Type, Schema};
+        use arrow::record_batch::RecordBatch;
+        use parquet::arrow::ArrowWriter;
+        use std::sync::Arc;
+
+        // The roundtrip test's schema plus a nullable list column
+        let item = Arc::new(Field::new("item", DataType::Int32, true));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("name", DataType::Utf8, false),
+            Field::new("age", DataType::Int32, false),
+            Field::new("scores", DataType::List(item), true),
+        ]));
+
+        let scores =
+            ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(9)]), None]);
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(StringArray::from(vec!["Alice", "Bob"])),
+                Arc::new(Int32Array::from(vec![30, 25])),
+                Arc::new(scores),
+            ],
+        )
+        .unwrap();
+
+        let mut parquet_buffer = Vec::new();
+        {
+            let mut writer = ArrowWriter::try_new(&mut parquet_buffer, schema, None).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+        }
+
+        let props = Properties::new().with("format", "parquet");
+        let output = match ParquetSchemaToJson
+            .convert(&parquet_buffer, &props)
+            .unwrap()
+        {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+        let schema: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+        assert_eq!(schema["$schema"], "http://json-schema.org/draft-07/schema#");
+        assert_eq!(schema["type"], "array");
+        let record = &schema["items"];
+        assert_eq!(record["type"], "object");
+        assert_eq!(
+            record["required"],
+            serde_json::json!(["name", "age", "scores"])
+        );
+        assert_eq!(
+            record["properties"],
+            serde_json::json!({
+                "name": { "type": "string" },
+                "age": { "type": "integer" },
+                "scores": {
+                    "anyOf": [
+                        {
+                            "type": "array",
+                            "items": { "anyOf": [{ "type": "integer" }, { "type": "null" }] }
+                        },
+                        { "type": "null" }
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_parquet_filter() {
+        use crate::ParquetToJson;
+        use arrow::array::{Int32Array, Int64Array, StringArray};
+        use arrow::datatypes::{DataType, Field, Schema};
+        use arrow::record_batch::RecordBatch;
+        use parquet::arrow::ArrowWriter;
+        use parquet::file::properties::WriterProperties;
+        use std::sync::Arc;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("age", DataType::Int32, false),
+            Field::new("status", DataType::Utf8, false),
+        ]));
+
+        // 1000 rows; only ids ending in 99 are active (and all of those are 49)
+        let ids: Vec<i64> = (0..1000).collect();
+        let ages: Vec<i32> = ids.iter().map(|i| (i % 50) as i32).collect();
+        let statuses: Vec<&str> = ids
+            .iter()
+            .map(|i| if i % 100 == 99 { "active" } else { "inactive" })
+            .collect();
+
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(ids)),
+                Arc::new(Int32Array::from(ages)),
+                Arc::new(StringArray::from(statuses)),
+            ],
+        )
+        .unwrap();
+
+        let mut parquet_buffer = Vec::new();
+        {
+            let write_props = WriterProperties::builder()
+                .set_max_row_group_size(100)
+                .build();
+            let mut writer =
+                ArrowWriter::try_new(&mut parquet_buffer, schema, Some(write_props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+        }
+
+        let props = Properties::new()
+            .with("format", "parquet")
+            .with("parquet_filter", "age > 18 AND status = 'active'");
+        let output = match ParquetToJson.convert(&parquet_buffer, &props).unwrap() {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        let arr = json.as_array().unwrap();
+        assert_eq!(arr.len(), 10);
+        assert!(arr.iter().all(|r| r["status"] == "active"));
+
+        // Ids are sorted, so statistics rule out all but the last row group
+        let props = Properties::new()
+            .with("format", "parquet")
+            .with("parquet_filter", "id >= 950 OR id IS NULL");
+        let output = match ParquetToJson.convert(&parquet_buffer, &props).unwrap() {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 50);
+
+        // Unparseable predicates are rejected
+        let props = Properties::new()
+            .with("format", "parquet")
+            .with("parquet_filter", "age >> 18");
+        assert!(matches!(
+            ParquetToJson.convert(&parquet_buffer, &props),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+
+        // Integer columns take integral floats but reject fractional ones
+        let filtered = |filter: &str| {
+            let props = Properties::new()
+                .with("format", "parquet")
+                .with("parquet_filter", filter);
+            ParquetToJson.convert(&parquet_buffer, &props)
+        };
+        assert!(filtered("age = 49.0").is_ok());
+        match filtered("age = 1.9") {
+            Err(err @ ConvertError::InvalidInput { .. }) => {
+                assert!(err.to_string().contains("non-integral 1.9"), "{}", err)
+            }
+            _ => panic!("Expected InvalidInput"),
+        }
+    }
+
     #[test]
     #[cfg(feature = "avro")]
     fn test_avro_roundtrip() {
@@ -2512,4 +11322,224 @@ mod tests {
         assert_eq!(arr[1]["name"], "Bob");
         assert_eq!(arr[1]["age"], 25);
     }
+
+    #[test]
+    #[cfg(feature = "avro")]
+    fn test_avro_reader_schema() {
+        use crate::AvroToJson;
+        use apache_avro::{Schema, Writer, types::Record};
+
+        let writer_schema = Schema::parse_str(
+            r#"{"type": "record", "name": "user", "fields": [
+                {"name": "name", "type": "string"},
+                {"name": "age", "type": "int"}
+            ]}"#,
+        )
+        .unwrap();
+        let mut writer = Writer::new(&writer_schema, Vec::new());
+        let mut record = Record::new(&writer_schema).unwrap();
+        record.put("name", "Alice");
+        record.put("age", 30i32);
+        writer.append(record).unwrap();
+        let avro_data = writer.into_inner().unwrap();
+
+        // Evolved schema: age widened to long, email added with a default
+        let reader_schema = r#"{"type": "record", "name": "user", "fields": [
+            {"name": "name", "type": "string"},
+            {"name": "age", "type": "long"},
+            {"name": "email", "type": "string", "default": ""}
+        ]}"#;
+        let props = Properties::new()
+            .with("format", "avro")
+            .with("avro_reader_schema", reader_schema);
+        let output = match AvroToJson.convert(&avro_data, &props).unwrap() {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{"name": "Alice", "age": 30, "email": ""}])
+        );
+
+        // A new field without a default can't be filled in
+        let incompatible = r#"{"type": "record", "name": "user", "fields": [
+            {"name": "name", "type": "string"},
+            {"name": "email", "type": "string"}
+        ]}"#;
+        let props = props.with("avro_reader_schema", incompatible);
+        assert!(matches!(
+            AvroToJson.convert(&avro_data, &props),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "avro")]
+    fn test_json_to_avro_codec() {
+        use crate::{AvroToJson, JsonToAvro};
+
+        let schema = r#"{"type": "record", "name": "event", "fields": [
+            {"name": "kind", "type": "string"},
+            {"name": "count", "type": "long"}
+        ]}"#;
+        let records: Vec<_> = (0..500)
+            .map(|i| serde_json::json!({"kind": "page_view", "count": i % 4}))
+            .collect();
+        let input = serde_json::to_vec(&records).unwrap();
+
+        let write = |codec: &str| {
+            let props = Properties::new()
+                .with("format", "json")
+                .with("avro_schema", schema)
+                .with("avro_codec", codec);
+            match JsonToAvro.convert(&input, &props).unwrap() {
+                ConvertOutput::Single(b, p) => {
+                    assert!(p.get("avro_codec").is_none());
+                    b
+                }
+                _ => panic!("Expected single"),
+            }
+        };
+        let plain = write("null");
+        let deflated = write("deflate");
+        assert!(deflated.len() < plain.len());
+
+        let props = Properties::new().with("format", "avro");
+        let output = match AvroToJson.convert(&deflated, &props).unwrap() {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(json, serde_json::Value::Array(records));
+
+        let props = Properties::new()
+            .with("format", "json")
+            .with("avro_schema", schema)
+            .with("avro_codec", "lzma4");
+        assert!(matches!(
+            JsonToAvro.convert(&input, &props),
+            Err(ConvertError::InvalidProperties(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "avro")]
+    fn test_json_to_avro_roundtrip() {
+        use crate::{AvroToJson, JsonToAvro};
+
+        let schema = r#"
+        {
+            "type": "record",
+            "name": "person",
+            "fields": [
+                {"name": "name", "type": "string"},
+                {"name": "age", "type": "int"},
+                {"name": "score", "type": "double"},
+                {"name": "nickname", "type": ["null", "string"]},
+                {"name": "tags", "type": {"type": "array", "items": "string"}},
+                {"name": "address", "type": {
+                    "type": "record",
+                    "name": "address",
+                    "fields": [
+                        {"name": "city", "type": "string"},
+                        {"name": "zip", "type": "long"}
+                    ]
+                }}
+            ]
+        }
+        "#;
+
+        let original = serde_json::json!([
+            {
+                "name": "Alice",
+                "age": 30,
+                "score": 91.5,
+                "nickname": null,
+                "tags": ["admin", "ops"],
+                "address": {"city": "Berlin", "zip": 10115}
+            },
+            {
+                "name": "Bob",
+                "age": 25,
+                "score": 78.25,
+                "nickname": "bobby",
+                "tags": [],
+                "address": {"city": "Paris", "zip": 75001}
+            }
+        ]);
+        let input = serde_json::to_vec(&original).unwrap();
+
+        let props = Properties::new()
+            .with("format", "json")
+            .with("avro_schema", schema);
+        let (avro_data, avro_props) = match JsonToAvro.convert(&input, &props).unwrap() {
+            ConvertOutput::Single(b, p) => (b, p),
+            _ => panic!("Expected single"),
+        };
+        assert_eq!(avro_props.get("format").unwrap().as_str(), Some("avro"));
+
+        let output = match AvroToJson.convert(&avro_data, &avro_props).unwrap() {
+            ConvertOutput::Single(b, _) => b,
+            _ => panic!("Expected single"),
+        };
+        let roundtripped: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(roundtripped, original);
+
+        // Records that don't fit the schema are rejected
+        let bad = br#"[{"name": "Carol", "age": "old"}]"#;
+        assert!(matches!(
+            JsonToAvro.convert(bad, &props),
+            Err(ConvertError::InvalidInput { .. })
+        ));
+
+        // Schema is required
+        let props = Properties::new().with("format", "json");
+        assert!(matches!(
+            JsonToAvro.convert(&input, &props),
+            Err(ConvertError::MissingProperty(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "avro")]
+    fn test_json_to_avro_plan() {
+        use paraphase_core::{Cardinality, Planner};
+
+        let mut registry = Registry::new();
+        register_all(&mut registry);
+        let planner = Planner::new(&registry);
+        let target = PropertyPattern::new().eq("format", "avro");
+        let plan = |props: &Properties| {
+            planner
+                .plan(props, &target, Cardinality::One, Cardinality::One)
+                .map(|plan| plan.steps.last().unwrap().converter_id.clone())
+        };
+
+        // Without a schema there is no route to Avro
+        let json = Properties::new().with("format", "json");
+        assert_eq!(plan(&json), None);
+        assert_eq!(
+            plan(&json.clone().with("avro_schema", "{}")).as_deref(),
+            Some("json-to-avro")
+        );
+        assert_eq!(
+            plan(&json.with("avro_schema_file", "schema.avsc")).as_deref(),
+            Some("json-to-avro-file")
+        );
+
+        let schema =
+            r#"{"type": "record", "name": "row", "fields": [{"name": "n", "type": "long"}]}"#;
+        let path = std::env::temp_dir().join(format!("paraphase-avsc-{}", std::process::id()));
+        std::fs::write(&path, schema).unwrap();
+        let props = Properties::new()
+            .with("format", "json")
+            .with("avro_schema_file", path.to_str().unwrap());
+        let result = crate::JsonToAvroFile.convert(br#"[{"n": 1}]"#, &props);
+        std::fs::remove_file(&path).unwrap();
+        match result.unwrap() {
+            ConvertOutput::Single(_, p) => assert!(p.get("avro_schema_file").is_none()),
+            _ => panic!("Expected single"),
+        }
+    }
 }
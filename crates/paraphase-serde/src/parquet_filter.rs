@@ -0,0 +1,531 @@
+//! Row filter expressions for Parquet reads.
+//!
+//! Parses simple predicates like `age > 18 AND status = 'active'` into an
+//! AST that can be evaluated against Arrow record batches (for row-level
+//! filtering) and against row group statistics (to skip row groups that
+//! cannot contain a match).
+//!
+//! Supported syntax:
+//! - comparisons: `=`, `!=` (or `<>`), `<`, `>`, `<=`, `>=`
+//! - null checks: `IS NULL`, `IS NOT NULL`
+//! - boolean connectives: `AND`, `OR`, parentheses
+//! - literals: integers, floats, `'single-quoted strings'`, `TRUE`, `FALSE`
+
+use arrow::array::{Array, ArrayRef, BooleanArray, Float64Array, Int64Array, Scalar, StringArray};
+use arrow::compute::kernels::cmp;
+use arrow::datatypes::Schema;
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use parquet::file::metadata::RowGroupMetaData;
+use parquet::file::statistics::Statistics;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Literal value on the right-hand side of a comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Int(i64),
+    Float(f64),
+    String(String),
+    Bool(bool),
+}
+
+/// Filter expression AST.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare {
+        column: String,
+        op: CmpOp,
+        value: Literal,
+    },
+    IsNull(String),
+    IsNotNull(String),
+}
+
+impl Expr {
+    /// Parse a filter expression.
+    pub fn parse(input: &str) -> Result<Expr, String> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(tok) => Err(format!("unexpected token {:?}", tok)),
+        }
+    }
+
+    /// Column names referenced by this expression, in first-use order.
+    pub fn columns(&self) -> Vec<&str> {
+        let mut out = Vec::new();
+        self.collect_columns(&mut out);
+        out
+    }
+
+    fn collect_columns<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Expr::And(l, r) | Expr::Or(l, r) => {
+                l.collect_columns(out);
+                r.collect_columns(out);
+            }
+            Expr::Compare { column, .. } | Expr::IsNull(column) | Expr::IsNotNull(column) => {
+                if !out.contains(&column.as_str()) {
+                    out.push(column);
+                }
+            }
+        }
+    }
+
+    /// Check literals against the column types they are compared with.
+    ///
+    /// Literals are cast to the column's type, so a non-integral float
+    /// against an integer column would silently truncate (`1.9` to `1`).
+    pub fn check_types(&self, schema: &Schema) -> Result<(), String> {
+        match self {
+            Expr::And(l, r) | Expr::Or(l, r) => {
+                l.check_types(schema)?;
+                r.check_types(schema)
+            }
+            Expr::Compare {
+                column,
+                value: Literal::Float(f),
+                ..
+            } if f.fract() != 0.0 => match schema.field_with_name(column) {
+                Ok(field) if field.data_type().is_integer() => Err(format!(
+                    "cannot compare integer column '{}' with non-integral {}",
+                    column, f
+                )),
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Evaluate against a batch, returning which rows match.
+    ///
+    /// Null comparison results are left null; the Parquet reader treats
+    /// them as non-matching.
+    pub fn evaluate(&self, batch: &RecordBatch) -> Result<BooleanArray, ArrowError> {
+        match self {
+            Expr::And(l, r) => arrow::compute::and_kleene(&l.evaluate(batch)?, &r.evaluate(batch)?),
+            Expr::Or(l, r) => arrow::compute::or_kleene(&l.evaluate(batch)?, &r.evaluate(batch)?),
+            Expr::IsNull(column) => arrow::compute::is_null(column_of(batch, column)?),
+            Expr::IsNotNull(column) => arrow::compute::is_not_null(column_of(batch, column)?),
+            Expr::Compare { column, op, value } => {
+                let array = column_of(batch, column)?;
+                let literal = arrow::compute::cast(&literal_array(value), array.data_type())?;
+                let scalar = Scalar::new(literal);
+                match op {
+                    CmpOp::Eq => cmp::eq(array, &scalar),
+                    CmpOp::Ne => cmp::neq(array, &scalar),
+                    CmpOp::Lt => cmp::lt(array, &scalar),
+                    CmpOp::Le => cmp::lt_eq(array, &scalar),
+                    CmpOp::Gt => cmp::gt(array, &scalar),
+                    CmpOp::Ge => cmp::gt_eq(array, &scalar),
+                }
+            }
+        }
+    }
+
+    /// Whether a row group could contain matching rows, judging by its
+    /// column statistics. Returns `true` whenever statistics are missing
+    /// or not comparable, so it never drops a row group that might match.
+    pub fn may_match(&self, row_group: &RowGroupMetaData) -> bool {
+        match self {
+            Expr::And(l, r) => l.may_match(row_group) && r.may_match(row_group),
+            Expr::Or(l, r) => l.may_match(row_group) || r.may_match(row_group),
+            Expr::IsNull(column) => match column_stats(row_group, column) {
+                Some(stats) => stats.null_count_opt() != Some(0),
+                None => true,
+            },
+            Expr::IsNotNull(column) => match column_stats(row_group, column) {
+                Some(stats) => stats
+                    .null_count_opt()
+                    .is_none_or(|nulls| (nulls as i64) < row_group.num_rows()),
+                None => true,
+            },
+            Expr::Compare { column, op, value } => {
+                let Some(stats) = column_stats(row_group, column) else {
+                    return true;
+                };
+                let Some((min, max)) = stats_bounds(stats, value) else {
+                    return true;
+                };
+                match op {
+                    CmpOp::Eq => min != Ordering::Greater && max != Ordering::Less,
+                    CmpOp::Ne => !(min == Ordering::Equal && max == Ordering::Equal),
+                    CmpOp::Lt => min == Ordering::Less,
+                    CmpOp::Le => min != Ordering::Greater,
+                    CmpOp::Gt => max == Ordering::Greater,
+                    CmpOp::Ge => max != Ordering::Less,
+                }
+            }
+        }
+    }
+}
+
+fn column_of<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a ArrayRef, ArrowError> {
+    batch
+        .column_by_name(name)
+        .ok_or_else(|| ArrowError::SchemaError(format!("Unknown column: {}", name)))
+}
+
+fn literal_array(value: &Literal) -> ArrayRef {
+    match value {
+        Literal::Int(i) => Arc::new(Int64Array::from(vec![*i])),
+        Literal::Float(f) => Arc::new(Float64Array::from(vec![*f])),
+        Literal::String(s) => Arc::new(StringArray::from(vec![s.as_str()])),
+        Literal::Bool(b) => Arc::new(BooleanArray::from(vec![*b])),
+    }
+}
+
+fn column_stats<'a>(row_group: &'a RowGroupMetaData, name: &str) -> Option<&'a Statistics> {
+    row_group
+        .columns()
+        .iter()
+        .find(|c| c.column_descr().name() == name)
+        .and_then(|c| c.statistics())
+}
+
+/// Compare a row group's min and max against a literal.
+///
+/// Returns `(min.cmp(literal), max.cmp(literal))`, or `None` if the
+/// statistics are absent or of a type the literal can't be compared with.
+fn stats_bounds(stats: &Statistics, value: &Literal) -> Option<(Ordering, Ordering)> {
+    fn numeric(min: Option<f64>, max: Option<f64>, v: f64) -> Option<(Ordering, Ordering)> {
+        Some((min?.partial_cmp(&v)?, max?.partial_cmp(&v)?))
+    }
+
+    let literal = match value {
+        Literal::Int(i) => Some(*i as f64),
+        Literal::Float(f) => Some(*f),
+        _ => None,
+    };
+
+    match (stats, value) {
+        (Statistics::Int32(s), _) => numeric(
+            s.min_opt().map(|v| *v as f64),
+            s.max_opt().map(|v| *v as f64),
+            literal?,
+        ),
+        (Statistics::Int64(s), _) => numeric(
+            s.min_opt().map(|v| *v as f64),
+            s.max_opt().map(|v| *v as f64),
+            literal?,
+        ),
+        (Statistics::Float(s), _) => numeric(
+            s.min_opt().map(|v| *v as f64),
+            s.max_opt().map(|v| *v as f64),
+            literal?,
+        ),
+        (Statistics::Double(s), _) => numeric(s.min_opt().copied(), s.max_opt().copied(), literal?),
+        (Statistics::ByteArray(s), Literal::String(v)) => Some((
+            s.min_opt()?.data().cmp(v.as_bytes()),
+            s.max_opt()?.data().cmp(v.as_bytes()),
+        )),
+        _ => None,
+    }
+}
+
+// ============================================
+// Tokenizer / parser
+// ============================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Op(CmpOp),
+    And,
+    Or,
+    Is,
+    Not,
+    Null,
+    True,
+    False,
+    LParen,
+    RParen,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '=' => {
+                tokens.push(Token::Op(CmpOp::Eq));
+                i += 1;
+            }
+            '!' | '<' | '>' => {
+                let next = chars.get(i + 1).copied();
+                let (op, len) = match (c, next) {
+                    ('!', Some('=')) | ('<', Some('>')) => (CmpOp::Ne, 2),
+                    ('<', Some('=')) => (CmpOp::Le, 2),
+                    ('>', Some('=')) => (CmpOp::Ge, 2),
+                    ('<', _) => (CmpOp::Lt, 1),
+                    ('>', _) => (CmpOp::Gt, 1),
+                    _ => return Err(format!("unexpected character '{}' at {}", c, i)),
+                };
+                tokens.push(Token::Op(op));
+                i += len;
+            }
+            '\'' => {
+                // Single-quoted string; '' escapes a quote
+                let mut s = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err("unterminated string literal".into()),
+                        Some('\'') if chars.get(i + 1) == Some(&'\'') => {
+                            s.push('\'');
+                            i += 2;
+                        }
+                        Some('\'') => {
+                            i += 1;
+                            break;
+                        }
+                        Some(&ch) => {
+                            s.push(ch);
+                            i += 1;
+                        }
+                    }
+                }
+                tokens.push(Token::Str(s));
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let token = if text.contains('.') {
+                    text.parse().map(Token::Float).ok()
+                } else {
+                    text.parse().map(Token::Int).ok()
+                };
+                tokens.push(token.ok_or_else(|| format!("invalid number '{}'", text))?);
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                tokens.push(match word.to_ascii_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "IS" => Token::Is,
+                    "NOT" => Token::Not,
+                    "NULL" => Token::Null,
+                    "TRUE" => Token::True,
+                    "FALSE" => Token::False,
+                    _ => Token::Ident(word),
+                });
+            }
+            _ => return Err(format!("unexpected character '{}' at {}", c, i)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        tok
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            let rhs = self.parse_and()?;
+            lhs = Expr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.parse_primary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            let rhs = self.parse_primary()?;
+            lhs = Expr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::LParen) => {
+                let expr = self.parse_or()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(expr),
+                    _ => Err("expected ')'".into()),
+                }
+            }
+            Some(Token::Ident(column)) => match self.next() {
+                Some(Token::Is) => match self.next() {
+                    Some(Token::Null) => Ok(Expr::IsNull(column)),
+                    Some(Token::Not) if self.next() == Some(Token::Null) => {
+                        Ok(Expr::IsNotNull(column))
+                    }
+                    _ => Err(format!("expected NULL or NOT NULL after '{} IS'", column)),
+                },
+                Some(Token::Op(op)) => {
+                    let value = match self.next() {
+                        Some(Token::Int(i)) => Literal::Int(i),
+                        Some(Token::Float(f)) => Literal::Float(f),
+                        Some(Token::Str(s)) => Literal::String(s),
+                        Some(Token::True) => Literal::Bool(true),
+                        Some(Token::False) => Literal::Bool(false),
+                        other => return Err(format!("expected literal, found {:?}", other)),
+                    };
+                    Ok(Expr::Compare { column, op, value })
+                }
+                other => Err(format!(
+                    "expected operator after '{}', found {:?}",
+                    column, other
+                )),
+            },
+            other => Err(format!("expected column or '(', found {:?}", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmp(column: &str, op: CmpOp, value: Literal) -> Expr {
+        Expr::Compare {
+            column: column.into(),
+            op,
+            value,
+        }
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        // AND binds tighter than OR
+        let expr = Expr::parse("a = 1 OR b > 2.5 AND c != 'x'").unwrap();
+        assert_eq!(
+            expr,
+            Expr::Or(
+                Box::new(cmp("a", CmpOp::Eq, Literal::Int(1))),
+                Box::new(Expr::And(
+                    Box::new(cmp("b", CmpOp::Gt, Literal::Float(2.5))),
+                    Box::new(cmp("c", CmpOp::Ne, Literal::String("x".into()))),
+                )),
+            )
+        );
+        assert_eq!(expr.columns(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_parse_null_checks_and_parens() {
+        let expr = Expr::parse("(name IS NULL or name is not null) AND ok = TRUE").unwrap();
+        assert_eq!(
+            expr,
+            Expr::And(
+                Box::new(Expr::Or(
+                    Box::new(Expr::IsNull("name".into())),
+                    Box::new(Expr::IsNotNull("name".into())),
+                )),
+                Box::new(cmp("ok", CmpOp::Eq, Literal::Bool(true))),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Expr::parse("").is_err());
+        assert!(Expr::parse("age >").is_err());
+        assert!(Expr::parse("age > 18 AND").is_err());
+        assert!(Expr::parse("name = 'open").is_err());
+        assert!(Expr::parse("(a = 1").is_err());
+        assert!(Expr::parse("a = 1 b = 2").is_err());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        use arrow::datatypes::{DataType, Field, Schema};
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("age", DataType::Int32, true),
+            Field::new("status", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(arrow::array::Int32Array::from(vec![
+                    Some(10),
+                    Some(20),
+                    None,
+                    Some(30),
+                ])),
+                Arc::new(StringArray::from(vec![
+                    "active", "active", "active", "gone",
+                ])),
+            ],
+        )
+        .unwrap();
+
+        let expr = Expr::parse("age > 18 AND status = 'active'").unwrap();
+        let mask = expr.evaluate(&batch).unwrap();
+        let selected: Vec<bool> = (0..mask.len())
+            .map(|i| mask.is_valid(i) && mask.value(i))
+            .collect();
+        assert_eq!(selected, vec![false, true, false, false]);
+
+        let mask = Expr::parse("age IS NULL")
+            .unwrap()
+            .evaluate(&batch)
+            .unwrap();
+        assert_eq!(mask, BooleanArray::from(vec![false, false, true, false]));
+
+        assert!(
+            Expr::parse("missing = 1")
+                .unwrap()
+                .evaluate(&batch)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Property-based round-trip tests for the serde format matrix.
+//!
+//! For every enabled format, arbitrary JSON values are converted
+//! JSON → format → JSON with [`SerdeConverter`] and compared with the
+//! original. Each format declares what it can represent in a [`Caps`]; the
+//! generator only produces values within those limits and the comparison
+//! accounts for the remaining, known lossiness.
+//!
+//! Bincode and Postcard aren't self-describing, so they can't be read back
+//! into an untyped value and are not covered here.
+//!
+//! Failing cases are persisted next to this file in
+//! `roundtrip_fuzz.proptest-regressions` and replayed first on later runs;
+//! commit that file along with the fix.
+#![cfg(feature = "json")]
+
+use paraphase_core::{ConvertOutput, Converter, Properties, PropertiesExt};
+use paraphase_serde::SerdeConverter;
+use proptest::prelude::*;
+use proptest::test_runner::FileFailurePersistence;
+use serde_json::{Number, Value};
+
+/// How floats survive a round trip.
+#[derive(Clone, Copy, PartialEq)]
+enum Floats {
+    /// Bit-exact.
+    Exact,
+    /// Within a relative epsilon (decimal formatting).
+    Approx,
+    /// Not representable; none are generated.
+    None,
+}
+
+/// What a format can represent.
+#[derive(Clone, Copy)]
+struct Caps {
+    /// `null` values (TOML, plist and XML have none).
+    null: bool,
+    /// Booleans.
+    bool: bool,
+    floats: Floats,
+    /// Integers above `i64::MAX`.
+    big_uint: bool,
+    /// Empty arrays and objects.
+    empty: bool,
+    /// Arrays directly inside arrays.
+    nested_arrays: bool,
+    /// Scalars read back as strings (XML, query strings).
+    stringly: bool,
+    /// Single-item arrays read back as the item (XML repeated elements).
+    collapse_single: bool,
+    /// Objects and arrays below the root.
+    nested: bool,
+}
+
+const FULL: Caps = Caps {
+    null: true,
+    bool: true,
+    floats: Floats::Exact,
+    big_uint: true,
+    empty: true,
+    nested_arrays: true,
+    stringly: false,
+    collapse_single: false,
+    nested: true,
+};
+
+fn arb_key() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9_]{0,7}"
+}
+
+fn arb_leaf(caps: Caps) -> BoxedStrategy<Value> {
+    let mut leaves: Vec<BoxedStrategy<Value>> = vec![
+        any::<i64>().prop_map(Value::from).boxed(),
+        if caps.stringly {
+            // Whitespace at the edges isn't significant in XML text
+            "([a-zA-Z0-9_.-]([a-zA-Z0-9 _.-]{0,10}[a-zA-Z0-9_.-])?)?"
+                .prop_map(Value::from)
+                .boxed()
+        } else {
+            "\\PC{0,12}".prop_map(Value::from).boxed()
+        },
+    ];
+    if caps.null {
+        leaves.push(Just(Value::Null).boxed());
+    }
+    if caps.bool {
+        leaves.push(any::<bool>().prop_map(Value::from).boxed());
+    }
+    if caps.floats != Floats::None {
+        leaves.push(
+            any::<f64>()
+                .prop_filter("finite", |f| f.is_finite())
+                .prop_map(Value::from)
+                .boxed(),
+        );
+    }
+    if caps.big_uint {
+        leaves.push(
+            ((i64::MAX as u64 + 1)..=u64::MAX)
+                .prop_map(Value::from)
+                .boxed(),
+        );
+    }
+    proptest::strategy::Union::new(leaves).boxed()
+}
+
+fn arb_value(caps: Caps) -> BoxedStrategy<Value> {
+    let min = if caps.empty { 0 } else { 1 };
+    if !caps.nested {
+        return arb_leaf(caps);
+    }
+    arb_leaf(caps)
+        .prop_recursive(3, 32, 4, move |inner| {
+            let array_item = if caps.nested_arrays {
+                inner.clone()
+            } else {
+                inner
+                    .clone()
+                    .prop_filter("no nested arrays", |v| !v.is_array())
+                    .boxed()
+            };
+            prop_oneof![
+                proptest::collection::vec(array_item, min..4).prop_map(Value::Array),
+                proptest::collection::btree_map(arb_key(), inner, min..4)
+                    .prop_map(|m| Value::Object(m.into_iter().collect())),
+            ]
+        })
+        .boxed()
+}
+
+/// Documents are objects: most formats require a table/map at the root.
+fn arb_document(caps: Caps) -> impl Strategy<Value = Value> {
+    let min = if caps.empty { 0 } else { 1 };
+    proptest::collection::btree_map(arb_key(), arb_value(caps), min..5)
+        .prop_map(|m| Value::Object(m.into_iter().collect()))
+}
+
+/// Apply the format's known lossiness to the original value.
+fn expected(value: &Value, caps: Caps) -> Value {
+    match value {
+        Value::Array(items) if caps.collapse_single && items.len() == 1 => {
+            expected(&items[0], caps)
+        }
+        Value::Array(items) => Value::Array(items.iter().map(|v| expected(v, caps)).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), expected(v, caps)))
+                .collect(),
+        ),
+        Value::String(_) => value.clone(),
+        scalar if caps.stringly => Value::String(scalar.to_string()),
+        scalar => scalar.clone(),
+    }
+}
+
+/// Compare values, allowing float imprecision where the format has it.
+fn equivalent(actual: &Value, expected: &Value, caps: Caps) -> bool {
+    match (actual, expected) {
+        (Value::Number(a), Value::Number(b)) => numbers_equivalent(a, b, caps),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| equivalent(a, b, caps))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && b.iter()
+                    .all(|(k, b)| a.get(k).is_some_and(|a| equivalent(a, b, caps)))
+        }
+        // Stringly formats spell numbers their own way ("1e100" vs "1e+100")
+        (Value::String(a), Value::String(b)) if caps.stringly && a != b => {
+            matches!((a.parse::<f64>(), b.parse::<f64>()), (Ok(x), Ok(y)) if x == y)
+        }
+        _ => actual == expected,
+    }
+}
+
+fn numbers_equivalent(a: &Number, b: &Number, caps: Caps) -> bool {
+    if a == b {
+        return true;
+    }
+    // Integral floats may come back as integers and vice versa
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) if b.is_f64() || a.is_f64() => match caps.floats {
+            Floats::Approx => x == y || ((x - y).abs() / x.abs().max(y.abs())) < 1e-12,
+            _ => x == y,
+        },
+        _ => false,
+    }
+}
+
+fn convert(from: &'static str, to: &'static str, data: &[u8]) -> Result<Vec<u8>, String> {
+    let props = Properties::new().with("format", from);
+    match SerdeConverter::new(from, to).convert(data, &props) {
+        Ok(ConvertOutput::Single(output, _)) => Ok(output),
+        Ok(_) => Err("expected single output".into()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn check_roundtrip(format: &'static str, caps: Caps, value: &Value) -> Result<(), TestCaseError> {
+    let json = serde_json::to_vec(value).unwrap();
+    let encoded = convert("json", format, &json)
+        .map_err(|e| TestCaseError::fail(format!("{} serialization failed: {}", format, e)))?;
+    let decoded = convert(format, "json", &encoded)
+        .map_err(|e| TestCaseError::fail(format!("{} deserialization failed: {}", format, e)))?;
+    let actual: Value = serde_json::from_slice(&decoded).unwrap();
+
+    let expected = expected(value, caps);
+    prop_assert!(
+        equivalent(&actual, &expected, caps),
+        "{} round trip changed the value\n  expected: {}\n  actual:   {}",
+        format,
+        expected,
+        actual
+    );
+    Ok(())
+}
+
+macro_rules! roundtrip_tests {
+    ($($name:ident: $feature:literal => $caps:expr;)*) => {
+        proptest! {
+            #![proptest_config(ProptestConfig::with_failure_persistence(
+                FileFailurePersistence::WithSource("proptest-regressions"),
+            ))]
+            $(
+                #[test]
+                #[cfg(feature = $feature)]
+                fn $name(value in arb_document($caps)) {
+                    check_roundtrip($feature, $caps, &value)?;
+                }
+            )*
+        }
+    };
+}
+
+roundtrip_tests! {
+    roundtrip_json: "json" => FULL;
+    roundtrip_yaml: "yaml" => Caps { floats: Floats::Approx, ..FULL };
+    roundtrip_toml: "toml" => Caps { null: false, big_uint: false, ..FULL };
+    roundtrip_ron: "ron" => FULL;
+    roundtrip_json5: "json5" => FULL;
+    // hcl-rs writes integral floats without a fraction and reads large
+    // ones back as saturated integers, and drops the comma after an empty
+    // array inside an array
+    roundtrip_hcl: "hcl" => Caps { floats: Floats::None, empty: false, ..FULL };
+    roundtrip_edn: "edn" => FULL;
+    roundtrip_xml: "xml" => Caps {
+        null: false,
+        empty: false,
+        nested_arrays: false,
+        stringly: true,
+        collapse_single: true,
+        ..FULL
+    };
+    // lexpr's reader parses floats with a last-digit error
+    roundtrip_lexpr: "lexpr" => Caps { floats: Floats::Approx, ..FULL };
+    roundtrip_urlencoded: "urlencoded" => Caps { stringly: true, nested: false, null: false, ..FULL };
+    roundtrip_qs: "qs" => Caps { stringly: true, null: false, empty: false, ..FULL };
+    roundtrip_msgpack: "msgpack" => FULL;
+    roundtrip_cbor: "cbor" => FULL;
+    roundtrip_bson: "bson" => Caps { big_uint: false, ..FULL };
+    roundtrip_flexbuffers: "flexbuffers" => FULL;
+    roundtrip_bencode: "bencode" => Caps {
+        null: false,
+        bool: false,
+        floats: Floats::None,
+        big_uint: false,
+        ..FULL
+    };
+    // serde-pickle reads Python longs beyond i64 as an error
+    roundtrip_pickle: "pickle" => Caps { big_uint: false, ..FULL };
+    roundtrip_plist: "plist" => Caps { null: false, ..FULL };
+}
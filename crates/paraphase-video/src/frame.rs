@@ -0,0 +1,132 @@
+//! Single-frame extraction via FFmpeg
+
+use crate::Container;
+use ffmpeg_next as ffmpeg;
+use ffmpeg_next::format::Pixel;
+use ffmpeg_next::software::scaling::{context::Context as ScalingContext, flag::Flags};
+use ffmpeg_next::util::frame::video::Video as VideoFrame;
+use paraphase_core::ConvertError;
+use std::path::Path;
+
+/// Decode the first frame at or after `time_secs`.
+///
+/// Falls back to the last decoded frame if the video is shorter than
+/// `time_secs`.
+pub fn extract_frame(
+    input: &[u8],
+    from: Container,
+    time_secs: f64,
+) -> Result<image::RgbImage, ConvertError> {
+    ffmpeg::init().map_err(|e| ConvertError::invalid_input("FFmpeg init failed", e))?;
+
+    // Write input to temp file (ffmpeg needs seekable input for most formats).
+    // The directory is unique per call and removed on drop.
+    let temp_dir = tempfile::tempdir()
+        .map_err(|e| ConvertError::invalid_input("Failed to create temp dir", e))?;
+
+    let input_path = temp_dir.path().join(format!("input.{}", from.as_str()));
+    std::fs::write(&input_path, input)
+        .map_err(|e| ConvertError::invalid_input("Failed to write input", e))?;
+
+    decode_frame_at(&input_path, time_secs.max(0.0))
+}
+
+fn decode_frame_at(path: &Path, time_secs: f64) -> Result<image::RgbImage, ConvertError> {
+    let mut ictx = ffmpeg::format::input(path)
+        .map_err(|e| ConvertError::invalid_input("Failed to open input", e))?;
+
+    let stream = ictx
+        .streams()
+        .best(ffmpeg::media::Type::Video)
+        .ok_or_else(|| ConvertError::invalid_input_msg("No video stream found"))?;
+    let stream_index = stream.index();
+    let target_ts = (time_secs / f64::from(stream.time_base())).round() as i64;
+
+    let decoder_ctx = ffmpeg::codec::context::Context::from_parameters(stream.parameters())
+        .map_err(|e| ConvertError::invalid_input("Failed to create decoder context", e))?;
+    let mut decoder = decoder_ctx
+        .decoder()
+        .video()
+        .map_err(|e| ConvertError::invalid_input("Failed to create decoder", e))?;
+
+    // Jump to the keyframe before the target (seek position is in
+    // AV_TIME_BASE units, i.e. microseconds). If seeking fails we simply
+    // decode from the start.
+    let seek_ts = (time_secs * 1_000_000.0) as i64;
+    if seek_ts > 0 {
+        let _ = ictx.seek(seek_ts, ..seek_ts);
+    }
+
+    let reached = |frame: &VideoFrame| {
+        frame
+            .timestamp()
+            .or_else(|| frame.pts())
+            .is_none_or(|ts| ts >= target_ts)
+    };
+
+    let mut decoded = VideoFrame::empty();
+    let mut last: Option<VideoFrame> = None;
+
+    for (stream, packet) in ictx.packets() {
+        if stream.index() != stream_index {
+            continue;
+        }
+        decoder.send_packet(&packet).ok();
+
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            if reached(&decoded) {
+                return frame_to_rgb(&decoded);
+            }
+            last = Some(decoded.clone());
+        }
+    }
+
+    // Drain buffered frames
+    decoder.send_eof().ok();
+    while decoder.receive_frame(&mut decoded).is_ok() {
+        if reached(&decoded) {
+            return frame_to_rgb(&decoded);
+        }
+        last = Some(decoded.clone());
+    }
+
+    match last {
+        Some(frame) => frame_to_rgb(&frame),
+        None => Err(ConvertError::invalid_input_msg(
+            "No video frames could be decoded",
+        )),
+    }
+}
+
+/// Convert a decoded frame to packed RGB.
+fn frame_to_rgb(frame: &VideoFrame) -> Result<image::RgbImage, ConvertError> {
+    let (width, height) = (frame.width(), frame.height());
+
+    let mut scaler = ScalingContext::get(
+        frame.format(),
+        width,
+        height,
+        Pixel::RGB24,
+        width,
+        height,
+        Flags::BILINEAR,
+    )
+    .map_err(|e| ConvertError::invalid_input("Failed to create scaler", e))?;
+
+    let mut rgb = VideoFrame::empty();
+    scaler
+        .run(frame, &mut rgb)
+        .map_err(|e| ConvertError::invalid_input("Scaling failed", e))?;
+
+    // Rows may be padded; copy out the visible part
+    let stride = rgb.stride(0);
+    let row_len = width as usize * 3;
+    let data = rgb.data(0);
+    let mut pixels = Vec::with_capacity(row_len * height as usize);
+    for row in 0..height as usize {
+        pixels.extend_from_slice(&data[row * stride..row * stride + row_len]);
+    }
+
+    image::RgbImage::from_raw(width, height, pixels)
+        .ok_or_else(|| ConvertError::failed_msg("Frame buffer size mismatch"))
+}
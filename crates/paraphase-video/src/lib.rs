@@ -3,9 +3,13 @@
 //! Provides video transcoding via FFmpeg. Requires FFmpeg libraries at runtime.
 
 use paraphase_core::{
-    ConvertError, ConvertOutput, Converter, ConverterDecl, Properties, PropertyPattern, Registry,
+    ConvertError, ConvertOutput, Converter, ConverterDecl, Predicate, Properties, PropertyPattern,
+    Registry, Value,
 };
+use std::io::Cursor;
 
+mod frame;
+mod metadata;
 mod transcode;
 
 /// Video container formats
@@ -225,7 +229,7 @@ impl Converter for VideoResizeConverter {
             .get("format")
             .and_then(|v| v.as_str())
             .and_then(Container::parse)
-            .ok_or_else(|| ConvertError::InvalidInput("Unknown video format".into()))?;
+            .ok_or_else(|| ConvertError::invalid_input_msg("Unknown video format"))?;
 
         let quality = props
             .get("quality")
@@ -246,8 +250,8 @@ impl Converter for VideoResizeConverter {
         let scale = props.get("scale").and_then(|v| v.as_f64());
 
         if max_width.is_none() && max_height.is_none() && scale.is_none() {
-            return Err(ConvertError::InvalidInput(
-                "Resize requires max_width, max_height, or scale".into(),
+            return Err(ConvertError::invalid_input_msg(
+                "Resize requires max_width, max_height, or scale",
             ));
         }
 
@@ -263,6 +267,153 @@ impl Converter for VideoResizeConverter {
     }
 }
 
+/// Frame extractor (video -> still image)
+///
+/// Registered twice: `video.extract-frame` writes PNG and
+/// `video.extract-frame-jpg` writes JPEG.
+///
+/// Options (via properties):
+/// - `frame_time_secs`: timestamp of the frame to grab (default: 0.1)
+pub struct VideoExtractFrame {
+    decl: ConverterDecl,
+    format_name: &'static str,
+    image_format: image::ImageFormat,
+}
+
+impl VideoExtractFrame {
+    /// Extract frames as PNG.
+    pub fn new(containers: &[Container]) -> Self {
+        Self::with_format(
+            containers,
+            "video.extract-frame",
+            "png",
+            image::ImageFormat::Png,
+        )
+    }
+
+    /// Extract frames as JPEG.
+    pub fn jpeg(containers: &[Container]) -> Self {
+        Self::with_format(
+            containers,
+            "video.extract-frame-jpg",
+            "jpg",
+            image::ImageFormat::Jpeg,
+        )
+    }
+
+    fn with_format(
+        containers: &[Container],
+        id: &str,
+        format_name: &'static str,
+        image_format: image::ImageFormat,
+    ) -> Self {
+        let formats = containers.iter().map(|c| Value::from(c.as_str())).collect();
+
+        let decl = ConverterDecl::simple(
+            id,
+            PropertyPattern::new().with("format", Predicate::OneOf(formats)),
+            PropertyPattern::new()
+                .eq("format", format_name)
+                .with("width", Predicate::Any)
+                .with("height", Predicate::Any),
+        )
+        .description(format!(
+            "Extract a single frame as {}",
+            format_name.to_uppercase()
+        ));
+
+        Self {
+            decl,
+            format_name,
+            image_format,
+        }
+    }
+}
+
+impl Converter for VideoExtractFrame {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        let container = props
+            .get("format")
+            .and_then(|v| v.as_str())
+            .and_then(Container::parse)
+            .ok_or_else(|| ConvertError::invalid_input_msg("Unknown video format"))?;
+
+        let time_secs = props
+            .get("frame_time_secs")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.1);
+
+        let img = frame::extract_frame(input, container, time_secs)?;
+        let (width, height) = img.dimensions();
+
+        let mut output = Cursor::new(Vec::new());
+        img.write_to(&mut output, self.image_format)
+            .map_err(|e| ConvertError::failed("Failed to encode frame", e))?;
+
+        let mut out_props = props.clone();
+        out_props.shift_remove("frame_time_secs");
+        out_props.insert("format".into(), self.format_name.into());
+        out_props.insert("width".into(), (width as i64).into());
+        out_props.insert("height".into(), (height as i64).into());
+
+        Ok(ConvertOutput::Single(output.into_inner(), out_props))
+    }
+}
+
+/// Video metadata extractor (any container -> JSON)
+///
+/// Reads container and stream headers with FFmpeg's probe; no frames are
+/// decoded. Reports duration, dimensions, frame rate (as a fraction),
+/// bitrate, codecs, audio sample rate and channels, the number of subtitle
+/// tracks, the container, and the `title`, `artist`, `comment` and
+/// `creation_time` tags.
+pub struct VideoMetadataToJson {
+    decl: ConverterDecl,
+}
+
+impl VideoMetadataToJson {
+    pub fn new(containers: &[Container]) -> Self {
+        let formats = containers.iter().map(|c| Value::from(c.as_str())).collect();
+
+        let decl = ConverterDecl::simple(
+            "video.metadata-to-json",
+            PropertyPattern::new().with("format", Predicate::OneOf(formats)),
+            PropertyPattern::new().eq("format", "json"),
+        )
+        .description("Extract video metadata as JSON");
+
+        Self { decl }
+    }
+}
+
+impl Converter for VideoMetadataToJson {
+    fn decl(&self) -> &ConverterDecl {
+        &self.decl
+    }
+
+    fn convert(&self, input: &[u8], props: &Properties) -> Result<ConvertOutput, ConvertError> {
+        let container = props
+            .get("format")
+            .and_then(|v| v.as_str())
+            .and_then(Container::parse)
+            .ok_or_else(|| ConvertError::invalid_input_msg("Unknown video format"))?;
+
+        let meta = metadata::probe_metadata(input, container)?;
+
+        let output = serde_json::to_vec_pretty(&meta.to_json())
+            .map_err(|e| ConvertError::failed("JSON serialization failed", e))?;
+
+        let mut out_props = props.clone();
+        out_props.insert("format".into(), "json".into());
+
+        Ok(ConvertOutput::Single(output, out_props))
+    }
+}
+
 /// Register all video converters
 pub fn register_all(registry: &mut Registry) {
     let containers = [
@@ -291,6 +442,13 @@ pub fn register_all(registry: &mut Registry) {
 
     // Register resize converter
     registry.register(VideoResizeConverter::new());
+
+    // Register frame extraction (any container -> image)
+    registry.register(VideoExtractFrame::new(&containers));
+    registry.register(VideoExtractFrame::jpeg(&containers));
+
+    // Register metadata extraction (any container -> JSON)
+    registry.register(VideoMetadataToJson::new(&containers));
 }
 
 /// Check if FFmpeg is available
@@ -298,3 +456,153 @@ pub fn is_available() -> bool {
     // Try to initialize FFmpeg
     ffmpeg_next::init().is_ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paraphase_core::PropertiesExt;
+    use std::process::Command;
+
+    /// Render a synthetic clip with FFmpeg's `testsrc` (and `sine` audio).
+    ///
+    /// `extra` is passed to the ffmpeg CLI before the output path. Returns
+    /// `None` if the ffmpeg CLI is not on PATH, in which case the calling
+    /// test is skipped.
+    fn testsrc_clip(ext: &str, extra: &[&str]) -> Option<Vec<u8>> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(format!("clip.{}", ext));
+        let status = Command::new("ffmpeg")
+            .args(["-hide_banner", "-loglevel", "error", "-f", "lavfi"])
+            .args(["-i", "testsrc=duration=1:size=64x48:rate=10"])
+            .args(extra)
+            .arg(&path)
+            .status();
+        let status = match status {
+            Ok(status) => status,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                eprintln!("skipping: the ffmpeg CLI is needed to generate test clips");
+                return None;
+            }
+            Err(e) => panic!("failed to run ffmpeg: {e}"),
+        };
+        assert!(status.success(), "ffmpeg failed to render the test clip");
+        Some(std::fs::read(&path).unwrap())
+    }
+
+    #[test]
+    #[cfg(feature = "mp4")]
+    fn test_extract_frame() {
+        let Some(clip) = testsrc_clip("mp4", &["-pix_fmt", "yuv420p"]) else {
+            return;
+        };
+        let converter = VideoExtractFrame::new(&[Container::Mp4]);
+        let props = Properties::new()
+            .with("format", "mp4")
+            .with("frame_time_secs", 0.5);
+
+        let (png, out_props) = match converter.convert(&clip, &props).unwrap() {
+            ConvertOutput::Single(data, props) => (data, props),
+            _ => panic!("Expected single output"),
+        };
+        assert!(png.starts_with(b"\x89PNG"));
+        assert_eq!(out_props.get("format").unwrap().as_str(), Some("png"));
+        assert_eq!(out_props.get("width").unwrap().as_i64(), Some(64));
+        assert_eq!(out_props.get("height").unwrap().as_i64(), Some(48));
+        assert!(out_props.get("frame_time_secs").is_none());
+
+        let frame = image::load_from_memory(&png).unwrap();
+        assert_eq!((frame.width(), frame.height()), (64, 48));
+
+        // Concurrent extractions each get their own temp dir
+        let converter = VideoExtractFrame::jpeg(&[Container::Mp4]);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| converter.convert(&clip, &props)))
+                .collect();
+            for handle in handles {
+                match handle.join().unwrap().unwrap() {
+                    ConvertOutput::Single(data, props) => {
+                        assert!(data.starts_with(&[0xFF, 0xD8, 0xFF]));
+                        assert_eq!(props.get("format").unwrap().as_str(), Some("jpg"));
+                    }
+                    _ => panic!("Expected single output"),
+                }
+            }
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "mp4")]
+    fn test_plan_frame_formats() {
+        use paraphase_core::{Cardinality, Planner};
+
+        let mut registry = Registry::new();
+        register_all(&mut registry);
+        let planner = Planner::new(&registry);
+        let source = Properties::new().with("format", "mp4");
+
+        for (format, id) in [
+            ("png", "video.extract-frame"),
+            ("jpg", "video.extract-frame-jpg"),
+        ] {
+            let target = PropertyPattern::new().eq("format", format);
+            let plan = planner
+                .plan(&source, &target, Cardinality::One, Cardinality::One)
+                .unwrap();
+            assert_eq!(plan.steps.len(), 1);
+            assert_eq!(plan.steps[0].converter_id, id);
+            assert_eq!(
+                plan.steps[0]
+                    .output_properties
+                    .get("format")
+                    .unwrap()
+                    .as_str(),
+                Some(format)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "mp4")]
+    fn test_metadata_to_json() {
+        let Some(clip) = testsrc_clip(
+            "mp4",
+            &[
+                "-f",
+                "lavfi",
+                "-i",
+                "sine=frequency=440:sample_rate=44100:duration=1",
+                "-c:v",
+                "mpeg4",
+                "-c:a",
+                "aac",
+                "-metadata",
+                "title=Demo",
+                "-shortest",
+            ],
+        ) else {
+            return;
+        };
+        let converter = VideoMetadataToJson::new(&[Container::Mp4]);
+        let props = Properties::new().with("format", "mp4");
+
+        let json = match converter.convert(&clip, &props).unwrap() {
+            ConvertOutput::Single(data, props) => {
+                assert_eq!(props.get("format").unwrap().as_str(), Some("json"));
+                serde_json::from_slice::<serde_json::Value>(&data).unwrap()
+            }
+            _ => panic!("Expected single output"),
+        };
+        assert_eq!(json["width"], 64);
+        assert_eq!(json["height"], 48);
+        assert_eq!(json["frame_rate"], "10/1");
+        let duration = json["duration_secs"].as_f64().unwrap();
+        assert!((duration - 1.0).abs() < 0.1, "duration {duration}");
+        assert_eq!(json["video_codec"], "mpeg4");
+        assert_eq!(json["audio_codec"], "aac");
+        assert_eq!(json["sample_rate"], 44100);
+        assert_eq!(json["channels"], 1);
+        assert_eq!(json["subtitle_tracks"], 0);
+        assert_eq!(json["title"], "Demo");
+    }
+}
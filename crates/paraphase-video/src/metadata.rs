@@ -0,0 +1,156 @@
+//! Container metadata via FFmpeg, without decoding frames
+
+use crate::Container;
+use ffmpeg_next as ffmpeg;
+use paraphase_core::ConvertError;
+use std::path::Path;
+
+/// Video file information read from container and stream headers.
+#[derive(Debug, Clone, Default)]
+pub struct VideoMetadata {
+    duration_secs: Option<f64>,
+    width: Option<u32>,
+    height: Option<u32>,
+    /// Average frame rate as a fraction, e.g. "30000/1001".
+    frame_rate: Option<String>,
+    bitrate_kbps: Option<f64>,
+    video_codec: Option<String>,
+    audio_codec: Option<String>,
+    sample_rate: Option<u32>,
+    channels: Option<u16>,
+    subtitle_tracks: usize,
+    /// FFmpeg demuxer name, e.g. "mov,mp4,m4a,3gp,3g2,mj2".
+    container: Option<String>,
+    title: Option<String>,
+    artist: Option<String>,
+    comment: Option<String>,
+    creation_time: Option<String>,
+}
+
+impl VideoMetadata {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "duration_secs": self.duration_secs,
+            "width": self.width,
+            "height": self.height,
+            "frame_rate": self.frame_rate,
+            "bitrate_kbps": self.bitrate_kbps,
+            "video_codec": self.video_codec,
+            "audio_codec": self.audio_codec,
+            "sample_rate": self.sample_rate,
+            "channels": self.channels,
+            "subtitle_tracks": self.subtitle_tracks,
+            "container": self.container,
+            "title": self.title,
+            "artist": self.artist,
+            "comment": self.comment,
+            "creation_time": self.creation_time,
+        })
+    }
+}
+
+/// Read stream information and tags without decoding any frames.
+pub fn probe_metadata(input: &[u8], from: Container) -> Result<VideoMetadata, ConvertError> {
+    ffmpeg::init().map_err(|e| ConvertError::invalid_input("FFmpeg init failed", e))?;
+
+    // Write input to temp file (ffmpeg needs seekable input for most formats).
+    // The directory is unique per call and removed on drop.
+    let temp_dir = tempfile::tempdir()
+        .map_err(|e| ConvertError::invalid_input("Failed to create temp dir", e))?;
+
+    let input_path = temp_dir.path().join(format!("input.{}", from.as_str()));
+    std::fs::write(&input_path, input)
+        .map_err(|e| ConvertError::invalid_input("Failed to write input", e))?;
+
+    probe_path(&input_path)
+}
+
+fn probe_path(path: &Path) -> Result<VideoMetadata, ConvertError> {
+    let ictx = ffmpeg::format::input(path)
+        .map_err(|e| ConvertError::invalid_input("Failed to open input", e))?;
+
+    let tags = ictx.metadata();
+    let tag = |key: &str| tags.get(key).map(String::from);
+
+    // Container duration is in AV_TIME_BASE units (microseconds)
+    let mut meta = VideoMetadata {
+        duration_secs: (ictx.duration() > 0).then(|| ictx.duration() as f64 / 1_000_000.0),
+        bitrate_kbps: (ictx.bit_rate() > 0).then(|| ictx.bit_rate() as f64 / 1000.0),
+        container: Some(ictx.format().name().to_string()),
+        title: tag("title"),
+        artist: tag("artist"),
+        comment: tag("comment"),
+        creation_time: tag("creation_time"),
+        ..Default::default()
+    };
+
+    if let Some(stream) = ictx.streams().best(ffmpeg::media::Type::Video) {
+        let params = stream.parameters();
+        meta.video_codec = Some(params.id().name().to_string());
+        meta.frame_rate = fraction(stream.avg_frame_rate());
+
+        // Opening a decoder reads the codec headers only
+        if let Ok(decoder) = ffmpeg::codec::context::Context::from_parameters(params)
+            .and_then(|ctx| ctx.decoder().video())
+        {
+            meta.width = Some(decoder.width());
+            meta.height = Some(decoder.height());
+        }
+    }
+
+    if let Some(stream) = ictx.streams().best(ffmpeg::media::Type::Audio) {
+        let params = stream.parameters();
+        meta.audio_codec = Some(params.id().name().to_string());
+
+        if let Ok(decoder) = ffmpeg::codec::context::Context::from_parameters(params)
+            .and_then(|ctx| ctx.decoder().audio())
+        {
+            meta.sample_rate = Some(decoder.rate());
+            meta.channels = Some(decoder.channels());
+        }
+    }
+
+    meta.subtitle_tracks = ictx
+        .streams()
+        .filter(|s| s.parameters().medium() == ffmpeg::media::Type::Subtitle)
+        .count();
+
+    Ok(meta)
+}
+
+/// Render a rational as "num/den", or `None` if it is unset (0/0 or 0/1).
+fn fraction(rate: ffmpeg::Rational) -> Option<String> {
+    (rate.numerator() > 0 && rate.denominator() > 0)
+        .then(|| format!("{}/{}", rate.numerator(), rate.denominator()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_json() {
+        assert_eq!(
+            fraction(ffmpeg::Rational::new(30000, 1001)).as_deref(),
+            Some("30000/1001")
+        );
+        assert_eq!(fraction(ffmpeg::Rational::new(0, 1)), None);
+
+        let meta = VideoMetadata {
+            width: Some(1920),
+            height: Some(1080),
+            frame_rate: fraction(ffmpeg::Rational::new(25, 1)),
+            video_codec: Some("h264".into()),
+            subtitle_tracks: 2,
+            title: Some("Demo".into()),
+            ..Default::default()
+        };
+        let json = meta.to_json();
+        assert_eq!(json["width"], 1920);
+        assert_eq!(json["frame_rate"], "25/1");
+        assert_eq!(json["video_codec"], "h264");
+        assert_eq!(json["subtitle_tracks"], 2);
+        assert_eq!(json["title"], "Demo");
+        assert!(json["audio_codec"].is_null());
+    }
+}
@@ -18,28 +18,28 @@ pub fn transcode(
     max_height: Option<u32>,
     scale: Option<f64>,
 ) -> Result<(Vec<u8>, HashMap<String, String>), ConvertError> {
-    ffmpeg::init().map_err(|e| ConvertError::InvalidInput(format!("FFmpeg init failed: {}", e)))?;
+    ffmpeg::init().map_err(|e| ConvertError::invalid_input("FFmpeg init failed", e))?;
 
     // Write input to temp file (ffmpeg needs seekable input for most formats)
     let temp_dir = std::env::temp_dir().join(format!("paraphase-video-{}", std::process::id()));
     std::fs::create_dir_all(&temp_dir)
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to create temp dir: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to create temp dir", e))?;
 
     let input_path = temp_dir.join(format!("input.{}", from.as_str()));
     let output_path = temp_dir.join(format!("output.{}", to.as_str()));
 
     std::fs::write(&input_path, input)
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to write input: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to write input", e))?;
 
     // Open input
     let mut ictx = ffmpeg::format::input(&input_path)
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to open input: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to open input", e))?;
 
     // Find video stream
     let video_stream_index = ictx
         .streams()
         .best(ffmpeg::media::Type::Video)
-        .ok_or_else(|| ConvertError::InvalidInput("No video stream found".into()))?
+        .ok_or_else(|| ConvertError::invalid_input_msg("No video stream found"))?
         .index();
 
     let _audio_stream_index = ictx
@@ -49,15 +49,14 @@ pub fn transcode(
 
     // Get input video info
     let input_stream = ictx.stream(video_stream_index).unwrap();
-    let decoder_ctx = ffmpeg::codec::context::Context::from_parameters(input_stream.parameters())
-        .map_err(|e| {
-        ConvertError::InvalidInput(format!("Failed to create decoder context: {}", e))
-    })?;
+    let decoder_ctx =
+        ffmpeg::codec::context::Context::from_parameters(input_stream.parameters())
+            .map_err(|e| ConvertError::invalid_input("Failed to create decoder context", e))?;
 
     let mut decoder = decoder_ctx
         .decoder()
         .video()
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to create decoder: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to create decoder", e))?;
 
     let src_width = decoder.width();
     let src_height = decoder.height();
@@ -68,16 +67,16 @@ pub fn transcode(
 
     // Create output
     let mut octx = ffmpeg::format::output(&output_path)
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to create output: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to create output", e))?;
 
     // Add video stream
     let video_codec = ffmpeg::encoder::find_by_name(to.default_video_codec())
         .or_else(|| ffmpeg::encoder::find(ffmpeg::codec::Id::H264))
-        .ok_or_else(|| ConvertError::InvalidInput("No suitable video encoder found".into()))?;
+        .ok_or_else(|| ConvertError::invalid_input_msg("No suitable video encoder found"))?;
 
     let mut video_stream = octx
         .add_stream(video_codec)
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to add video stream: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to add video stream", e))?;
 
     let _video_stream_index_out = video_stream.index();
 
@@ -86,7 +85,7 @@ pub fn transcode(
     let mut encoder = encoder_ctx
         .encoder()
         .video()
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to create encoder: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to create encoder", e))?;
 
     encoder.set_width(dst_width);
     encoder.set_height(dst_height);
@@ -100,13 +99,13 @@ pub fn transcode(
 
     let encoder = encoder
         .open_with(opts)
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to open encoder: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to open encoder", e))?;
 
     video_stream.set_parameters(&encoder);
 
     // Write header
     octx.write_header()
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to write header: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to write header", e))?;
 
     // Create scaler if needed
     let needs_scale = dst_width != src_width || dst_height != src_height;
@@ -121,7 +120,7 @@ pub fn transcode(
                 dst_height,
                 Flags::BILINEAR,
             )
-            .map_err(|e| ConvertError::InvalidInput(format!("Failed to create scaler: {}", e)))?,
+            .map_err(|e| ConvertError::invalid_input("Failed to create scaler", e))?,
         )
     } else {
         None
@@ -137,9 +136,9 @@ pub fn transcode(
             while decoder.receive_frame(&mut decoded).is_ok() {
                 let mut output_frame = if let Some(ref mut scaler) = scaler {
                     let mut scaled = VideoFrame::empty();
-                    scaler.run(&decoded, &mut scaled).map_err(|e| {
-                        ConvertError::InvalidInput(format!("Scaling failed: {}", e))
-                    })?;
+                    scaler
+                        .run(&decoded, &mut scaled)
+                        .map_err(|e| ConvertError::invalid_input("Scaling failed", e))?;
                     scaled
                 } else {
                     decoded.clone()
@@ -155,11 +154,11 @@ pub fn transcode(
 
     // Flush and write trailer
     octx.write_trailer()
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to write trailer: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to write trailer", e))?;
 
     // Read output
     let output = std::fs::read(&output_path)
-        .map_err(|e| ConvertError::InvalidInput(format!("Failed to read output: {}", e)))?;
+        .map_err(|e| ConvertError::invalid_input("Failed to read output", e))?;
 
     // Cleanup
     let _ = std::fs::remove_dir_all(&temp_dir);
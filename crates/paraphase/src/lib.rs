@@ -0,0 +1,260 @@
+//! Paraphase: Type-driven data transformation pipeline
+//!
+//! Convenience crate bundling the core planner with the converter backends
+//! enabled via features. Everything from `paraphase-core` is re-exported, so
+//! library users only need this one dependency.
+//!
+//! For one-off conversions, [`convert`] and [`convert_with_props`] handle
+//! registry setup, planning and execution:
+//!
+//! ```ignore
+//! let yaml = paraphase::convert("json", "yaml", br#"{"a": 1}"#)?;
+//! ```
+//!
+//! Applications that pick their backends or limit memory build a
+//! [`Paraphase`] instead. It is cheap to clone and can be shared across
+//! threads:
+//!
+//! ```ignore
+//! let paraphase = ParaphaseBuilder::new()
+//!     .with_serde()
+//!     .with_image()
+//!     .with_memory_limit(100_000_000)
+//!     .build();
+//! let yaml = paraphase.convert("json", "yaml", br#"{"a": 1}"#)?;
+//! ```
+
+pub use paraphase_core::*;
+
+#[cfg(feature = "audio")]
+pub use paraphase_audio as audio;
+#[cfg(feature = "image")]
+pub use paraphase_image as image;
+#[cfg(feature = "pdf")]
+pub use paraphase_pdf as pdf;
+#[cfg(feature = "serde")]
+pub use paraphase_serde as serde;
+#[cfg(feature = "video")]
+pub use paraphase_video as video;
+
+use std::sync::{Arc, OnceLock};
+
+static SHARED: OnceLock<Paraphase> = OnceLock::new();
+
+/// Create a registry with all converters from the enabled backends.
+pub fn default_registry() -> Registry {
+    let mut registry = Registry::new();
+
+    #[cfg(feature = "serde")]
+    paraphase_serde::register_all(&mut registry);
+
+    #[cfg(feature = "image")]
+    paraphase_image::register_all(&mut registry);
+
+    #[cfg(feature = "video")]
+    paraphase_video::register_all(&mut registry);
+
+    #[cfg(feature = "audio")]
+    paraphase_audio::register_all(&mut registry);
+
+    #[cfg(feature = "pdf")]
+    paraphase_pdf::register_all(&mut registry);
+
+    registry
+}
+
+/// Shared instance with the default registry, built on first use.
+fn shared() -> &'static Paraphase {
+    SHARED.get_or_init(|| Paraphase::new(default_registry()))
+}
+
+/// Convert `data` from one format to another.
+///
+/// Uses a cached registry of all enabled converters, so repeated calls only
+/// pay for planning and execution.
+pub fn convert(from: &str, to: &str, data: &[u8]) -> Result<Vec<u8>, ConvertError> {
+    shared().convert(from, to, data)
+}
+
+/// Convert `data` described by `from_props` into something matching `to_pattern`.
+pub fn convert_with_props(
+    from_props: Properties,
+    to_pattern: PropertyPattern,
+    data: &[u8],
+) -> Result<Vec<u8>, ConvertError> {
+    shared().convert_with_props(from_props, to_pattern, data)
+}
+
+/// Builder for a [`Paraphase`] with a chosen set of backends.
+///
+/// Starts with an empty registry; each `with_*` backend method registers
+/// that crate's converters.
+#[derive(Clone, Default)]
+pub struct ParaphaseBuilder {
+    registry: Registry,
+    memory_limit: Option<usize>,
+}
+
+impl ParaphaseBuilder {
+    /// Create a builder with no converters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the serde converters (data formats, compression, archives).
+    #[cfg(feature = "serde")]
+    pub fn with_serde(mut self) -> Self {
+        paraphase_serde::register_all(&mut self.registry);
+        self
+    }
+
+    /// Register the image converters.
+    #[cfg(feature = "image")]
+    pub fn with_image(mut self) -> Self {
+        paraphase_image::register_all(&mut self.registry);
+        self
+    }
+
+    /// Register the audio converters.
+    #[cfg(feature = "audio")]
+    pub fn with_audio(mut self) -> Self {
+        paraphase_audio::register_all(&mut self.registry);
+        self
+    }
+
+    /// Register the PDF text extraction converters.
+    #[cfg(feature = "pdf")]
+    pub fn with_pdf(mut self) -> Self {
+        paraphase_pdf::register_all(&mut self.registry);
+        self
+    }
+
+    /// Register the video converters.
+    #[cfg(feature = "video")]
+    pub fn with_video(mut self) -> Self {
+        paraphase_video::register_all(&mut self.registry);
+        self
+    }
+
+    /// Fail conversions whose estimated memory use exceeds `bytes`.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = Some(bytes);
+        self
+    }
+
+    /// Build the instance.
+    pub fn build(self) -> Paraphase {
+        let paraphase = Paraphase::new(self.registry);
+        match self.memory_limit {
+            Some(limit) => Paraphase {
+                ctx: paraphase.ctx.with_memory_limit(limit),
+            },
+            None => paraphase,
+        }
+    }
+}
+
+/// A converter registry with planning and execution, for embedding.
+///
+/// Cloning shares the registry, so one instance can serve many threads.
+#[derive(Clone)]
+pub struct Paraphase {
+    ctx: ExecutionContext,
+}
+
+impl Paraphase {
+    /// Wrap an existing registry, without a memory limit.
+    pub fn new(registry: Registry) -> Self {
+        Self {
+            ctx: ExecutionContext::new(Arc::new(registry)),
+        }
+    }
+
+    /// The registered converters.
+    pub fn registry(&self) -> &Registry {
+        &self.ctx.registry
+    }
+
+    /// Find a conversion path from `from` to something matching `to`.
+    pub fn plan(&self, from: &Properties, to: &PropertyPattern) -> Option<Plan> {
+        Planner::new(&self.ctx.registry).plan(from, to, Cardinality::One, Cardinality::One)
+    }
+
+    /// Run a plan.
+    ///
+    /// With a memory limit, the plan's estimated memory use is checked
+    /// before anything runs.
+    pub fn execute(
+        &self,
+        plan: &Plan,
+        data: Vec<u8>,
+        props: Properties,
+    ) -> Result<ExecutionResult, ExecuteError> {
+        if self.ctx.memory_limit.is_some() {
+            BoundedExecutor::new().execute(&self.ctx, plan, data, props)
+        } else {
+            SimpleExecutor::new().execute(&self.ctx, plan, data, props)
+        }
+    }
+
+    /// Convert `data` from one format to another.
+    pub fn convert(&self, from: &str, to: &str, data: &[u8]) -> Result<Vec<u8>, ConvertError> {
+        self.convert_with_props(
+            Properties::new().with("format", from),
+            PropertyPattern::new().eq("format", to),
+            data,
+        )
+    }
+
+    /// Convert `data` described by `from_props` into something matching `to_pattern`.
+    pub fn convert_with_props(
+        &self,
+        from_props: Properties,
+        to_pattern: PropertyPattern,
+        data: &[u8],
+    ) -> Result<Vec<u8>, ConvertError> {
+        let plan = self.plan(&from_props, &to_pattern).ok_or_else(|| {
+            let source = from_props.get("format").and_then(|v| v.as_str());
+            let target = match to_pattern.predicates.get("format") {
+                Some(Predicate::Eq(value)) => value.as_str(),
+                _ => None,
+            };
+            ConvertError::failed_msg(format!(
+                "no conversion path found from '{}' to '{}'",
+                source.unwrap_or("unknown"),
+                target.unwrap_or("unknown")
+            ))
+        })?;
+
+        let result = self
+            .execute(&plan, data.to_vec(), from_props)
+            .map_err(|e| match e {
+                ExecuteError::ConversionFailed { source, .. } => source,
+                other => ConvertError::failed_msg(other.to_string()),
+            })?;
+
+        Ok(result.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_json_to_yaml() {
+        let yaml = convert("json", "yaml", b"{\"a\":1}").unwrap();
+        let value: serde_yaml::Value = serde_yaml::from_slice(&yaml).unwrap();
+        assert_eq!(value["a"], serde_yaml::Value::from(1));
+    }
+
+    #[test]
+    fn test_convert_no_path() {
+        let err = convert("json", "no-such-format", b"{}").unwrap_err();
+        assert!(matches!(err, ConvertError::Failed { .. }));
+        assert_eq!(
+            err.to_string(),
+            "conversion failed: no conversion path found from 'json' to 'no-such-format'"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "serde")]
+
+use paraphase::{
+    ExecuteError, ParaphaseBuilder, Properties, PropertiesExt, PropertyPattern, Registry,
+};
+
+#[test]
+fn test_builder_registers_selected_backends() {
+    let empty = ParaphaseBuilder::new().build();
+    assert!(empty.registry().is_empty());
+
+    let paraphase = ParaphaseBuilder::new().with_serde().build();
+    let mut serde_only = Registry::new();
+    paraphase::serde::register_all(&mut serde_only);
+    assert_eq!(paraphase.registry().len(), serde_only.len());
+
+    #[cfg(feature = "image")]
+    {
+        let both = ParaphaseBuilder::new().with_serde().with_image().build();
+        assert!(both.registry().len() > serde_only.len());
+    }
+}
+
+#[test]
+fn test_plan_execute_and_convert() {
+    let paraphase = ParaphaseBuilder::new().with_serde().build();
+    let input = br#"{"name": "paraphase"}"#;
+
+    let from = Properties::new().with("format", "json");
+    let plan = paraphase
+        .plan(&from, &PropertyPattern::new().eq("format", "yaml"))
+        .expect("json -> yaml plan");
+    let result = paraphase.execute(&plan, input.to_vec(), from).unwrap();
+    assert_eq!(result.props.get("format").unwrap().as_str(), Some("yaml"));
+
+    let yaml = paraphase.convert("json", "yaml", input).unwrap();
+    assert_eq!(yaml, result.data);
+    assert!(paraphase.convert("json", "no-such-format", input).is_err());
+}
+
+#[test]
+fn test_memory_limit() {
+    let paraphase = ParaphaseBuilder::new()
+        .with_serde()
+        .with_memory_limit(16)
+        .build();
+    let input = br#"{"name": "paraphase", "tags": ["a", "b", "c"]}"#;
+
+    let from = Properties::new().with("format", "json");
+    let plan = paraphase
+        .plan(&from, &PropertyPattern::new().eq("format", "yaml"))
+        .unwrap();
+    let err = paraphase.execute(&plan, input.to_vec(), from).unwrap_err();
+    assert!(matches!(
+        err,
+        ExecuteError::MemoryLimitExceeded { limit: 16, .. }
+    ));
+}
+
+#[test]
+fn test_shared_across_threads() {
+    let paraphase = ParaphaseBuilder::new().with_serde().build();
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let paraphase = paraphase.clone();
+            std::thread::spawn(move || {
+                let input = format!(r#"{{"n": {}}}"#, i);
+                paraphase.convert("json", "yaml", input.as_bytes()).unwrap()
+            })
+        })
+        .collect();
+
+    for (i, handle) in handles.into_iter().enumerate() {
+        assert_eq!(handle.join().unwrap(), format!("n: {}\n", i).into_bytes());
+    }
+}